@@ -0,0 +1,221 @@
+use std::fmt::{self, Write};
+
+use crate::aarch64_generator::{
+    AArch64Address, AArch64BinaryOperator, AArch64Condition, AArch64Construct, AArch64Function, AArch64Global, AArch64Instruction,
+    AArch64Operand, AArch64Register, AArch64UnaryOperator,
+};
+
+// Scratch registers AAPCS64 sets aside for veneers, free between branches. x16 holds the page of a
+// global, x17 the upper part of a stack offset too large to encode.
+const PAGE_REGISTER: &str = "x16";
+const OFFSET_REGISTER: &str = "x17";
+
+// Prints the AArch64 assembly AST in GNU as syntax, instructions are written as whole indented lines
+// since some of them expand to several machine instructions
+pub fn emit(program: &AArch64Construct) -> String {
+    let mut output = String::new();
+    // Writing to a String cannot fail
+    let _ = write_program(&mut output, program);
+    output
+}
+
+fn write_program(f: &mut dyn Write, program: &AArch64Construct) -> fmt::Result {
+    let AArch64Construct::Program { functions, globals } = program;
+    writeln!(f, ".text")?;
+    for function in functions {
+        write_function(f, function)?;
+    }
+    for global in globals {
+        write_global(f, global)?;
+    }
+    write!(f, "\n.section .note.GNU-stack,\"\",@progbits")
+}
+
+// Globals come after all functions, so switching sections does not affect the code. Zero-initialized
+// globals go to .bss, which takes no space in the object file.
+fn write_global(f: &mut dyn Write, global: &AArch64Global) -> fmt::Result {
+    writeln!(f, ".globl {}", global.name)?;
+    writeln!(f, "{}", if global.initializer == 0 { ".bss" } else { ".data" })?;
+    writeln!(f, ".p2align 2")?;
+    writeln!(f, "{}:", global.name)?;
+    match global.initializer {
+        0 => writeln!(f, "    .zero 4"),
+        value => writeln!(f, "    .word {}", value),
+    }
+}
+
+// The frame record of the caller's frame pointer and the return address sits at the top of the frame,
+// with the frame pointer pointing to it
+fn write_function(f: &mut dyn Write, function: &AArch64Function) -> fmt::Result {
+    let AArch64Function::Function { name, global, frame_size, instructions } = function;
+    if *global {
+        writeln!(f, ".globl {}", name)?;
+    }
+    writeln!(f, ".type {}, %function", name)?;
+    writeln!(f, ".p2align 2")?;
+    writeln!(f, "{}:", name)?;
+    writeln!(f, "    stp x29, x30, [sp, #-16]!")?;
+    writeln!(f, "    mov x29, sp")?;
+    let (upper, lower) = split_offset(*frame_size);
+    if upper > 0 {
+        writeln!(f, "    sub sp, sp, #{}, lsl #12", upper)?;
+    }
+    if lower > 0 {
+        writeln!(f, "    sub sp, sp, #{}", lower)?;
+    }
+    for instruction in instructions {
+        write_instruction(f, instruction)?;
+    }
+    Ok(())
+}
+
+fn write_instruction(f: &mut dyn Write, instruction: &AArch64Instruction) -> fmt::Result {
+    match instruction {
+        AArch64Instruction::MovImmediate(dst, value) => write_mov_immediate(f, dst, *value),
+        AArch64Instruction::AddressOf(dst, address) => write_address_of(f, dst, address),
+        AArch64Instruction::Load(dst, address) => write_access(f, "ldr", dst, address),
+        AArch64Instruction::Store(src, address) => write_access(f, "str", src, address),
+        AArch64Instruction::Unary(op, dst, src) => writeln!(f, "    {} {}, {}", unary_mnemonic(op), register_name(dst), register_name(src)),
+        AArch64Instruction::Binary(op, dst, lhs, rhs) => {
+            writeln!(f, "    {} {}, {}, {}", binary_mnemonic(op), register_name(dst), register_name(lhs), operand(rhs))
+        }
+        AArch64Instruction::Msub(dst, lhs, rhs, minuend) => writeln!(
+            f,
+            "    msub {}, {}, {}, {}",
+            register_name(dst),
+            register_name(lhs),
+            register_name(rhs),
+            register_name(minuend)
+        ),
+        AArch64Instruction::Cmp(lhs, rhs) => writeln!(f, "    cmp {}, {}", register_name(lhs), operand(rhs)),
+        AArch64Instruction::Cset(dst, condition) => writeln!(f, "    cset {}, {}", register_name(dst), condition_name(condition)),
+        AArch64Instruction::Csel(dst, lhs, rhs, condition) => writeln!(
+            f,
+            "    csel {}, {}, {}, {}",
+            register_name(dst),
+            register_name(lhs),
+            register_name(rhs),
+            condition_name(condition)
+        ),
+        AArch64Instruction::B(label) => writeln!(f, "    b {}", label),
+        AArch64Instruction::Cbz(register, label) => writeln!(f, "    cbz {}, {}", register_name(register), label),
+        AArch64Instruction::Cbnz(register, label) => writeln!(f, "    cbnz {}, {}", register_name(register), label),
+        AArch64Instruction::Label(label) => writeln!(f, "{}:", label),
+        AArch64Instruction::Bl(name) => writeln!(f, "    bl {}", name),
+        AArch64Instruction::Ret => {
+            writeln!(f, "    mov sp, x29")?;
+            writeln!(f, "    ldp x29, x30, [sp], #16")?;
+            writeln!(f, "    ret")
+        }
+    }
+}
+
+// mov takes any value movz or movn can build from a single 16-bit half, the others need a movk for the
+// upper half
+fn write_mov_immediate(f: &mut dyn Write, dst: &AArch64Register, value: i32) -> fmt::Result {
+    let bits = value as u32;
+    if bits <= 0xffff || !bits <= 0xffff {
+        return writeln!(f, "    mov {}, #{}", register_name(dst), value);
+    }
+    writeln!(f, "    mov {}, #{}", register_name(dst), bits & 0xffff)?;
+    writeln!(f, "    movk {}, #{}, lsl #16", register_name(dst), bits >> 16)
+}
+
+fn write_address_of(f: &mut dyn Write, dst: &AArch64Register, address: &AArch64Address) -> fmt::Result {
+    let dst = register_name(dst);
+    match address {
+        AArch64Address::Global(name) => {
+            writeln!(f, "    adrp {}, {}", dst, name)?;
+            writeln!(f, "    add {}, {}, :lo12:{}", dst, dst, name)
+        }
+        AArch64Address::Stack(offset) | AArch64Address::Frame(offset) => {
+            let (upper, lower) = split_offset(*offset);
+            let mut base = base_register(address).to_string();
+            if upper > 0 {
+                writeln!(f, "    add {}, {}, #{}, lsl #12", dst, base, upper)?;
+                base = dst.clone();
+            }
+            writeln!(f, "    add {}, {}, #{}", dst, base, lower)
+        }
+    }
+}
+
+// Loads and stores encode offsets up to 4095 times the access size, larger ones add their upper part
+// to the base first
+fn write_access(f: &mut dyn Write, mnemonic: &str, register: &AArch64Register, address: &AArch64Address) -> fmt::Result {
+    let register = register_name(register);
+    match address {
+        AArch64Address::Global(name) => {
+            writeln!(f, "    adrp {}, {}", PAGE_REGISTER, name)?;
+            writeln!(f, "    {} {}, [{}, :lo12:{}]", mnemonic, register, PAGE_REGISTER, name)
+        }
+        AArch64Address::Stack(offset) | AArch64Address::Frame(offset) => {
+            let size = if register.starts_with('x') { 8 } else { 4 };
+            let base = base_register(address);
+            if *offset < 4096 * size {
+                return writeln!(f, "    {} {}, [{}, #{}]", mnemonic, register, base, offset);
+            }
+            let (upper, lower) = split_offset(*offset);
+            writeln!(f, "    add {}, {}, #{}, lsl #12", OFFSET_REGISTER, base, upper)?;
+            writeln!(f, "    {} {}, [{}, #{}]", mnemonic, register, OFFSET_REGISTER, lower)
+        }
+    }
+}
+
+fn base_register(address: &AArch64Address) -> &'static str {
+    match address {
+        AArch64Address::Frame(_) => "x29",
+        _ => "sp",
+    }
+}
+
+// Immediates of add and sub are 12 bits, optionally shifted left by 12
+fn split_offset(offset: i32) -> (i32, i32) {
+    (offset >> 12, offset & 0xfff)
+}
+
+fn operand(operand: &AArch64Operand) -> String {
+    match operand {
+        AArch64Operand::Register(register) => register_name(register),
+        AArch64Operand::Immediate(value) => format!("#{}", value),
+    }
+}
+
+fn register_name(register: &AArch64Register) -> String {
+    match register {
+        AArch64Register::W(number) => format!("w{}", number),
+        AArch64Register::X(number) => format!("x{}", number),
+    }
+}
+
+fn unary_mnemonic(op: &AArch64UnaryOperator) -> &'static str {
+    match op {
+        AArch64UnaryOperator::Neg => "neg",
+        AArch64UnaryOperator::Mvn => "mvn",
+    }
+}
+
+fn binary_mnemonic(op: &AArch64BinaryOperator) -> &'static str {
+    match op {
+        AArch64BinaryOperator::Add => "add",
+        AArch64BinaryOperator::Sub => "sub",
+        AArch64BinaryOperator::Mul => "mul",
+        AArch64BinaryOperator::Sdiv => "sdiv",
+        AArch64BinaryOperator::Udiv => "udiv",
+    }
+}
+
+fn condition_name(condition: &AArch64Condition) -> &'static str {
+    match condition {
+        AArch64Condition::Eq => "eq",
+        AArch64Condition::Ne => "ne",
+        AArch64Condition::Gt => "gt",
+        AArch64Condition::Ge => "ge",
+        AArch64Condition::Lt => "lt",
+        AArch64Condition::Le => "le",
+        AArch64Condition::Hi => "hi",
+        AArch64Condition::Hs => "hs",
+        AArch64Condition::Lo => "lo",
+        AArch64Condition::Ls => "ls",
+    }
+}
@@ -0,0 +1,379 @@
+use crate::llvm_ir::{
+    IRType, LLVMBasicBlock, LLVMBinaryOp, LLVMComparison, LLVMConstruct, LLVMFunction, LLVMInstruction, LLVMUnaryOp, LLVMValue, Linkage,
+    ValueId,
+};
+use crate::stack_slots::{SlotLowering, StackFrame};
+
+// A straightforward AArch64 backend: every value lives in a stack slot, and each instruction loads its
+// operands into scratch registers, computes and stores its result. Functions follow AAPCS64, keeping a
+// frame record in x29 and x30 and only touching caller-saved registers otherwise.
+
+#[derive(Debug, Clone)]
+pub enum AArch64Construct {
+    Program {
+        functions: Vec<AArch64Function>,
+        globals: Vec<AArch64Global>,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub enum AArch64Function {
+    Function {
+        name: String,
+        // Whether the symbol is visible to other object files
+        global: bool,
+        // Bytes allocated below the frame record, a multiple of 16 so that the stack stays aligned
+        frame_size: i32,
+        instructions: Vec<AArch64Instruction>,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct AArch64Global {
+    pub name: String,
+    pub initializer: i32,
+}
+
+#[derive(Debug, Clone)]
+pub enum AArch64Instruction {
+    // Any 32-bit constant, built from up to two 16-bit halves
+    MovImmediate(AArch64Register, i32),
+    AddressOf(AArch64Register, AArch64Address),
+    Load(AArch64Register, AArch64Address),
+    Store(AArch64Register, AArch64Address),
+    Unary(AArch64UnaryOperator, AArch64Register, AArch64Register),
+    // Destination, lhs and rhs
+    Binary(AArch64BinaryOperator, AArch64Register, AArch64Register, AArch64Operand),
+    // destination = minuend - lhs * rhs, with the destination, lhs, rhs and minuend in that order
+    Msub(AArch64Register, AArch64Register, AArch64Register, AArch64Register),
+    Cmp(AArch64Register, AArch64Operand),
+    Cset(AArch64Register, AArch64Condition),
+    // Destination, then the value taken when the condition holds and the one taken otherwise
+    Csel(AArch64Register, AArch64Register, AArch64Register, AArch64Condition),
+    B(String),
+    Cbz(AArch64Register, String),
+    Cbnz(AArch64Register, String),
+    Label(String),
+    Bl(String),
+    // Restores the frame record before returning
+    Ret,
+}
+
+#[derive(Debug, Clone)]
+pub enum AArch64UnaryOperator {
+    Neg,
+    Mvn,
+}
+
+// Only additions and subtractions take an immediate rhs
+#[derive(Debug, Clone)]
+pub enum AArch64BinaryOperator {
+    Add,
+    Sub,
+    Mul,
+    Sdiv,
+    Udiv,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AArch64Condition {
+    Eq,
+    Ne,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+    Hi,
+    Hs,
+    Lo,
+    Ls,
+}
+
+// The 32-bit w and 64-bit x views of the general purpose registers
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AArch64Register {
+    W(u8),
+    X(u8),
+}
+
+// Immediates are the unsigned 12-bit ones arithmetic and comparisons encode directly
+#[derive(Debug, Clone)]
+pub enum AArch64Operand {
+    Register(AArch64Register),
+    Immediate(i32),
+}
+
+// Stack slots are addressed from the stack pointer, incoming stack arguments from the frame pointer.
+// Globals are reached through their page, computed by adrp.
+#[derive(Debug, Clone)]
+pub enum AArch64Address {
+    Stack(i32),
+    Frame(i32),
+    Global(String),
+}
+
+// AAPCS64 passes the first eight integer arguments in x0 to x7, the others in 8-byte stack slots
+const ARGUMENT_REGISTERS: u8 = 8;
+
+// Caller-saved registers holding operands while an instruction is computed
+const SCRATCH: [u8; 3] = [9, 10, 11];
+
+// Largest immediate add, sub and cmp encode without a shift
+const MAX_IMMEDIATE: i32 = 4095;
+
+pub fn generate(llvm_ir: &LLVMConstruct) -> Result<AArch64Construct, String> {
+    match llvm_ir {
+        LLVMConstruct::Module { globals, functions } => {
+            let functions = functions.iter()
+                .filter(|function| !function.is_declaration())
+                .map(generate_function)
+                .collect::<Result<Vec<_>, String>>()?;
+            let globals = globals.iter()
+                .map(|global| AArch64Global { name: global.name.clone(), initializer: global.initializer })
+                .collect();
+            Ok(AArch64Construct::Program { functions, globals })
+        }
+    }
+}
+
+struct FunctionGenerator<'a> {
+    frame: StackFrame<'a>,
+}
+
+fn generate_function(function: &LLVMFunction) -> Result<AArch64Function, String> {
+    let LLVMFunction::Function { name, linkage, params, blocks, .. } = function;
+    let mut generator = FunctionGenerator { frame: StackFrame::new(function, ARGUMENT_REGISTERS as usize) };
+
+    // Parameters beyond the eighth are above the frame record
+    let mut asm_instructions = Vec::new();
+    for (index, (id, ty)) in params.iter().enumerate() {
+        let register = if index < ARGUMENT_REGISTERS as usize {
+            register(*ty, index as u8)
+        } else {
+            let register = register(*ty, SCRATCH[0]);
+            let offset = 16 + 8 * (index - ARGUMENT_REGISTERS as usize) as i32;
+            asm_instructions.push(AArch64Instruction::Load(register, AArch64Address::Frame(offset)));
+            register
+        };
+        asm_instructions.push(generator.store(register, *id));
+    }
+
+    asm_instructions.extend(generator.generate_blocks(blocks)?);
+
+    Ok(AArch64Function::Function {
+        name: name.clone(),
+        global: *linkage == Linkage::External,
+        frame_size: generator.frame.size(),
+        instructions: asm_instructions,
+    })
+}
+
+impl<'a> SlotLowering<'a> for FunctionGenerator<'a> {
+    type Instruction = AArch64Instruction;
+
+    fn frame(&mut self) -> &mut StackFrame<'a> {
+        &mut self.frame
+    }
+
+    fn label(label: String) -> AArch64Instruction {
+        AArch64Instruction::Label(label)
+    }
+
+    fn copy(&mut self, dst: ValueId, ty: IRType, value: &LLVMValue) -> Vec<AArch64Instruction> {
+        let mut instructions = self.load(value, ty, SCRATCH[0]);
+        instructions.push(self.store(register(ty, SCRATCH[0]), dst));
+        instructions
+    }
+
+    fn generate_instruction(&mut self, block: &LLVMBasicBlock, instruction: &LLVMInstruction) -> Result<Vec<AArch64Instruction>, String> {
+        let [first, second, third] = SCRATCH;
+        match instruction {
+            LLVMInstruction::Return => Ok(vec![AArch64Instruction::Ret]),
+            LLVMInstruction::ReturnValue(ty, value) => {
+                let mut instructions = self.load(value, *ty, 0);
+                instructions.push(AArch64Instruction::Ret);
+                Ok(instructions)
+            },
+            LLVMInstruction::Branch(target) => {
+                let mut instructions = self.edge_copies(&block.label, target);
+                instructions.push(AArch64Instruction::B(self.frame.block_label(target)));
+                Ok(instructions)
+            },
+            // Copies for phi nodes go on the edge they belong to, so that a value still live along the
+            // other edge is not overwritten
+            LLVMInstruction::CondBranch(condition, then_target, else_target) => {
+                let then_copies = self.edge_copies(&block.label, then_target);
+                let else_copies = self.edge_copies(&block.label, else_target);
+                let mut instructions = self.load(condition, IRType::I1, first);
+                let condition = AArch64Register::W(first);
+                if then_copies.is_empty() {
+                    instructions.push(AArch64Instruction::Cbnz(condition, self.frame.block_label(then_target)));
+                } else {
+                    let else_edge = self.frame.new_label();
+                    instructions.push(AArch64Instruction::Cbz(condition, else_edge.clone()));
+                    instructions.extend(then_copies);
+                    instructions.push(AArch64Instruction::B(self.frame.block_label(then_target)));
+                    instructions.push(AArch64Instruction::Label(else_edge));
+                }
+                instructions.extend(else_copies);
+                instructions.push(AArch64Instruction::B(self.frame.block_label(else_target)));
+                Ok(instructions)
+            },
+            LLVMInstruction::UnaryOp(dst, ty, op, value) => {
+                let asm_op = match op {
+                    LLVMUnaryOp::Not => AArch64UnaryOperator::Mvn,
+                    LLVMUnaryOp::Neg => AArch64UnaryOperator::Neg,
+                };
+                let register = register(*ty, first);
+                let mut instructions = self.load(value, *ty, first);
+                instructions.push(AArch64Instruction::Unary(asm_op, register, register));
+                instructions.push(self.store(register, *dst));
+                Ok(instructions)
+            },
+            // The hardware has no remainder instruction, the quotient is multiplied back and subtracted
+            // from the dividend instead. Division by zero gives zero rather than trapping.
+            LLVMInstruction::BinaryOp(dst, ty, op, lhs, rhs) => {
+                let (lhs_register, rhs_register) = (register(*ty, first), register(*ty, second));
+                let mut instructions = self.load(lhs, *ty, first);
+                let rhs = match op {
+                    LLVMBinaryOp::Add | LLVMBinaryOp::Subtract => self.operand(rhs, *ty, second, &mut instructions),
+                    _ => {
+                        instructions.extend(self.load(rhs, *ty, second));
+                        AArch64Operand::Register(rhs_register)
+                    }
+                };
+                let asm_op = match op {
+                    LLVMBinaryOp::Add => AArch64BinaryOperator::Add,
+                    LLVMBinaryOp::Subtract => AArch64BinaryOperator::Sub,
+                    LLVMBinaryOp::Multiply => AArch64BinaryOperator::Mul,
+                    LLVMBinaryOp::Divide | LLVMBinaryOp::Remainder => AArch64BinaryOperator::Sdiv,
+                    LLVMBinaryOp::UnsignedDivide | LLVMBinaryOp::UnsignedRemainder => AArch64BinaryOperator::Udiv,
+                };
+                if matches!(op, LLVMBinaryOp::Remainder | LLVMBinaryOp::UnsignedRemainder) {
+                    let quotient = register(*ty, third);
+                    instructions.push(AArch64Instruction::Binary(asm_op, quotient, lhs_register, rhs));
+                    instructions.push(AArch64Instruction::Msub(lhs_register, quotient, rhs_register, lhs_register));
+                } else {
+                    instructions.push(AArch64Instruction::Binary(asm_op, lhs_register, lhs_register, rhs));
+                }
+                instructions.push(self.store(lhs_register, *dst));
+                Ok(instructions)
+            },
+            LLVMInstruction::ICmp(dst, comparison, ty, lhs, rhs) => {
+                let mut instructions = self.load(lhs, *ty, first);
+                let rhs = self.operand(rhs, *ty, second, &mut instructions);
+                instructions.push(AArch64Instruction::Cmp(register(*ty, first), rhs));
+                instructions.push(AArch64Instruction::Cset(AArch64Register::W(first), condition_code(comparison)));
+                instructions.push(self.store(AArch64Register::W(first), *dst));
+                Ok(instructions)
+            },
+            // Booleans are already kept as 0 or 1 in a full register
+            LLVMInstruction::ZExt(dst, ty, value, _) => {
+                let mut instructions = self.load(value, *ty, first);
+                instructions.push(self.store(AArch64Register::W(first), *dst));
+                Ok(instructions)
+            },
+            LLVMInstruction::Select(dst, ty, condition, lhs, rhs) => {
+                let mut instructions = self.load(condition, IRType::I1, first);
+                instructions.extend(self.load(lhs, *ty, second));
+                instructions.extend(self.load(rhs, *ty, third));
+                let (result, lhs, rhs) = (register(*ty, first), register(*ty, second), register(*ty, third));
+                instructions.push(AArch64Instruction::Cmp(AArch64Register::W(first), AArch64Operand::Immediate(0)));
+                instructions.push(AArch64Instruction::Csel(result, lhs, rhs, AArch64Condition::Ne));
+                instructions.push(self.store(result, *dst));
+                Ok(instructions)
+            },
+            LLVMInstruction::Call(dst, ty, callee, args) => Ok(self.generate_call(*dst, *ty, callee, args)),
+            LLVMInstruction::Store(ty, src, dst) => {
+                let mut instructions = self.load(src, *ty, first);
+                instructions.push(AArch64Instruction::Store(register(*ty, first), self.pointer(dst)?));
+                Ok(instructions)
+            },
+            LLVMInstruction::Load(dst, ty, src) => {
+                let register = register(*ty, first);
+                Ok(vec![AArch64Instruction::Load(register, self.pointer(src)?), self.store(register, *dst)])
+            },
+            LLVMInstruction::Alloca(..) => Ok(Vec::new()),
+            LLVMInstruction::Phi(..) => Ok(Vec::new()),
+        }
+    }
+}
+
+impl FunctionGenerator<'_> {
+    // Stack arguments are stored first, since loading them goes through a scratch register rather than
+    // the argument registers
+    fn generate_call(&mut self, dst: Option<ValueId>, ty: IRType, callee: &str, args: &[(IRType, LLVMValue)]) -> Vec<AArch64Instruction> {
+        let mut instructions = Vec::new();
+        for (index, (ty, value)) in args.iter().enumerate().skip(ARGUMENT_REGISTERS as usize) {
+            instructions.extend(self.load(value, *ty, SCRATCH[0]));
+            let offset = 8 * (index as i32 - ARGUMENT_REGISTERS as i32);
+            instructions.push(AArch64Instruction::Store(register(*ty, SCRATCH[0]), AArch64Address::Stack(offset)));
+        }
+        for (index, (ty, value)) in args.iter().take(ARGUMENT_REGISTERS as usize).enumerate() {
+            instructions.extend(self.load(value, *ty, index as u8));
+        }
+        instructions.push(AArch64Instruction::Bl(callee.to_string()));
+        if let Some(dst) = dst {
+            instructions.push(self.store(register(ty, 0), dst));
+        }
+        instructions
+    }
+
+    // Brings a value into the given register, allocas and globals being their address
+    fn load(&mut self, value: &LLVMValue, ty: IRType, number: u8) -> Vec<AArch64Instruction> {
+        match value {
+            LLVMValue::Immediate(value) => vec![AArch64Instruction::MovImmediate(AArch64Register::W(number), *value)],
+            LLVMValue::Register(id) if self.frame.is_alloca(*id) => {
+                vec![AArch64Instruction::AddressOf(AArch64Register::X(number), AArch64Address::Stack(self.frame.slot(*id)))]
+            }
+            LLVMValue::Register(id) => vec![AArch64Instruction::Load(register(ty, number), AArch64Address::Stack(self.frame.slot(*id)))],
+            LLVMValue::Global(name) => vec![AArch64Instruction::AddressOf(AArch64Register::X(number), AArch64Address::Global(name.clone()))],
+        }
+    }
+
+    // Immediates that fit are used as is, other values are loaded into the given register
+    fn operand(&mut self, value: &LLVMValue, ty: IRType, number: u8, instructions: &mut Vec<AArch64Instruction>) -> AArch64Operand {
+        match value {
+            LLVMValue::Immediate(value) if (0..=MAX_IMMEDIATE).contains(value) => AArch64Operand::Immediate(*value),
+            _ => {
+                instructions.extend(self.load(value, ty, number));
+                AArch64Operand::Register(register(ty, number))
+            }
+        }
+    }
+
+    fn store(&mut self, register: AArch64Register, id: ValueId) -> AArch64Instruction {
+        AArch64Instruction::Store(register, AArch64Address::Stack(self.frame.slot(id)))
+    }
+
+    fn pointer(&mut self, pointer: &LLVMValue) -> Result<AArch64Address, String> {
+        match pointer {
+            LLVMValue::Register(id) if self.frame.is_alloca(*id) => Ok(AArch64Address::Stack(self.frame.slot(*id))),
+            LLVMValue::Global(name) => Ok(AArch64Address::Global(name.clone())),
+            other => Err(format!("Memory access through {} is not supported by the AArch64 backend", other)),
+        }
+    }
+}
+
+// Pointers take a full x register, other values the w view of it
+fn register(ty: IRType, number: u8) -> AArch64Register {
+    match ty {
+        IRType::Ptr => AArch64Register::X(number),
+        _ => AArch64Register::W(number),
+    }
+}
+
+fn condition_code(comparison: &LLVMComparison) -> AArch64Condition {
+    match comparison {
+        LLVMComparison::Equal => AArch64Condition::Eq,
+        LLVMComparison::NotEqual => AArch64Condition::Ne,
+        LLVMComparison::SignedGreater => AArch64Condition::Gt,
+        LLVMComparison::SignedGreaterOrEqual => AArch64Condition::Ge,
+        LLVMComparison::SignedLess => AArch64Condition::Lt,
+        LLVMComparison::SignedLessOrEqual => AArch64Condition::Le,
+        LLVMComparison::UnsignedGreater => AArch64Condition::Hi,
+        LLVMComparison::UnsignedGreaterOrEqual => AArch64Condition::Hs,
+        LLVMComparison::UnsignedLess => AArch64Condition::Lo,
+        LLVMComparison::UnsignedLessOrEqual => AArch64Condition::Ls,
+    }
+}
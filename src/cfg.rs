@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+
+use crate::llvm_ir::LLVMBasicBlock;
+
+//
+// Control-flow graph
+//
+
+// Blocks are identified by their index in the function, the entry block being 0. Each edge is listed once,
+// even when a conditional branch has the same block as both targets.
+pub struct ControlFlowGraph {
+    pub successors: Vec<Vec<usize>>,
+    pub predecessors: Vec<Vec<usize>>,
+    // Blocks reachable from the entry block, each one after all its predecessors except along back edges
+    pub reverse_postorder: Vec<usize>,
+}
+
+impl ControlFlowGraph {
+    // Branches to labels that do not exist are ignored, the verifier reports them
+    pub fn new(blocks: &[LLVMBasicBlock]) -> Self {
+        let indices = blocks.iter()
+            .enumerate()
+            .map(|(index, block)| (block.label.as_str(), index))
+            .collect::<HashMap<_, _>>();
+
+        let mut successors = vec![Vec::new(); blocks.len()];
+        let mut predecessors = vec![Vec::new(); blocks.len()];
+        for (index, block) in blocks.iter().enumerate() {
+            for successor in block.instructions.iter().flat_map(|instruction| instruction.successors()) {
+                let Some(&target) = indices.get(successor) else {
+                    continue;
+                };
+                if !successors[index].contains(&target) {
+                    successors[index].push(target);
+                    predecessors[target].push(index);
+                }
+            }
+        }
+
+        let reverse_postorder = reverse_postorder(&successors);
+        ControlFlowGraph { successors, predecessors, reverse_postorder }
+    }
+
+    pub fn is_reachable(&self, block: usize) -> bool {
+        self.reverse_postorder.contains(&block)
+    }
+}
+
+// Iterative depth-first search, so that deeply nested control flow cannot overflow the stack
+fn reverse_postorder(successors: &[Vec<usize>]) -> Vec<usize> {
+    let mut postorder = Vec::with_capacity(successors.len());
+    if successors.is_empty() {
+        return postorder;
+    }
+
+    let mut visited = vec![false; successors.len()];
+    let mut stack = vec![(0, 0)];
+    visited[0] = true;
+    while let Some((block, next)) = stack.last_mut() {
+        match successors[*block].get(*next) {
+            Some(&successor) => {
+                *next += 1;
+                if !visited[successor] {
+                    visited[successor] = true;
+                    stack.push((successor, 0));
+                }
+            }
+            None => {
+                postorder.push(*block);
+                stack.pop();
+            }
+        }
+    }
+    postorder.reverse();
+    postorder
+}
@@ -0,0 +1,205 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::cfg::ControlFlowGraph;
+use crate::llvm_ir::{LLVMBasicBlock, LLVMFunction, LLVMInstruction, LLVMValue};
+
+//
+// Unreachable block removal
+//
+
+// Removes the blocks that cannot be reached from the entry block, along with the phi operands flowing
+// from them
+pub fn remove_unreachable_blocks(function: &mut LLVMFunction) -> bool {
+    let LLVMFunction::Function { blocks, .. } = function;
+    let cfg = ControlFlowGraph::new(blocks);
+    if cfg.reverse_postorder.len() == blocks.len() {
+        return false;
+    }
+
+    let reachable = cfg.reverse_postorder.iter().map(|index| blocks[*index].label.clone()).collect::<HashSet<_>>();
+    blocks.retain(|block| reachable.contains(&block.label));
+    for instruction in blocks.iter_mut().flat_map(|block| &mut block.instructions) {
+        if let LLVMInstruction::Phi(_, _, incoming) = instruction {
+            incoming.retain(|(_, label)| reachable.contains(label));
+        }
+    }
+    true
+}
+
+//
+// CFG simplification
+//
+
+// Folds conditional branches whose condition is known, threads jumps through blocks that only branch
+// elsewhere and merges blocks into their predecessor when the predecessor is their only way in
+pub fn run(function: &mut LLVMFunction) -> bool {
+    let LLVMFunction::Function { blocks, .. } = function;
+    let mut changed = false;
+    while fold_branches(blocks) || thread_jumps(blocks) || merge_blocks(blocks) {
+        changed = true;
+    }
+    changed
+}
+
+fn fold_branches(blocks: &mut [LLVMBasicBlock]) -> bool {
+    let mut folded = false;
+    let mut removed_edges = Vec::new();
+    for block in blocks.iter_mut() {
+        let Some(terminator) = block.instructions.last_mut() else {
+            continue;
+        };
+        let LLVMInstruction::CondBranch(condition, then_target, else_target) = terminator else {
+            continue;
+        };
+        let (taken, not_taken) = match condition {
+            _ if then_target == else_target => (then_target.clone(), None),
+            LLVMValue::Immediate(0) => (else_target.clone(), Some(then_target.clone())),
+            LLVMValue::Immediate(_) => (then_target.clone(), Some(else_target.clone())),
+            _ => continue,
+        };
+        if let Some(not_taken) = not_taken {
+            removed_edges.push((block.label.clone(), not_taken));
+        }
+        *terminator = LLVMInstruction::Branch(taken);
+        folded = true;
+    }
+
+    for (predecessor, target) in &removed_edges {
+        for incoming in phi_operands_mut(blocks, target) {
+            incoming.retain(|(_, label)| label != predecessor);
+        }
+    }
+    folded
+}
+
+// A block holding nothing but a branch is bypassed: its predecessors branch to its target directly and
+// the block, now unreachable, is removed
+fn thread_jumps(blocks: &mut Vec<LLVMBasicBlock>) -> bool {
+    for index in 1..blocks.len() {
+        let [LLVMInstruction::Branch(target)] = blocks[index].instructions.as_slice() else {
+            continue;
+        };
+        let label = blocks[index].label.clone();
+        let target = target.clone();
+        if target == label {
+            continue;
+        }
+
+        // The target's phis get one operand per predecessor, which is ambiguous when a predecessor
+        // already branches to the target directly
+        let target_predecessors = predecessors(blocks, &target);
+        let predecessors = predecessors(blocks, &label);
+        if predecessors.is_empty() || predecessors.iter().any(|predecessor| target_predecessors.contains(predecessor)) {
+            continue;
+        }
+
+        for incoming in phi_operands_mut(blocks, &target) {
+            let Some(position) = incoming.iter().position(|(_, from)| *from == label) else {
+                continue;
+            };
+            let (value, _) = incoming.remove(position);
+            for predecessor in &predecessors {
+                incoming.push((value.clone(), predecessor.clone()));
+            }
+        }
+        for predecessor in &predecessors {
+            retarget(blocks, predecessor, &label, &target);
+        }
+        blocks.remove(index);
+        return true;
+    }
+    false
+}
+
+// A block whose only successor has it as its only predecessor absorbs that successor
+fn merge_blocks(blocks: &mut Vec<LLVMBasicBlock>) -> bool {
+    for index in 0..blocks.len() {
+        let Some(LLVMInstruction::Branch(target)) = blocks[index].instructions.last() else {
+            continue;
+        };
+        let target = target.clone();
+        let Some(target_index) = blocks.iter().position(|block| block.label == target) else {
+            continue;
+        };
+        if target_index == 0 || target_index == index || predecessors(blocks, &target).len() != 1 {
+            continue;
+        }
+
+        let merged = blocks.remove(target_index);
+        let index = if target_index < index { index - 1 } else { index };
+        let label = blocks[index].label.clone();
+
+        // The merged block's phis have a single operand, which they are replaced with
+        let mut replacements = HashMap::new();
+        let mut instructions = Vec::new();
+        for instruction in merged.instructions {
+            match instruction {
+                LLVMInstruction::Phi(id, _, incoming) => {
+                    replacements.insert(id, incoming[0].0.clone());
+                }
+                other => instructions.push(other),
+            }
+        }
+        let block = &mut blocks[index];
+        block.instructions.pop();
+        block.instructions.extend(instructions);
+
+        // Successors of the merged block are now reached from the block it was merged into
+        for instruction in blocks.iter_mut().flat_map(|block| &mut block.instructions) {
+            if let LLVMInstruction::Phi(_, _, incoming) = instruction {
+                for (_, from) in incoming.iter_mut() {
+                    if *from == merged.label {
+                        *from = label.clone();
+                    }
+                }
+            }
+            for operand in instruction.operands_mut() {
+                while let LLVMValue::Register(id) = operand {
+                    match replacements.get(id) {
+                        Some(replacement) => *operand = replacement.clone(),
+                        None => break,
+                    }
+                }
+            }
+        }
+        return true;
+    }
+    false
+}
+
+//
+// Helpers
+//
+
+fn terminator_successors(block: &LLVMBasicBlock) -> Vec<&str> {
+    block.instructions.last().map(|terminator| terminator.successors()).unwrap_or_default()
+}
+
+// Labels of the blocks branching to the given label, each listed once
+fn predecessors(blocks: &[LLVMBasicBlock], label: &str) -> Vec<String> {
+    blocks.iter()
+        .filter(|block| terminator_successors(block).contains(&label))
+        .map(|block| block.label.clone())
+        .collect()
+}
+
+fn retarget(blocks: &mut [LLVMBasicBlock], label: &str, from: &str, to: &str) {
+    let Some(block) = blocks.iter_mut().find(|block| block.label == label) else {
+        return;
+    };
+    let targets = block.instructions.last_mut().map(|terminator| terminator.successors_mut()).unwrap_or_default();
+    for target in targets.into_iter().filter(|target| *target == from) {
+        *target = to.to_string();
+    }
+}
+
+// Incoming values of the phis of the given block
+fn phi_operands_mut<'a>(blocks: &'a mut [LLVMBasicBlock], label: &'a str) -> impl Iterator<Item = &'a mut Vec<(LLVMValue, String)>> {
+    blocks.iter_mut()
+        .filter(move |block| block.label == label)
+        .flat_map(|block| &mut block.instructions)
+        .filter_map(|instruction| match instruction {
+            LLVMInstruction::Phi(_, _, incoming) => Some(incoming),
+            _ => None,
+        })
+}
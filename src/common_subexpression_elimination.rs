@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+
+use crate::llvm_ir::{LLVMBinaryOp, LLVMComparison, LLVMFunction, LLVMInstruction, LLVMValue, ValueId};
+
+// Within each basic block, an instruction computing the same thing as an earlier one is removed and its uses
+// read the earlier result. Only instructions that depend on nothing but their operands are considered,
+// loads and calls may observe memory that changed in between.
+pub fn run(function: &mut LLVMFunction) -> bool {
+    let LLVMFunction::Function { blocks, .. } = function;
+    let mut replacements = HashMap::new();
+
+    for block in blocks.iter_mut() {
+        let mut available = HashMap::new();
+        block.instructions.retain_mut(|instruction| {
+            for operand in instruction.operands_mut() {
+                if let Some(replacement) = operand_replacement(&replacements, operand) {
+                    *operand = replacement;
+                }
+            }
+            let Some((key, id)) = expression(instruction) else {
+                return true;
+            };
+            match available.get(&key) {
+                Some(earlier) => {
+                    replacements.insert(id, LLVMValue::Register(*earlier));
+                    false
+                }
+                None => {
+                    available.insert(key, id);
+                    true
+                }
+            }
+        });
+    }
+
+    function.replace_uses(&replacements);
+    !replacements.is_empty()
+}
+
+fn operand_replacement(replacements: &HashMap<ValueId, LLVMValue>, operand: &LLVMValue) -> Option<LLVMValue> {
+    match operand {
+        LLVMValue::Register(id) => replacements.get(id).cloned(),
+        _ => None,
+    }
+}
+
+// The computation an instruction performs, with its result left out so that equal computations compare
+// equal. Operands of commutative operations are put in a canonical order.
+fn expression(instruction: &LLVMInstruction) -> Option<(LLVMInstruction, ValueId)> {
+    let (id, _) = instruction.result()?;
+    let mut key = match instruction {
+        LLVMInstruction::BinaryOp(_, ty, op @ (LLVMBinaryOp::Add | LLVMBinaryOp::Multiply), lhs, rhs) => {
+            let (lhs, rhs) = canonical_order(lhs, rhs);
+            LLVMInstruction::BinaryOp(id, *ty, op.clone(), lhs, rhs)
+        }
+        LLVMInstruction::ICmp(_, comparison @ (LLVMComparison::Equal | LLVMComparison::NotEqual), ty, lhs, rhs) => {
+            let (lhs, rhs) = canonical_order(lhs, rhs);
+            LLVMInstruction::ICmp(id, *comparison, *ty, lhs, rhs)
+        }
+        LLVMInstruction::UnaryOp(..)
+        | LLVMInstruction::BinaryOp(..)
+        | LLVMInstruction::ICmp(..)
+        | LLVMInstruction::ZExt(..)
+        | LLVMInstruction::Select(..) => instruction.clone(),
+        _ => return None,
+    };
+    if let Some(result) = key.result_mut() {
+        *result = ValueId(usize::MAX);
+    }
+    Some((key, id))
+}
+
+fn canonical_order(lhs: &LLVMValue, rhs: &LLVMValue) -> (LLVMValue, LLVMValue) {
+    let rank = |value: &LLVMValue| match value {
+        LLVMValue::Register(id) => (0, id.0, 0),
+        LLVMValue::Global(_) => (1, 0, 0),
+        LLVMValue::Immediate(value) => (2, 0, *value),
+    };
+    if rank(rhs) < rank(lhs) {
+        (rhs.clone(), lhs.clone())
+    } else {
+        (lhs.clone(), rhs.clone())
+    }
+}
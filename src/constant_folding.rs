@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+
+use crate::llvm_ir::{
+    IRType, LLVMBinaryOp, LLVMComparison, LLVMFunction, LLVMInstruction, LLVMUnaryOp, LLVMValue, ValueId,
+};
+
+// Evaluates instructions whose operands are constants and applies algebraic identities such as x+0 or x-x.
+// Arithmetic wraps like the generated code does, but divisions that would trap at runtime (by zero, or
+// INT_MIN by -1) are left alone so the program still fails where it would have failed unoptimized.
+pub fn run(function: &mut LLVMFunction) -> bool {
+    let LLVMFunction::Function { blocks, .. } = function;
+    let mut replacements = HashMap::new();
+    let mut folded = false;
+
+    // Folding one instruction may make its users foldable, including users that come earlier through phis
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for block in blocks.iter_mut() {
+            block.instructions.retain_mut(|instruction| {
+                for operand in instruction.operands_mut() {
+                    *operand = resolve(&replacements, operand);
+                }
+                match simplify(instruction) {
+                    Some(value) => {
+                        if let Some((id, _)) = instruction.result() {
+                            replacements.insert(id, value);
+                        }
+                        changed = true;
+                        folded = true;
+                        false
+                    }
+                    None => true,
+                }
+            });
+        }
+    }
+    folded
+}
+
+fn resolve(replacements: &HashMap<ValueId, LLVMValue>, value: &LLVMValue) -> LLVMValue {
+    match value {
+        LLVMValue::Register(id) => replacements.get(id).cloned().unwrap_or_else(|| value.clone()),
+        other => other.clone(),
+    }
+}
+
+// The value an instruction always computes, if it can be known without running it
+fn simplify(instruction: &LLVMInstruction) -> Option<LLVMValue> {
+    match instruction {
+        LLVMInstruction::UnaryOp(_, ty, op, LLVMValue::Immediate(value)) => {
+            let value = match op {
+                LLVMUnaryOp::Not => !value,
+                LLVMUnaryOp::Neg => value.wrapping_neg(),
+            };
+            // i1 constants are 0 or 1
+            Some(LLVMValue::Immediate(if *ty == IRType::I1 { value & 1 } else { value }))
+        }
+        LLVMInstruction::BinaryOp(_, ty, op, lhs, rhs) => simplify_binary(*ty, op, lhs, rhs),
+        LLVMInstruction::ICmp(_, comparison, _, LLVMValue::Immediate(lhs), LLVMValue::Immediate(rhs)) => {
+            Some(LLVMValue::Immediate(compare(comparison, *lhs, *rhs) as i32))
+        }
+        LLVMInstruction::ZExt(_, _, LLVMValue::Immediate(value), _) => Some(LLVMValue::Immediate(*value)),
+        LLVMInstruction::Select(_, _, LLVMValue::Immediate(condition), lhs, rhs) => {
+            Some(if *condition != 0 { lhs.clone() } else { rhs.clone() })
+        }
+        LLVMInstruction::Select(_, _, _, lhs, rhs) if lhs == rhs => Some(lhs.clone()),
+        _ => None,
+    }
+}
+
+fn simplify_binary(ty: IRType, op: &LLVMBinaryOp, lhs: &LLVMValue, rhs: &LLVMValue) -> Option<LLVMValue> {
+    if ty != IRType::I32 {
+        return None;
+    }
+
+    if let (LLVMValue::Immediate(lhs), LLVMValue::Immediate(rhs)) = (lhs, rhs) {
+        let value = match op {
+            LLVMBinaryOp::Add => lhs.wrapping_add(*rhs),
+            LLVMBinaryOp::Subtract => lhs.wrapping_sub(*rhs),
+            LLVMBinaryOp::Multiply => lhs.wrapping_mul(*rhs),
+            LLVMBinaryOp::Divide => lhs.checked_div(*rhs)?,
+            LLVMBinaryOp::Remainder => lhs.checked_rem(*rhs)?,
+            LLVMBinaryOp::UnsignedDivide => (*lhs as u32).checked_div(*rhs as u32)? as i32,
+            LLVMBinaryOp::UnsignedRemainder => (*lhs as u32).checked_rem(*rhs as u32)? as i32,
+        };
+        return Some(LLVMValue::Immediate(value));
+    }
+
+    let zero = LLVMValue::Immediate(0);
+    let one = LLVMValue::Immediate(1);
+    match op {
+        LLVMBinaryOp::Add if *rhs == zero => Some(lhs.clone()),
+        LLVMBinaryOp::Add if *lhs == zero => Some(rhs.clone()),
+        LLVMBinaryOp::Subtract if *rhs == zero => Some(lhs.clone()),
+        LLVMBinaryOp::Subtract if lhs == rhs => Some(zero),
+        LLVMBinaryOp::Multiply if *rhs == one => Some(lhs.clone()),
+        LLVMBinaryOp::Multiply if *lhs == one => Some(rhs.clone()),
+        LLVMBinaryOp::Multiply if *lhs == zero || *rhs == zero => Some(zero),
+        LLVMBinaryOp::Divide | LLVMBinaryOp::UnsignedDivide if *rhs == one => Some(lhs.clone()),
+        LLVMBinaryOp::Remainder | LLVMBinaryOp::UnsignedRemainder if *rhs == one => Some(zero),
+        _ => None,
+    }
+}
+
+fn compare(comparison: &LLVMComparison, lhs: i32, rhs: i32) -> bool {
+    let (unsigned_lhs, unsigned_rhs) = (lhs as u32, rhs as u32);
+    match comparison {
+        LLVMComparison::Equal => lhs == rhs,
+        LLVMComparison::NotEqual => lhs != rhs,
+        LLVMComparison::SignedGreater => lhs > rhs,
+        LLVMComparison::SignedGreaterOrEqual => lhs >= rhs,
+        LLVMComparison::SignedLess => lhs < rhs,
+        LLVMComparison::SignedLessOrEqual => lhs <= rhs,
+        LLVMComparison::UnsignedGreater => unsigned_lhs > unsigned_rhs,
+        LLVMComparison::UnsignedGreaterOrEqual => unsigned_lhs >= unsigned_rhs,
+        LLVMComparison::UnsignedLess => unsigned_lhs < unsigned_rhs,
+        LLVMComparison::UnsignedLessOrEqual => unsigned_lhs <= unsigned_rhs,
+    }
+}
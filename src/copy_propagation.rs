@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+
+use crate::llvm_ir::{LLVMFunction, LLVMInstruction, LLVMValue, ValueId};
+
+// The IR has no copy instruction, copies show up as phis that merge a single value, either because every
+// incoming value is the same or because the block has a single predecessor left. Such phis are removed and
+// their uses read the copied value directly.
+pub fn run(function: &mut LLVMFunction) -> bool {
+    let mut replacements = HashMap::new();
+
+    // Removing a phi may make the phis using it trivial in turn
+    loop {
+        let LLVMFunction::Function { blocks, .. } = &mut *function;
+        let mut found = false;
+        for block in blocks.iter_mut() {
+            block.instructions.retain(|instruction| match copied_value(instruction, &replacements) {
+                Some((id, value)) => {
+                    replacements.insert(id, value);
+                    found = true;
+                    false
+                }
+                None => true,
+            });
+        }
+        if !found {
+            break;
+        }
+    }
+
+    function.replace_uses(&replacements);
+    !replacements.is_empty()
+}
+
+// A phi whose incoming values are all the same value, ignoring the phi itself, copies that value
+fn copied_value(instruction: &LLVMInstruction, replacements: &HashMap<ValueId, LLVMValue>) -> Option<(ValueId, LLVMValue)> {
+    let LLVMInstruction::Phi(id, _, incoming) = instruction else {
+        return None;
+    };
+
+    let mut same = None;
+    for (value, _) in incoming {
+        let value = resolve(replacements, value);
+        if value == LLVMValue::Register(*id) || Some(&value) == same.as_ref() {
+            continue;
+        }
+        if same.is_some() {
+            return None;
+        }
+        same = Some(value);
+    }
+    // A phi that only merges itself is never given a value, any value will do
+    Some((*id, same.unwrap_or(LLVMValue::Immediate(0))))
+}
+
+fn resolve(replacements: &HashMap<ValueId, LLVMValue>, value: &LLVMValue) -> LLVMValue {
+    let mut value = value.clone();
+    while let LLVMValue::Register(id) = value {
+        match replacements.get(&id) {
+            Some(replacement) => value = replacement.clone(),
+            None => break,
+        }
+    }
+    value
+}
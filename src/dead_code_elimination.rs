@@ -0,0 +1,47 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::llvm_ir::{LLVMFunction, LLVMInstruction, LLVMValue};
+
+// Removes instructions whose result is never used and that have no effect besides computing it. Values
+// are marked live starting from the instructions with side effects, so that unused cycles of phis are
+// removed as well.
+pub fn run(function: &mut LLVMFunction) -> bool {
+    let LLVMFunction::Function { blocks, .. } = function;
+
+    let mut live = HashSet::new();
+    let mut worklist = blocks.iter()
+        .flat_map(|block| &block.instructions)
+        .filter(|instruction| has_side_effects(instruction))
+        .collect::<Vec<_>>();
+    let definitions = blocks.iter()
+        .flat_map(|block| &block.instructions)
+        .filter_map(|instruction| instruction.result().map(|(id, _)| (id, instruction)))
+        .collect::<HashMap<_, _>>();
+
+    while let Some(instruction) = worklist.pop() {
+        for operand in instruction.operands() {
+            if let LLVMValue::Register(id) = operand {
+                if live.insert(*id) {
+                    // Parameters have no defining instruction
+                    worklist.extend(definitions.get(id));
+                }
+            }
+        }
+    }
+
+    let mut removed = false;
+    for block in blocks.iter_mut() {
+        block.instructions.retain(|instruction| {
+            let dead = !has_side_effects(instruction) && instruction.result().is_some_and(|(id, _)| !live.contains(&id));
+            removed |= dead;
+            !dead
+        });
+    }
+    removed
+}
+
+// Stores, calls and control flow must stay even when nothing uses their result. Division by zero is
+// undefined behavior in C, so an unused division may be removed even though it could trap.
+fn has_side_effects(instruction: &LLVMInstruction) -> bool {
+    instruction.is_terminator() || matches!(instruction, LLVMInstruction::Store(..) | LLVMInstruction::Call(..))
+}
@@ -0,0 +1,230 @@
+use std::fmt;
+use std::rc::Rc;
+
+//
+// Diagnostic records
+//
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum DiagnosticsFormat {
+    Text,
+    Json,
+    Sarif,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+// Lines and columns are 1-based, the end position is exclusive
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct SourcePosition {
+    pub line: usize,
+    pub column: usize,
+}
+
+// The file is only known when the input carries line markers, the driver fills it in otherwise
+#[derive(Debug, PartialEq, Clone)]
+pub struct SourceRange {
+    pub file: Option<Rc<str>>,
+    pub start: SourcePosition,
+    pub end: SourcePosition,
+}
+
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub code: &'static str,
+    pub message: String,
+    pub file: Option<Rc<str>>,
+    pub range: Option<SourceRange>,
+}
+
+//
+// Diagnostic codes
+//
+
+pub const DRIVER_USAGE: &str = "E0001";
+pub const DRIVER_IO: &str = "E0002";
+pub const DRIVER_TOOLCHAIN: &str = "E0003";
+pub const DRIVER_UNUSED_INPUT: &str = "W0001";
+pub const PREPROCESSOR_INVALID_TOKEN: &str = "E0101";
+pub const PREPROCESSOR_INVALID_DIRECTIVE: &str = "E0102";
+pub const PREPROCESSOR_INCLUDE_NOT_FOUND: &str = "E0103";
+pub const PREPROCESSOR_ERROR_DIRECTIVE: &str = "E0104";
+pub const PREPROCESSOR_INVALID_EXPRESSION: &str = "E0105";
+pub const PREPROCESSOR_MACRO_INVOCATION: &str = "E0106";
+pub const PREPROCESSOR_UNTERMINATED_CONDITIONAL: &str = "E0107";
+pub const PREPROCESSOR_WARNING_DIRECTIVE: &str = "W0101";
+pub const LEX_UNEXPECTED_CHARACTER: &str = "E1001";
+pub const LEX_INVALID_CONSTANT: &str = "E1002";
+pub const LEX_UNSUPPORTED_TOKEN: &str = "E1003";
+pub const PARSE_UNEXPECTED_TOKEN: &str = "E2001";
+pub const PARSE_TRAILING_CONTENT: &str = "E2002";
+pub const IR_GENERATION: &str = "E3001";
+pub const IR_VERIFICATION: &str = "E3002";
+pub const IR_PARSE: &str = "E3003";
+pub const CODE_GENERATION: &str = "E4001";
+pub const OBJECT_EMISSION: &str = "E4002";
+pub const LINK: &str = "E5001";
+// A bug in britten rather than in its input
+pub const INTERNAL_ERROR: &str = "E9001";
+
+impl SourcePosition {
+    pub fn start() -> Self {
+        SourcePosition { line: 1, column: 1 }
+    }
+
+    pub fn advance(self, text: &str) -> Self {
+        text.chars().fold(self, |pos, c| match c {
+            '\n' => SourcePosition { line: pos.line + 1, column: 1 },
+            _ => SourcePosition { line: pos.line, column: pos.column + 1 },
+        })
+    }
+}
+
+impl Diagnostic {
+    pub fn error(code: &'static str, message: impl Into<String>) -> Self {
+        Diagnostic {
+            severity: Severity::Error,
+            code,
+            message: message.into(),
+            file: None,
+            range: None,
+        }
+    }
+
+    pub fn warning(code: &'static str, message: impl Into<String>) -> Self {
+        Diagnostic { severity: Severity::Warning, ..Diagnostic::error(code, message) }
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+
+    pub fn with_range(mut self, range: SourceRange) -> Self {
+        if let Some(file) = &range.file {
+            self.file = Some(file.clone());
+        }
+        self.range = Some(range);
+        self
+    }
+
+    // Stages do not know which file they are working on, the driver fills it in
+    pub fn in_file(mut self, file: &str) -> Self {
+        if self.file.is_none() {
+            self.file = Some(Rc::from(file));
+        }
+        self
+    }
+}
+
+//
+// Emission
+//
+
+pub fn emit(format: DiagnosticsFormat, diagnostics: &[Diagnostic]) {
+    match format {
+        DiagnosticsFormat::Text => {
+            for diagnostic in diagnostics {
+                eprintln!("{}", diagnostic);
+            }
+        }
+        DiagnosticsFormat::Json => {
+            for diagnostic in diagnostics {
+                eprintln!("{}", to_json(diagnostic));
+            }
+        }
+        DiagnosticsFormat::Sarif => eprintln!("{}", to_sarif(diagnostics)),
+    }
+}
+
+fn to_json(diagnostic: &Diagnostic) -> String {
+    let range = match &diagnostic.range {
+        Some(range) => format!(
+            "{{\"start\":{{\"line\":{},\"column\":{}}},\"end\":{{\"line\":{},\"column\":{}}}}}",
+            range.start.line, range.start.column, range.end.line, range.end.column
+        ),
+        None => "null".to_string(),
+    };
+    format!(
+        "{{\"file\":{},\"range\":{},\"severity\":\"{}\",\"code\":\"{}\",\"message\":\"{}\"}}",
+        diagnostic.file.as_ref().map_or("null".to_string(), |file| format!("\"{}\"", escape_json(file))),
+        range,
+        diagnostic.severity,
+        diagnostic.code,
+        escape_json(&diagnostic.message)
+    )
+}
+
+fn to_sarif(diagnostics: &[Diagnostic]) -> String {
+    let results = diagnostics.iter().map(|diagnostic| {
+        let mut location = String::new();
+        if let Some(file) = &diagnostic.file {
+            let region = diagnostic.range.as_ref().map_or(String::new(), |range| format!(
+                ",\"region\":{{\"startLine\":{},\"startColumn\":{},\"endLine\":{},\"endColumn\":{}}}",
+                range.start.line, range.start.column, range.end.line, range.end.column
+            ));
+            location = format!(
+                ",\"locations\":[{{\"physicalLocation\":{{\"artifactLocation\":{{\"uri\":\"{}\"}}{}}}}}]",
+                escape_json(file),
+                region
+            );
+        }
+        format!(
+            "{{\"ruleId\":\"{}\",\"level\":\"{}\",\"message\":{{\"text\":\"{}\"}}{}}}",
+            diagnostic.code,
+            diagnostic.severity,
+            escape_json(&diagnostic.message),
+            location
+        )
+    }).collect::<Vec<_>>();
+
+    format!(
+        "{{\"version\":\"2.1.0\",\"$schema\":\"https://json.schemastore.org/sarif-2.1.0.json\",\"runs\":[{{\"tool\":{{\"driver\":{{\"name\":\"britten\",\"version\":\"{}\",\"informationUri\":\"https://github.com/jlvoiseux/britten\"}}}},\"results\":[{}]}}]}}",
+        env!("CARGO_PKG_VERSION"),
+        results.join(",")
+    )
+}
+
+fn escape_json(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+//
+// Text output
+//
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.file, &self.range) {
+            (Some(file), Some(range)) => write!(f, "{}:{}:{}: ", file, range.start.line, range.start.column)?,
+            (Some(file), None) => write!(f, "{}: ", file)?,
+            _ => {}
+        }
+        write!(f, "{}[{}]: {}", self.severity, self.code, self.message)
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use crate::cfg::ControlFlowGraph;
+
+//
+// Dominator tree
+//
+
+// Built with Cooper, Harvey and Kennedy's "A Simple, Fast Dominance Algorithm", which iterates over the
+// blocks in reverse postorder until the immediate dominators settle. Unreachable blocks are not in the tree.
+pub struct DominatorTree {
+    immediate_dominators: Vec<Option<usize>>,
+    #[allow(dead_code)]
+    children: Vec<Vec<usize>>,
+    // Position of each reachable block in reverse postorder
+    order: Vec<Option<usize>>,
+}
+
+impl DominatorTree {
+    pub fn new(cfg: &ControlFlowGraph) -> Self {
+        let count = cfg.successors.len();
+        let mut order = vec![None; count];
+        for (position, block) in cfg.reverse_postorder.iter().enumerate() {
+            order[*block] = Some(position);
+        }
+
+        let mut immediate_dominators = vec![None; count];
+        if count > 0 {
+            immediate_dominators[0] = Some(0);
+        }
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for &block in cfg.reverse_postorder.iter().skip(1) {
+                let mut processed = cfg.predecessors[block].iter().filter(|predecessor| immediate_dominators[**predecessor].is_some());
+                let Some(&first) = processed.next() else {
+                    continue;
+                };
+                let dominator = processed.fold(first, |dominator, &predecessor| {
+                    intersect(&immediate_dominators, &order, dominator, predecessor)
+                });
+                if immediate_dominators[block] != Some(dominator) {
+                    immediate_dominators[block] = Some(dominator);
+                    changed = true;
+                }
+            }
+        }
+
+        let mut children = vec![Vec::new(); count];
+        for &block in cfg.reverse_postorder.iter().skip(1) {
+            if let Some(parent) = immediate_dominators[block] {
+                children[parent].push(block);
+            }
+        }
+        DominatorTree { immediate_dominators, children, order }
+    }
+
+    // The entry block has no immediate dominator, and neither do unreachable blocks
+    pub fn immediate_dominator(&self, block: usize) -> Option<usize> {
+        self.immediate_dominators[block].filter(|dominator| *dominator != block)
+    }
+
+    // Blocks immediately dominated by the given one, in reverse postorder
+    #[allow(dead_code)]
+    pub fn children(&self, block: usize) -> &[usize] {
+        &self.children[block]
+    }
+
+    // Every block dominates itself. Unreachable blocks neither dominate nor are dominated.
+    pub fn dominates(&self, dominator: usize, block: usize) -> bool {
+        if self.order[dominator].is_none() || self.order[block].is_none() {
+            return false;
+        }
+        let mut current = block;
+        loop {
+            if current == dominator {
+                return true;
+            }
+            match self.immediate_dominator(current) {
+                Some(parent) => current = parent,
+                None => return false,
+            }
+        }
+    }
+
+    // The blocks where the dominance of each block ends: successors of the blocks it dominates that it does
+    // not strictly dominate itself. This is where SSA construction places phis.
+    #[allow(dead_code)]
+    pub fn dominance_frontiers(&self, cfg: &ControlFlowGraph) -> Vec<Vec<usize>> {
+        let mut frontiers = vec![Vec::new(); cfg.successors.len()];
+        for &block in &cfg.reverse_postorder {
+            let predecessors = cfg.predecessors[block].iter().filter(|predecessor| self.order[**predecessor].is_some()).collect::<Vec<_>>();
+            if predecessors.len() < 2 {
+                continue;
+            }
+            let Some(dominator) = self.immediate_dominator(block) else {
+                continue;
+            };
+            for &predecessor in predecessors {
+                let mut runner = predecessor;
+                while runner != dominator {
+                    if !frontiers[runner].contains(&block) {
+                        frontiers[runner].push(block);
+                    }
+                    match self.immediate_dominator(runner) {
+                        Some(parent) => runner = parent,
+                        None => break,
+                    }
+                }
+            }
+        }
+        frontiers
+    }
+}
+
+// Walks both blocks up the tree until they meet at their closest common dominator
+fn intersect(immediate_dominators: &[Option<usize>], order: &[Option<usize>], mut lhs: usize, mut rhs: usize) -> usize {
+    while lhs != rhs {
+        while order[lhs] > order[rhs] {
+            lhs = immediate_dominators[lhs].unwrap_or(0);
+        }
+        while order[rhs] > order[lhs] {
+            rhs = immediate_dominators[rhs].unwrap_or(0);
+        }
+    }
+    lhs
+}
@@ -0,0 +1,592 @@
+use std::env;
+use std::fs;
+use std::io::{self, Write};
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+
+use crate::diagnostics::{self, Diagnostic, DiagnosticsFormat};
+use crate::linker;
+use crate::preprocessor;
+use crate::pass_manager::Pass;
+use crate::x86_64_emitter::AssemblySyntax;
+use crate::x86_64_register_allocator::RegisterAllocator;
+use crate::target::{self, TargetArchitecture};
+use crate::{process_file, process_ir_file, CompileOptions, CompilerStage};
+
+//
+// Command line options
+//
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum DriverMode {
+    Preprocess,
+    Assemble,
+    CompileOnly,
+    Link,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Linker {
+    System,
+    Builtin,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+enum InputKind {
+    C,
+    Preprocessed,
+    LLVMIr,
+    Assembly,
+    LinkerInput,
+}
+
+// Make rules listing the headers of C inputs, requested with -MD for all headers or -MMD for those not
+// found in system directories
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Dependencies {
+    None,
+    AllHeaders,
+    UserHeaders,
+}
+
+// Libraries are kept alongside input files since the linker resolves them in command line order
+#[derive(Debug, Clone)]
+enum LinkerArgument {
+    File(PathBuf),
+    Library(String),
+}
+
+struct Options {
+    mode: DriverMode,
+    stage: CompilerStage,
+    output: Option<PathBuf>,
+    inputs: Vec<PathBuf>,
+    include_dirs: Vec<String>,
+    defines: Vec<String>,
+    undefines: Vec<String>,
+    library_dirs: Vec<String>,
+    libraries: Vec<(usize, String)>,
+    line_markers: bool,
+    integrated_assembler: bool,
+    from_llvm: bool,
+    dependencies: Dependencies,
+    dependency_file: Option<PathBuf>,
+    dependency_targets: Vec<String>,
+    linker: Linker,
+    compile: CompileOptions,
+    diagnostics_format: DiagnosticsFormat,
+}
+
+pub fn run(args: &[String]) -> i32 {
+    let options = match parse_args(args) {
+        Ok(Some(options)) => options,
+        Ok(None) => return 0,
+        Err(err) => {
+            let format = requested_diagnostics_format(args);
+            diagnostics::emit(format, &[Diagnostic::error(diagnostics::DRIVER_USAGE, err)]);
+            return 1;
+        }
+    };
+
+    let mut reported = Vec::new();
+    let result = Driver::new(&options, &mut reported).and_then(|mut driver| {
+        let result = driver.run();
+        driver.cleanup();
+        result
+    });
+    if let Err(diagnostic) = result {
+        reported.push(diagnostic);
+    }
+
+    let failed = reported.iter().any(Diagnostic::is_error);
+    if !reported.is_empty() || options.diagnostics_format == DiagnosticsFormat::Sarif {
+        diagnostics::emit(options.diagnostics_format, &reported);
+    }
+    if failed { 1 } else { 0 }
+}
+
+fn parse_args(args: &[String]) -> Result<Option<Options>, String> {
+    let mut options = Options {
+        mode: DriverMode::Link,
+        stage: CompilerStage::Compile,
+        output: None,
+        inputs: Vec::new(),
+        include_dirs: Vec::new(),
+        defines: Vec::new(),
+        undefines: Vec::new(),
+        library_dirs: Vec::new(),
+        libraries: Vec::new(),
+        line_markers: true,
+        integrated_assembler: true,
+        from_llvm: false,
+        dependencies: Dependencies::None,
+        dependency_file: None,
+        dependency_targets: Vec::new(),
+        linker: Linker::System,
+        compile: CompileOptions::default(),
+        diagnostics_format: DiagnosticsFormat::Text,
+    };
+
+    let mut remaining = args[1..].iter();
+    while let Some(arg) = remaining.next() {
+        let mut value_of = |flag: &str| -> Result<String, String> {
+            match &arg[flag.len()..] {
+                "" => remaining.next().cloned().ok_or_else(|| format!("Missing argument to {}", flag)),
+                attached => Ok(attached.to_string()),
+            }
+        };
+
+        match arg.as_str() {
+            "--version" => {
+                println!("britten {}", env!("CARGO_PKG_VERSION"));
+                return Ok(None);
+            }
+            "--lex" => options.stage = CompilerStage::Lex,
+            "--parse" => options.stage = CompilerStage::Parse,
+            "--llvm" => options.stage = CompilerStage::LLVMGen,
+            "--codegen" => options.stage = CompilerStage::CodeGen,
+            "-E" => options.mode = DriverMode::Preprocess,
+            "-S" => options.mode = DriverMode::Assemble,
+            "-c" => options.mode = DriverMode::CompileOnly,
+            "-P" => options.line_markers = false,
+            "-MD" => options.dependencies = Dependencies::AllHeaders,
+            "-MMD" => options.dependencies = Dependencies::UserHeaders,
+            "-fintegrated-as" => options.integrated_assembler = true,
+            "-fno-integrated-as" => options.integrated_assembler = false,
+            "--verify-ir" => options.compile.verify_ir = true,
+            "--from-llvm" => options.from_llvm = true,
+            "-masm=att" => options.compile.assembly_syntax = AssemblySyntax::Att,
+            "-masm=intel" => options.compile.assembly_syntax = AssemblySyntax::Intel,
+            _ if arg.starts_with("-masm=") => return Err(format!("Unknown assembler dialect: {}", &arg["-masm=".len()..])),
+            "--linker=system" => options.linker = Linker::System,
+            "--linker=builtin" => options.linker = Linker::Builtin,
+            _ if arg.starts_with("--linker=") => return Err(format!("Unknown linker: {}", &arg["--linker=".len()..])),
+            _ if arg.starts_with("--target=") => {
+                let name = &arg["--target=".len()..];
+                options.compile.target = TargetArchitecture::from_name(name).ok_or_else(|| format!("Unknown target: {}", name))?;
+            }
+            "-mred-zone" => options.compile.red_zone = true,
+            "-mno-red-zone" => options.compile.red_zone = false,
+            "--regalloc=linear" => options.compile.register_allocator = Some(RegisterAllocator::Linear),
+            "--regalloc=graph" => options.compile.register_allocator = Some(RegisterAllocator::Graph),
+            "--regalloc=spill-all" => options.compile.register_allocator = Some(RegisterAllocator::SpillAll),
+            _ if arg.starts_with("--regalloc=") => {
+                return Err(format!("Unknown register allocator: {}", &arg["--regalloc=".len()..]))
+            }
+            _ if arg.starts_with("--diagnostics-format=") => {
+                options.diagnostics_format = parse_diagnostics_format(&arg["--diagnostics-format=".len()..])?;
+            }
+            _ if arg.starts_with("-O") => options.compile.optimization_level = parse_optimization_level(&arg["-O".len()..])?,
+            _ if arg.starts_with("--passes=") => options.compile.pass_toggles.extend(parse_pass_toggles(&arg["--passes=".len()..])?),
+            _ if arg.starts_with("-MF") => options.dependency_file = Some(PathBuf::from(value_of("-MF")?)),
+            _ if arg.starts_with("-MT") => options.dependency_targets.push(value_of("-MT")?),
+            _ if arg.starts_with("-o") => options.output = Some(PathBuf::from(value_of("-o")?)),
+            _ if arg.starts_with("-I") => options.include_dirs.push(value_of("-I")?),
+            _ if arg.starts_with("-D") => options.defines.push(value_of("-D")?),
+            _ if arg.starts_with("-U") => options.undefines.push(value_of("-U")?),
+            _ if arg.starts_with("-L") => options.library_dirs.push(value_of("-L")?),
+            _ if arg.starts_with("-l") => options.libraries.push((options.inputs.len(), value_of("-l")?)),
+            // Flags build systems commonly pass that have no effect on britten's output
+            _ if is_ignored_flag(arg) => {}
+            "-" => return Err("Reading from standard input is not supported".to_string()),
+            _ if arg.starts_with('-') => return Err(format!("Unknown option: {}", arg)),
+            _ => options.inputs.push(PathBuf::from(arg)),
+        }
+    }
+
+    if options.inputs.is_empty() {
+        return Err(format!(
+            "Usage: {} [--lex|--parse|--llvm|--codegen] [-E [-P]|-S|-c] [-o <output>] [-MD|-MMD [-MF <file>] [-MT <target>]] [-I <dir>] [-D <macro>[=<value>]] \
+             [-L <dir>] [-l <library>] [-O<level>] [--passes=[+|-]<pass>,...] [-masm=att|intel] [-m[no-]red-zone] [-fno-integrated-as] [--linker=system|builtin] [--target=x86_64-linux-gnu|aarch64-linux-gnu|riscv64] [--regalloc=linear|graph|spill-all] [--verify-ir] [--from-llvm] [--diagnostics-format=text|json|sarif] <input_file>...",
+            args[0]
+        ));
+    }
+    if options.compile.target != TargetArchitecture::X86_64 {
+        if options.compile.assembly_syntax == AssemblySyntax::Intel {
+            return Err("Intel syntax is only available for x86-64".to_string());
+        }
+        if options.linker == Linker::Builtin {
+            return Err("The built-in linker only supports x86-64".to_string());
+        }
+    }
+    if options.output.is_some() && options.inputs.len() > 1 && options.mode != DriverMode::Link {
+        return Err("Cannot specify -o with -c, -S or -E with multiple files".to_string());
+    }
+
+    Ok(Some(options))
+}
+
+fn is_ignored_flag(arg: &str) -> bool {
+    arg.starts_with("-g")
+        || arg.starts_with("-W")
+        || arg.starts_with("-f")
+        || arg.starts_with("-std=")
+        || arg.starts_with("-pedantic")
+        || matches!(arg, "-w" | "-m64" | "-pipe")
+}
+
+// Size optimizations are treated as -O2, there is no separate size-oriented pipeline
+fn parse_optimization_level(level: &str) -> Result<u8, String> {
+    match level {
+        "0" => Ok(0),
+        "" | "1" => Ok(1),
+        "2" | "3" | "s" | "z" | "fast" => Ok(2),
+        _ => Err(format!("Unknown optimization level: -O{}", level)),
+    }
+}
+
+// Each pass is enabled by its name, optionally prefixed with +, and disabled by its name prefixed with -
+fn parse_pass_toggles(list: &str) -> Result<Vec<(Pass, bool)>, String> {
+    list.split(',')
+        .map(|toggle| {
+            let (name, enabled) = match toggle.strip_prefix('-') {
+                Some(name) => (name, false),
+                None => (toggle.strip_prefix('+').unwrap_or(toggle), true),
+            };
+            Pass::from_name(name).map(|pass| (pass, enabled)).ok_or_else(|| format!("Unknown pass: {}", name))
+        })
+        .collect()
+}
+
+fn parse_diagnostics_format(format: &str) -> Result<DiagnosticsFormat, String> {
+    match format {
+        "text" => Ok(DiagnosticsFormat::Text),
+        "json" => Ok(DiagnosticsFormat::Json),
+        "sarif" => Ok(DiagnosticsFormat::Sarif),
+        _ => Err(format!("Unknown diagnostics format: {}", format)),
+    }
+}
+
+// Usage errors should still honor the requested format when it can be recovered
+fn requested_diagnostics_format(args: &[String]) -> DiagnosticsFormat {
+    args.iter()
+        .rev()
+        .filter_map(|arg| arg.strip_prefix("--diagnostics-format="))
+        .find_map(|format| parse_diagnostics_format(format).ok())
+        .unwrap_or(DiagnosticsFormat::Text)
+}
+
+// With --from-llvm, everything but assembly and objects is read as textual LLVM IR
+fn input_kind(path: &Path, from_llvm: bool) -> InputKind {
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("s") => InputKind::Assembly,
+        Some("o" | "a" | "so") => InputKind::LinkerInput,
+        _ if from_llvm => InputKind::LLVMIr,
+        Some("c") => InputKind::C,
+        Some("i") => InputKind::Preprocessed,
+        _ => InputKind::LinkerInput,
+    }
+}
+
+//
+// Compilation
+//
+
+struct Driver<'a> {
+    options: &'a Options,
+    reported: &'a mut Vec<Diagnostic>,
+    temp_dir: PathBuf,
+    temp_count: usize,
+    linker_arguments: Vec<LinkerArgument>,
+    compiler_include_dir: Option<Option<PathBuf>>,
+}
+
+impl<'a> Driver<'a> {
+    fn new(options: &'a Options, reported: &'a mut Vec<Diagnostic>) -> Result<Self, Diagnostic> {
+        let temp_dir = env::temp_dir().join(format!("britten-{}", process::id()));
+        fs::create_dir_all(&temp_dir)
+            .map_err(|e| Diagnostic::error(diagnostics::DRIVER_IO, format!("Failed to create temporary directory: {}", e)))?;
+        Ok(Driver { options, reported, temp_dir, temp_count: 0, linker_arguments: Vec::new(), compiler_include_dir: None })
+    }
+
+    fn run(&mut self) -> Result<(), Diagnostic> {
+        let options = self.options;
+        for (index, input) in options.inputs.iter().enumerate() {
+            self.push_libraries_before(index);
+            if let Err(diagnostic) = self.process_input(input) {
+                self.reported.push(diagnostic.in_file(&input.display().to_string()));
+            }
+        }
+        self.push_libraries_before(options.inputs.len());
+
+        if options.mode != DriverMode::Link || options.stage != CompilerStage::Compile {
+            return Ok(());
+        }
+        if self.reported.iter().any(Diagnostic::is_error) {
+            return Ok(());
+        }
+        self.link()
+    }
+
+    fn cleanup(&self) {
+        let _ = fs::remove_dir_all(&self.temp_dir);
+    }
+
+    fn push_libraries_before(&mut self, index: usize) {
+        let libraries = self.options.libraries.iter()
+            .filter(|(position, _)| *position == index)
+            .map(|(_, name)| LinkerArgument::Library(name.clone()));
+        self.linker_arguments.extend(libraries);
+    }
+
+    fn process_input(&mut self, input: &Path) -> Result<(), Diagnostic> {
+        if !input.exists() {
+            return Err(Diagnostic::error(diagnostics::DRIVER_IO, format!("Input file does not exist: {}", input.display())));
+        }
+
+        let kind = input_kind(input, self.options.from_llvm);
+        let source = match kind {
+            InputKind::C => {
+                let options = self.options;
+                let compiler_include_dir = self.compiler_include_dir();
+                let preprocessed = preprocessor::preprocess(
+                    input,
+                    &options.include_dirs,
+                    compiler_include_dir.as_deref(),
+                    &options.defines,
+                    &options.undefines,
+                    options.line_markers,
+                    options.compile.target,
+                )?;
+                self.reported.extend(preprocessed.warnings);
+                if self.options.mode == DriverMode::Preprocess {
+                    self.write_output(input, None, preprocessed.text.as_bytes())?;
+                } else {
+                    self.compile_source(input, kind, &preprocessed.text)?;
+                }
+                // Make must not see a rule for an output that was never produced
+                if options.dependencies != Dependencies::None {
+                    self.write_dependencies(input, &preprocessed.headers)?;
+                }
+                return Ok(());
+            }
+            InputKind::Preprocessed | InputKind::LLVMIr => fs::read_to_string(input)
+                .map_err(|e| Diagnostic::error(diagnostics::DRIVER_IO, format!("Failed to read input file: {}", e)))?,
+            InputKind::Assembly => return self.assemble_input(input),
+            InputKind::LinkerInput => {
+                if self.options.mode == DriverMode::Link {
+                    self.linker_arguments.push(LinkerArgument::File(input.to_path_buf()));
+                } else {
+                    self.reported.push(
+                        Diagnostic::warning(diagnostics::DRIVER_UNUSED_INPUT, "Linker input file unused because linking not done")
+                            .in_file(&input.display().to_string()),
+                    );
+                }
+                return Ok(());
+            }
+        };
+        if self.options.mode == DriverMode::Preprocess {
+            return self.write_output(input, None, source.as_bytes());
+        }
+        self.compile_source(input, kind, &source)
+    }
+
+    fn compile_source(&mut self, input: &Path, kind: InputKind, source: &str) -> Result<(), Diagnostic> {
+
+        // Object files are emitted directly unless the system assembler was requested, or the target has
+        // no integrated assembler
+        let native_object = self.options.stage == CompilerStage::Compile
+            && self.options.mode != DriverMode::Assemble
+            && self.options.integrated_assembler
+            && target::target(self.options.compile.target).has_integrated_assembler();
+        let stage = if native_object { CompilerStage::Assemble } else { self.options.stage };
+        let compile = if kind == InputKind::LLVMIr { process_ir_file } else { process_file };
+        let output = match compile(stage, &self.options.compile, source)? {
+            Some(output) => output,
+            None => return Ok(()),
+        };
+        if self.options.stage == CompilerStage::LLVMGen {
+            return self.write_output(input, Some("ll"), &output);
+        }
+
+        match self.options.mode {
+            DriverMode::Assemble => self.write_output(input, Some("s"), &output),
+            DriverMode::CompileOnly if native_object => self.write_output(input, Some("o"), &output),
+            DriverMode::CompileOnly => {
+                let assembly_file = self.temp_file(input, "s");
+                write_file(&assembly_file, &output)?;
+                self.assemble(&assembly_file, &self.output_path(input, "o"))
+            }
+            _ if native_object => {
+                let object_file = self.temp_file(input, "o");
+                write_file(&object_file, &output)?;
+                self.linker_arguments.push(LinkerArgument::File(object_file));
+                Ok(())
+            }
+            _ => {
+                let assembly_file = self.temp_file(input, "s");
+                write_file(&assembly_file, &output)?;
+                let object_file = self.temp_file(input, "o");
+                self.assemble(&assembly_file, &object_file)?;
+                self.linker_arguments.push(LinkerArgument::File(object_file));
+                Ok(())
+            }
+        }
+    }
+
+    fn assemble_input(&mut self, input: &Path) -> Result<(), Diagnostic> {
+        match self.options.mode {
+            DriverMode::Preprocess | DriverMode::Assemble => Ok(()),
+            DriverMode::CompileOnly => self.assemble(input, &self.output_path(input, "o")),
+            DriverMode::Link => {
+                let object_file = self.temp_file(input, "o");
+                self.assemble(input, &object_file)?;
+                self.linker_arguments.push(LinkerArgument::File(object_file));
+                Ok(())
+            }
+        }
+    }
+
+    // Without -o, outputs are written to the working directory like gcc does, preprocessed output goes to stdout
+    fn output_path(&self, input: &Path, extension: &str) -> PathBuf {
+        match &self.options.output {
+            Some(output) => output.clone(),
+            None => PathBuf::from(input.file_name().unwrap_or_default()).with_extension(extension),
+        }
+    }
+
+    fn write_output(&self, input: &Path, extension: Option<&str>, contents: &[u8]) -> Result<(), Diagnostic> {
+        match extension {
+            Some(extension) => write_file(&self.output_path(input, extension), contents),
+            None if self.options.output.is_some() => write_file(&self.output_path(input, "i"), contents),
+            None => io::stdout().write_all(contents)
+                .map_err(|e| Diagnostic::error(diagnostics::DRIVER_IO, format!("Failed to write output: {}", e))),
+        }
+    }
+
+    // The rule names the object file, or the -MT targets, and is written next to the object file with a .d
+    // extension unless -MF says otherwise
+    fn write_dependencies(&self, input: &Path, headers: &[(PathBuf, bool)]) -> Result<(), Diagnostic> {
+        let object = match (&self.options.output, self.options.mode) {
+            (Some(output), DriverMode::CompileOnly) => output.clone(),
+            _ => PathBuf::from(input.file_name().unwrap_or_default()).with_extension("o"),
+        };
+        let targets = match self.options.dependency_targets.is_empty() {
+            true => escape_make_path(&object),
+            false => self.options.dependency_targets.join(" "),
+        };
+        let user_headers_only = self.options.dependencies == Dependencies::UserHeaders;
+        let prerequisites = headers.iter()
+            .filter(|(_, system)| !(user_headers_only && *system))
+            .map(|(header, _)| escape_make_path(header));
+
+        let mut rule = format!("{}: {}", targets, escape_make_path(input));
+        for prerequisite in prerequisites {
+            rule.push_str(" \\\n  ");
+            rule.push_str(&prerequisite);
+        }
+        rule.push('\n');
+        let path = self.options.dependency_file.clone().unwrap_or_else(|| object.with_extension("d"));
+        write_file(&path, rule.as_bytes())
+    }
+
+    fn temp_file(&mut self, input: &Path, extension: &str) -> PathBuf {
+        self.temp_count += 1;
+        let stem = input.file_stem().and_then(|stem| stem.to_str()).unwrap_or("input");
+        self.temp_dir.join(format!("{}-{}.{}", stem, self.temp_count, extension))
+    }
+
+    //
+    // External tools
+    //
+
+    // Cross toolchains are installed with the target triple as prefix, as Debian and Fedora do
+    fn tool(&self, native: &str, cross: &str) -> String {
+        match target::target(self.options.compile.target).toolchain_prefix() {
+            Some(prefix) => format!("{}{}", prefix, cross),
+            None => native.to_string(),
+        }
+    }
+
+    // Freestanding headers such as stddef.h and stdarg.h come with the compiler rather than with libc, so they
+    // are taken from the gcc that links for the target. gcc prints the name back unchanged when it has none.
+    fn compiler_include_dir(&mut self) -> Option<PathBuf> {
+        if self.compiler_include_dir.is_none() {
+            let output = Command::new(self.tool("cc", "gcc")).arg("-print-file-name=include").output().ok();
+            let dir = output
+                .and_then(|output| String::from_utf8(output.stdout).ok())
+                .map(|stdout| PathBuf::from(stdout.trim()))
+                .filter(|dir| dir.is_absolute() && dir.is_dir());
+            self.compiler_include_dir = Some(dir);
+        }
+        self.compiler_include_dir.clone().flatten()
+    }
+
+    fn assemble(&self, input: &Path, output: &Path) -> Result<(), Diagnostic> {
+        let mut command = Command::new(self.tool("as", "as"));
+        command.arg(input).arg("-o").arg(output);
+        run_tool(command, "assembler").map(|_| ())
+    }
+
+    fn link(&self) -> Result<(), Diagnostic> {
+        match self.options.linker {
+            Linker::System => self.link_system(),
+            Linker::Builtin => self.link_builtin(),
+        }
+    }
+
+    // The system compiler driver knows where the C runtime startup files live, so it is used as the linker front-end
+    fn link_system(&self) -> Result<(), Diagnostic> {
+        let mut command = Command::new(self.tool("cc", "gcc"));
+        command.arg("-o").arg(self.options.output.clone().unwrap_or_else(|| PathBuf::from("a.out")));
+        command.args(self.options.library_dirs.iter().map(|dir| format!("-L{}", dir)));
+        for argument in &self.linker_arguments {
+            match argument {
+                LinkerArgument::File(path) => command.arg(path),
+                LinkerArgument::Library(name) => command.arg(format!("-l{}", name)),
+            };
+        }
+        run_tool(command, "linker").map(|_| ())
+    }
+
+    // The built-in linker only handles the objects britten itself produces, there is no C library to link against
+    fn link_builtin(&self) -> Result<(), Diagnostic> {
+        let mut objects = Vec::new();
+        for argument in &self.linker_arguments {
+            match argument {
+                LinkerArgument::File(path) => objects.push(fs::read(path).map_err(|e| {
+                    Diagnostic::error(diagnostics::DRIVER_IO, format!("Failed to read {}: {}", path.display(), e))
+                })?),
+                LinkerArgument::Library(name) => {
+                    return Err(Diagnostic::error(
+                        diagnostics::DRIVER_USAGE,
+                        format!("Libraries are not supported by the built-in linker: -l{}", name),
+                    ));
+                }
+            }
+        }
+
+        let executable = linker::link(&objects)
+            .map_err(|e| Diagnostic::error(diagnostics::LINK, format!("Linking failed: {}", e)))?;
+        let output = self.options.output.clone().unwrap_or_else(|| PathBuf::from("a.out"));
+        write_file(&output, &executable)?;
+        fs::set_permissions(&output, fs::Permissions::from_mode(0o755))
+            .map_err(|e| Diagnostic::error(diagnostics::DRIVER_IO, format!("Failed to make {} executable: {}", output.display(), e)))
+    }
+}
+
+fn write_file(path: &Path, contents: &[u8]) -> Result<(), Diagnostic> {
+    fs::write(path, contents)
+        .map_err(|e| Diagnostic::error(diagnostics::DRIVER_IO, format!("Failed to write {}: {}", path.display(), e)))
+}
+
+// Spaces separate paths in make rules and dollar signs start variable references
+fn escape_make_path(path: &Path) -> String {
+    path.display().to_string().replace(' ', "\\ ").replace('$', "$$")
+}
+
+fn run_tool(mut command: Command, description: &str) -> Result<String, Diagnostic> {
+    let program = command.get_program().to_string_lossy().into_owned();
+    let output = command.output().map_err(|e| {
+        Diagnostic::error(diagnostics::DRIVER_TOOLCHAIN, format!("Failed to run {} '{}': {}", description, program, e))
+    })?;
+
+    if !output.status.success() {
+        return Err(Diagnostic::error(
+            diagnostics::DRIVER_TOOLCHAIN,
+            format!("{} '{}' failed:\n{}", description, program, String::from_utf8_lossy(&output.stderr).trim_end()),
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
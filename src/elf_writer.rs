@@ -0,0 +1,283 @@
+//
+// Relocatable object model
+//
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum SectionKind {
+    Text,
+    Data,
+    Rodata,
+    Bss,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum SymbolKind {
+    Function,
+    Object,
+    NoType,
+}
+
+#[derive(Debug, Clone)]
+pub struct Symbol {
+    pub name: String,
+    pub kind: SymbolKind,
+    pub global: bool,
+    // Undefined symbols have no section and are resolved by the linker
+    pub section: Option<SectionKind>,
+    pub offset: u64,
+    pub size: u64,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum RelocationKind {
+    Pc32,
+    Plt32,
+}
+
+// Relocations patch the .text section
+#[derive(Debug, Clone)]
+pub struct Relocation {
+    pub offset: u64,
+    pub symbol: String,
+    pub kind: RelocationKind,
+    pub addend: i64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ObjectFile {
+    pub text: Vec<u8>,
+    pub data: Vec<u8>,
+    pub rodata: Vec<u8>,
+    pub bss_size: u64,
+    pub symbols: Vec<Symbol>,
+    pub relocations: Vec<Relocation>,
+}
+
+//
+// ELF64 constants
+//
+
+const SHT_PROGBITS: u32 = 1;
+const SHT_SYMTAB: u32 = 2;
+const SHT_STRTAB: u32 = 3;
+const SHT_RELA: u32 = 4;
+const SHT_NOBITS: u32 = 8;
+
+const SHF_WRITE: u64 = 0x1;
+const SHF_ALLOC: u64 = 0x2;
+const SHF_EXECINSTR: u64 = 0x4;
+const SHF_INFO_LINK: u64 = 0x40;
+
+const STB_LOCAL: u8 = 0;
+const STB_GLOBAL: u8 = 1;
+const STT_NOTYPE: u8 = 0;
+const STT_OBJECT: u8 = 1;
+const STT_FUNC: u8 = 2;
+const STT_SECTION: u8 = 3;
+
+const R_X86_64_PC32: u64 = 2;
+const R_X86_64_PLT32: u64 = 4;
+
+// Section header indices, in the order sections are laid out
+const TEXT_INDEX: u16 = 1;
+const DATA_INDEX: u16 = 2;
+const RODATA_INDEX: u16 = 3;
+const BSS_INDEX: u16 = 4;
+const SYMTAB_INDEX: u16 = 7;
+const STRTAB_INDEX: u16 = 8;
+const SECTION_COUNT: u16 = 10;
+
+const ELF_HEADER_SIZE: usize = 64;
+const SECTION_HEADER_SIZE: usize = 64;
+const SYMBOL_SIZE: usize = 24;
+const RELA_SIZE: usize = 24;
+
+struct SectionHeader {
+    name: u32,
+    kind: u32,
+    flags: u64,
+    offset: u64,
+    size: u64,
+    link: u32,
+    info: u32,
+    align: u64,
+    entry_size: u64,
+}
+
+struct StringTable {
+    bytes: Vec<u8>,
+}
+
+impl StringTable {
+    fn new() -> Self {
+        StringTable { bytes: vec![0] }
+    }
+
+    fn add(&mut self, name: &str) -> u32 {
+        let offset = self.bytes.len() as u32;
+        self.bytes.extend_from_slice(name.as_bytes());
+        self.bytes.push(0);
+        offset
+    }
+}
+
+fn section_index(section: SectionKind) -> u16 {
+    match section {
+        SectionKind::Text => TEXT_INDEX,
+        SectionKind::Data => DATA_INDEX,
+        SectionKind::Rodata => RODATA_INDEX,
+        SectionKind::Bss => BSS_INDEX,
+    }
+}
+
+//
+// Serialization
+//
+
+pub fn write(object: &ObjectFile) -> Result<Vec<u8>, String> {
+    // Local symbols must precede global ones in the symbol table, the first four entries after the null
+    // symbol are the section symbols
+    let mut symbol_order = object.symbols.iter().filter(|symbol| !symbol.global).collect::<Vec<_>>();
+    let first_global = 5 + symbol_order.len();
+    symbol_order.extend(object.symbols.iter().filter(|symbol| symbol.global));
+
+    let mut strtab = StringTable::new();
+    let mut symtab = vec![0u8; SYMBOL_SIZE];
+    for section in [TEXT_INDEX, DATA_INDEX, RODATA_INDEX, BSS_INDEX] {
+        write_symbol(&mut symtab, 0, STB_LOCAL, STT_SECTION, section, 0, 0);
+    }
+    for symbol in &symbol_order {
+        let name = strtab.add(&symbol.name);
+        let kind = match symbol.kind {
+            SymbolKind::Function => STT_FUNC,
+            SymbolKind::Object => STT_OBJECT,
+            SymbolKind::NoType => STT_NOTYPE,
+        };
+        let bind = if symbol.global { STB_GLOBAL } else { STB_LOCAL };
+        let section = symbol.section.map_or(0, section_index);
+        write_symbol(&mut symtab, name, bind, kind, section, symbol.offset, symbol.size);
+    }
+
+    let mut rela_text = Vec::new();
+    for relocation in &object.relocations {
+        let index = symbol_order.iter()
+            .position(|symbol| symbol.name == relocation.symbol)
+            .map(|position| position + 5)
+            .ok_or_else(|| format!("Relocation against unknown symbol {}", relocation.symbol))?;
+        let kind = match relocation.kind {
+            RelocationKind::Pc32 => R_X86_64_PC32,
+            RelocationKind::Plt32 => R_X86_64_PLT32,
+        };
+        rela_text.extend_from_slice(&relocation.offset.to_le_bytes());
+        rela_text.extend_from_slice(&(((index as u64) << 32) | kind).to_le_bytes());
+        rela_text.extend_from_slice(&relocation.addend.to_le_bytes());
+    }
+
+    let mut shstrtab = StringTable::new();
+    let names = [
+        "", ".text", ".data", ".rodata", ".bss", ".rela.text", ".note.GNU-stack", ".symtab", ".strtab", ".shstrtab",
+    ].map(|name| if name.is_empty() { 0 } else { shstrtab.add(name) });
+
+    // Section contents follow the ELF header, each aligned to 16 bytes
+    let mut file = vec![0u8; ELF_HEADER_SIZE];
+    let place = |file: &mut Vec<u8>, contents: &[u8]| -> u64 {
+        file.resize(file.len().next_multiple_of(16), 0);
+        let offset = file.len() as u64;
+        file.extend_from_slice(contents);
+        offset
+    };
+    let text_offset = place(&mut file, &object.text);
+    let data_offset = place(&mut file, &object.data);
+    let rodata_offset = place(&mut file, &object.rodata);
+    let rela_offset = place(&mut file, &rela_text);
+    let symtab_offset = place(&mut file, &symtab);
+    let strtab_offset = place(&mut file, &strtab.bytes);
+    let shstrtab_offset = place(&mut file, &shstrtab.bytes);
+    let note_offset = file.len() as u64;
+
+    let headers = [
+        SectionHeader { name: 0, kind: 0, flags: 0, offset: 0, size: 0, link: 0, info: 0, align: 0, entry_size: 0 },
+        SectionHeader {
+            name: names[1], kind: SHT_PROGBITS, flags: SHF_ALLOC | SHF_EXECINSTR, offset: text_offset,
+            size: object.text.len() as u64, link: 0, info: 0, align: 16, entry_size: 0,
+        },
+        SectionHeader {
+            name: names[2], kind: SHT_PROGBITS, flags: SHF_ALLOC | SHF_WRITE, offset: data_offset,
+            size: object.data.len() as u64, link: 0, info: 0, align: 8, entry_size: 0,
+        },
+        SectionHeader {
+            name: names[3], kind: SHT_PROGBITS, flags: SHF_ALLOC, offset: rodata_offset,
+            size: object.rodata.len() as u64, link: 0, info: 0, align: 16, entry_size: 0,
+        },
+        SectionHeader {
+            name: names[4], kind: SHT_NOBITS, flags: SHF_ALLOC | SHF_WRITE, offset: rodata_offset,
+            size: object.bss_size, link: 0, info: 0, align: 16, entry_size: 0,
+        },
+        SectionHeader {
+            name: names[5], kind: SHT_RELA, flags: SHF_INFO_LINK, offset: rela_offset,
+            size: rela_text.len() as u64, link: SYMTAB_INDEX as u32, info: TEXT_INDEX as u32, align: 8,
+            entry_size: RELA_SIZE as u64,
+        },
+        // An empty .note.GNU-stack marks the object as not requiring an executable stack
+        SectionHeader {
+            name: names[6], kind: SHT_PROGBITS, flags: 0, offset: note_offset,
+            size: 0, link: 0, info: 0, align: 1, entry_size: 0,
+        },
+        SectionHeader {
+            name: names[7], kind: SHT_SYMTAB, flags: 0, offset: symtab_offset,
+            size: symtab.len() as u64, link: STRTAB_INDEX as u32, info: first_global as u32, align: 8,
+            entry_size: SYMBOL_SIZE as u64,
+        },
+        SectionHeader {
+            name: names[8], kind: SHT_STRTAB, flags: 0, offset: strtab_offset,
+            size: strtab.bytes.len() as u64, link: 0, info: 0, align: 1, entry_size: 0,
+        },
+        SectionHeader {
+            name: names[9], kind: SHT_STRTAB, flags: 0, offset: shstrtab_offset,
+            size: shstrtab.bytes.len() as u64, link: 0, info: 0, align: 1, entry_size: 0,
+        },
+    ];
+
+    file.resize(file.len().next_multiple_of(8), 0);
+    let section_headers_offset = file.len() as u64;
+    for header in &headers {
+        file.extend_from_slice(&header.name.to_le_bytes());
+        file.extend_from_slice(&header.kind.to_le_bytes());
+        file.extend_from_slice(&header.flags.to_le_bytes());
+        file.extend_from_slice(&0u64.to_le_bytes());
+        file.extend_from_slice(&header.offset.to_le_bytes());
+        file.extend_from_slice(&header.size.to_le_bytes());
+        file.extend_from_slice(&header.link.to_le_bytes());
+        file.extend_from_slice(&header.info.to_le_bytes());
+        file.extend_from_slice(&header.align.to_le_bytes());
+        file.extend_from_slice(&header.entry_size.to_le_bytes());
+    }
+
+    let mut header = Vec::with_capacity(ELF_HEADER_SIZE);
+    header.extend_from_slice(&[0x7f, b'E', b'L', b'F', 2, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    header.extend_from_slice(&1u16.to_le_bytes()); // ET_REL
+    header.extend_from_slice(&62u16.to_le_bytes()); // EM_X86_64
+    header.extend_from_slice(&1u32.to_le_bytes());
+    header.extend_from_slice(&0u64.to_le_bytes());
+    header.extend_from_slice(&0u64.to_le_bytes());
+    header.extend_from_slice(&section_headers_offset.to_le_bytes());
+    header.extend_from_slice(&0u32.to_le_bytes());
+    header.extend_from_slice(&(ELF_HEADER_SIZE as u16).to_le_bytes());
+    header.extend_from_slice(&0u16.to_le_bytes());
+    header.extend_from_slice(&0u16.to_le_bytes());
+    header.extend_from_slice(&(SECTION_HEADER_SIZE as u16).to_le_bytes());
+    header.extend_from_slice(&SECTION_COUNT.to_le_bytes());
+    header.extend_from_slice(&(SECTION_COUNT - 1).to_le_bytes());
+    file[..ELF_HEADER_SIZE].copy_from_slice(&header);
+
+    Ok(file)
+}
+
+fn write_symbol(symtab: &mut Vec<u8>, name: u32, bind: u8, kind: u8, section: u16, value: u64, size: u64) {
+    symtab.extend_from_slice(&name.to_le_bytes());
+    symtab.push((bind << 4) | kind);
+    symtab.push(0);
+    symtab.extend_from_slice(&section.to_le_bytes());
+    symtab.extend_from_slice(&value.to_le_bytes());
+    symtab.extend_from_slice(&size.to_le_bytes());
+}
@@ -0,0 +1,258 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::llvm_ir::{
+    Inlining, LLVMBasicBlock, LLVMConstruct, LLVMFunction, LLVMInstruction, LLVMValue, Linkage, ValueId,
+};
+
+// Replaces calls to small functions defined in the same module with a copy of their body. Recursive
+// functions are never inlined, since their body always contains another call to inline.
+pub fn run(module: &mut LLVMConstruct) -> bool {
+    let LLVMConstruct::Module { functions, .. } = module;
+
+    // Callees are inlined as they were before this run, so a body never grows while being copied
+    let definitions = functions.iter()
+        .filter(|function| !function.is_declaration())
+        .map(|function| {
+            let LLVMFunction::Function { name, .. } = function;
+            (name.clone(), function.clone())
+        })
+        .collect::<HashMap<_, _>>();
+    let recursive = recursive_functions(functions);
+    let call_sites = call_counts(functions);
+
+    let mut inlined = false;
+    for caller in functions.iter_mut() {
+        let LLVMFunction::Function { name: caller_name, .. } = caller;
+        let caller_name = caller_name.clone();
+        while let Some((block, position, callee)) = find_inlinable_call(caller, |callee| {
+            let function = definitions.get(callee)?;
+            let candidate = callee != caller_name
+                && !recursive.contains(callee)
+                && should_inline(function, call_sites.get(callee).copied().unwrap_or(0));
+            candidate.then_some(function)
+        }) {
+            inline_call(caller, block, position, callee);
+            inlined = true;
+        }
+    }
+
+    // Internal functions are only reachable through calls in the module, once they are all inlined the
+    // function can go
+    if inlined {
+        let called = call_counts(functions);
+        functions.retain(|LLVMFunction::Function { name, linkage, .. }| *linkage == Linkage::External || called.contains_key(name));
+    }
+    inlined
+}
+
+//
+// Cost model
+//
+
+// Instructions a function may have to be inlined by default, and when it is declared inline. Inlining
+// saves the call sequence, argument moves and prologue, which is worth a few instructions of growth.
+const INLINE_THRESHOLD: usize = 12;
+const INLINE_HINT_THRESHOLD: usize = 40;
+
+fn should_inline(callee: &LLVMFunction, call_sites: usize) -> bool {
+    let LLVMFunction::Function { linkage, inlining, .. } = callee;
+    match inlining {
+        Inlining::Never => false,
+        Inlining::Always => true,
+        // An internal function called once disappears once inlined, so the code does not grow at all
+        _ if *linkage == Linkage::Internal && call_sites == 1 => true,
+        Inlining::Hint => cost(callee) <= INLINE_HINT_THRESHOLD,
+        Inlining::Default => cost(callee) <= INLINE_THRESHOLD,
+    }
+}
+
+// Phis and branches mostly turn into nothing once the body is merged into the caller, calls are expensive
+// enough that a body full of them is rarely worth copying
+fn cost(function: &LLVMFunction) -> usize {
+    let LLVMFunction::Function { blocks, .. } = function;
+    blocks.iter()
+        .flat_map(|block| &block.instructions)
+        .map(|instruction| match instruction {
+            LLVMInstruction::Phi(..) | LLVMInstruction::Branch(_) | LLVMInstruction::Return | LLVMInstruction::ReturnValue(..) => 0,
+            LLVMInstruction::Call(..) => 5,
+            _ => 1,
+        })
+        .sum()
+}
+
+//
+// Call graph
+//
+
+fn callees(function: &LLVMFunction) -> impl Iterator<Item = &str> {
+    let LLVMFunction::Function { blocks, .. } = function;
+    blocks.iter().flat_map(|block| &block.instructions).filter_map(|instruction| match instruction {
+        LLVMInstruction::Call(_, _, callee, _) => Some(callee.as_str()),
+        _ => None,
+    })
+}
+
+fn call_counts(functions: &[LLVMFunction]) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
+    for callee in functions.iter().flat_map(callees) {
+        *counts.entry(callee.to_string()).or_insert(0) += 1;
+    }
+    counts
+}
+
+// Functions that can reach themselves through calls
+fn recursive_functions(functions: &[LLVMFunction]) -> HashSet<String> {
+    let graph = functions.iter()
+        .map(|function| {
+            let LLVMFunction::Function { name, .. } = function;
+            (name.as_str(), callees(function).collect::<HashSet<_>>())
+        })
+        .collect::<HashMap<_, _>>();
+
+    let mut recursive = HashSet::new();
+    for &start in graph.keys() {
+        let mut visited = HashSet::new();
+        let mut worklist = graph[start].iter().copied().collect::<Vec<_>>();
+        while let Some(function) = worklist.pop() {
+            if function == start {
+                recursive.insert(start.to_string());
+                break;
+            }
+            if visited.insert(function) {
+                worklist.extend(graph.get(function).into_iter().flatten());
+            }
+        }
+    }
+    recursive
+}
+
+//
+// Inlining
+//
+
+fn find_inlinable_call<'a>(
+    caller: &LLVMFunction,
+    inlinable: impl Fn(&str) -> Option<&'a LLVMFunction>,
+) -> Option<(usize, usize, &'a LLVMFunction)> {
+    let LLVMFunction::Function { blocks, .. } = caller;
+    blocks.iter().enumerate().find_map(|(block_index, block)| {
+        block.instructions.iter().enumerate().find_map(|(position, instruction)| match instruction {
+            LLVMInstruction::Call(_, _, callee, _) => inlinable(callee).map(|callee| (block_index, position, callee)),
+            _ => None,
+        })
+    })
+}
+
+// The block holding the call is split after the call: the callee's blocks go in between, and every return
+// becomes a branch to the second half, where a phi merges the returned values
+fn inline_call(caller: &mut LLVMFunction, block_index: usize, position: usize, callee: &LLVMFunction) {
+    let value_offset = caller.next_value_id().0;
+    let LLVMFunction::Function { name: callee_name, params, blocks: callee_blocks, .. } = callee;
+    let LLVMFunction::Function { blocks, .. } = caller;
+
+    let labels = unique_labels(blocks, callee_name, callee_blocks);
+    let continuation_label = labels[callee_blocks.len()].clone();
+    let label_map = callee_blocks.iter()
+        .map(|block| block.label.as_str())
+        .zip(labels.iter().cloned())
+        .collect::<HashMap<_, _>>();
+
+    let tail = blocks[block_index].instructions.split_off(position + 1);
+    let Some(LLVMInstruction::Call(result, return_type, _, args)) = blocks[block_index].instructions.pop() else {
+        return;
+    };
+    let original_label = blocks[block_index].label.clone();
+    blocks[block_index].instructions.push(LLVMInstruction::Branch(labels[0].clone()));
+
+    // Successors of the original block are now reached from the second half
+    for instruction in blocks.iter_mut().flat_map(|block| &mut block.instructions) {
+        if let LLVMInstruction::Phi(_, _, incoming) = instruction {
+            for (_, from) in incoming.iter_mut().filter(|(_, from)| *from == original_label) {
+                *from = continuation_label.clone();
+            }
+        }
+    }
+
+    // Callee values are shifted past the caller's, parameters are replaced by the arguments
+    let mut replacements = params.iter()
+        .map(|(id, _)| ValueId(id.0 + value_offset))
+        .zip(args.into_iter().map(|(_, value)| value))
+        .collect::<HashMap<_, _>>();
+    let mut returned = Vec::new();
+    let mut allocas = Vec::new();
+    let mut inlined_blocks = Vec::new();
+    for (index, block) in callee_blocks.iter().enumerate() {
+        let label = label_map[block.label.as_str()].clone();
+        let mut instructions = Vec::new();
+        for instruction in &block.instructions {
+            let mut instruction = instruction.clone();
+            if let Some(dst) = instruction.result_mut() {
+                *dst = ValueId(dst.0 + value_offset);
+            }
+            for operand in instruction.operands_mut() {
+                if let LLVMValue::Register(id) = operand {
+                    *id = ValueId(id.0 + value_offset);
+                }
+            }
+            match &mut instruction {
+                LLVMInstruction::ReturnValue(_, value) => {
+                    returned.push((value.clone(), label.clone()));
+                    instruction = LLVMInstruction::Branch(continuation_label.clone());
+                }
+                LLVMInstruction::Return => instruction = LLVMInstruction::Branch(continuation_label.clone()),
+                LLVMInstruction::Branch(target) => *target = label_map[target.as_str()].clone(),
+                LLVMInstruction::CondBranch(_, then_target, else_target) => {
+                    *then_target = label_map[then_target.as_str()].clone();
+                    *else_target = label_map[else_target.as_str()].clone();
+                }
+                LLVMInstruction::Phi(_, _, incoming) => {
+                    for (_, from) in incoming.iter_mut() {
+                        *from = label_map[from.as_str()].clone();
+                    }
+                }
+                _ => {}
+            }
+            // Stack slots of the callee's entry block are static, they belong in the caller's entry block
+            match instruction {
+                LLVMInstruction::Alloca(..) if index == 0 => allocas.push(instruction),
+                other => instructions.push(other),
+            }
+        }
+        inlined_blocks.push(LLVMBasicBlock { label, instructions });
+    }
+
+    let mut continuation = LLVMBasicBlock { label: continuation_label, instructions: tail };
+    if let Some(dst) = result {
+        match returned.as_slice() {
+            [(value, _)] => {
+                replacements.insert(dst, value.clone());
+            }
+            // A callee that never returns leaves the result undefined
+            [] => {
+                replacements.insert(dst, LLVMValue::Immediate(0));
+            }
+            _ => continuation.instructions.insert(0, LLVMInstruction::Phi(dst, return_type, returned)),
+        }
+    }
+
+    inlined_blocks.push(continuation);
+    blocks.splice(block_index + 1..block_index + 1, inlined_blocks);
+    blocks[0].instructions.splice(0..0, allocas);
+    caller.replace_uses(&replacements);
+}
+
+// Labels for the callee's blocks, numbered after the first instance of the callee that clashes with no
+// label of the caller, followed by the label of the second half of the split block. Having one part less
+// than the others, the latter cannot be confused with any of them.
+fn unique_labels(blocks: &[LLVMBasicBlock], callee: &str, callee_blocks: &[LLVMBasicBlock]) -> Vec<String> {
+    let existing = blocks.iter().map(|block| block.label.as_str()).collect::<HashSet<_>>();
+    (1..)
+        .map(|instance| {
+            callee_blocks.iter()
+                .map(|block| format!("{}.{}.{}", callee, instance, block.label))
+                .chain([format!("{}.{}", callee, instance)])
+                .collect::<Vec<_>>()
+        })
+        .find(|labels| labels.iter().all(|label| !existing.contains(label.as_str())))
+        .unwrap_or_default()
+}
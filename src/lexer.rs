@@ -1,5 +1,9 @@
 use regex::Regex;
+use std::rc::Rc;
 use std::str::FromStr;
+use std::sync::LazyLock;
+
+use crate::diagnostics::{self, Diagnostic, SourcePosition, SourceRange};
 
 //
 // Tokens
@@ -20,7 +24,8 @@ pub enum Token {
     Addition,
     Multiplication,
     Division,
-    Remainder
+    Remainder,
+    Comma,
 }
 
 //
@@ -32,6 +37,9 @@ pub enum Keyword {
     Int,
     Void,
     Return,
+    Static,
+    Inline,
+    Attribute,
 }
 
 impl FromStr for Keyword {
@@ -42,6 +50,9 @@ impl FromStr for Keyword {
             "int" => Ok(Keyword::Int),
             "void" => Ok(Keyword::Void),
             "return" => Ok(Keyword::Return),
+            "static" => Ok(Keyword::Static),
+            "inline" => Ok(Keyword::Inline),
+            "__attribute__" => Ok(Keyword::Attribute),
             _ => Err(()),
         }
     }
@@ -51,26 +62,13 @@ impl FromStr for Keyword {
 // Tokenization logic
 //
 
-pub fn tokenize(input: &str) -> Result<Vec<Token>, String> {
-    let mut tokens = Vec::new();
-    let mut remaining = input.trim_start();
-
-    while !remaining.is_empty() {
-        match tokenize_next(remaining) {
-            Ok((token, rest)) => {
-                tokens.push(token);
-                remaining = rest.trim_start();
-            }
-            Err(e) => return Err(e),
-        }
-    }
-
-    Ok(tokens)
-}
+type Tokenizer = fn(&str) -> Result<Token, String>;
+type TokenPattern = (Regex, Tokenizer);
 
-// Order matters - if a token matches two expressions, we should use the longest match
-fn tokenize_next(input: &str) -> Result<(Token, &str), String> {
-    let token_patterns: Vec<(&str, fn(&str) -> Result<Token, String>)> = vec![
+// Order matters - if a token matches two expressions, we should use the longest match. The patterns are
+// compiled once, on first use.
+static TOKEN_PATTERNS: LazyLock<Result<Vec<TokenPattern>, regex::Error>> = LazyLock::new(|| {
+    let patterns: Vec<(&str, Tokenizer)> = vec![
         (r"^[a-zA-Z_]\w*\b", tokenize_identifier_or_keyword),
         (r"^[0-9]+\b", tokenize_constant),
         (r"^--", |_| Err("Decrement token not implemented yet".to_string())),
@@ -85,17 +83,78 @@ fn tokenize_next(input: &str) -> Result<(Token, &str), String> {
         (r"^\*", |_| Ok(Token::Multiplication)),
         (r"^/", |_| Ok(Token::Division)),
         (r"^%", |_| Ok(Token::Remainder)),
+        (r"^,", |_| Ok(Token::Comma)),
     ];
+    patterns.into_iter().map(|(pattern, tokenizer)| Ok((Regex::new(pattern)?, tokenizer))).collect()
+});
 
-    for (pattern, tokenizer) in token_patterns.iter() {
-        if let Some(cap) = Regex::new(pattern).map_err(|e| e.to_string())?.captures(input) {
+static LINE_MARKER: LazyLock<Result<Regex, regex::Error>> =
+    LazyLock::new(|| Regex::new(r#"^#(?:line)?[ \t]+([0-9]+)(?:[ \t]+"((?:[^"\\]|\\.)*)")?[^\n]*(?:\n|$)"#));
+
+pub fn tokenize(input: &str) -> Result<Vec<(Token, SourceRange)>, Diagnostic> {
+    // The patterns are built in, so failing to compile one is a bug in britten rather than in the input
+    let internal_error = |e: &regex::Error| Diagnostic::error(diagnostics::INTERNAL_ERROR, format!("Invalid built-in pattern: {}", e));
+    let token_patterns = TOKEN_PATTERNS.as_ref().map_err(internal_error)?;
+    let line_marker = LINE_MARKER.as_ref().map_err(internal_error)?;
+    let mut tokens = Vec::new();
+    let mut remaining = input.trim_start();
+    let mut position = SourcePosition::start().advance(&input[..input.len() - remaining.len()]);
+    let mut file = None;
+
+    while !remaining.is_empty() {
+        // Line markers left by the preprocessor map positions back to the original source files
+        if let Some((line, marker_file, rest)) = parse_line_marker(line_marker, remaining) {
+            if marker_file.is_some() {
+                file = marker_file;
+            }
+            remaining = rest.trim_start();
+            position = SourcePosition { line, column: 1 }.advance(&rest[..rest.len() - remaining.len()]);
+            continue;
+        }
+
+        let (token, rest) = tokenize_next(token_patterns, remaining, position, &file)?;
+        let end = position.advance(&remaining[..remaining.len() - rest.len()]);
+        tokens.push((token, SourceRange { file: file.clone(), start: position, end }));
+
+        remaining = rest.trim_start();
+        position = end.advance(&rest[..rest.len() - remaining.len()]);
+    }
+
+    Ok(tokens)
+}
+
+fn tokenize_next<'a>(
+    token_patterns: &[TokenPattern],
+    input: &'a str,
+    position: SourcePosition,
+    file: &Option<Rc<str>>,
+) -> Result<(Token, &'a str), Diagnostic> {
+    for (regex, tokenizer) in token_patterns {
+        if let Some(cap) = regex.captures(input) {
             let matched = cap.get(0).unwrap().as_str();
-            let token = tokenizer(matched)?;
+            let range = SourceRange { file: file.clone(), start: position, end: position.advance(matched) };
+            let token = tokenizer(matched).map_err(|e| {
+                let code = match matched.chars().next() {
+                    Some(c) if c.is_ascii_digit() => diagnostics::LEX_INVALID_CONSTANT,
+                    _ => diagnostics::LEX_UNSUPPORTED_TOKEN,
+                };
+                Diagnostic::error(code, e).with_range(range)
+            })?;
             return Ok((token, &input[matched.len()..]));
         }
     }
 
-    Err(format!("Unexpected token: {}", input))
+    let unexpected = input.chars().take_while(|c| !c.is_whitespace()).collect::<String>();
+    Err(Diagnostic::error(diagnostics::LEX_UNEXPECTED_CHARACTER, format!("Unexpected token: {}", unexpected))
+        .with_range(SourceRange { file: file.clone(), start: position, end: position.advance(&unexpected) }))
+}
+
+// Parses a '# <line> "<file>" <flags>' marker, returning the line number of the following line
+fn parse_line_marker<'a>(marker: &Regex, input: &'a str) -> Option<(usize, Option<Rc<str>>, &'a str)> {
+    let captures = marker.captures(input)?;
+    let line = captures.get(1)?.as_str().parse().ok()?;
+    let file = captures.get(2).map(|file| Rc::from(file.as_str().replace("\\\"", "\"").replace("\\\\", "\\")));
+    Some((line, file, &input[captures.get(0)?.end()..]))
 }
 
 fn tokenize_identifier_or_keyword(s: &str) -> Result<Token, String> {
@@ -0,0 +1,120 @@
+use std::collections::HashSet;
+
+use crate::cfg::ControlFlowGraph;
+use crate::dominators::DominatorTree;
+use crate::llvm_ir::{LLVMBasicBlock, LLVMBinaryOp, LLVMFunction, LLVMInstruction, LLVMValue, ValueId};
+use crate::loops::{self, NaturalLoop};
+
+// Loop-invariant code motion: instructions computing the same value on every iteration of a loop are
+// moved to its preheader, so that they run once before the loop. Inner loops go first, so code can move
+// out of a whole loop nest one level at a time.
+pub fn run(function: &mut LLVMFunction) -> bool {
+    let mut next_value = function.next_value_id().0;
+    let LLVMFunction::Function { blocks, .. } = function;
+
+    // Inserting a preheader shifts the blocks, so the analyses are redone after each loop that changes
+    let mut changed = false;
+    while hoist_from_first_loop(blocks, &mut next_value) {
+        changed = true;
+    }
+    changed
+}
+
+fn hoist_from_first_loop(blocks: &mut Vec<LLVMBasicBlock>, next_value: &mut usize) -> bool {
+    let cfg = ControlFlowGraph::new(blocks);
+    let dominators = DominatorTree::new(&cfg);
+    for natural_loop in loops::find_loops(&cfg, &dominators) {
+        let invariant = invariant_instructions(blocks, &cfg, &natural_loop);
+        if invariant.is_empty() {
+            continue;
+        }
+
+        let mut hoisted = Vec::new();
+        for (block, position) in invariant {
+            hoisted.push(blocks[block].instructions[position].clone());
+        }
+        let hoisted_ids = hoisted.iter().filter_map(|instruction| instruction.result()).map(|(id, _)| id).collect::<HashSet<_>>();
+        for block in &natural_loop.blocks {
+            blocks[*block].instructions.retain(|instruction| {
+                instruction.result().is_none_or(|(id, _)| !hoisted_ids.contains(&id))
+            });
+        }
+
+        let preheader = loops::insert_preheader(blocks, next_value, &cfg, &natural_loop);
+        let instructions = &mut blocks[preheader].instructions;
+        let terminator = instructions.len() - 1;
+        instructions.splice(terminator..terminator, hoisted);
+        return true;
+    }
+    false
+}
+
+// Block and position of the invariant instructions, in an order where each one comes after the invariant
+// instructions it uses
+fn invariant_instructions(blocks: &[LLVMBasicBlock], cfg: &ControlFlowGraph, natural_loop: &NaturalLoop) -> Vec<(usize, usize)> {
+    let loop_instructions = || natural_loop.blocks.iter().flat_map(|block| &blocks[*block].instructions);
+    let defined_in_loop = loop_instructions().filter_map(|instruction| instruction.result()).map(|(id, _)| id).collect::<HashSet<_>>();
+    let writes_memory = loop_instructions().any(|instruction| matches!(instruction, LLVMInstruction::Store(..) | LLVMInstruction::Call(..)));
+    let allocas = blocks.iter()
+        .flat_map(|block| &block.instructions)
+        .filter_map(|instruction| match instruction {
+            LLVMInstruction::Alloca(id, _) => Some(*id),
+            _ => None,
+        })
+        .collect::<HashSet<_>>();
+
+    // Walking the blocks in reverse postorder sees most definitions before their uses, repeating the walk
+    // catches the remaining ones
+    let order = cfg.reverse_postorder.iter().filter(|block| natural_loop.contains(**block)).collect::<Vec<_>>();
+    let mut invariant_values = HashSet::new();
+    let mut invariant = Vec::new();
+    let mut found = true;
+    while found {
+        found = false;
+        for &&block in &order {
+            for (position, instruction) in blocks[block].instructions.iter().enumerate() {
+                let Some((id, _)) = instruction.result() else {
+                    continue;
+                };
+                if invariant_values.contains(&id) || !can_hoist(instruction, writes_memory, &allocas) {
+                    continue;
+                }
+                let operands_invariant = instruction.operands().iter().all(|operand| match operand {
+                    LLVMValue::Register(operand) => !defined_in_loop.contains(operand) || invariant_values.contains(operand),
+                    LLVMValue::Immediate(_) | LLVMValue::Global(_) => true,
+                });
+                if operands_invariant {
+                    invariant_values.insert(id);
+                    invariant.push((block, position));
+                    found = true;
+                }
+            }
+        }
+    }
+    invariant
+}
+
+// Hoisted instructions run even when the loop body would not have, so they must not trap. Divisions are
+// only moved when the divisor is a constant that cannot fault, loads only from variables when nothing in
+// the loop may write to memory.
+fn can_hoist(instruction: &LLVMInstruction, writes_memory: bool, allocas: &HashSet<ValueId>) -> bool {
+    match instruction {
+        LLVMInstruction::BinaryOp(_, _, LLVMBinaryOp::Divide | LLVMBinaryOp::Remainder, _, divisor) => {
+            matches!(divisor, LLVMValue::Immediate(divisor) if *divisor != 0 && *divisor != -1)
+        }
+        LLVMInstruction::BinaryOp(_, _, LLVMBinaryOp::UnsignedDivide | LLVMBinaryOp::UnsignedRemainder, _, divisor) => {
+            matches!(divisor, LLVMValue::Immediate(divisor) if *divisor != 0)
+        }
+        LLVMInstruction::Load(_, _, pointer) => !writes_memory && match pointer {
+            LLVMValue::Register(id) => allocas.contains(id),
+            LLVMValue::Global(_) => true,
+            LLVMValue::Immediate(_) => false,
+        },
+        LLVMInstruction::UnaryOp(..)
+        | LLVMInstruction::BinaryOp(..)
+        | LLVMInstruction::ICmp(..)
+        | LLVMInstruction::ZExt(..)
+        | LLVMInstruction::Select(..) => true,
+        _ => false,
+    }
+}
@@ -0,0 +1,405 @@
+use std::collections::HashMap;
+
+use crate::elf_writer::{ObjectFile, Relocation, RelocationKind, SectionKind, Symbol, SymbolKind};
+
+//
+// ELF64 constants
+//
+
+const SHT_SYMTAB: u32 = 2;
+const SHT_RELA: u32 = 4;
+const SHT_NOBITS: u32 = 8;
+const SHF_ALLOC: u64 = 0x2;
+
+const SHN_UNDEF: u16 = 0;
+const STB_LOCAL: u8 = 0;
+const STT_OBJECT: u8 = 1;
+const STT_FUNC: u8 = 2;
+const STT_SECTION: u8 = 3;
+const STT_FILE: u8 = 4;
+
+const R_X86_64_PC32: u32 = 2;
+const R_X86_64_PLT32: u32 = 4;
+
+const PT_LOAD: u32 = 1;
+const PT_GNU_STACK: u32 = 0x6474e551;
+const PF_X: u32 = 0x1;
+const PF_W: u32 = 0x2;
+const PF_R: u32 = 0x4;
+
+const BASE_ADDRESS: u64 = 0x400000;
+const PAGE_SIZE: u64 = 0x1000;
+const ELF_HEADER_SIZE: u64 = 64;
+const PROGRAM_HEADER_SIZE: u64 = 56;
+
+// _start: xorl %ebp, %ebp; movl (%rsp), %edi; leaq 8(%rsp), %rsi; call main; movl %eax, %edi;
+// movl $231, %eax; syscall (exit_group)
+const START_STUB: [u8; 24] = [
+    0x31, 0xed, 0x8b, 0x3c, 0x24, 0x48, 0x8d, 0x74, 0x24, 0x08, 0xe8, 0x00, 0x00, 0x00, 0x00, 0x89, 0xc7, 0xb8,
+    0xe7, 0x00, 0x00, 0x00, 0x0f, 0x05,
+];
+const START_STUB_CALL_OFFSET: u64 = 11;
+
+struct ProgramHeader {
+    kind: u32,
+    flags: u32,
+    offset: u64,
+    address: u64,
+    file_size: u64,
+    memory_size: u64,
+    align: u64,
+}
+
+// Where each input's sections ended up in the output image
+struct Placement {
+    text: u64,
+    data: u64,
+    rodata: u64,
+    bss: u64,
+}
+
+//
+// Linking
+//
+
+// Links relocatable objects into a static executable whose entry point is a built-in _start calling main
+pub fn link(inputs: &[Vec<u8>]) -> Result<Vec<u8>, String> {
+    let mut objects = vec![start_object()];
+    for input in inputs {
+        objects.push(read(input)?);
+    }
+
+    // Concatenate each kind of section, keeping every input section 16-byte aligned
+    let mut text = Vec::new();
+    let mut data = Vec::new();
+    let mut rodata = Vec::new();
+    let mut bss_size = 0;
+    let mut placements = Vec::new();
+    for object in &objects {
+        text.resize(text.len().next_multiple_of(16), 0);
+        data.resize(data.len().next_multiple_of(16), 0);
+        rodata.resize(rodata.len().next_multiple_of(16), 0);
+        bss_size = u64::next_multiple_of(bss_size, 16);
+        placements.push(Placement {
+            text: text.len() as u64,
+            data: data.len() as u64,
+            rodata: rodata.len() as u64,
+            bss: bss_size,
+        });
+        text.extend_from_slice(&object.text);
+        data.extend_from_slice(&object.data);
+        rodata.extend_from_slice(&object.rodata);
+        bss_size += object.bss_size;
+    }
+
+    // Text and read-only data share the first segment with the headers, data and bss get their own page
+    let program_header_count = if data.is_empty() && bss_size == 0 { 2 } else { 3 };
+    let headers_size = ELF_HEADER_SIZE + program_header_count * PROGRAM_HEADER_SIZE;
+    let text_offset = headers_size.next_multiple_of(16);
+    let rodata_offset = (text_offset + text.len() as u64).next_multiple_of(16);
+    let code_end = rodata_offset + rodata.len() as u64;
+    let data_offset = code_end.next_multiple_of(PAGE_SIZE);
+    let bss_offset = (data_offset + data.len() as u64).next_multiple_of(16);
+    let section_address = |section: SectionKind, placement: &Placement| -> u64 {
+        BASE_ADDRESS + match section {
+            SectionKind::Text => text_offset + placement.text,
+            SectionKind::Data => data_offset + placement.data,
+            SectionKind::Rodata => rodata_offset + placement.rodata,
+            SectionKind::Bss => bss_offset + placement.bss,
+        }
+    };
+
+    let mut globals = HashMap::new();
+    for (object, placement) in objects.iter().zip(&placements) {
+        for symbol in object.symbols.iter().filter(|symbol| symbol.global) {
+            if let Some(section) = symbol.section {
+                let address = section_address(section, placement) + symbol.offset;
+                if globals.insert(symbol.name.clone(), address).is_some() {
+                    return Err(format!("Multiple definition of '{}'", symbol.name));
+                }
+            }
+        }
+    }
+
+    for (object, placement) in objects.iter().zip(&placements) {
+        for relocation in &object.relocations {
+            // References resolve to a definition in the same object first, then to a global one
+            let local = object.symbols.iter()
+                .filter(|symbol| symbol.name == relocation.symbol)
+                .find_map(|symbol| symbol.section.map(|section| section_address(section, placement) + symbol.offset));
+            let target = local
+                .or_else(|| globals.get(&relocation.symbol).copied())
+                .ok_or_else(|| format!("Undefined reference to '{}'", relocation.symbol))?;
+            let position = placement.text + relocation.offset;
+            let value = match relocation.kind {
+                RelocationKind::Pc32 | RelocationKind::Plt32 => {
+                    target as i64 + relocation.addend - (BASE_ADDRESS + text_offset + position) as i64
+                }
+            };
+            let value = i32::try_from(value)
+                .map_err(|_| format!("Relocation against '{}' is out of range", relocation.symbol))?;
+            let position = position as usize;
+            text[position..position + 4].copy_from_slice(&value.to_le_bytes());
+        }
+    }
+
+    let mut program_headers = vec![ProgramHeader {
+        kind: PT_LOAD,
+        flags: PF_R | PF_X,
+        offset: 0,
+        address: BASE_ADDRESS,
+        file_size: code_end,
+        memory_size: code_end,
+        align: PAGE_SIZE,
+    }];
+    if program_header_count == 3 {
+        program_headers.push(ProgramHeader {
+            kind: PT_LOAD,
+            flags: PF_R | PF_W,
+            offset: data_offset,
+            address: BASE_ADDRESS + data_offset,
+            file_size: data.len() as u64,
+            memory_size: bss_offset + bss_size - data_offset,
+            align: PAGE_SIZE,
+        });
+    }
+    // Marks the stack as non-executable
+    program_headers.push(ProgramHeader {
+        kind: PT_GNU_STACK,
+        flags: PF_R | PF_W,
+        offset: 0,
+        address: 0,
+        file_size: 0,
+        memory_size: 0,
+        align: 16,
+    });
+
+    let mut file = Vec::new();
+    file.extend_from_slice(&[0x7f, b'E', b'L', b'F', 2, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    file.extend_from_slice(&2u16.to_le_bytes()); // ET_EXEC
+    file.extend_from_slice(&62u16.to_le_bytes()); // EM_X86_64
+    file.extend_from_slice(&1u32.to_le_bytes());
+    file.extend_from_slice(&(BASE_ADDRESS + text_offset).to_le_bytes());
+    file.extend_from_slice(&ELF_HEADER_SIZE.to_le_bytes());
+    file.extend_from_slice(&0u64.to_le_bytes());
+    file.extend_from_slice(&0u32.to_le_bytes());
+    file.extend_from_slice(&(ELF_HEADER_SIZE as u16).to_le_bytes());
+    file.extend_from_slice(&(PROGRAM_HEADER_SIZE as u16).to_le_bytes());
+    file.extend_from_slice(&(program_headers.len() as u16).to_le_bytes());
+    file.extend_from_slice(&64u16.to_le_bytes());
+    file.extend_from_slice(&0u16.to_le_bytes());
+    file.extend_from_slice(&0u16.to_le_bytes());
+    for header in &program_headers {
+        file.extend_from_slice(&header.kind.to_le_bytes());
+        file.extend_from_slice(&header.flags.to_le_bytes());
+        file.extend_from_slice(&header.offset.to_le_bytes());
+        file.extend_from_slice(&header.address.to_le_bytes());
+        file.extend_from_slice(&header.address.to_le_bytes());
+        file.extend_from_slice(&header.file_size.to_le_bytes());
+        file.extend_from_slice(&header.memory_size.to_le_bytes());
+        file.extend_from_slice(&header.align.to_le_bytes());
+    }
+
+    for (offset, contents) in [(text_offset, &text), (rodata_offset, &rodata), (data_offset, &data)] {
+        file.resize(offset as usize, 0);
+        file.extend_from_slice(contents);
+    }
+    Ok(file)
+}
+
+fn start_object() -> ObjectFile {
+    ObjectFile {
+        text: START_STUB.to_vec(),
+        symbols: vec![
+            Symbol {
+                name: "_start".to_string(),
+                kind: SymbolKind::Function,
+                global: true,
+                section: Some(SectionKind::Text),
+                offset: 0,
+                size: START_STUB.len() as u64,
+            },
+            Symbol { name: "main".to_string(), kind: SymbolKind::NoType, global: true, section: None, offset: 0, size: 0 },
+        ],
+        relocations: vec![Relocation {
+            offset: START_STUB_CALL_OFFSET,
+            symbol: "main".to_string(),
+            kind: RelocationKind::Plt32,
+            addend: -4,
+        }],
+        ..ObjectFile::default()
+    }
+}
+
+//
+// Object file parsing
+//
+
+struct Section<'a> {
+    name: &'a str,
+    kind: u32,
+    flags: u64,
+    contents: &'a [u8],
+    size: u64,
+    link: u32,
+    info: u32,
+}
+
+pub fn read(bytes: &[u8]) -> Result<ObjectFile, String> {
+    if bytes.len() < ELF_HEADER_SIZE as usize || bytes[..4] != [0x7f, b'E', b'L', b'F'] {
+        return Err("Not an ELF file".to_string());
+    }
+    if bytes[4] != 2 || bytes[5] != 1 || u16_at(bytes, 16)? != 1 || u16_at(bytes, 18)? != 62 {
+        return Err("Not an x86-64 relocatable object file".to_string());
+    }
+
+    let section_headers_offset = u64_at(bytes, 40)? as usize;
+    let section_count = u16_at(bytes, 60)? as usize;
+    let names_index = u16_at(bytes, 62)? as usize;
+    let mut sections = Vec::with_capacity(section_count);
+    for index in 0..section_count {
+        let header = section_headers_offset + index * 64;
+        let kind = u32_at(bytes, header + 4)?;
+        let offset = u64_at(bytes, header + 24)? as usize;
+        let size = u64_at(bytes, header + 32)?;
+        let contents = match kind {
+            SHT_NOBITS => &[][..],
+            _ => bytes.get(offset..offset + size as usize).ok_or("Section extends past the end of the file")?,
+        };
+        sections.push((u32_at(bytes, header)?, Section {
+            name: "",
+            kind,
+            flags: u64_at(bytes, header + 8)?,
+            contents,
+            size,
+            link: u32_at(bytes, header + 40)?,
+            info: u32_at(bytes, header + 44)?,
+        }));
+    }
+    let section_names = sections.get(names_index).map(|(_, section)| section.contents).unwrap_or_default();
+    let mut sections = sections.into_iter()
+        .map(|(name, section)| Ok(Section { name: string_at(section_names, name as usize)?, ..section }))
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let mut object = ObjectFile::default();
+    let mut section_kinds = vec![None; sections.len()];
+    for (index, section) in sections.iter_mut().enumerate() {
+        if section.flags & SHF_ALLOC == 0 {
+            continue;
+        }
+        let kind = match section.name {
+            ".text" => SectionKind::Text,
+            ".data" => SectionKind::Data,
+            ".bss" => SectionKind::Bss,
+            name if name == ".rodata" || name.starts_with(".rodata.") => SectionKind::Rodata,
+            name => return Err(format!("Unsupported section {}", name)),
+        };
+        let destination = match kind {
+            SectionKind::Text => &mut object.text,
+            SectionKind::Data => &mut object.data,
+            SectionKind::Rodata => &mut object.rodata,
+            SectionKind::Bss => {
+                object.bss_size = object.bss_size.next_multiple_of(16);
+                section_kinds[index] = Some((kind, object.bss_size));
+                object.bss_size += section.size;
+                continue;
+            }
+        };
+        if kind == SectionKind::Text && !destination.is_empty() {
+            return Err("Multiple .text sections are not supported".to_string());
+        }
+        destination.resize(destination.len().next_multiple_of(16), 0);
+        section_kinds[index] = Some((kind, destination.len() as u64));
+        destination.extend_from_slice(section.contents);
+    }
+
+    let symtab = sections.iter().position(|section| section.kind == SHT_SYMTAB);
+    let mut symbol_names = Vec::new();
+    if let Some(symtab) = symtab {
+        let strtab = sections.get(sections[symtab].link as usize).map(|section| section.contents).unwrap_or_default();
+        for entry in sections[symtab].contents.chunks_exact(24) {
+            let info = entry[4];
+            let section_index = u16_at(entry, 6)?;
+            let kind = info & 0xf;
+            // Section symbols are named after their section so relocations against them can be resolved by name
+            let name = match kind {
+                STT_SECTION => sections.get(section_index as usize).map_or("", |section| section.name),
+                _ => string_at(strtab, u32_at(entry, 0)? as usize)?,
+            };
+            symbol_names.push(name.to_string());
+            if name.is_empty() || kind == STT_FILE {
+                continue;
+            }
+
+            let section = match section_index {
+                SHN_UNDEF => None,
+                index => match section_kinds.get(index as usize) {
+                    Some(Some(section)) => Some(*section),
+                    Some(None) if kind == STT_SECTION => continue,
+                    _ => return Err(format!("Symbol {} is in an unsupported section", name)),
+                },
+            };
+            object.symbols.push(Symbol {
+                name: name.to_string(),
+                kind: match kind {
+                    STT_FUNC => SymbolKind::Function,
+                    STT_OBJECT => SymbolKind::Object,
+                    _ => SymbolKind::NoType,
+                },
+                global: info >> 4 != STB_LOCAL,
+                section: section.map(|(kind, _)| kind),
+                offset: section.map_or(0, |(_, base)| base) + u64_at(entry, 8)?,
+                size: u64_at(entry, 16)?,
+            });
+        }
+    }
+
+    for section in sections.iter().filter(|section| section.kind == SHT_RELA) {
+        match section_kinds.get(section.info as usize) {
+            Some(Some((SectionKind::Text, _))) => {}
+            Some(None) => continue,
+            _ => return Err(format!("Relocations in {} are not supported", section.name)),
+        }
+        for entry in section.contents.chunks_exact(24) {
+            let info = u64_at(entry, 8)?;
+            let kind = match info as u32 {
+                R_X86_64_PC32 => RelocationKind::Pc32,
+                R_X86_64_PLT32 => RelocationKind::Plt32,
+                kind => return Err(format!("Unsupported relocation type {}", kind)),
+            };
+            let symbol = symbol_names.get((info >> 32) as usize).ok_or("Relocation against an invalid symbol")?;
+            object.relocations.push(Relocation {
+                offset: u64_at(entry, 0)?,
+                symbol: symbol.clone(),
+                kind,
+                addend: u64_at(entry, 16)? as i64,
+            });
+        }
+    }
+
+    Ok(object)
+}
+
+fn u16_at(bytes: &[u8], offset: usize) -> Result<u16, String> {
+    bytes.get(offset..offset + 2)
+        .map(|slice| u16::from_le_bytes(slice.try_into().unwrap()))
+        .ok_or_else(|| "Truncated ELF file".to_string())
+}
+
+fn u32_at(bytes: &[u8], offset: usize) -> Result<u32, String> {
+    bytes.get(offset..offset + 4)
+        .map(|slice| u32::from_le_bytes(slice.try_into().unwrap()))
+        .ok_or_else(|| "Truncated ELF file".to_string())
+}
+
+fn u64_at(bytes: &[u8], offset: usize) -> Result<u64, String> {
+    bytes.get(offset..offset + 8)
+        .map(|slice| u64::from_le_bytes(slice.try_into().unwrap()))
+        .ok_or_else(|| "Truncated ELF file".to_string())
+}
+
+fn string_at(table: &[u8], offset: usize) -> Result<&str, String> {
+    let bytes = table.get(offset..).ok_or("Invalid string table offset")?;
+    let end = bytes.iter().position(|&byte| byte == 0).unwrap_or(bytes.len());
+    std::str::from_utf8(&bytes[..end]).map_err(|_| "Invalid symbol name".to_string())
+}
@@ -0,0 +1,789 @@
+use std::collections::HashMap;
+use std::fmt;
+
+//
+// IR model
+//
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum IRType {
+    Void,
+    I1,
+    I32,
+    Ptr,
+}
+
+// Values are numbered per function, in the order the builder creates them
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+pub struct ValueId(pub usize);
+
+#[derive(Debug, Clone)]
+pub enum LLVMConstruct {
+    Module {
+        globals: Vec<LLVMGlobal>,
+        functions: Vec<LLVMFunction>,
+    },
+}
+
+// Global variables are scalars with a constant initializer
+#[derive(Debug, Clone)]
+pub struct LLVMGlobal {
+    pub name: String,
+    pub ty: IRType,
+    pub initializer: i32,
+}
+
+// A function without blocks is a declaration of a function defined elsewhere
+#[derive(Debug, Clone)]
+pub enum LLVMFunction {
+    Function {
+        name: String,
+        linkage: Linkage,
+        inlining: Inlining,
+        return_type: IRType,
+        params: Vec<(ValueId, IRType)>,
+        blocks: Vec<LLVMBasicBlock>,
+    },
+}
+
+// Internal functions, `static` ones in C, are only visible to their translation unit
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Linkage {
+    External,
+    Internal,
+}
+
+// What the function asks of the inliner: `inline` in C is a hint, the noinline and always_inline
+// attributes are orders
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Inlining {
+    Default,
+    Hint,
+    Always,
+    Never,
+}
+
+#[derive(Debug, Clone)]
+pub struct LLVMBasicBlock {
+    pub label: String,
+    pub instructions: Vec<LLVMInstruction>,
+}
+
+// Memory instructions carry the type of the value stored in memory, pointers themselves are untyped
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub enum LLVMInstruction {
+    Return,
+    ReturnValue(IRType, LLVMValue),
+    Branch(String),
+    CondBranch(LLVMValue, String, String),
+    Store(IRType, LLVMValue, LLVMValue),
+    Load(ValueId, IRType, LLVMValue),
+    Alloca(ValueId, IRType),
+    UnaryOp(ValueId, IRType, LLVMUnaryOp, LLVMValue),
+    BinaryOp(ValueId, IRType, LLVMBinaryOp, LLVMValue, LLVMValue),
+    ICmp(ValueId, LLVMComparison, IRType, LLVMValue, LLVMValue),
+    // Source type, value and destination type
+    ZExt(ValueId, IRType, LLVMValue, IRType),
+    Select(ValueId, IRType, LLVMValue, LLVMValue, LLVMValue),
+    // Calls to void functions define no value
+    Call(Option<ValueId>, IRType, String, Vec<(IRType, LLVMValue)>),
+    // Incoming values are paired with the label of the predecessor they flow from
+    Phi(ValueId, IRType, Vec<(LLVMValue, String)>),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub enum LLVMUnaryOp {
+    Not,
+    Neg,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub enum LLVMBinaryOp {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Remainder,
+    UnsignedDivide,
+    UnsignedRemainder,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum LLVMComparison {
+    Equal,
+    NotEqual,
+    SignedGreater,
+    SignedGreaterOrEqual,
+    SignedLess,
+    SignedLessOrEqual,
+    UnsignedGreater,
+    UnsignedGreaterOrEqual,
+    UnsignedLess,
+    UnsignedLessOrEqual,
+}
+
+// Globals are pointers to their storage
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub enum LLVMValue {
+    Register(ValueId),
+    Immediate(i32),
+    Global(String),
+}
+
+impl IRType {
+    pub fn is_integer(self) -> bool {
+        matches!(self, IRType::I1 | IRType::I32)
+    }
+
+    pub fn bits(self) -> u32 {
+        match self {
+            IRType::Void => 0,
+            IRType::I1 => 1,
+            IRType::I32 => 32,
+            IRType::Ptr => 64,
+        }
+    }
+}
+
+impl LLVMInstruction {
+    // The value an instruction defines, along with its type
+    pub fn result(&self) -> Option<(ValueId, IRType)> {
+        match self {
+            LLVMInstruction::Load(dst, ty, _)
+            | LLVMInstruction::UnaryOp(dst, ty, _, _)
+            | LLVMInstruction::BinaryOp(dst, ty, _, _, _)
+            | LLVMInstruction::Select(dst, ty, _, _, _)
+            | LLVMInstruction::ZExt(dst, _, _, ty)
+            | LLVMInstruction::Call(Some(dst), ty, _, _)
+            | LLVMInstruction::Phi(dst, ty, _) => Some((*dst, *ty)),
+            LLVMInstruction::Alloca(dst, _) => Some((*dst, IRType::Ptr)),
+            LLVMInstruction::ICmp(dst, ..) => Some((*dst, IRType::I1)),
+            LLVMInstruction::Return
+            | LLVMInstruction::ReturnValue(..)
+            | LLVMInstruction::Branch(_)
+            | LLVMInstruction::CondBranch(..)
+            | LLVMInstruction::Call(None, ..)
+            | LLVMInstruction::Store(..) => None,
+        }
+    }
+
+    pub fn result_mut(&mut self) -> Option<&mut ValueId> {
+        match self {
+            LLVMInstruction::Load(dst, _, _)
+            | LLVMInstruction::UnaryOp(dst, _, _, _)
+            | LLVMInstruction::BinaryOp(dst, _, _, _, _)
+            | LLVMInstruction::ICmp(dst, ..)
+            | LLVMInstruction::ZExt(dst, ..)
+            | LLVMInstruction::Select(dst, ..)
+            | LLVMInstruction::Call(Some(dst), ..)
+            | LLVMInstruction::Phi(dst, _, _)
+            | LLVMInstruction::Alloca(dst, _) => Some(dst),
+            LLVMInstruction::Return
+            | LLVMInstruction::ReturnValue(..)
+            | LLVMInstruction::Branch(_)
+            | LLVMInstruction::CondBranch(..)
+            | LLVMInstruction::Call(None, ..)
+            | LLVMInstruction::Store(..) => None,
+        }
+    }
+
+    pub fn operands(&self) -> Vec<&LLVMValue> {
+        match self {
+            LLVMInstruction::ReturnValue(_, value)
+            | LLVMInstruction::CondBranch(value, _, _)
+            | LLVMInstruction::Load(_, _, value)
+            | LLVMInstruction::ZExt(_, _, value, _)
+            | LLVMInstruction::UnaryOp(_, _, _, value) => vec![value],
+            LLVMInstruction::Store(_, lhs, rhs)
+            | LLVMInstruction::BinaryOp(_, _, _, lhs, rhs)
+            | LLVMInstruction::ICmp(_, _, _, lhs, rhs) => vec![lhs, rhs],
+            LLVMInstruction::Select(_, _, condition, lhs, rhs) => vec![condition, lhs, rhs],
+            LLVMInstruction::Call(_, _, _, args) => args.iter().map(|(_, value)| value).collect(),
+            LLVMInstruction::Phi(_, _, incoming) => incoming.iter().map(|(value, _)| value).collect(),
+            LLVMInstruction::Return | LLVMInstruction::Branch(_) | LLVMInstruction::Alloca(..) => Vec::new(),
+        }
+    }
+
+    pub fn operands_mut(&mut self) -> Vec<&mut LLVMValue> {
+        match self {
+            LLVMInstruction::ReturnValue(_, value)
+            | LLVMInstruction::CondBranch(value, _, _)
+            | LLVMInstruction::Load(_, _, value)
+            | LLVMInstruction::ZExt(_, _, value, _)
+            | LLVMInstruction::UnaryOp(_, _, _, value) => vec![value],
+            LLVMInstruction::Store(_, lhs, rhs)
+            | LLVMInstruction::BinaryOp(_, _, _, lhs, rhs)
+            | LLVMInstruction::ICmp(_, _, _, lhs, rhs) => vec![lhs, rhs],
+            LLVMInstruction::Select(_, _, condition, lhs, rhs) => vec![condition, lhs, rhs],
+            LLVMInstruction::Call(_, _, _, args) => args.iter_mut().map(|(_, value)| value).collect(),
+            LLVMInstruction::Phi(_, _, incoming) => incoming.iter_mut().map(|(value, _)| value).collect(),
+            LLVMInstruction::Return | LLVMInstruction::Branch(_) | LLVMInstruction::Alloca(..) => Vec::new(),
+        }
+    }
+
+    pub fn is_terminator(&self) -> bool {
+        matches!(
+            self,
+            LLVMInstruction::Return | LLVMInstruction::ReturnValue(..) | LLVMInstruction::Branch(_) | LLVMInstruction::CondBranch(..)
+        )
+    }
+
+    // Labels of the blocks control may transfer to
+    pub fn successors(&self) -> Vec<&str> {
+        match self {
+            LLVMInstruction::Branch(target) => vec![target],
+            LLVMInstruction::CondBranch(_, then_target, else_target) => vec![then_target, else_target],
+            _ => Vec::new(),
+        }
+    }
+
+    pub fn successors_mut(&mut self) -> Vec<&mut String> {
+        match self {
+            LLVMInstruction::Branch(target) => vec![target],
+            LLVMInstruction::CondBranch(_, then_target, else_target) => vec![then_target, else_target],
+            _ => Vec::new(),
+        }
+    }
+}
+
+impl LLVMFunction {
+    pub fn is_declaration(&self) -> bool {
+        let LLVMFunction::Function { blocks, .. } = self;
+        blocks.is_empty()
+    }
+
+    // First value ID that is not used in the function, for passes that create values
+    pub fn next_value_id(&self) -> ValueId {
+        let LLVMFunction::Function { params, blocks, .. } = self;
+        let last = blocks.iter()
+            .flat_map(|block| &block.instructions)
+            .filter_map(|instruction| instruction.result())
+            .chain(params.iter().copied())
+            .map(|(id, _)| id.0)
+            .max();
+        ValueId(last.map_or(0, |id| id + 1))
+    }
+
+    // Rewrites every use of a replaced value, following chains of replacements
+    pub fn replace_uses(&mut self, replacements: &HashMap<ValueId, LLVMValue>) {
+        let LLVMFunction::Function { blocks, .. } = self;
+        for operand in blocks.iter_mut().flat_map(|block| &mut block.instructions).flat_map(|instruction| instruction.operands_mut()) {
+            while let LLVMValue::Register(id) = operand {
+                match replacements.get(id) {
+                    Some(replacement) => *operand = replacement.clone(),
+                    None => break,
+                }
+            }
+        }
+    }
+
+    // Textual IR requires unnamed values to be numbered in order of definition, without gaps
+    fn renumbered(&self) -> LLVMFunction {
+        let mut function = self.clone();
+        let LLVMFunction::Function { params, blocks, .. } = &mut function;
+        let mut numbers = HashMap::new();
+        let results = blocks.iter_mut()
+            .flat_map(|block| &mut block.instructions)
+            .filter_map(|instruction| instruction.result_mut());
+        for dst in params.iter_mut().map(|(id, _)| id).chain(results) {
+            let number = ValueId(numbers.len());
+            numbers.insert(*dst, number);
+            *dst = number;
+        }
+        for instruction in blocks.iter_mut().flat_map(|block| &mut block.instructions) {
+            for operand in instruction.operands_mut() {
+                if let LLVMValue::Register(id) = operand {
+                    *id = numbers[id];
+                }
+            }
+        }
+        function
+    }
+}
+
+//
+// IR builder
+//
+
+// Owns everything needed to generate a single function: its signature, value numbering, basic blocks and
+// named locals. Each function gets its own builder, so functions and modules can be generated independently.
+// Every instruction is type-checked as it is built and `finish` rejects unterminated blocks and phis that do
+// not match their predecessors, so IR built this way is well-formed except for dominance, which the verifier
+// checks. Passes that rewrite existing functions build instructions directly and rely on the verifier.
+pub struct IRBuilder {
+    return_type: IRType,
+    params: Vec<(ValueId, IRType)>,
+    value_types: Vec<IRType>,
+    blocks: Vec<LLVMBasicBlock>,
+    current_block: usize,
+    locals: HashMap<String, ValueId>,
+}
+
+impl IRBuilder {
+    // Parameters are the first values of the function, the insertion point starts in the entry block
+    pub fn new(return_type: IRType, param_types: &[IRType]) -> Result<Self, String> {
+        if let Some(ty) = param_types.iter().find(|ty| !ty.is_integer()) {
+            return Err(format!("Parameters must have an integer type, found {}", ty));
+        }
+        let params = param_types.iter().enumerate().map(|(index, ty)| (ValueId(index), *ty)).collect();
+        Ok(IRBuilder {
+            return_type,
+            params,
+            value_types: param_types.to_vec(),
+            blocks: vec![LLVMBasicBlock { label: "entry".to_string(), instructions: Vec::new() }],
+            current_block: 0,
+            locals: HashMap::new(),
+        })
+    }
+
+    #[allow(dead_code)]
+    pub fn param(&self, index: usize) -> Option<LLVMValue> {
+        self.params.get(index).map(|(id, _)| LLVMValue::Register(*id))
+    }
+
+    fn new_value(&mut self, ty: IRType) -> ValueId {
+        self.value_types.push(ty);
+        ValueId(self.value_types.len() - 1)
+    }
+
+    // Appends an empty block without moving the insertion point, labels are made unique within the function
+    #[allow(dead_code)]
+    pub fn append_block(&mut self, label: &str) -> usize {
+        let mut unique_label = label.to_string();
+        let mut suffix = 1;
+        while self.blocks.iter().any(|block| block.label == unique_label) {
+            unique_label = format!("{}{}", label, suffix);
+            suffix += 1;
+        }
+        self.blocks.push(LLVMBasicBlock { label: unique_label, instructions: Vec::new() });
+        self.blocks.len() - 1
+    }
+
+    #[allow(dead_code)]
+    pub fn position_at_end(&mut self, block: usize) {
+        self.current_block = block;
+    }
+
+    fn label(&self, block: usize) -> Result<String, String> {
+        match self.blocks.get(block) {
+            Some(block) => Ok(block.label.clone()),
+            None => Err(format!("Block {} does not exist", block)),
+        }
+    }
+
+    // Nothing can follow the terminator of a block
+    fn insert(&mut self, instruction: LLVMInstruction) -> Result<(), String> {
+        let block = &mut self.blocks[self.current_block];
+        if block.instructions.last().is_some_and(|last| last.is_terminator()) {
+            return Err(format!("Block %{} is already terminated", block.label));
+        }
+        block.instructions.push(instruction);
+        Ok(())
+    }
+
+    fn check(&self, value: &LLVMValue, ty: IRType, context: &str) -> Result<(), String> {
+        let valid = match value {
+            LLVMValue::Register(id) => self.value_types.get(id.0) == Some(&ty),
+            LLVMValue::Immediate(_) => ty.is_integer(),
+            LLVMValue::Global(_) => ty == IRType::Ptr,
+        };
+        if !valid {
+            return Err(format!("{} expects a value of type {}, found {}", context, ty, value));
+        }
+        Ok(())
+    }
+
+    fn check_integer(ty: IRType, context: &str) -> Result<(), String> {
+        if !ty.is_integer() {
+            return Err(format!("{} expects an integer type, found {}", context, ty));
+        }
+        Ok(())
+    }
+
+    // Stack slot allocated at the insertion point
+    #[allow(dead_code)]
+    pub fn alloca(&mut self, ty: IRType) -> Result<LLVMValue, String> {
+        Self::check_integer(ty, "alloca")?;
+        let slot = self.new_value(IRType::Ptr);
+        self.insert(LLVMInstruction::Alloca(slot, ty))?;
+        Ok(LLVMValue::Register(slot))
+    }
+
+    // Named locals get a single stack slot for the whole function, at the top of the entry block
+    #[allow(dead_code)]
+    pub fn declare_local(&mut self, name: &str, ty: IRType) -> Result<LLVMValue, String> {
+        if let Some(slot) = self.locals.get(name) {
+            return Ok(LLVMValue::Register(*slot));
+        }
+        Self::check_integer(ty, &format!("local {}", name))?;
+        let slot = self.new_value(IRType::Ptr);
+        let position = self.blocks[0].instructions.iter()
+            .take_while(|instruction| matches!(instruction, LLVMInstruction::Alloca(..)))
+            .count();
+        self.blocks[0].instructions.insert(position, LLVMInstruction::Alloca(slot, ty));
+        self.locals.insert(name.to_string(), slot);
+        Ok(LLVMValue::Register(slot))
+    }
+
+    #[allow(dead_code)]
+    pub fn local(&self, name: &str) -> Option<LLVMValue> {
+        self.locals.get(name).map(|slot| LLVMValue::Register(*slot))
+    }
+
+    #[allow(dead_code)]
+    pub fn store(&mut self, ty: IRType, value: LLVMValue, pointer: LLVMValue) -> Result<(), String> {
+        Self::check_integer(ty, "store")?;
+        self.check(&value, ty, "store")?;
+        self.check(&pointer, IRType::Ptr, "store")?;
+        self.insert(LLVMInstruction::Store(ty, value, pointer))
+    }
+
+    #[allow(dead_code)]
+    pub fn load(&mut self, ty: IRType, pointer: LLVMValue) -> Result<LLVMValue, String> {
+        Self::check_integer(ty, "load")?;
+        self.check(&pointer, IRType::Ptr, "load")?;
+        let result = self.new_value(ty);
+        self.insert(LLVMInstruction::Load(result, ty, pointer))?;
+        Ok(LLVMValue::Register(result))
+    }
+
+    pub fn unary(&mut self, op: LLVMUnaryOp, ty: IRType, value: LLVMValue) -> Result<LLVMValue, String> {
+        Self::check_integer(ty, "unary operation")?;
+        self.check(&value, ty, "unary operation")?;
+        let result = self.new_value(ty);
+        self.insert(LLVMInstruction::UnaryOp(result, ty, op, value))?;
+        Ok(LLVMValue::Register(result))
+    }
+
+    pub fn binary(&mut self, op: LLVMBinaryOp, ty: IRType, lhs: LLVMValue, rhs: LLVMValue) -> Result<LLVMValue, String> {
+        Self::check_integer(ty, "binary operation")?;
+        self.check(&lhs, ty, "binary operation")?;
+        self.check(&rhs, ty, "binary operation")?;
+        let result = self.new_value(ty);
+        self.insert(LLVMInstruction::BinaryOp(result, ty, op, lhs, rhs))?;
+        Ok(LLVMValue::Register(result))
+    }
+
+    #[allow(dead_code)]
+    pub fn icmp(&mut self, comparison: LLVMComparison, ty: IRType, lhs: LLVMValue, rhs: LLVMValue) -> Result<LLVMValue, String> {
+        Self::check_integer(ty, "icmp")?;
+        self.check(&lhs, ty, "icmp")?;
+        self.check(&rhs, ty, "icmp")?;
+        let result = self.new_value(IRType::I1);
+        self.insert(LLVMInstruction::ICmp(result, comparison, ty, lhs, rhs))?;
+        Ok(LLVMValue::Register(result))
+    }
+
+    #[allow(dead_code)]
+    pub fn zext(&mut self, from: IRType, value: LLVMValue, to: IRType) -> Result<LLVMValue, String> {
+        Self::check_integer(from, "zext")?;
+        Self::check_integer(to, "zext")?;
+        if from.bits() >= to.bits() {
+            return Err(format!("zext from {} to {} does not widen", from, to));
+        }
+        self.check(&value, from, "zext")?;
+        let result = self.new_value(to);
+        self.insert(LLVMInstruction::ZExt(result, from, value, to))?;
+        Ok(LLVMValue::Register(result))
+    }
+
+    #[allow(dead_code)]
+    pub fn select(&mut self, ty: IRType, condition: LLVMValue, lhs: LLVMValue, rhs: LLVMValue) -> Result<LLVMValue, String> {
+        Self::check_integer(ty, "select")?;
+        self.check(&condition, IRType::I1, "select")?;
+        self.check(&lhs, ty, "select")?;
+        self.check(&rhs, ty, "select")?;
+        let result = self.new_value(ty);
+        self.insert(LLVMInstruction::Select(result, ty, condition, lhs, rhs))?;
+        Ok(LLVMValue::Register(result))
+    }
+
+    // The callee's signature is checked against the arguments, calls to void functions return no value
+    #[allow(dead_code)]
+    pub fn call(&mut self, callee: &str, return_type: IRType, params: &[IRType], args: Vec<LLVMValue>) -> Result<Option<LLVMValue>, String> {
+        if args.len() != params.len() {
+            return Err(format!("@{} takes {} arguments, found {}", callee, params.len(), args.len()));
+        }
+        for (arg, ty) in args.iter().zip(params) {
+            self.check(arg, *ty, &format!("call to @{}", callee))?;
+        }
+        let result = (return_type != IRType::Void).then(|| self.new_value(return_type));
+        let args = params.iter().copied().zip(args).collect();
+        self.insert(LLVMInstruction::Call(result, return_type, callee.to_string(), args))?;
+        Ok(result.map(LLVMValue::Register))
+    }
+
+    // Phis start without incoming values, since values flowing in over back edges are built later
+    #[allow(dead_code)]
+    pub fn phi(&mut self, ty: IRType) -> Result<LLVMValue, String> {
+        Self::check_integer(ty, "phi")?;
+        let block = &self.blocks[self.current_block];
+        if block.instructions.iter().any(|instruction| !matches!(instruction, LLVMInstruction::Phi(..))) {
+            return Err(format!("Phis must come first in block %{}", block.label));
+        }
+        let result = self.new_value(ty);
+        self.insert(LLVMInstruction::Phi(result, ty, Vec::new()))?;
+        Ok(LLVMValue::Register(result))
+    }
+
+    #[allow(dead_code)]
+    pub fn add_incoming(&mut self, phi: &LLVMValue, value: LLVMValue, predecessor: usize) -> Result<(), String> {
+        let label = self.label(predecessor)?;
+        let found = self.blocks.iter_mut()
+            .flat_map(|block| &mut block.instructions)
+            .find_map(|instruction| match instruction {
+                LLVMInstruction::Phi(id, ty, incoming) if LLVMValue::Register(*id) == *phi => Some((*ty, incoming)),
+                _ => None,
+            });
+        let Some((ty, incoming)) = found else {
+            return Err(format!("{} is not a phi", phi));
+        };
+        if incoming.iter().any(|(_, existing)| *existing == label) {
+            return Err(format!("{} already has a value from %{}", phi, label));
+        }
+        let valid = match &value {
+            LLVMValue::Register(id) => self.value_types.get(id.0) == Some(&ty),
+            LLVMValue::Immediate(_) => ty.is_integer(),
+            LLVMValue::Global(_) => false,
+        };
+        if !valid {
+            return Err(format!("phi expects a value of type {}, found {}", ty, value));
+        }
+        incoming.push((value, label));
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub fn br(&mut self, target: usize) -> Result<(), String> {
+        let target = self.label(target)?;
+        self.insert(LLVMInstruction::Branch(target))
+    }
+
+    #[allow(dead_code)]
+    pub fn cond_br(&mut self, condition: LLVMValue, then_target: usize, else_target: usize) -> Result<(), String> {
+        self.check(&condition, IRType::I1, "br")?;
+        let (then_target, else_target) = (self.label(then_target)?, self.label(else_target)?);
+        self.insert(LLVMInstruction::CondBranch(condition, then_target, else_target))
+    }
+
+    pub fn ret(&mut self, value: LLVMValue) -> Result<(), String> {
+        if self.return_type == IRType::Void {
+            return Err("ret with a value in a function returning void".to_string());
+        }
+        self.check(&value, self.return_type, "ret")?;
+        self.insert(LLVMInstruction::ReturnValue(self.return_type, value))
+    }
+
+    #[allow(dead_code)]
+    pub fn ret_void(&mut self) -> Result<(), String> {
+        if self.return_type != IRType::Void {
+            return Err(format!("ret without a value in a function returning {}", self.return_type));
+        }
+        self.insert(LLVMInstruction::Return)
+    }
+
+    pub fn finish(self, name: &str, linkage: Linkage, inlining: Inlining) -> Result<LLVMFunction, String> {
+        let mut predecessors = HashMap::<&str, Vec<&str>>::new();
+        for block in &self.blocks {
+            match block.instructions.last() {
+                Some(last) if last.is_terminator() => {
+                    for successor in last.successors() {
+                        predecessors.entry(successor).or_default().push(&block.label);
+                    }
+                }
+                _ => return Err(format!("@{}: block %{} does not end with a terminator", name, block.label)),
+            }
+        }
+        for block in &self.blocks {
+            let mut expected = predecessors.remove(block.label.as_str()).unwrap_or_default();
+            expected.sort_unstable();
+            expected.dedup();
+            for instruction in &block.instructions {
+                if let LLVMInstruction::Phi(id, _, incoming) = instruction {
+                    let mut listed = incoming.iter().map(|(_, label)| label.as_str()).collect::<Vec<_>>();
+                    listed.sort_unstable();
+                    if listed != expected {
+                        return Err(format!("@{}: phi {} does not list each predecessor of %{} once", name, id, block.label));
+                    }
+                }
+            }
+        }
+        Ok(LLVMFunction::Function {
+            name: name.to_string(),
+            linkage,
+            inlining,
+            return_type: self.return_type,
+            params: self.params,
+            blocks: self.blocks,
+        })
+    }
+}
+
+//
+// Textual IR
+//
+
+impl fmt::Display for LLVMConstruct {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LLVMConstruct::Module { globals, functions } => {
+                for global in globals {
+                    writeln!(f, "{}", global)?;
+                }
+                if !globals.is_empty() {
+                    writeln!(f)?;
+                }
+                for function in functions {
+                    writeln!(f, "{}", function)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl fmt::Display for LLVMGlobal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "@{} = global {} {}", self.name, self.ty, self.initializer)
+    }
+}
+
+impl fmt::Display for LLVMFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LLVMFunction::Function { name, return_type, params, .. } if self.is_declaration() => {
+                let params = params.iter().map(|(_, ty)| ty.to_string()).collect::<Vec<_>>();
+                writeln!(f, "declare {} @{}({})", return_type, name, params.join(", "))
+            }
+            LLVMFunction::Function { name, linkage, inlining, return_type, .. } => {
+                let LLVMFunction::Function { params, blocks, .. } = self.renumbered();
+                let params = params.iter().map(|(id, ty)| format!("{} {}", ty, id)).collect::<Vec<_>>();
+                let linkage = match linkage {
+                    Linkage::External => "",
+                    Linkage::Internal => "internal ",
+                };
+                let attributes = match inlining {
+                    Inlining::Default => "",
+                    Inlining::Hint => " inlinehint",
+                    Inlining::Always => " alwaysinline",
+                    Inlining::Never => " noinline",
+                };
+                writeln!(f, "define {}{} @{}({}){} {{", linkage, return_type, name, params.join(", "), attributes)?;
+                for block in blocks {
+                    write!(f, "{}", block)?;
+                }
+                writeln!(f, "}}")
+            }
+        }
+    }
+}
+
+impl fmt::Display for LLVMBasicBlock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}:", self.label)?;
+        for instruction in &self.instructions {
+            write!(f, "    {}", instruction)?;
+        }
+        Ok(())
+    }
+}
+
+// Pointers are printed with the pointee type, as LLVM versions without opaque pointers require
+impl fmt::Display for LLVMInstruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LLVMInstruction::Return => writeln!(f, "ret void"),
+            LLVMInstruction::ReturnValue(ty, value) => writeln!(f, "ret {} {}", ty, value),
+            LLVMInstruction::Branch(target) => writeln!(f, "br label %{}", target),
+            LLVMInstruction::CondBranch(condition, then_target, else_target) =>
+                writeln!(f, "br i1 {}, label %{}, label %{}", condition, then_target, else_target),
+            LLVMInstruction::Store(ty, src, dst) => writeln!(f, "store {} {}, {}* {}", ty, src, ty, dst),
+            LLVMInstruction::Load(dst, ty, src) => writeln!(f, "{} = load {}, {}* {}", dst, ty, ty, src),
+            LLVMInstruction::Alloca(dst, ty) => writeln!(f, "{} = alloca {}", dst, ty),
+            LLVMInstruction::UnaryOp(dst, ty, op, value) => {
+                match op {
+                    LLVMUnaryOp::Not => writeln!(f, "{} = xor {} {}, -1", dst, ty, value),
+                    LLVMUnaryOp::Neg => writeln!(f, "{} = sub {} 0, {}", dst, ty, value),
+                }
+            }
+            LLVMInstruction::BinaryOp(dst, ty, op, lhs, rhs) =>
+                writeln!(f, "{} = {} {} {}, {}", dst, op, ty, lhs, rhs),
+            LLVMInstruction::ICmp(dst, comparison, ty, lhs, rhs) =>
+                writeln!(f, "{} = icmp {} {} {}, {}", dst, comparison, ty, lhs, rhs),
+            LLVMInstruction::ZExt(dst, from, value, to) => writeln!(f, "{} = zext {} {} to {}", dst, from, value, to),
+            LLVMInstruction::Select(dst, ty, condition, lhs, rhs) =>
+                writeln!(f, "{} = select i1 {}, {} {}, {} {}", dst, condition, ty, lhs, ty, rhs),
+            LLVMInstruction::Call(dst, ty, callee, args) => {
+                let args = args.iter().map(|(ty, value)| format!("{} {}", ty, value)).collect::<Vec<_>>();
+                match dst {
+                    Some(dst) => writeln!(f, "{} = call {} @{}({})", dst, ty, callee, args.join(", ")),
+                    None => writeln!(f, "call {} @{}({})", ty, callee, args.join(", ")),
+                }
+            }
+            LLVMInstruction::Phi(dst, ty, incoming) => {
+                let incoming = incoming.iter()
+                    .map(|(value, label)| format!("[ {}, %{} ]", value, label))
+                    .collect::<Vec<_>>();
+                writeln!(f, "{} = phi {} {}", dst, ty, incoming.join(", "))
+            }
+        }
+    }
+}
+
+impl fmt::Display for IRType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IRType::Void => write!(f, "void"),
+            IRType::I1 => write!(f, "i1"),
+            IRType::I32 => write!(f, "i32"),
+            IRType::Ptr => write!(f, "ptr"),
+        }
+    }
+}
+
+impl fmt::Display for ValueId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "%{}", self.0)
+    }
+}
+
+impl fmt::Display for LLVMValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LLVMValue::Register(id) => write!(f, "{}", id),
+            LLVMValue::Immediate(value) => write!(f, "{}", value),
+            LLVMValue::Global(name) => write!(f, "@{}", name),
+        }
+    }
+}
+
+impl fmt::Display for LLVMBinaryOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LLVMBinaryOp::Add => write!(f, "add"),
+            LLVMBinaryOp::Subtract => write!(f, "sub"),
+            LLVMBinaryOp::Multiply => write!(f, "mul"),
+            LLVMBinaryOp::Divide => write!(f, "sdiv"),
+            LLVMBinaryOp::Remainder => write!(f, "srem"),
+            LLVMBinaryOp::UnsignedDivide => write!(f, "udiv"),
+            LLVMBinaryOp::UnsignedRemainder => write!(f, "urem"),
+        }
+    }
+}
+
+impl fmt::Display for LLVMComparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LLVMComparison::Equal => write!(f, "eq"),
+            LLVMComparison::NotEqual => write!(f, "ne"),
+            LLVMComparison::SignedGreater => write!(f, "sgt"),
+            LLVMComparison::SignedGreaterOrEqual => write!(f, "sge"),
+            LLVMComparison::SignedLess => write!(f, "slt"),
+            LLVMComparison::SignedLessOrEqual => write!(f, "sle"),
+            LLVMComparison::UnsignedGreater => write!(f, "ugt"),
+            LLVMComparison::UnsignedGreaterOrEqual => write!(f, "uge"),
+            LLVMComparison::UnsignedLess => write!(f, "ult"),
+            LLVMComparison::UnsignedLessOrEqual => write!(f, "ule"),
+        }
+    }
+}
@@ -0,0 +1,734 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use crate::diagnostics::{self, Diagnostic, SourcePosition, SourceRange};
+use crate::llvm_ir::{
+    IRType, Inlining, LLVMBasicBlock, LLVMBinaryOp, LLVMComparison, LLVMConstruct, LLVMFunction, LLVMGlobal,
+    LLVMInstruction, LLVMUnaryOp, LLVMValue, Linkage, ValueId,
+};
+
+//
+// Tokens
+//
+
+// Covers textual IR as written by britten, by hand or by clang. Attributes, metadata and other annotations
+// that do not change the meaning of the program are recognized and skipped.
+#[derive(Debug, PartialEq, Clone)]
+enum Token {
+    Local(String),
+    Global(String),
+    Integer(i64),
+    Word(String),
+    // A block label, the colon is part of the token
+    Label(String),
+    AttributeGroup(String),
+    Metadata(String),
+    Str(String),
+    Punct(char),
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '-' | '$' | '.' | '_')
+}
+
+fn tokenize(input: &str) -> Result<Vec<(Token, SourceRange)>, Diagnostic> {
+    let mut tokens = Vec::new();
+    let mut position = SourcePosition::start();
+    let mut rest = input;
+
+    while let Some(c) = rest.chars().next() {
+        if c == ';' || c.is_whitespace() {
+            let length = if c == ';' { rest.find('\n').unwrap_or(rest.len()) } else { c.len_utf8() };
+            position = position.advance(&rest[..length]);
+            rest = &rest[length..];
+            continue;
+        }
+
+        let (token, length) = next_token(c, rest)
+            .ok_or_else(|| syntax_error(format!("Unexpected character '{}'", c), position, c.len_utf8()))?;
+        let end = position.advance(&rest[..length]);
+        tokens.push((token, SourceRange { file: None, start: position, end }));
+        position = end;
+        rest = &rest[length..];
+    }
+    Ok(tokens)
+}
+
+// The token at the start of the input and its length in bytes
+fn next_token(c: char, rest: &str) -> Option<(Token, usize)> {
+    match c {
+        '%' | '@' | '!' | '#' => {
+            let (name, length) = match rest[1..].strip_prefix('"') {
+                Some(quoted) => {
+                    let end = quoted.find('"')?;
+                    (quoted[..end].to_string(), end + 3)
+                }
+                None => {
+                    let end = rest[1..].find(|c| !is_name_char(c)).unwrap_or(rest.len() - 1);
+                    (rest[1..1 + end].to_string(), end + 1)
+                }
+            };
+            let token = match c {
+                '%' => Token::Local(name),
+                '@' => Token::Global(name),
+                '!' => Token::Metadata(name),
+                _ => Token::AttributeGroup(name),
+            };
+            Some((token, length))
+        }
+        '"' => {
+            let end = rest[1..].find('"')?;
+            Some((Token::Str(rest[1..1 + end].to_string()), end + 2))
+        }
+        c if is_name_char(c) => {
+            let end = rest.find(|c| !is_name_char(c)).unwrap_or(rest.len());
+            let word = &rest[..end];
+            if rest[end..].starts_with(':') {
+                return Some((Token::Label(word.to_string()), end + 1));
+            }
+            match word.parse::<i64>() {
+                Ok(value) => Some((Token::Integer(value), end)),
+                Err(_) => Some((Token::Word(word.to_string()), end)),
+            }
+        }
+        c if "=,*()[]{}<>".contains(c) => Some((Token::Punct(c), 1)),
+        _ => None,
+    }
+}
+
+fn syntax_error(message: String, start: SourcePosition, length: usize) -> Diagnostic {
+    let end = SourcePosition { line: start.line, column: start.column + length };
+    Diagnostic::error(diagnostics::IR_PARSE, message).with_range(SourceRange { file: None, start, end })
+}
+
+//
+// Parser
+//
+
+pub fn parse(input: &str) -> Result<LLVMConstruct, Diagnostic> {
+    let tokens = tokenize(input)?;
+    let mut parser = Parser { tokens, position: 0 };
+    let mut globals = Vec::new();
+    let mut functions = Vec::new();
+    // Attribute groups may be defined after the functions referring to them
+    let mut attribute_groups = HashMap::new();
+    let mut function_groups = Vec::new();
+
+    while let Some(token) = parser.peek().cloned() {
+        match token {
+            Token::Word(word) if word == "source_filename" => {
+                parser.next()?;
+                parser.expect(Token::Punct('='))?;
+                parser.expect_string()?;
+            }
+            Token::Word(word) if word == "target" => {
+                parser.next()?;
+                parser.expect_word()?;
+                parser.expect(Token::Punct('='))?;
+                parser.expect_string()?;
+            }
+            Token::Word(word) if word == "attributes" => {
+                parser.next()?;
+                let group = match parser.next()? {
+                    Token::AttributeGroup(group) => group,
+                    other => return Err(parser.error_before(format!("Expected an attribute group, found {}", other))),
+                };
+                parser.expect(Token::Punct('='))?;
+                attribute_groups.insert(group, parser.parse_attribute_group()?);
+            }
+            Token::Word(word) if word == "declare" => {
+                parser.next()?;
+                let (function, groups) = parser.parse_declaration()?;
+                functions.push(function);
+                function_groups.push(groups);
+            }
+            Token::Word(word) if word == "define" => {
+                parser.next()?;
+                let (function, groups) = parser.parse_definition()?;
+                functions.push(function);
+                function_groups.push(groups);
+            }
+            Token::Metadata(_) => parser.skip_line(),
+            Token::Global(name) => {
+                parser.next()?;
+                globals.push(parser.parse_global(name)?);
+            }
+            other => return Err(parser.error(format!("Unexpected {} at top level", other))),
+        }
+    }
+
+    for (LLVMFunction::Function { inlining, .. }, groups) in functions.iter_mut().zip(function_groups) {
+        let attributes = groups.iter().filter_map(|group| attribute_groups.get(group)).flatten();
+        if let Some(attribute) = attributes.filter_map(|attribute: &String| inlining_attribute(attribute)).next_back() {
+            *inlining = attribute;
+        }
+    }
+
+    Ok(LLVMConstruct::Module { globals, functions })
+}
+
+fn inlining_attribute(attribute: &str) -> Option<Inlining> {
+    match attribute {
+        "inlinehint" => Some(Inlining::Hint),
+        "alwaysinline" => Some(Inlining::Always),
+        "noinline" => Some(Inlining::Never),
+        _ => None,
+    }
+}
+
+struct Parser {
+    tokens: Vec<(Token, SourceRange)>,
+    position: usize,
+}
+
+// Values of the function being parsed. Names are numbered in order of appearance, forward
+// references are allowed as long as the name is defined somewhere in the function.
+struct FunctionScope {
+    values: HashMap<String, ValueId>,
+    defined: HashSet<ValueId>,
+    first_uses: Vec<(String, SourceRange)>,
+    // Number of the entry block when it is left unlabeled, which branches and phis can still refer to
+    entry_number: Option<String>,
+}
+
+impl FunctionScope {
+    fn value(&mut self, name: &str) -> ValueId {
+        let next = ValueId(self.values.len());
+        *self.values.entry(name.to_string()).or_insert(next)
+    }
+
+    fn define(&mut self, name: &str, range: &SourceRange) -> Result<ValueId, Diagnostic> {
+        let id = self.value(name);
+        if !self.defined.insert(id) {
+            return Err(Diagnostic::error(diagnostics::IR_PARSE, format!("Value %{} is defined more than once", name))
+                .with_range(range.clone()));
+        }
+        Ok(id)
+    }
+
+    fn block(&self, name: &str) -> String {
+        match &self.entry_number {
+            Some(number) if number == name => "entry".to_string(),
+            _ => block_name(name),
+        }
+    }
+}
+
+// Unnamed blocks are numbered like values in textual IR, they are renamed so that the values can be
+// renumbered independently when the IR is printed again
+fn block_name(name: &str) -> String {
+    match name.parse::<usize>() {
+        Ok(number) => format!("bb{}", number),
+        Err(_) => name.to_string(),
+    }
+}
+
+// Linkage, visibility and other keywords that make no difference to a single translation unit
+const IGNORED_KEYWORDS: &[&str] = &[
+    "private", "internal", "external", "linkonce", "linkonce_odr", "weak", "weak_odr", "common", "dso_local",
+    "dso_preemptable", "hidden", "protected", "default", "unnamed_addr", "local_unnamed_addr", "noundef", "signext",
+    "zeroext", "inreg", "nsw", "nuw", "exact", "tail", "musttail", "notail", "ccc", "fastcc",
+];
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position).map(|(token, _)| token)
+    }
+
+    fn range(&self) -> SourceRange {
+        match self.tokens.get(self.position).or(self.tokens.last()) {
+            Some((_, range)) => range.clone(),
+            None => SourceRange { file: None, start: SourcePosition::start(), end: SourcePosition::start() },
+        }
+    }
+
+    fn error(&self, message: String) -> Diagnostic {
+        Diagnostic::error(diagnostics::IR_PARSE, message).with_range(self.range())
+    }
+
+    fn next(&mut self) -> Result<Token, Diagnostic> {
+        let token = self.peek().cloned().ok_or_else(|| self.error("Unexpected end of input".to_string()))?;
+        self.position += 1;
+        Ok(token)
+    }
+
+    fn expect(&mut self, expected: Token) -> Result<(), Diagnostic> {
+        match self.peek() {
+            Some(token) if *token == expected => {
+                self.position += 1;
+                Ok(())
+            }
+            Some(token) => Err(self.error(format!("Expected {}, found {}", expected, token))),
+            None => Err(self.error(format!("Expected {}, found end of input", expected))),
+        }
+    }
+
+    fn expect_word(&mut self) -> Result<String, Diagnostic> {
+        match self.next()? {
+            Token::Word(word) => Ok(word),
+            other => Err(self.error_before(format!("Expected a keyword, found {}", other))),
+        }
+    }
+
+    fn expect_string(&mut self) -> Result<String, Diagnostic> {
+        match self.next()? {
+            Token::Str(string) => Ok(string),
+            other => Err(self.error_before(format!("Expected a string, found {}", other))),
+        }
+    }
+
+    // Error about the token that was just consumed
+    fn error_before(&self, message: String) -> Diagnostic {
+        let (_, range) = &self.tokens[self.position - 1];
+        Diagnostic::error(diagnostics::IR_PARSE, message).with_range(range.clone())
+    }
+
+    fn eat(&mut self, token: &Token) -> bool {
+        if self.peek() == Some(token) {
+            self.position += 1;
+            return true;
+        }
+        false
+    }
+
+    fn eat_word(&mut self, word: &str) -> bool {
+        self.eat(&Token::Word(word.to_string()))
+    }
+
+    fn parse_linkage(&mut self) -> Linkage {
+        let mut linkage = Linkage::External;
+        while let Some(Token::Word(word)) = self.peek() {
+            if word == "internal" || word == "private" {
+                linkage = Linkage::Internal;
+            } else if !IGNORED_KEYWORDS.contains(&word.as_str()) {
+                break;
+            }
+            self.position += 1;
+        }
+        linkage
+    }
+
+    fn skip_ignored_keywords(&mut self) {
+        while let Some(Token::Word(word)) = self.peek() {
+            if !IGNORED_KEYWORDS.contains(&word.as_str()) {
+                break;
+            }
+            self.position += 1;
+        }
+    }
+
+    // Function attributes, attribute groups, sections and the like after a signature. Only inlining
+    // attributes matter, attribute groups are returned to be looked up once they are all known.
+    fn parse_function_attributes(&mut self, inlining: &mut Inlining) -> Vec<String> {
+        let mut groups = Vec::new();
+        while !matches!(self.peek(), None | Some(Token::Punct('{'))) && !self.at_line_start() {
+            match self.peek() {
+                Some(Token::Word(word)) => {
+                    if let Some(attribute) = inlining_attribute(word) {
+                        *inlining = attribute;
+                    }
+                }
+                Some(Token::AttributeGroup(group)) => groups.push(group.clone()),
+                _ => {}
+            }
+            self.position += 1;
+        }
+        groups
+    }
+
+    // The attributes of a group, ignoring the "key"="value" ones
+    fn parse_attribute_group(&mut self) -> Result<Vec<String>, Diagnostic> {
+        self.expect(Token::Punct('{'))?;
+        let mut attributes = Vec::new();
+        loop {
+            match self.next()? {
+                Token::Punct('}') => return Ok(attributes),
+                Token::Word(word) => attributes.push(word),
+                _ => {}
+            }
+        }
+    }
+
+    fn at_line_start(&self) -> bool {
+        match (self.position.checked_sub(1).and_then(|previous| self.tokens.get(previous)), self.tokens.get(self.position)) {
+            (Some((_, previous)), Some((_, current))) => previous.start.line != current.start.line,
+            _ => true,
+        }
+    }
+
+    // Trailing alignment and metadata attachments, as in ', align 4' or ', !dbg !12'
+    fn skip_trailing(&mut self) {
+        while self.peek() == Some(&Token::Punct(',')) {
+            match self.tokens.get(self.position + 1).map(|(token, _)| token) {
+                Some(Token::Word(word)) if word == "align" => self.position += 3,
+                Some(Token::Metadata(_)) => self.position += 3,
+                _ => break,
+            }
+        }
+    }
+
+    fn skip_line(&mut self) {
+        self.position += 1;
+        while self.position < self.tokens.len() && !self.at_line_start() {
+            self.position += 1;
+        }
+    }
+
+    //
+    // Types and values
+    //
+
+    // Pointers are opaque, whatever they point to
+    fn parse_type(&mut self) -> Result<IRType, Diagnostic> {
+        let word = match self.next()? {
+            Token::Word(word) => word,
+            other => return Err(self.error_before(format!("Expected a type, found {}", other))),
+        };
+        let mut pointer = false;
+        while self.eat(&Token::Punct('*')) {
+            pointer = true;
+        }
+        match word.as_str() {
+            _ if pointer => Ok(IRType::Ptr),
+            "void" => Ok(IRType::Void),
+            "i1" => Ok(IRType::I1),
+            "i32" => Ok(IRType::I32),
+            "ptr" => Ok(IRType::Ptr),
+            _ => Err(self.error_before(format!("Unsupported type {}", word))),
+        }
+    }
+
+    fn parse_value(&mut self, scope: &mut FunctionScope, ty: IRType) -> Result<LLVMValue, Diagnostic> {
+        let range = self.range();
+        let value = match self.next()? {
+            Token::Local(name) => {
+                let id = scope.value(&name);
+                if !scope.defined.contains(&id) {
+                    scope.first_uses.push((name, range));
+                }
+                LLVMValue::Register(id)
+            }
+            Token::Global(name) => LLVMValue::Global(name),
+            Token::Integer(value) => {
+                if value < i32::MIN as i64 || value > u32::MAX as i64 {
+                    return Err(self.error_before(format!("Constant {} does not fit in {}", value, ty)));
+                }
+                LLVMValue::Immediate(value as i32)
+            }
+            Token::Word(word) if word == "true" => LLVMValue::Immediate(1),
+            Token::Word(word) if word == "false" => LLVMValue::Immediate(0),
+            // Any value is as good as another for undefined ones
+            Token::Word(word) if word == "undef" || word == "poison" => LLVMValue::Immediate(0),
+            other => return Err(self.error_before(format!("Expected a value, found {}", other))),
+        };
+        // Booleans only keep their low bit, so 'i1 -1' is true
+        Ok(match (ty, value) {
+            (IRType::I1, LLVMValue::Immediate(value)) => LLVMValue::Immediate(value & 1),
+            (_, value) => value,
+        })
+    }
+
+    fn parse_typed_value(&mut self, scope: &mut FunctionScope) -> Result<(IRType, LLVMValue), Diagnostic> {
+        let ty = self.parse_type()?;
+        self.skip_ignored_keywords();
+        let value = self.parse_value(scope, ty)?;
+        Ok((ty, value))
+    }
+
+    fn parse_label(&mut self, scope: &FunctionScope) -> Result<String, Diagnostic> {
+        match self.next()? {
+            Token::Local(name) => Ok(scope.block(&name)),
+            other => Err(self.error_before(format!("Expected a label, found {}", other))),
+        }
+    }
+
+    //
+    // Top-level entities
+    //
+
+    fn parse_global(&mut self, name: String) -> Result<LLVMGlobal, Diagnostic> {
+        self.expect(Token::Punct('='))?;
+        self.skip_ignored_keywords();
+        match self.expect_word()?.as_str() {
+            "global" | "constant" => {}
+            other => return Err(self.error_before(format!("Expected global or constant, found {}", other))),
+        }
+        let ty = self.parse_type()?;
+        let initializer = match self.next()? {
+            Token::Integer(value) if (i32::MIN as i64..=u32::MAX as i64).contains(&value) => value as i32,
+            Token::Word(word) if word == "zeroinitializer" || word == "false" => 0,
+            Token::Word(word) if word == "true" => 1,
+            other => return Err(self.error_before(format!("Unsupported initializer {}", other))),
+        };
+        self.skip_trailing();
+        Ok(LLVMGlobal { name, ty, initializer })
+    }
+
+    fn parse_signature(&mut self) -> Result<(String, Linkage, IRType), Diagnostic> {
+        let linkage = self.parse_linkage();
+        let return_type = self.parse_type()?;
+        match self.next()? {
+            Token::Global(name) => Ok((name, linkage, return_type)),
+            other => Err(self.error_before(format!("Expected a function name, found {}", other))),
+        }
+    }
+
+    fn parse_declaration(&mut self) -> Result<(LLVMFunction, Vec<String>), Diagnostic> {
+        let (name, linkage, return_type) = self.parse_signature()?;
+        self.expect(Token::Punct('('))?;
+        let mut params = Vec::new();
+        while !self.eat(&Token::Punct(')')) {
+            if !params.is_empty() {
+                self.expect(Token::Punct(','))?;
+            }
+            if self.eat_word("...") {
+                return Err(self.error_before("Variadic functions are not supported".to_string()));
+            }
+            params.push((ValueId(params.len()), self.parse_type()?));
+            self.skip_ignored_keywords();
+        }
+        let mut inlining = Inlining::Default;
+        let groups = self.parse_function_attributes(&mut inlining);
+        Ok((LLVMFunction::Function { name, linkage, inlining, return_type, params, blocks: Vec::new() }, groups))
+    }
+
+    fn parse_definition(&mut self) -> Result<(LLVMFunction, Vec<String>), Diagnostic> {
+        let (name, linkage, return_type) = self.parse_signature()?;
+        let mut scope = FunctionScope { values: HashMap::new(), defined: HashSet::new(), first_uses: Vec::new(), entry_number: None };
+
+        self.expect(Token::Punct('('))?;
+        let mut params = Vec::new();
+        while !self.eat(&Token::Punct(')')) {
+            if !params.is_empty() {
+                self.expect(Token::Punct(','))?;
+            }
+            let ty = self.parse_type()?;
+            self.skip_ignored_keywords();
+            let range = self.range();
+            let id = match self.next()? {
+                Token::Local(name) => scope.define(&name, &range)?,
+                other => return Err(self.error_before(format!("Expected a parameter name, found {}", other))),
+            };
+            params.push((id, ty));
+        }
+        let mut inlining = Inlining::Default;
+        let groups = self.parse_function_attributes(&mut inlining);
+        self.expect(Token::Punct('{'))?;
+
+        // The entry block may be left unlabeled, it is then numbered after the unnamed parameters
+        if !matches!(self.peek(), Some(Token::Label(_))) {
+            let unnamed_params = scope.values.keys().filter(|name| name.parse::<usize>().is_ok()).count();
+            scope.entry_number = Some(unnamed_params.to_string());
+        }
+        let mut blocks = Vec::new();
+        let mut current = LLVMBasicBlock { label: "entry".to_string(), instructions: Vec::new() };
+        let mut implicit_entry = true;
+        loop {
+            match self.peek().cloned() {
+                Some(Token::Punct('}')) => {
+                    self.position += 1;
+                    break;
+                }
+                Some(Token::Label(label)) => {
+                    self.position += 1;
+                    if !(implicit_entry && current.instructions.is_empty()) {
+                        blocks.push(current);
+                    }
+                    current = LLVMBasicBlock { label: block_name(&label), instructions: Vec::new() };
+                    implicit_entry = false;
+                }
+                Some(_) => {
+                    let instruction = self.parse_instruction(&mut scope)?;
+                    current.instructions.push(instruction);
+                    implicit_entry = false;
+                }
+                None => return Err(self.error(format!("Unterminated body of @{}", name))),
+            }
+        }
+        blocks.push(current);
+
+        if let Some((name, range)) = scope.first_uses.iter().find(|(name, _)| !scope.defined.contains(&scope.values[name])) {
+            return Err(Diagnostic::error(diagnostics::IR_PARSE, format!("Use of undefined value %{}", name)).with_range(range.clone()));
+        }
+        Ok((LLVMFunction::Function { name, linkage, inlining, return_type, params, blocks }, groups))
+    }
+
+    //
+    // Instructions
+    //
+
+    fn parse_instruction(&mut self, scope: &mut FunctionScope) -> Result<LLVMInstruction, Diagnostic> {
+        let dst = match (self.peek().cloned(), self.tokens.get(self.position + 1).map(|(token, _)| token)) {
+            (Some(Token::Local(name)), Some(Token::Punct('='))) => {
+                let range = self.range();
+                self.position += 2;
+                Some(scope.define(&name, &range)?)
+            }
+            _ => None,
+        };
+        self.skip_ignored_keywords();
+        let opcode = self.expect_word()?;
+        let defines = |parser: &Parser| {
+            dst.ok_or_else(|| parser.error_before(format!("The result of {} must be named", opcode)))
+        };
+
+        let instruction = match opcode.as_str() {
+            "ret" => match self.peek() {
+                Some(Token::Word(word)) if word == "void" => {
+                    self.position += 1;
+                    LLVMInstruction::Return
+                }
+                _ => {
+                    let (ty, value) = self.parse_typed_value(scope)?;
+                    LLVMInstruction::ReturnValue(ty, value)
+                }
+            },
+            "br" => {
+                if self.eat_word("label") {
+                    LLVMInstruction::Branch(self.parse_label(scope)?)
+                } else {
+                    let (_, condition) = self.parse_typed_value(scope)?;
+                    self.expect(Token::Punct(','))?;
+                    self.expect(Token::Word("label".to_string()))?;
+                    let then_target = self.parse_label(scope)?;
+                    self.expect(Token::Punct(','))?;
+                    self.expect(Token::Word("label".to_string()))?;
+                    LLVMInstruction::CondBranch(condition, then_target, self.parse_label(scope)?)
+                }
+            }
+            "add" | "sub" | "mul" | "sdiv" | "srem" | "udiv" | "urem" | "xor" => {
+                let dst = defines(self)?;
+                self.skip_ignored_keywords();
+                let (ty, lhs) = self.parse_typed_value(scope)?;
+                self.expect(Token::Punct(','))?;
+                let rhs = self.parse_value(scope, ty)?;
+                match (opcode.as_str(), lhs, rhs) {
+                    ("sub", LLVMValue::Immediate(0), value) => LLVMInstruction::UnaryOp(dst, ty, LLVMUnaryOp::Neg, value),
+                    ("xor", value, LLVMValue::Immediate(-1)) | ("xor", LLVMValue::Immediate(-1), value) => {
+                        LLVMInstruction::UnaryOp(dst, ty, LLVMUnaryOp::Not, value)
+                    }
+                    ("xor", _, _) => return Err(self.error_before("Only xor with -1 is supported".to_string())),
+                    (_, lhs, rhs) => {
+                        let op = match opcode.as_str() {
+                            "add" => LLVMBinaryOp::Add,
+                            "sub" => LLVMBinaryOp::Subtract,
+                            "mul" => LLVMBinaryOp::Multiply,
+                            "sdiv" => LLVMBinaryOp::Divide,
+                            "srem" => LLVMBinaryOp::Remainder,
+                            "udiv" => LLVMBinaryOp::UnsignedDivide,
+                            _ => LLVMBinaryOp::UnsignedRemainder,
+                        };
+                        LLVMInstruction::BinaryOp(dst, ty, op, lhs, rhs)
+                    }
+                }
+            }
+            "icmp" => {
+                let dst = defines(self)?;
+                let comparison = match self.expect_word()?.as_str() {
+                    "eq" => LLVMComparison::Equal,
+                    "ne" => LLVMComparison::NotEqual,
+                    "sgt" => LLVMComparison::SignedGreater,
+                    "sge" => LLVMComparison::SignedGreaterOrEqual,
+                    "slt" => LLVMComparison::SignedLess,
+                    "sle" => LLVMComparison::SignedLessOrEqual,
+                    "ugt" => LLVMComparison::UnsignedGreater,
+                    "uge" => LLVMComparison::UnsignedGreaterOrEqual,
+                    "ult" => LLVMComparison::UnsignedLess,
+                    "ule" => LLVMComparison::UnsignedLessOrEqual,
+                    other => return Err(self.error_before(format!("Unknown comparison {}", other))),
+                };
+                let (ty, lhs) = self.parse_typed_value(scope)?;
+                self.expect(Token::Punct(','))?;
+                let rhs = self.parse_value(scope, ty)?;
+                LLVMInstruction::ICmp(dst, comparison, ty, lhs, rhs)
+            }
+            "alloca" => LLVMInstruction::Alloca(defines(self)?, self.parse_type()?),
+            "load" => {
+                let dst = defines(self)?;
+                let ty = self.parse_type()?;
+                self.expect(Token::Punct(','))?;
+                let (_, pointer) = self.parse_typed_value(scope)?;
+                LLVMInstruction::Load(dst, ty, pointer)
+            }
+            "store" => {
+                let (ty, value) = self.parse_typed_value(scope)?;
+                self.expect(Token::Punct(','))?;
+                let (_, pointer) = self.parse_typed_value(scope)?;
+                LLVMInstruction::Store(ty, value, pointer)
+            }
+            "zext" => {
+                let dst = defines(self)?;
+                let (from, value) = self.parse_typed_value(scope)?;
+                self.expect(Token::Word("to".to_string()))?;
+                LLVMInstruction::ZExt(dst, from, value, self.parse_type()?)
+            }
+            "select" => {
+                let dst = defines(self)?;
+                let (_, condition) = self.parse_typed_value(scope)?;
+                self.expect(Token::Punct(','))?;
+                let (ty, lhs) = self.parse_typed_value(scope)?;
+                self.expect(Token::Punct(','))?;
+                let (_, rhs) = self.parse_typed_value(scope)?;
+                LLVMInstruction::Select(dst, ty, condition, lhs, rhs)
+            }
+            "phi" => {
+                let dst = defines(self)?;
+                let ty = self.parse_type()?;
+                let mut incoming = Vec::new();
+                loop {
+                    self.expect(Token::Punct('['))?;
+                    let value = self.parse_value(scope, ty)?;
+                    self.expect(Token::Punct(','))?;
+                    incoming.push((value, self.parse_label(scope)?));
+                    self.expect(Token::Punct(']'))?;
+                    if self.peek() != Some(&Token::Punct(',')) || self.tokens.get(self.position + 1).map(|(token, _)| token) != Some(&Token::Punct('[')) {
+                        break;
+                    }
+                    self.position += 1;
+                }
+                LLVMInstruction::Phi(dst, ty, incoming)
+            }
+            "call" => {
+                let (callee, _, ty) = self.parse_signature()?;
+                self.expect(Token::Punct('('))?;
+                let mut args = Vec::new();
+                while !self.eat(&Token::Punct(')')) {
+                    if !args.is_empty() {
+                        self.expect(Token::Punct(','))?;
+                    }
+                    args.push(self.parse_typed_value(scope)?);
+                }
+                while let Some(Token::AttributeGroup(_)) = self.peek() {
+                    self.position += 1;
+                }
+                if ty == IRType::Void && dst.is_some() {
+                    return Err(self.error_before(format!("Call to void function @{} cannot be named", callee)));
+                }
+                LLVMInstruction::Call(dst, ty, callee, args)
+            }
+            other => return Err(self.error_before(format!("Unsupported instruction {}", other))),
+        };
+
+        if dst.is_some() && instruction.result().is_none() {
+            return Err(self.error_before(format!("The result of {} cannot be named", opcode)));
+        }
+        self.skip_trailing();
+        Ok(instruction)
+    }
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Local(name) => write!(f, "'%{}'", name),
+            Token::Global(name) => write!(f, "'@{}'", name),
+            Token::Integer(value) => write!(f, "'{}'", value),
+            Token::Word(word) => write!(f, "'{}'", word),
+            Token::Label(label) => write!(f, "label '{}:'", label),
+            Token::AttributeGroup(name) => write!(f, "'#{}'", name),
+            Token::Metadata(name) => write!(f, "'!{}'", name),
+            Token::Str(string) => write!(f, "\"{}\"", string),
+            Token::Punct(c) => write!(f, "'{}'", c),
+        }
+    }
+}
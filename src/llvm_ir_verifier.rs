@@ -0,0 +1,296 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::cfg::ControlFlowGraph;
+use crate::dominators::DominatorTree;
+use crate::llvm_ir::{IRType, LLVMBasicBlock, LLVMConstruct, LLVMFunction, LLVMInstruction, LLVMValue, ValueId};
+
+// Checks the structural invariants the backend and external LLVM tools rely on. A failure means britten
+// itself produced broken IR, so the error describes the offending instruction rather than the source.
+pub fn verify(module: &LLVMConstruct) -> Result<(), String> {
+    match module {
+        LLVMConstruct::Module { globals, functions } => {
+            let mut names = HashSet::new();
+            let global_names = globals.iter().map(|global| global.name.as_str());
+            let function_names = functions.iter().map(|LLVMFunction::Function { name, .. }| name.as_str());
+            for name in global_names.chain(function_names) {
+                if !names.insert(name) {
+                    return Err(format!("@{} is defined more than once", name));
+                }
+            }
+
+            for global in globals {
+                if !global.ty.is_integer() {
+                    return Err(format!("@{}: global of non-integer type {}", global.name, global.ty));
+                }
+            }
+
+            let signatures = functions.iter()
+                .map(|LLVMFunction::Function { name, return_type, params, .. }| {
+                    (name.as_str(), (*return_type, params.iter().map(|(_, ty)| *ty).collect::<Vec<_>>()))
+                })
+                .collect::<HashMap<_, _>>();
+            let globals = globals.iter().map(|global| global.name.as_str()).collect::<HashSet<_>>();
+            functions.iter()
+                .filter(|function| !function.is_declaration())
+                .try_for_each(|function| verify_function(function, &signatures, &globals))
+        }
+    }
+}
+
+type Signature = (IRType, Vec<IRType>);
+
+struct FunctionVerifier<'a> {
+    name: &'a str,
+    return_type: IRType,
+    blocks: &'a [LLVMBasicBlock],
+    signatures: &'a HashMap<&'a str, Signature>,
+    globals: &'a HashSet<&'a str>,
+    cfg: ControlFlowGraph,
+    dominators: DominatorTree,
+    // Block and position of each definition, parameters come before the first instruction of the entry block
+    definitions: HashMap<ValueId, (usize, usize, IRType)>,
+}
+
+fn verify_function(
+    function: &LLVMFunction,
+    signatures: &HashMap<&str, Signature>,
+    globals: &HashSet<&str>,
+) -> Result<(), String> {
+    let LLVMFunction::Function { name, return_type, params, blocks, .. } = function;
+
+    let mut labels = HashMap::new();
+    for (index, block) in blocks.iter().enumerate() {
+        if labels.insert(block.label.as_str(), index).is_some() {
+            return Err(format!("@{}: block label %{} is defined more than once", name, block.label));
+        }
+    }
+
+    for block in blocks {
+        for successor in block.instructions.iter().flat_map(|instruction| instruction.successors()) {
+            if !labels.contains_key(successor) {
+                return Err(format!("@{}: block %{} branches to unknown block %{}", name, block.label, successor));
+            }
+        }
+    }
+    let cfg = ControlFlowGraph::new(blocks);
+    if !cfg.predecessors[0].is_empty() {
+        return Err(format!("@{}: entry block %{} has predecessors", name, blocks[0].label));
+    }
+
+    let mut definitions = HashMap::new();
+    for (id, ty) in params {
+        if !ty.is_integer() {
+            return Err(format!("@{}: parameter {} of non-integer type {}", name, id, ty));
+        }
+        if definitions.insert(*id, (0, 0, *ty)).is_some() {
+            return Err(format!("@{}: parameter {} is defined more than once", name, id));
+        }
+    }
+    for (block_index, block) in blocks.iter().enumerate() {
+        for (position, instruction) in block.instructions.iter().enumerate() {
+            if let Some((id, ty)) = instruction.result() {
+                if definitions.insert(id, (block_index, position + 1, ty)).is_some() {
+                    return Err(format!("@{}: value {} is defined more than once", name, id));
+                }
+            }
+        }
+    }
+
+    let dominators = DominatorTree::new(&cfg);
+    let verifier = FunctionVerifier {
+        name,
+        return_type: *return_type,
+        blocks,
+        signatures,
+        globals,
+        cfg,
+        dominators,
+        definitions,
+    };
+    for (index, block) in blocks.iter().enumerate() {
+        verifier.verify_block(index, block)?;
+    }
+    Ok(())
+}
+
+impl FunctionVerifier<'_> {
+    fn error(&self, block: &LLVMBasicBlock, instruction: &LLVMInstruction, message: &str) -> String {
+        format!("@{}: {} in block %{}: {}", self.name, message, block.label, instruction.to_string().trim())
+    }
+
+    fn verify_block(&self, index: usize, block: &LLVMBasicBlock) -> Result<(), String> {
+        match block.instructions.last() {
+            Some(last) if last.is_terminator() => {}
+            _ => return Err(format!("@{}: block %{} does not end with a terminator", self.name, block.label)),
+        }
+
+        let mut seen_non_phi = false;
+        for (position, instruction) in block.instructions.iter().enumerate() {
+            if instruction.is_terminator() && position + 1 != block.instructions.len() {
+                return Err(self.error(block, instruction, "terminator in the middle of a block"));
+            }
+            match instruction {
+                LLVMInstruction::Phi(..) if seen_non_phi => {
+                    return Err(self.error(block, instruction, "phi node after a non-phi instruction"));
+                }
+                LLVMInstruction::Phi(..) => self.verify_phi(index, block, instruction)?,
+                _ => {
+                    seen_non_phi = true;
+                    for operand in instruction.operands() {
+                        self.verify_use(index, position + 1, block, instruction, operand)?;
+                    }
+                }
+            }
+            self.verify_types(block, instruction)?;
+        }
+        Ok(())
+    }
+
+    fn verify_phi(&self, index: usize, block: &LLVMBasicBlock, instruction: &LLVMInstruction) -> Result<(), String> {
+        let LLVMInstruction::Phi(_, _, incoming) = instruction else {
+            return Ok(());
+        };
+        let mut expected = self.cfg.predecessors[index].iter()
+            .map(|predecessor| self.blocks[*predecessor].label.as_str())
+            .collect::<Vec<_>>();
+        let mut listed = incoming.iter().map(|(_, label)| label.as_str()).collect::<Vec<_>>();
+        expected.sort_unstable();
+        listed.sort_unstable();
+        if expected != listed {
+            return Err(self.error(block, instruction, "phi node does not list each predecessor exactly once"));
+        }
+
+        // An incoming value must be available at the end of the predecessor it flows from
+        for (value, label) in incoming {
+            let predecessor = self.blocks.iter().position(|block| &block.label == label).unwrap_or(index);
+            let end = self.blocks[predecessor].instructions.len() + 1;
+            self.verify_use(predecessor, end, block, instruction, value)?;
+        }
+        Ok(())
+    }
+
+    // The definition must dominate the point of use: earlier in the same block, or in a dominating block.
+    // Code in unreachable blocks never runs and is not checked for dominance.
+    fn verify_use(
+        &self,
+        block_index: usize,
+        position: usize,
+        block: &LLVMBasicBlock,
+        instruction: &LLVMInstruction,
+        operand: &LLVMValue,
+    ) -> Result<(), String> {
+        let id = match operand {
+            LLVMValue::Register(id) => id,
+            LLVMValue::Global(name) if !self.globals.contains(name.as_str()) => {
+                return Err(self.error(block, instruction, &format!("use of undefined global @{}", name)));
+            }
+            LLVMValue::Immediate(_) | LLVMValue::Global(_) => return Ok(()),
+        };
+        let Some(&(definition_block, definition_position, _)) = self.definitions.get(id) else {
+            return Err(self.error(block, instruction, &format!("use of undefined value {}", id)));
+        };
+
+        let dominates = if definition_block == block_index {
+            definition_position < position
+        } else {
+            !self.cfg.is_reachable(block_index) || self.dominators.dominates(definition_block, block_index)
+        };
+        if !dominates {
+            return Err(self.error(block, instruction, &format!("value {} does not dominate this use", id)));
+        }
+        Ok(())
+    }
+
+    fn value_type(&self, value: &LLVMValue) -> Option<IRType> {
+        match value {
+            LLVMValue::Register(id) => self.definitions.get(id).map(|(_, _, ty)| *ty),
+            LLVMValue::Global(_) => Some(IRType::Ptr),
+            LLVMValue::Immediate(_) => None,
+        }
+    }
+
+    // Immediates take the type of the instruction they appear in, as long as it is an integer type
+    fn expect(&self, block: &LLVMBasicBlock, instruction: &LLVMInstruction, value: &LLVMValue, ty: IRType) -> Result<(), String> {
+        let valid = match self.value_type(value) {
+            Some(actual) => actual == ty,
+            None => ty.is_integer(),
+        };
+        if !valid {
+            return Err(self.error(block, instruction, &format!("operand {} is not of type {}", value, ty)));
+        }
+        Ok(())
+    }
+
+    fn expect_integer(&self, block: &LLVMBasicBlock, instruction: &LLVMInstruction, ty: IRType) -> Result<(), String> {
+        if !ty.is_integer() {
+            return Err(self.error(block, instruction, &format!("{} is not an integer type", ty)));
+        }
+        Ok(())
+    }
+
+    fn verify_types(&self, block: &LLVMBasicBlock, instruction: &LLVMInstruction) -> Result<(), String> {
+        match instruction {
+            LLVMInstruction::Return => match self.return_type {
+                IRType::Void => Ok(()),
+                _ => Err(self.error(block, instruction, "missing return value")),
+            },
+            LLVMInstruction::ReturnValue(ty, value) => {
+                if *ty != self.return_type {
+                    return Err(self.error(block, instruction, &format!("function returns {}", self.return_type)));
+                }
+                self.expect(block, instruction, value, *ty)
+            }
+            LLVMInstruction::Branch(_) => Ok(()),
+            LLVMInstruction::CondBranch(condition, _, _) => self.expect(block, instruction, condition, IRType::I1),
+            LLVMInstruction::Store(ty, value, pointer) => {
+                self.expect_integer(block, instruction, *ty)?;
+                self.expect(block, instruction, value, *ty)?;
+                self.expect(block, instruction, pointer, IRType::Ptr)
+            }
+            LLVMInstruction::Load(_, ty, pointer) => {
+                self.expect_integer(block, instruction, *ty)?;
+                self.expect(block, instruction, pointer, IRType::Ptr)
+            }
+            LLVMInstruction::Alloca(_, ty) => self.expect_integer(block, instruction, *ty),
+            LLVMInstruction::UnaryOp(_, ty, _, value) => {
+                self.expect_integer(block, instruction, *ty)?;
+                self.expect(block, instruction, value, *ty)
+            }
+            LLVMInstruction::BinaryOp(_, ty, _, lhs, rhs) | LLVMInstruction::ICmp(_, _, ty, lhs, rhs) => {
+                self.expect_integer(block, instruction, *ty)?;
+                self.expect(block, instruction, lhs, *ty)?;
+                self.expect(block, instruction, rhs, *ty)
+            }
+            LLVMInstruction::ZExt(_, from, value, to) => {
+                self.expect_integer(block, instruction, *from)?;
+                self.expect_integer(block, instruction, *to)?;
+                if from.bits() >= to.bits() {
+                    return Err(self.error(block, instruction, "zext must widen its operand"));
+                }
+                self.expect(block, instruction, value, *from)
+            }
+            LLVMInstruction::Select(_, ty, condition, lhs, rhs) => {
+                self.expect(block, instruction, condition, IRType::I1)?;
+                self.expect_integer(block, instruction, *ty)?;
+                self.expect(block, instruction, lhs, *ty)?;
+                self.expect(block, instruction, rhs, *ty)
+            }
+            LLVMInstruction::Call(dst, ty, callee, args) => {
+                let Some((return_type, params)) = self.signatures.get(callee.as_str()) else {
+                    return Err(self.error(block, instruction, &format!("call to undefined function @{}", callee)));
+                };
+                if ty != return_type || dst.is_some() == (*ty == IRType::Void) {
+                    return Err(self.error(block, instruction, &format!("@{} returns {}", callee, return_type)));
+                }
+                if args.len() != params.len() || args.iter().zip(params).any(|((arg_type, _), param)| arg_type != param) {
+                    return Err(self.error(block, instruction, &format!("arguments do not match the parameters of @{}", callee)));
+                }
+                args.iter().try_for_each(|(arg_type, value)| self.expect(block, instruction, value, *arg_type))
+            }
+            LLVMInstruction::Phi(_, ty, incoming) => {
+                self.expect_integer(block, instruction, *ty)?;
+                incoming.iter().try_for_each(|(value, _)| self.expect(block, instruction, value, *ty))
+            }
+        }
+    }
+}
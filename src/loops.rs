@@ -0,0 +1,131 @@
+use std::collections::HashSet;
+
+use crate::cfg::ControlFlowGraph;
+use crate::dominators::DominatorTree;
+use crate::llvm_ir::{LLVMBasicBlock, LLVMInstruction, LLVMValue, ValueId};
+
+// A natural loop is the set of blocks that can reach a back edge, an edge to a block dominating its source,
+// without going through the block it targets, the loop header. Back edges to the same header form a
+// single loop.
+pub struct NaturalLoop {
+    pub header: usize,
+    // Sources of the back edges
+    pub latches: Vec<usize>,
+    // Every block of the loop, header included, in ascending order
+    pub blocks: Vec<usize>,
+}
+
+#[allow(dead_code)]
+impl NaturalLoop {
+    pub fn contains(&self, block: usize) -> bool {
+        self.blocks.binary_search(&block).is_ok()
+    }
+
+    // Blocks outside the loop that branch to the header
+    pub fn entering_blocks(&self, cfg: &ControlFlowGraph) -> Vec<usize> {
+        cfg.predecessors[self.header].iter().copied().filter(|predecessor| !self.contains(*predecessor)).collect()
+    }
+
+    // Blocks outside the loop that are branched to from inside it
+    pub fn exit_blocks(&self, cfg: &ControlFlowGraph) -> Vec<usize> {
+        let mut exits = self.blocks.iter()
+            .flat_map(|block| &cfg.successors[*block])
+            .copied()
+            .filter(|successor| !self.contains(*successor))
+            .collect::<Vec<_>>();
+        exits.sort_unstable();
+        exits.dedup();
+        exits
+    }
+}
+
+// Loops are returned innermost first: a loop nested in another one has fewer blocks
+pub fn find_loops(cfg: &ControlFlowGraph, dominators: &DominatorTree) -> Vec<NaturalLoop> {
+    let mut loops: Vec<NaturalLoop> = Vec::new();
+    for &latch in &cfg.reverse_postorder {
+        for &header in &cfg.successors[latch] {
+            if !dominators.dominates(header, latch) {
+                continue;
+            }
+            match loops.iter_mut().find(|natural_loop| natural_loop.header == header) {
+                Some(natural_loop) => natural_loop.latches.push(latch),
+                None => loops.push(NaturalLoop { header, latches: vec![latch], blocks: Vec::new() }),
+            }
+        }
+    }
+
+    for natural_loop in &mut loops {
+        let mut blocks = vec![natural_loop.header];
+        let mut worklist = natural_loop.latches.clone();
+        while let Some(block) = worklist.pop() {
+            if blocks.contains(&block) {
+                continue;
+            }
+            blocks.push(block);
+            worklist.extend(cfg.predecessors[block].iter().filter(|predecessor| cfg.is_reachable(**predecessor)));
+        }
+        blocks.sort_unstable();
+        natural_loop.blocks = blocks;
+    }
+    loops.sort_by_key(|natural_loop| natural_loop.blocks.len());
+    loops
+}
+
+// Returns the block that loop-invariant code can be moved to: the only block entering the loop, when it
+// has no other successor. Otherwise a block is inserted right before the header for all entering edges to
+// go through, with phis merging the values the header's phis receive along them.
+pub fn insert_preheader(
+    blocks: &mut Vec<LLVMBasicBlock>,
+    next_value: &mut usize,
+    cfg: &ControlFlowGraph,
+    natural_loop: &NaturalLoop,
+) -> usize {
+    let entering = natural_loop.entering_blocks(cfg);
+    if let [block] = entering.as_slice() {
+        if cfg.successors[*block].len() == 1 {
+            return *block;
+        }
+    }
+
+    let header = natural_loop.header;
+    let header_label = blocks[header].label.clone();
+    let existing = blocks.iter().map(|block| block.label.as_str()).collect::<HashSet<_>>();
+    let label = (1..)
+        .map(|instance| match instance {
+            1 => format!("{}.preheader", header_label),
+            _ => format!("{}.preheader.{}", header_label, instance),
+        })
+        .find(|label| !existing.contains(label.as_str()))
+        .unwrap_or_default();
+    let entering_labels = entering.iter().map(|block| blocks[*block].label.clone()).collect::<HashSet<_>>();
+
+    let mut instructions = Vec::new();
+    for instruction in &mut blocks[header].instructions {
+        let LLVMInstruction::Phi(_, ty, incoming) = instruction else {
+            continue;
+        };
+        let (outside, inside): (Vec<_>, Vec<_>) = incoming.drain(..).partition(|(_, from)| entering_labels.contains(from));
+        let value = match outside.as_slice() {
+            [(value, _), rest @ ..] if rest.iter().all(|(other, _)| other == value) => value.clone(),
+            _ => {
+                let id = ValueId(*next_value);
+                *next_value += 1;
+                instructions.push(LLVMInstruction::Phi(id, *ty, outside));
+                LLVMValue::Register(id)
+            }
+        };
+        *incoming = inside;
+        incoming.push((value, label.clone()));
+    }
+    instructions.push(LLVMInstruction::Branch(header_label.clone()));
+
+    for block in &entering {
+        if let Some(terminator) = blocks[*block].instructions.last_mut() {
+            for target in terminator.successors_mut().into_iter().filter(|target| **target == header_label) {
+                *target = label.clone();
+            }
+        }
+    }
+    blocks.insert(header, LLVMBasicBlock { label, instructions });
+    header
+}
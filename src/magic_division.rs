@@ -0,0 +1,99 @@
+// Magic numbers turning a 32-bit division by a constant into a multiplication keeping the high half of
+// the product, after Hacker's Delight, chapter 10. Divisors that are 0, 1, -1 or a power of two are
+// better handled otherwise and are not accepted.
+
+// Division of signed n by d: q = high(m * n), plus n when d > 0 and m < 0, minus n when d < 0 and m > 0,
+// shifted right arithmetically by the shift, plus 1 when that is negative
+pub struct SignedMagic {
+    pub multiplier: i32,
+    pub shift: u32,
+}
+
+// Division of unsigned n by d: q = high(m * n) >> shift. When the multiplier does not fit in 32 bits its
+// 33rd bit is implied, and q = (((n - t) >> 1) + t) >> (shift - 1) with t = high(m * n).
+pub struct UnsignedMagic {
+    pub multiplier: u32,
+    pub add: bool,
+    pub shift: u32,
+}
+
+pub fn is_power_of_two(divisor: u32) -> bool {
+    divisor.count_ones() == 1
+}
+
+pub fn signed_magic(divisor: i32) -> SignedMagic {
+    debug_assert!(!is_power_of_two(divisor.unsigned_abs()) && divisor.unsigned_abs() > 1);
+    const TWO_31: u32 = 0x8000_0000;
+    let absolute = divisor.unsigned_abs();
+    let t = TWO_31 + (divisor as u32 >> 31);
+    // Absolute value of the largest dividend that is not a multiple of the divisor plus one
+    let absolute_nc = t - 1 - t % absolute;
+    let mut p = 31;
+    let mut q1 = TWO_31 / absolute_nc;
+    let mut r1 = TWO_31 - q1 * absolute_nc;
+    let mut q2 = TWO_31 / absolute;
+    let mut r2 = TWO_31 - q2 * absolute;
+    loop {
+        p += 1;
+        q1 = q1.wrapping_mul(2);
+        r1 = r1.wrapping_mul(2);
+        if r1 >= absolute_nc {
+            q1 = q1.wrapping_add(1);
+            r1 = r1.wrapping_sub(absolute_nc);
+        }
+        q2 = q2.wrapping_mul(2);
+        r2 = r2.wrapping_mul(2);
+        if r2 >= absolute {
+            q2 = q2.wrapping_add(1);
+            r2 = r2.wrapping_sub(absolute);
+        }
+        let delta = absolute - r2;
+        if !(q1 < delta || (q1 == delta && r1 == 0)) {
+            break;
+        }
+    }
+    let multiplier = q2.wrapping_add(1) as i32;
+    SignedMagic {
+        multiplier: if divisor < 0 { multiplier.wrapping_neg() } else { multiplier },
+        shift: p - 32,
+    }
+}
+
+pub fn unsigned_magic(divisor: u32) -> UnsignedMagic {
+    debug_assert!(!is_power_of_two(divisor) && divisor > 1);
+    let nc = u32::MAX - divisor.wrapping_neg() % divisor;
+    let mut add = false;
+    let mut p = 31;
+    let mut q1 = 0x8000_0000 / nc;
+    let mut r1 = 0x8000_0000 - q1 * nc;
+    let mut q2 = 0x7fff_ffff / divisor;
+    let mut r2 = 0x7fff_ffff - q2 * divisor;
+    loop {
+        p += 1;
+        if r1 >= nc - r1 {
+            q1 = q1.wrapping_mul(2).wrapping_add(1);
+            r1 = r1.wrapping_mul(2).wrapping_sub(nc);
+        } else {
+            q1 = q1.wrapping_mul(2);
+            r1 = r1.wrapping_mul(2);
+        }
+        if r2 + 1 >= divisor - r2 {
+            if q2 >= 0x7fff_ffff {
+                add = true;
+            }
+            q2 = q2.wrapping_mul(2).wrapping_add(1);
+            r2 = r2.wrapping_mul(2).wrapping_add(1).wrapping_sub(divisor);
+        } else {
+            if q2 >= 0x8000_0000 {
+                add = true;
+            }
+            q2 = q2.wrapping_mul(2);
+            r2 = r2.wrapping_mul(2).wrapping_add(1);
+        }
+        let delta = divisor - 1 - r2;
+        if !(p < 64 && (q1 < delta || (q1 == delta && r1 == 0))) {
+            break;
+        }
+    }
+    UnsignedMagic { multiplier: q2.wrapping_add(1), add, shift: p - 32 }
+}
@@ -1,12 +1,50 @@
 use std::env;
-use std::fs;
-use std::path::{Path, PathBuf};
 use std::process;
 
+use diagnostics::Diagnostic;
+use target::TargetArchitecture;
+use x86_64_emitter::AssemblySyntax;
+use x86_64_register_allocator::RegisterAllocator;
+
+mod aarch64_emitter;
+mod aarch64_generator;
+mod diagnostics;
+mod driver;
+mod elf_writer;
 mod lexer;
+mod linker;
 mod parser;
+mod preprocessor;
+mod riscv64_emitter;
+mod riscv64_generator;
+mod stack_slots;
+mod target;
+mod x86_64_emitter;
+mod x86_64_encoder;
 mod x86_64_generator;
+mod x86_64_instruction_selection;
+mod x86_64_liveness;
+mod x86_64_peephole;
+mod x86_64_register_allocator;
+mod x86_64_scheduler;
+mod llvm_ir;
 mod llvm_ir_generator;
+mod llvm_ir_parser;
+mod llvm_ir_verifier;
+mod magic_division;
+mod cfg;
+mod dominators;
+mod loops;
+mod mem2reg;
+mod pass_manager;
+mod inliner;
+mod constant_folding;
+mod copy_propagation;
+mod common_subexpression_elimination;
+mod licm;
+mod strength_reduction;
+mod cfg_simplification;
+mod dead_code_elimination;
 
 #[derive(Debug, PartialEq, Clone, Copy)]
 enum CompilerStage {
@@ -15,89 +53,103 @@ enum CompilerStage {
     LLVMGen,
     CodeGen,
     Compile,
+    Assemble,
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    match parse_args(&args) {
-        Ok((stage, input_file)) => {
-            let input_path = Path::new(&input_file);
-            if !input_path.exists() {
-                eprintln!("Error: Input file does not exist: {}", input_file);
-                process::exit(1);
-            }
-
-            match process_file(stage, input_path) {
-                Ok(output_path) => {
-                    println!("Compilation completed. Output: {}", output_path.display());
-                }
-                Err(err) => {
-                    eprintln!("Error: {}", err);
-                    process::exit(1);
-                }
-            }
-        }
-        Err(err) => {
-            eprintln!("Error: {}", err);
-            process::exit(1);
-        }
-    }
+// Options for compiling a single translation unit, as opposed to the ones deciding which stages run
+#[derive(Debug, Clone)]
+struct CompileOptions {
+    target: TargetArchitecture,
+    assembly_syntax: AssemblySyntax,
+    // Check the IR between passes, always done in debug builds
+    verify_ir: bool,
+    // 0 for -O0, 1 for -O1 and 2 for -O2 and above
+    optimization_level: u8,
+    // Passes enabled or disabled with --passes=, overriding the optimization level
+    pass_toggles: Vec<(pass_manager::Pass, bool)>,
+    // Chosen with --regalloc=, otherwise by the optimization level
+    register_allocator: Option<RegisterAllocator>,
+    // Let leaf functions use the red zone below the stack pointer, enabled with -mred-zone
+    red_zone: bool,
 }
 
-fn parse_args(args: &[String]) -> Result<(CompilerStage, String), String> {
-    match args.len() {
-        2 => Ok((CompilerStage::Compile, args[1].clone())),
-        3 => {
-            let stage = match args[1].as_str() {
-                "--lex" => CompilerStage::Lex,
-                "--parse" => CompilerStage::Parse,
-                "--llvm" => CompilerStage::LLVMGen,
-                "--codegen" => CompilerStage::CodeGen,
-                _ => return Err(format!("Unknown option: {}", args[1])),
-            };
-            Ok((stage, args[2].clone()))
-        }
-        _ => Err(format!(
-            "Usage: {} [--lex|--parse|--llvm|--codegen] <input_file>",
-            args[0]
-        )),
+impl Default for CompileOptions {
+    fn default() -> Self {
+        CompileOptions { target: TargetArchitecture::X86_64, assembly_syntax: AssemblySyntax::Att, verify_ir: cfg!(debug_assertions), optimization_level: 0, pass_toggles: Vec::new(), register_allocator: None, red_zone: false }
     }
 }
 
-fn process_file(target_stage: CompilerStage, input_path: &Path) -> Result<PathBuf, String> {
-    let input = fs::read_to_string(input_path).map_err(|e| format!("Failed to read input file: {}", e))?;
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    process::exit(driver::run(&args));
+}
 
-    let tokens = lexer::tokenize(&input).map_err(|e| format!("Lexing failed: {}", e))?;
+// Runs a preprocessed translation unit up to the target stage, returning the contents of the file
+// the stage produces if any (LLVM IR, assembly or an object file)
+fn process_file(target_stage: CompilerStage, options: &CompileOptions, input: &str) -> Result<Option<Vec<u8>>, Diagnostic> {
+    let tokens = lexer::tokenize(input)?;
     if target_stage == CompilerStage::Lex {
+        let tokens = tokens.into_iter().map(|(token, _)| token).collect::<Vec<_>>();
         println!("Lexing completed. Tokens:\n{:?}", tokens);
-        return Ok(input_path.to_path_buf());
+        return Ok(None);
     }
 
-    let c_ast = parser::parse(tokens).map_err(|e| format!("Parsing failed: {}", e))?;
+    let c_ast = parser::parse(tokens)?;
     if target_stage == CompilerStage::Parse {
         println!("Parsing completed. AST:\n{}", c_ast);
-        return Ok(input_path.to_path_buf());
+        return Ok(None);
+    }
+
+    let llvm_ir_ast = llvm_ir_generator::generate(&c_ast)
+        .map_err(|e| Diagnostic::error(diagnostics::IR_GENERATION, format!("LLVM IR generation failed: {}", e)))?;
+    verify_ir(options, &llvm_ir_ast, "IR generation")?;
+    process_ir(target_stage, options, llvm_ir_ast)
+}
+
+// Runs textual LLVM IR through the backend. The input is not britten's own output, so it is always
+// verified and problems are reported as errors in the input.
+fn process_ir_file(target_stage: CompilerStage, options: &CompileOptions, input: &str) -> Result<Option<Vec<u8>>, Diagnostic> {
+    let llvm_ir_ast = llvm_ir_parser::parse(input)?;
+    llvm_ir_verifier::verify(&llvm_ir_ast)
+        .map_err(|e| Diagnostic::error(diagnostics::IR_VERIFICATION, format!("Invalid LLVM IR: {}", e)))?;
+    if matches!(target_stage, CompilerStage::Lex | CompilerStage::Parse) {
+        println!("Parsing completed. IR:\n{}", llvm_ir_ast);
+        return Ok(None);
     }
+    process_ir(target_stage, options, llvm_ir_ast)
+}
 
-    let llvm_ir_ast = llvm_ir_generator::generate(&c_ast).map_err(|e| format!("LLVM IR generation failed: {}", e))?;
+fn process_ir(target_stage: CompilerStage, options: &CompileOptions, mut llvm_ir_ast: llvm_ir::LLVMConstruct) -> Result<Option<Vec<u8>>, Diagnostic> {
+    mem2reg::run(&mut llvm_ir_ast);
+    verify_ir(options, &llvm_ir_ast, "mem2reg")?;
+    let passes = pass_manager::pipeline(options.optimization_level, &options.pass_toggles);
+    pass_manager::run(&mut llvm_ir_ast, &passes, |module, pass| verify_ir(options, module, pass))?;
     if target_stage == CompilerStage::LLVMGen {
-        let llvm_file = input_path.with_extension("ll");
-        fs::write(&llvm_file, format!("{}", llvm_ir_ast))
-            .map_err(|e| format!("Failed to write LLVM IR file: {}", e))?;
-        println!("LLVM IR file generated: {}", llvm_file.display());
-        return Ok(llvm_file);
+        return Ok(Some(format!("{}", llvm_ir_ast).into_bytes()));
     }
 
-    let x86_64_ast = x86_64_generator::generate(&llvm_ir_ast).map_err(|e| format!("Code generation failed: {}", e))?;
+    let program = target::target(options.target).generate(&llvm_ir_ast, options)
+        .map_err(|e| Diagnostic::error(diagnostics::CODE_GENERATION, format!("Code generation failed: {}", e)))?;
     if target_stage == CompilerStage::CodeGen {
         println!("Code generation completed");
-        return Ok(input_path.to_path_buf());
+        return Ok(None);
     }
 
-    let assembly = format!("{}", x86_64_ast);
-    let assembly_file = input_path.with_extension("s");
-    fs::write(&assembly_file, assembly).map_err(|e| format!("Failed to write assembly file: {}", e))?;
+    if target_stage == CompilerStage::Compile {
+        return Ok(Some(format!("{}\n", program.assembly(options)).into_bytes()));
+    }
 
-    println!("Assembly file generated: {}", assembly_file.display());
-    Ok(assembly_file)
-}
\ No newline at end of file
+    let object = program.object()
+        .map_err(|e| Diagnostic::error(diagnostics::OBJECT_EMISSION, format!("Object file emission failed: {}", e)))?;
+    Ok(Some(object))
+}
+
+// Broken IR is a bug in britten rather than in the input, so failures are reported as internal errors
+fn verify_ir(options: &CompileOptions, module: &llvm_ir::LLVMConstruct, after: &str) -> Result<(), Diagnostic> {
+    if !options.verify_ir {
+        return Ok(());
+    }
+    llvm_ir_verifier::verify(module).map_err(|e| {
+        Diagnostic::error(diagnostics::IR_VERIFICATION, format!("Internal error: invalid IR after {}: {}", after, e))
+    })
+}
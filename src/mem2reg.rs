@@ -0,0 +1,235 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::cfg::ControlFlowGraph;
+use crate::llvm_ir::{IRType, LLVMConstruct, LLVMFunction, LLVMInstruction, LLVMValue, ValueId};
+
+// Promotes scalar allocas that are only ever loaded from and stored to into SSA values, following
+// Braun et al., "Simple and Efficient Construction of Static Single Assignment Form". Phi nodes are
+// created on demand while reading a variable, blocks are sealed once all their predecessors are filled.
+pub fn run(module: &mut LLVMConstruct) {
+    match module {
+        LLVMConstruct::Module { functions, .. } => functions.iter_mut().for_each(promote),
+    }
+}
+
+struct Phi {
+    block: usize,
+    ty: IRType,
+    incoming: Vec<(LLVMValue, String)>,
+}
+
+struct Promotion {
+    labels: Vec<String>,
+    predecessors: Vec<Vec<usize>>,
+    variable_types: HashMap<ValueId, IRType>,
+    // Value of each variable at the end of each block, or as far as the block has been processed
+    definitions: HashMap<(ValueId, usize), LLVMValue>,
+    phis: HashMap<ValueId, Phi>,
+    incomplete_phis: HashMap<usize, Vec<(ValueId, ValueId)>>,
+    sealed: HashSet<usize>,
+    // Loads and trivial phis are replaced by the value they turned out to be
+    replacements: HashMap<ValueId, LLVMValue>,
+    next_value: usize,
+}
+
+fn promote(function: &mut LLVMFunction) {
+    let next_value = function.next_value_id().0;
+    let LLVMFunction::Function { blocks, .. } = function;
+
+    let variable_types = promotable_allocas(blocks.iter().flat_map(|block| &block.instructions));
+    if variable_types.is_empty() {
+        return;
+    }
+
+    let labels = blocks.iter().map(|block| block.label.clone()).collect::<Vec<_>>();
+    let predecessors = ControlFlowGraph::new(blocks).predecessors;
+
+    let mut promotion = Promotion {
+        labels,
+        predecessors,
+        variable_types,
+        definitions: HashMap::new(),
+        phis: HashMap::new(),
+        incomplete_phis: HashMap::new(),
+        sealed: HashSet::new(),
+        replacements: HashMap::new(),
+        next_value,
+    };
+
+    // Rewrite every block, dropping the allocas, loads and stores of promoted variables. A block is sealed
+    // as soon as all its predecessors are filled, since no new phi operand can show up after that.
+    let mut filled = HashSet::new();
+    for (index, block) in blocks.iter_mut().enumerate() {
+        promotion.seal_ready(&filled);
+        let mut instructions = Vec::with_capacity(block.instructions.len());
+        for instruction in block.instructions.drain(..) {
+            match instruction {
+                LLVMInstruction::Alloca(slot, _) if promotion.is_variable(&slot) => {}
+                LLVMInstruction::Store(_, value, LLVMValue::Register(slot)) if promotion.is_variable(&slot) => {
+                    promotion.definitions.insert((slot, index), value);
+                }
+                LLVMInstruction::Load(dst, _, LLVMValue::Register(slot)) if promotion.is_variable(&slot) => {
+                    let value = promotion.read(slot, index);
+                    promotion.replacements.insert(dst, value);
+                }
+                other => instructions.push(other),
+            }
+        }
+        block.instructions = instructions;
+        filled.insert(index);
+    }
+    promotion.seal_ready(&filled);
+    promotion.remove_trivial_phis();
+
+    // Phis go first in their block, in creation order so the output is deterministic
+    let mut phis = promotion.phis.into_iter().collect::<Vec<_>>();
+    phis.sort_by_key(|(id, _)| *id);
+    for (id, phi) in phis.into_iter().rev() {
+        blocks[phi.block].instructions.insert(0, LLVMInstruction::Phi(id, phi.ty, phi.incoming));
+    }
+
+    for instruction in blocks.iter_mut().flat_map(|block| &mut block.instructions) {
+        for operand in instruction.operands_mut() {
+            *operand = resolve(&promotion.replacements, operand);
+        }
+    }
+}
+
+// Allocas whose address never escapes: every use is the pointer operand of a load or store of the
+// allocated type
+fn promotable_allocas<'a>(instructions: impl Iterator<Item = &'a LLVMInstruction> + Clone) -> HashMap<ValueId, IRType> {
+    let mut candidates = instructions.clone()
+        .filter_map(|instruction| match instruction {
+            LLVMInstruction::Alloca(slot, ty) => Some((*slot, *ty)),
+            _ => None,
+        })
+        .collect::<HashMap<_, _>>();
+
+    for instruction in instructions {
+        match instruction {
+            LLVMInstruction::Load(_, ty, LLVMValue::Register(slot)) => {
+                if candidates.get(slot).is_some_and(|allocated| allocated != ty) {
+                    candidates.remove(slot);
+                }
+            }
+            LLVMInstruction::Store(ty, value, pointer) => {
+                if let LLVMValue::Register(slot) = value {
+                    candidates.remove(slot);
+                }
+                if let LLVMValue::Register(slot) = pointer {
+                    if candidates.get(slot).is_some_and(|allocated| allocated != ty) {
+                        candidates.remove(slot);
+                    }
+                }
+            }
+            other => {
+                for operand in other.operands() {
+                    if let LLVMValue::Register(id) = operand {
+                        candidates.remove(id);
+                    }
+                }
+            }
+        }
+    }
+    candidates
+}
+
+fn resolve(replacements: &HashMap<ValueId, LLVMValue>, value: &LLVMValue) -> LLVMValue {
+    let mut value = value.clone();
+    while let LLVMValue::Register(id) = value {
+        match replacements.get(&id) {
+            Some(replacement) => value = replacement.clone(),
+            None => break,
+        }
+    }
+    value
+}
+
+impl Promotion {
+    fn is_variable(&self, slot: &ValueId) -> bool {
+        self.variable_types.contains_key(slot)
+    }
+
+    fn read(&mut self, variable: ValueId, block: usize) -> LLVMValue {
+        if let Some(value) = self.definitions.get(&(variable, block)) {
+            return value.clone();
+        }
+
+        let value = if !self.sealed.contains(&block) {
+            let phi = self.new_phi(variable, block);
+            self.incomplete_phis.entry(block).or_default().push((variable, phi));
+            LLVMValue::Register(phi)
+        } else if self.predecessors[block].len() == 1 {
+            let predecessor = self.predecessors[block][0];
+            self.read(variable, predecessor)
+        } else if self.predecessors[block].is_empty() {
+            // Reading a variable before any store is undefined behavior, any value will do
+            LLVMValue::Immediate(0)
+        } else {
+            // Record the phi first so that loops reading the variable back terminate
+            let phi = self.new_phi(variable, block);
+            self.definitions.insert((variable, block), LLVMValue::Register(phi));
+            self.add_phi_operands(variable, phi);
+            LLVMValue::Register(phi)
+        };
+        self.definitions.insert((variable, block), value.clone());
+        value
+    }
+
+    fn new_phi(&mut self, variable: ValueId, block: usize) -> ValueId {
+        let id = ValueId(self.next_value);
+        self.next_value += 1;
+        self.phis.insert(id, Phi { block, ty: self.variable_types[&variable], incoming: Vec::new() });
+        id
+    }
+
+    fn add_phi_operands(&mut self, variable: ValueId, phi: ValueId) {
+        let block = self.phis[&phi].block;
+        for predecessor in self.predecessors[block].clone() {
+            let value = self.read(variable, predecessor);
+            let label = self.labels[predecessor].clone();
+            if let Some(phi) = self.phis.get_mut(&phi) {
+                phi.incoming.push((value, label));
+            }
+        }
+    }
+
+    fn seal_ready(&mut self, filled: &HashSet<usize>) {
+        for block in 0..self.predecessors.len() {
+            let ready = self.predecessors[block].iter().all(|predecessor| filled.contains(predecessor));
+            if ready && self.sealed.insert(block) {
+                for (variable, phi) in self.incomplete_phis.remove(&block).unwrap_or_default() {
+                    self.add_phi_operands(variable, phi);
+                }
+            }
+        }
+    }
+
+    // A phi whose incoming values are all the same value (or the phi itself) is replaced by that value,
+    // which may in turn make the phis using it trivial
+    fn remove_trivial_phis(&mut self) {
+        loop {
+            let trivial = self.phis.iter().find_map(|(id, phi)| {
+                let mut same = None;
+                for (value, _) in &phi.incoming {
+                    let value = resolve(&self.replacements, value);
+                    if value == LLVMValue::Register(*id) || Some(&value) == same.as_ref() {
+                        continue;
+                    }
+                    if same.is_some() {
+                        return None;
+                    }
+                    same = Some(value);
+                }
+                Some((*id, same.unwrap_or(LLVMValue::Immediate(0))))
+            });
+            match trivial {
+                Some((id, value)) => {
+                    self.phis.remove(&id);
+                    self.replacements.insert(id, value);
+                }
+                None => break,
+            }
+        }
+    }
+}
@@ -2,6 +2,7 @@ use std::fmt;
 use std::iter::Peekable;
 use std::vec::IntoIter;
 
+use crate::diagnostics::{self, Diagnostic, SourceRange};
 use crate::lexer::{Token, Keyword};
 
 //
@@ -22,10 +23,19 @@ pub enum Program {
 pub enum FunctionDefinition {
     Function {
         name: Identifier,
+        specifiers: FunctionSpecifiers,
         body: Statement,
     },
 }
 
+// Storage class, function specifier and attributes written before the return type
+#[derive(Debug, Clone, Default)]
+pub struct FunctionSpecifiers {
+    pub is_static: bool,
+    pub is_inline: bool,
+    pub attributes: Vec<Identifier>,
+}
+
 #[derive(Debug, Clone)]
 pub enum Statement {
     Return(Expression),
@@ -57,25 +67,32 @@ pub enum BinaryOperator {
 // Parser
 //
 
-type TokenIterator = Peekable<IntoIter<Token>>;
+type TokenIterator = Peekable<IntoIter<(Token, SourceRange)>>;
 
-pub fn parse(tokens: Vec<Token>) -> Result<Program, String> {
+pub fn parse(tokens: Vec<(Token, SourceRange)>) -> Result<Program, Diagnostic> {
+    // Input that ends too early is reported just past its last token
+    let end_of_input = tokens.last().map(|(_, range)| SourceRange { start: range.end, ..range.clone() });
     let mut tokens = tokens.into_iter().peekable();
-    let program = parse_program(&mut tokens)?;
-
-    if tokens.peek().is_some() {
-        return Err("Unexpected content after main function".to_string());
+    let program = parse_program(&mut tokens).map_err(|diagnostic| match (&diagnostic.range, end_of_input) {
+        (None, Some(range)) => diagnostic.with_range(range),
+        _ => diagnostic,
+    })?;
+
+    if let Some((_, range)) = tokens.peek() {
+        return Err(Diagnostic::error(diagnostics::PARSE_TRAILING_CONTENT, "Unexpected content after main function")
+            .with_range(range.clone()));
     }
 
     Ok(program)
 }
 
-fn parse_program(tokens: &mut TokenIterator) -> Result<Program, String> {
+fn parse_program(tokens: &mut TokenIterator) -> Result<Program, Diagnostic> {
     let function = parse_function(tokens)?;
     Ok(Program::Program(function))
 }
 
-fn parse_function(tokens: &mut TokenIterator) -> Result<FunctionDefinition, String> {
+fn parse_function(tokens: &mut TokenIterator) -> Result<FunctionDefinition, Diagnostic> {
+    let specifiers = parse_function_specifiers(tokens)?;
     expect(tokens, &Token::Keyword(Keyword::Int))?;
     let name = parse_identifier(tokens)?;
     expect(tokens, &Token::OpenParen)?;
@@ -84,23 +101,55 @@ fn parse_function(tokens: &mut TokenIterator) -> Result<FunctionDefinition, Stri
     expect(tokens, &Token::OpenBrace)?;
     let body = parse_statement(tokens)?;
     expect(tokens, &Token::CloseBrace)?;
-    Ok(FunctionDefinition::Function { name, body })
+    Ok(FunctionDefinition::Function { name, specifiers, body })
 }
 
-fn parse_statement(tokens: &mut TokenIterator) -> Result<Statement, String> {
+fn parse_function_specifiers(tokens: &mut TokenIterator) -> Result<FunctionSpecifiers, Diagnostic> {
+    let mut specifiers = FunctionSpecifiers::default();
+    loop {
+        match tokens.peek().map(|(token, _)| token) {
+            Some(Token::Keyword(Keyword::Static)) => specifiers.is_static = true,
+            Some(Token::Keyword(Keyword::Inline)) => specifiers.is_inline = true,
+            Some(Token::Keyword(Keyword::Attribute)) => {
+                tokens.next();
+                specifiers.attributes.extend(parse_attribute_list(tokens)?);
+                continue;
+            }
+            _ => return Ok(specifiers),
+        }
+        tokens.next();
+    }
+}
+
+// The attributes in '__attribute__((name, ...))', arguments to attributes are not supported
+fn parse_attribute_list(tokens: &mut TokenIterator) -> Result<Vec<Identifier>, Diagnostic> {
+    expect(tokens, &Token::OpenParen)?;
+    expect(tokens, &Token::OpenParen)?;
+    let mut attributes = Vec::new();
+    while tokens.peek().map(|(token, _)| token) != Some(&Token::CloseParen) {
+        if !attributes.is_empty() {
+            expect(tokens, &Token::Comma)?;
+        }
+        attributes.push(parse_identifier(tokens)?);
+    }
+    expect(tokens, &Token::CloseParen)?;
+    expect(tokens, &Token::CloseParen)?;
+    Ok(attributes)
+}
+
+fn parse_statement(tokens: &mut TokenIterator) -> Result<Statement, Diagnostic> {
     expect(tokens, &Token::Keyword(Keyword::Return))?;
     let expr = parse_expression(tokens, 0)?;
     expect(tokens, &Token::Semicolon)?;
     Ok(Statement::Return(expr))
 }
 
-fn parse_factor(tokens: &mut TokenIterator) -> Result<Expression, String> {
-    match tokens.peek() {
+fn parse_factor(tokens: &mut TokenIterator) -> Result<Expression, Diagnostic> {
+    match tokens.peek().map(|(token, _)| token) {
         Some(Token::Constant(_)) => {
-            if let Some(Token::Constant(value)) = tokens.next() {
-                Ok(Expression::Constant(value))
-            } else {
-                Err("Expected constant".to_string())
+            match tokens.next() {
+                Some((Token::Constant(value), _)) => Ok(Expression::Constant(value)),
+                other => Err(unexpected("Expected constant", other)),
             }
         },
         Some(Token::BitwiseComplement) => {
@@ -117,14 +166,14 @@ fn parse_factor(tokens: &mut TokenIterator) -> Result<Expression, String> {
             expect(tokens, &Token::CloseParen)?;
             Ok(expr)
         },
-        _ => Err("Expected valid factor".to_string()),
+        _ => Err(unexpected("Expected valid factor", tokens.next())),
     }
 }
 
-fn parse_expression(tokens: &mut TokenIterator, min_prec: i32) -> Result<Expression, String> {
+fn parse_expression(tokens: &mut TokenIterator, min_prec: i32) -> Result<Expression, Diagnostic> {
     let mut left = parse_factor(tokens)?;
 
-    while let Some(token) = tokens.peek() {
+    while let Some((token, _)) = tokens.peek() {
         if let Some(op) = token_to_binary_operator(token) {
             if get_precedence(&op) < min_prec {
                 break;
@@ -139,27 +188,29 @@ fn parse_expression(tokens: &mut TokenIterator, min_prec: i32) -> Result<Express
     Ok(left)
 }
 
-fn parse_unary_operation(operator: UnaryOperator, tokens: &mut TokenIterator) -> Result<Expression, String> {
+fn parse_unary_operation(operator: UnaryOperator, tokens: &mut TokenIterator) -> Result<Expression, Diagnostic> {
     parse_factor(tokens).map(|expr| Expression::Unary(operator, Box::new(expr)))
 }
 
-fn parse_identifier(tokens: &mut TokenIterator) -> Result<Identifier, String> {
-    if let Some(Token::Identifier(name)) = tokens.next() {
-        Ok(Identifier::Identifier(name))
-    } else {
-        Err("Expected identifier".to_string())
+fn parse_identifier(tokens: &mut TokenIterator) -> Result<Identifier, Diagnostic> {
+    match tokens.next() {
+        Some((Token::Identifier(name), _)) => Ok(Identifier::Identifier(name)),
+        other => Err(unexpected("Expected identifier", other)),
     }
 }
 
-fn expect(tokens: &mut TokenIterator, expected: &Token) -> Result<(), String> {
-    if let Some(token) = tokens.next() {
-        if &token == expected {
-            Ok(())
-        } else {
-            Err(format!("Expected {:?}, found {:?}", expected, token))
-        }
-    } else {
-        Err(format!("Expected {:?}, found EOF", expected))
+fn expect(tokens: &mut TokenIterator, expected: &Token) -> Result<(), Diagnostic> {
+    match tokens.next() {
+        Some((token, _)) if &token == expected => Ok(()),
+        other => Err(unexpected(&format!("Expected {:?}", expected), other)),
+    }
+}
+
+fn unexpected(message: &str, found: Option<(Token, SourceRange)>) -> Diagnostic {
+    match found {
+        Some((token, range)) => Diagnostic::error(diagnostics::PARSE_UNEXPECTED_TOKEN, format!("{}, found {:?}", message, token))
+            .with_range(range),
+        None => Diagnostic::error(diagnostics::PARSE_UNEXPECTED_TOKEN, format!("{}, found EOF", message)),
     }
 }
 
@@ -199,8 +250,18 @@ impl fmt::Display for Program {
 impl fmt::Display for FunctionDefinition {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            FunctionDefinition::Function { name, body } => {
-                write!(f, "Function(\n  name=\"{}\",\n  body={}\n)", name, body.to_string().replace("\n", "\n  "))
+            FunctionDefinition::Function { name, specifiers, body } => {
+                writeln!(f, "Function(\n  name=\"{}\",", name)?;
+                if specifiers.is_static {
+                    writeln!(f, "  static,")?;
+                }
+                if specifiers.is_inline {
+                    writeln!(f, "  inline,")?;
+                }
+                for attribute in &specifiers.attributes {
+                    writeln!(f, "  attribute={},", attribute)?;
+                }
+                write!(f, "  body={}\n)", body.to_string().replace("\n", "\n  "))
             }
         }
     }
@@ -0,0 +1,100 @@
+use crate::cfg_simplification;
+use crate::common_subexpression_elimination;
+use crate::constant_folding;
+use crate::copy_propagation;
+use crate::dead_code_elimination;
+use crate::inliner;
+use crate::licm;
+use crate::llvm_ir::{LLVMConstruct, LLVMFunction};
+use crate::strength_reduction;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Pass {
+    Inlining,
+    ConstantFolding,
+    CopyPropagation,
+    CommonSubexpressionElimination,
+    LoopInvariantCodeMotion,
+    StrengthReduction,
+    CFGSimplification,
+    UnreachableBlockElimination,
+    DeadCodeElimination,
+}
+
+// Every pass in the order they run, with the name --passes= knows it by and the lowest -O level enabling it
+const PASSES: [(Pass, &str, u8); 9] = [
+    (Pass::Inlining, "inline", 2),
+    (Pass::ConstantFolding, "constant-folding", 1),
+    (Pass::CopyPropagation, "copy-propagation", 1),
+    (Pass::CommonSubexpressionElimination, "cse", 2),
+    (Pass::LoopInvariantCodeMotion, "licm", 2),
+    (Pass::StrengthReduction, "strength-reduction", 2),
+    (Pass::CFGSimplification, "simplify-cfg", 1),
+    (Pass::UnreachableBlockElimination, "unreachable-blocks", 1),
+    (Pass::DeadCodeElimination, "dce", 1),
+];
+
+// Passes feed each other, for example folding a branch condition lets the CFG be simplified, which leaves
+// phis to propagate and fold. The pipeline is repeated until nothing changes, up to this many times.
+const MAX_ITERATIONS: usize = 8;
+
+impl Pass {
+    pub fn from_name(name: &str) -> Option<Pass> {
+        PASSES.iter().find(|(_, pass_name, _)| *pass_name == name).map(|(pass, _, _)| *pass)
+    }
+
+    pub fn name(self) -> &'static str {
+        PASSES.iter().find(|(pass, _, _)| *pass == self).map_or("", |(_, name, _)| name)
+    }
+
+    // Whether the pass changed the module. Inlining works across functions, the other passes run on each
+    // function defined in the module separately.
+    fn run(self, module: &mut LLVMConstruct) -> bool {
+        let function_pass: fn(&mut LLVMFunction) -> bool = match self {
+            Pass::Inlining => return inliner::run(module),
+            Pass::ConstantFolding => constant_folding::run,
+            Pass::CopyPropagation => copy_propagation::run,
+            Pass::CommonSubexpressionElimination => common_subexpression_elimination::run,
+            Pass::LoopInvariantCodeMotion => licm::run,
+            Pass::StrengthReduction => strength_reduction::run,
+            Pass::CFGSimplification => cfg_simplification::run,
+            Pass::UnreachableBlockElimination => cfg_simplification::remove_unreachable_blocks,
+            Pass::DeadCodeElimination => dead_code_elimination::run,
+        };
+        let LLVMConstruct::Module { functions, .. } = module;
+        let mut changed = false;
+        for function in functions.iter_mut().filter(|function| !function.is_declaration()) {
+            changed |= function_pass(function);
+        }
+        changed
+    }
+}
+
+// The passes enabled at an optimization level, after applying the --passes= toggles in order
+pub fn pipeline(optimization_level: u8, toggles: &[(Pass, bool)]) -> Vec<Pass> {
+    PASSES.iter()
+        .filter(|(pass, _, level)| {
+            let toggle = toggles.iter().rev().find(|(toggled, _)| toggled == pass);
+            toggle.map_or(optimization_level >= *level, |(_, enabled)| *enabled)
+        })
+        .map(|(pass, _, _)| *pass)
+        .collect()
+}
+
+// Runs the passes over every function. `after_pass` is called with the name of each pass that changed the
+// module, so the caller can check the IR it left behind.
+pub fn run<E>(module: &mut LLVMConstruct, passes: &[Pass], mut after_pass: impl FnMut(&LLVMConstruct, &str) -> Result<(), E>) -> Result<(), E> {
+    for _ in 0..MAX_ITERATIONS {
+        let mut changed = false;
+        for pass in passes {
+            if pass.run(module) {
+                after_pass(module, pass.name())?;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+    Ok(())
+}
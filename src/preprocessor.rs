@@ -0,0 +1,1099 @@
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use crate::diagnostics::{self, Diagnostic, SourcePosition, SourceRange};
+use crate::target::TargetArchitecture;
+
+//
+// Preprocessing tokens
+//
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum PPTokenKind {
+    Identifier,
+    Number,
+    CharLiteral,
+    StringLiteral,
+    Punctuator,
+    Placemarker,
+    Other,
+}
+
+#[derive(Debug, Clone)]
+struct PPToken {
+    kind: PPTokenKind,
+    text: String,
+    space_before: bool,
+    line: usize,
+    column: usize,
+    // Names of the macros this token was produced by, which must not be expanded again (Prosser's hide sets)
+    hideset: BTreeSet<String>,
+}
+
+#[derive(Debug, Clone)]
+struct Macro {
+    params: Option<Vec<String>>,
+    variadic: bool,
+    body: Vec<PPToken>,
+}
+
+// Longest punctuators first so that matching is greedy
+const PUNCTUATORS: [&str; 54] = [
+    "%:%:", "...", "<<=", ">>=", "->", "++", "--", "<<", ">>", "<=", ">=", "==", "!=", "&&", "||", "*=", "/=",
+    "%=", "+=", "-=", "&=", "^=", "|=", "##", "<:", ":>", "<%", "%>", "%:", "[", "]", "(", ")", "{", "}", ".",
+    "&", "*", "+", "-", "~", "!", "/", "%", "<", ">", "^", "|", "?", ":", ";", "=", ",", "#",
+];
+
+const MAX_INCLUDE_DEPTH: usize = 200;
+
+//
+// Preprocessor state
+//
+
+struct Preprocessor {
+    include_dirs: Vec<PathBuf>,
+    // Number of directories at the start of include_dirs given with -I, the rest are system directories
+    user_include_dirs: usize,
+    headers: Vec<(PathBuf, bool)>,
+    warnings: Vec<Diagnostic>,
+    macros: HashMap<String, Macro>,
+    once_files: HashSet<PathBuf>,
+    output: Output,
+    depth: usize,
+}
+
+// Presumed location of the file being processed, as altered by #line
+struct FileContext {
+    name: Rc<str>,
+    directory: PathBuf,
+    line_offset: isize,
+    // Whether the file is a system header, which the headers it includes from its own directory are too
+    system: bool,
+    // Include directory the file was found in, where #include_next resumes the search
+    include_dir: Option<usize>,
+}
+
+struct Conditional {
+    active: bool,
+    taken: bool,
+    seen_else: bool,
+    line: usize,
+    column: usize,
+}
+
+struct Output {
+    text: String,
+    line_markers: bool,
+    file: Option<Rc<str>>,
+    line: usize,
+    column: usize,
+    previous: Option<(PPTokenKind, String)>,
+}
+
+// Preprocessed source, along with the headers it included in the order they were first included and the
+// warnings reported. Each header is paired with whether it was found in a system include directory.
+pub struct PreprocessedFile {
+    pub text: String,
+    pub headers: Vec<(PathBuf, bool)>,
+    pub warnings: Vec<Diagnostic>,
+}
+
+pub fn preprocess(
+    path: &Path,
+    include_dirs: &[String],
+    compiler_include_dir: Option<&Path>,
+    defines: &[String],
+    undefines: &[String],
+    line_markers: bool,
+    target: TargetArchitecture,
+) -> Result<PreprocessedFile, Diagnostic> {
+    let mut preprocessor = Preprocessor {
+        include_dirs: include_dirs.iter().map(PathBuf::from)
+            .chain(compiler_include_dir.map(Path::to_path_buf))
+            .chain(target.system_include_dirs().iter().map(PathBuf::from))
+            .collect(),
+        user_include_dirs: include_dirs.len(),
+        headers: Vec::new(),
+        warnings: Vec::new(),
+        macros: HashMap::new(),
+        once_files: HashSet::new(),
+        output: Output {
+            text: String::new(),
+            line_markers,
+            file: None,
+            line: 1,
+            column: 1,
+            previous: None,
+        },
+        depth: 0,
+    };
+
+    let predefined = [
+        ("__STDC__", "1"),
+        ("__STDC_HOSTED__", "1"),
+        ("__STDC_VERSION__", "201710L"),
+        ("__linux__", "1"),
+        ("__britten__", "1"),
+        // Every supported target is LP64, the compiler's own limits.h and stddef.h are written in these terms
+        ("__LP64__", "1"),
+        ("_LP64", "1"),
+        ("__CHAR_BIT__", "8"),
+        ("__SIZEOF_INT__", "4"),
+        ("__SIZEOF_LONG__", "8"),
+        ("__SIZEOF_POINTER__", "8"),
+        ("__SCHAR_MAX__", "0x7f"),
+        ("__SHRT_MAX__", "0x7fff"),
+        ("__INT_MAX__", "0x7fffffff"),
+        ("__LONG_MAX__", "0x7fffffffffffffffL"),
+        ("__LONG_LONG_MAX__", "0x7fffffffffffffffLL"),
+        ("__SIZE_TYPE__", "long unsigned int"),
+        ("__PTRDIFF_TYPE__", "long int"),
+    ];
+    for (name, value) in predefined.iter().chain(target.predefined_macros()) {
+        preprocessor.define_from_command_line(name, value);
+    }
+    for define in defines {
+        match define.split_once('=') {
+            Some((name, value)) => preprocessor.define_from_command_line(name, value),
+            None => preprocessor.define_from_command_line(define, "1"),
+        }
+    }
+    for undefine in undefines {
+        preprocessor.macros.remove(undefine);
+    }
+
+    preprocessor.process_file(path, &path.display().to_string(), false, None)?;
+    if preprocessor.output.column > 1 {
+        preprocessor.output.text.push('\n');
+    }
+    Ok(PreprocessedFile { text: preprocessor.output.text, headers: preprocessor.headers, warnings: preprocessor.warnings })
+}
+
+impl Preprocessor {
+    fn define_from_command_line(&mut self, name: &str, value: &str) {
+        let body = tokenize_line(value, 0);
+        self.macros.insert(name.to_string(), Macro { params: None, variadic: false, body });
+    }
+
+    fn process_file(&mut self, path: &Path, name: &str, system: bool, include_dir: Option<usize>) -> Result<(), Diagnostic> {
+        let canonical = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+        if self.once_files.contains(&canonical) {
+            return Ok(());
+        }
+        let source = fs::read_to_string(path).map_err(|e| {
+            Diagnostic::error(diagnostics::DRIVER_IO, format!("Failed to read {}: {}", path.display(), e))
+        })?;
+
+        let mut context = FileContext {
+            name: Rc::from(name),
+            directory: path.parent().map(Path::to_path_buf).unwrap_or_default(),
+            line_offset: 0,
+            system,
+            include_dir,
+        };
+        let lines = tokenize_source(&source).map_err(|(message, line, column)| {
+            error_at(diagnostics::PREPROCESSOR_INVALID_TOKEN, message, &context, line, column)
+        })?;
+
+        let mut conditionals: Vec<Conditional> = Vec::new();
+        let mut pending = Vec::new();
+        for line in lines {
+            let active = conditionals.last().is_none_or(|conditional| conditional.active);
+            if line.first().is_some_and(|token| token.text == "#" && token.kind == PPTokenKind::Punctuator) {
+                if active {
+                    self.flush(std::mem::take(&mut pending), &context)?;
+                }
+                let once = self.directive(&line, &mut context, &mut conditionals)?;
+                if once {
+                    self.once_files.insert(canonical.clone());
+                }
+            } else if active {
+                pending.extend(line);
+            }
+        }
+        self.flush(pending, &context)?;
+
+        match conditionals.last() {
+            None => Ok(()),
+            Some(conditional) => Err(error_at(
+                diagnostics::PREPROCESSOR_UNTERMINATED_CONDITIONAL,
+                "Unterminated conditional directive".to_string(),
+                &context, conditional.line, conditional.column,
+            )),
+        }
+    }
+
+    fn flush(&mut self, tokens: Vec<PPToken>, context: &FileContext) -> Result<(), Diagnostic> {
+        if tokens.is_empty() {
+            return Ok(());
+        }
+        for token in self.expand(tokens, context)? {
+            let line = presumed_line(context, token.line);
+            self.output.emit(&token, &context.name, line);
+        }
+        Ok(())
+    }
+
+    //
+    // Directives
+    //
+
+    // Returns whether the current file asked to be included once
+    fn directive(
+        &mut self,
+        line: &[PPToken],
+        context: &mut FileContext,
+        conditionals: &mut Vec<Conditional>,
+    ) -> Result<bool, Diagnostic> {
+        let hash = &line[0];
+        let (name, arguments) = match line.get(1) {
+            Some(token) => (token.text.as_str(), &line[2..]),
+            None => return Ok(false),
+        };
+        let active = conditionals.last().is_none_or(|conditional| conditional.active);
+        let error = |code: &'static str, message: String| error_at(code, message, context, hash.line, hash.column);
+
+        match name {
+            "if" | "ifdef" | "ifndef" => {
+                let condition = match (active, name) {
+                    (false, _) => false,
+                    (true, "if") => self.evaluate_condition(arguments, context, hash)?,
+                    (true, _) => {
+                        let macro_name = expect_identifier(arguments)
+                            .ok_or_else(|| error(diagnostics::PREPROCESSOR_INVALID_DIRECTIVE, format!("Macro name missing in #{}", name)))?;
+                        self.macros.contains_key(macro_name) == (name == "ifdef")
+                    }
+                };
+                conditionals.push(Conditional {
+                    active: condition,
+                    taken: condition || !active,
+                    seen_else: false,
+                    line: hash.line,
+                    column: hash.column,
+                });
+            }
+            "elif" | "else" => {
+                let parent_active = conditionals.len() < 2 || conditionals[conditionals.len() - 2].active;
+                let conditional = conditionals.last_mut()
+                    .ok_or_else(|| error(diagnostics::PREPROCESSOR_INVALID_DIRECTIVE, format!("#{} without #if", name)))?;
+                if conditional.seen_else {
+                    return Err(error(diagnostics::PREPROCESSOR_INVALID_DIRECTIVE, format!("#{} after #else", name)));
+                }
+                if conditional.taken || !parent_active {
+                    conditional.active = false;
+                } else if name == "else" {
+                    conditional.active = true;
+                    conditional.taken = true;
+                } else {
+                    let condition = self.evaluate_condition(arguments, context, hash)?;
+                    let conditional = conditionals.last_mut().unwrap();
+                    conditional.active = condition;
+                    conditional.taken = condition;
+                }
+                if name == "else" {
+                    conditionals.last_mut().unwrap().seen_else = true;
+                }
+            }
+            "endif" => {
+                conditionals.pop()
+                    .ok_or_else(|| error(diagnostics::PREPROCESSOR_INVALID_DIRECTIVE, "#endif without #if".to_string()))?;
+            }
+            // Everything else is ignored in skipped groups, even unknown directives
+            _ if !active => {}
+            "define" => self.define(arguments, context, hash)?,
+            "undef" => {
+                let macro_name = expect_identifier(arguments)
+                    .ok_or_else(|| error(diagnostics::PREPROCESSOR_INVALID_DIRECTIVE, "Macro name missing in #undef".to_string()))?;
+                self.macros.remove(macro_name);
+            }
+            "include" => self.include(arguments, context, hash, false)?,
+            "include_next" => self.include(arguments, context, hash, true)?,
+            "line" => {
+                let expanded = self.expand(arguments.to_vec(), context)?;
+                let number = expanded.first()
+                    .filter(|token| token.kind == PPTokenKind::Number)
+                    .and_then(|token| token.text.parse::<isize>().ok())
+                    .ok_or_else(|| error(diagnostics::PREPROCESSOR_INVALID_DIRECTIVE, "#line requires a line number".to_string()))?;
+                if let Some(file) = expanded.get(1).filter(|token| token.kind == PPTokenKind::StringLiteral) {
+                    context.name = Rc::from(unquote(&file.text));
+                }
+                // The line following the directive gets the requested number
+                context.line_offset = number - (hash.line as isize + 1);
+            }
+            "error" => {
+                return Err(error(diagnostics::PREPROCESSOR_ERROR_DIRECTIVE, format!("#error {}", join_tokens(arguments))));
+            }
+            "warning" => {
+                let message = format!("#warning {}", join_tokens(arguments));
+                self.warnings.push(Diagnostic::warning(diagnostics::PREPROCESSOR_WARNING_DIRECTIVE, message)
+                    .with_range(range_at(context, hash.line, hash.column)));
+            }
+            "pragma" => {
+                if arguments.first().is_some_and(|token| token.text == "once") {
+                    return Ok(true);
+                }
+            }
+            _ => return Err(error(diagnostics::PREPROCESSOR_INVALID_DIRECTIVE, format!("Invalid preprocessing directive #{}", name))),
+        }
+        Ok(false)
+    }
+
+    fn define(&mut self, arguments: &[PPToken], context: &FileContext, hash: &PPToken) -> Result<(), Diagnostic> {
+        let error = |message: &str| error_at(diagnostics::PREPROCESSOR_INVALID_DIRECTIVE, message.to_string(), context, hash.line, hash.column);
+        let name = expect_identifier(arguments).ok_or_else(|| error("Macro name missing in #define"))?;
+
+        // A parenthesis immediately following the name introduces a function-like macro
+        let is_function = arguments.get(1).is_some_and(|token| token.text == "(" && !token.space_before);
+        if !is_function {
+            self.macros.insert(name.to_string(), Macro { params: None, variadic: false, body: arguments[1..].to_vec() });
+            return Ok(());
+        }
+
+        let mut params = Vec::new();
+        let mut variadic = false;
+        let mut index = 2;
+        loop {
+            let token = arguments.get(index).ok_or_else(|| error("Missing ')' in macro parameter list"))?;
+            index += 1;
+            match token.text.as_str() {
+                ")" if params.is_empty() || variadic => break,
+                "..." => {
+                    params.push("__VA_ARGS__".to_string());
+                    variadic = true;
+                }
+                _ if token.kind == PPTokenKind::Identifier && !variadic => params.push(token.text.clone()),
+                _ => return Err(error("Invalid macro parameter list")),
+            }
+            if variadic {
+                continue;
+            }
+            let separator = arguments.get(index).ok_or_else(|| error("Missing ')' in macro parameter list"))?;
+            index += 1;
+            match separator.text.as_str() {
+                "," => {}
+                ")" => break,
+                _ => return Err(error("Expected ',' or ')' in macro parameter list")),
+            }
+        }
+
+        self.macros.insert(name.to_string(), Macro { params: Some(params), variadic, body: arguments[index..].to_vec() });
+        Ok(())
+    }
+
+    // #include_next skips the current file's directory and the include directories up to the one the current
+    // file was found in, so a header can wrap the one of the same name it shadows
+    fn include(&mut self, arguments: &[PPToken], context: &FileContext, hash: &PPToken, next: bool) -> Result<(), Diagnostic> {
+        let error = |code: &'static str, message: String| error_at(code, message, context, hash.line, hash.column);
+
+        // Computed includes are macro expanded until they look like one of the two header name forms
+        let tokens = match arguments.first() {
+            Some(token) if token.kind == PPTokenKind::StringLiteral || token.text == "<" => arguments.to_vec(),
+            _ => self.expand(arguments.to_vec(), context)?,
+        };
+        let (header, quoted) = match tokens.first() {
+            Some(token) if token.kind == PPTokenKind::StringLiteral => (unquote(&token.text), true),
+            Some(token) if token.text == "<" => {
+                let end = tokens.iter().position(|token| token.text == ">")
+                    .ok_or_else(|| error(diagnostics::PREPROCESSOR_INVALID_DIRECTIVE, "Missing '>' in #include".to_string()))?;
+                (join_tokens(&tokens[1..end]), false)
+            }
+            _ => return Err(error(diagnostics::PREPROCESSOR_INVALID_DIRECTIVE, "#include expects \"FILENAME\" or <FILENAME>".to_string())),
+        };
+
+        let current_directory = (quoted && !next).then(|| (context.directory.clone(), context.system, None));
+        let first_dir = if next { context.include_dir.map_or(0, |index| index + 1) } else { 0 };
+        let include_dirs = self.include_dirs.iter()
+            .enumerate()
+            .skip(first_dir)
+            .map(|(index, dir)| (dir.clone(), index >= self.user_include_dirs, Some(index)));
+        let (path, system, include_dir) = current_directory.into_iter()
+            .chain(include_dirs)
+            .map(|(dir, system, index)| (dir.join(&header), system, index))
+            .find(|(path, _, _)| path.is_file())
+            .ok_or_else(|| error(diagnostics::PREPROCESSOR_INCLUDE_NOT_FOUND, format!("{}: No such file or directory", header)))?;
+        if !self.headers.iter().any(|(included, _)| *included == path) {
+            self.headers.push((path.clone(), system));
+        }
+
+        if self.depth >= MAX_INCLUDE_DEPTH {
+            return Err(error(diagnostics::PREPROCESSOR_INVALID_DIRECTIVE, "#include nested too deeply".to_string()));
+        }
+        self.depth += 1;
+        let result = self.process_file(&path, &path.display().to_string(), system, include_dir);
+        self.depth -= 1;
+        result
+    }
+
+    //
+    // Macro expansion
+    //
+
+    fn expand(&self, tokens: Vec<PPToken>, context: &FileContext) -> Result<Vec<PPToken>, Diagnostic> {
+        let mut input = VecDeque::from(tokens);
+        let mut output = Vec::new();
+
+        while let Some(token) = input.pop_front() {
+            if token.kind != PPTokenKind::Identifier || token.hideset.contains(&token.text) {
+                output.push(token);
+                continue;
+            }
+
+            match token.text.as_str() {
+                "__FILE__" => {
+                    output.push(PPToken { kind: PPTokenKind::StringLiteral, text: quote(&context.name), ..token });
+                    continue;
+                }
+                "__LINE__" => {
+                    let line = presumed_line(context, token.line);
+                    output.push(PPToken { kind: PPTokenKind::Number, text: line.to_string(), ..token });
+                    continue;
+                }
+                _ => {}
+            }
+
+            let definition = match self.macros.get(&token.text) {
+                Some(definition) => definition,
+                None => {
+                    output.push(token);
+                    continue;
+                }
+            };
+
+            let mut expansion = match &definition.params {
+                None => {
+                    let mut hideset = token.hideset.clone();
+                    hideset.insert(token.text.clone());
+                    self.substitute(definition, &[], &hideset, &token, context)?
+                }
+                Some(params) => {
+                    // A function-like macro name not followed by a parenthesis is an ordinary identifier
+                    if input.front().is_none_or(|next| next.text != "(") {
+                        output.push(token);
+                        continue;
+                    }
+                    let (arguments, close) = collect_arguments(&mut input, definition.variadic.then_some(params.len()))
+                        .ok_or_else(|| error_at(
+                            diagnostics::PREPROCESSOR_MACRO_INVOCATION,
+                            format!("Unterminated argument list invoking macro '{}'", token.text),
+                            context, token.line, token.column,
+                        ))?;
+                    let arguments = match_arguments(arguments, params.len(), definition.variadic)
+                        .ok_or_else(|| error_at(
+                            diagnostics::PREPROCESSOR_MACRO_INVOCATION,
+                            format!("Macro '{}' expects {} arguments", token.text, params.len()),
+                            context, token.line, token.column,
+                        ))?;
+                    let mut hideset = token.hideset.intersection(&close.hideset).cloned().collect::<BTreeSet<_>>();
+                    hideset.insert(token.text.clone());
+                    self.substitute(definition, &arguments, &hideset, &token, context)?
+                }
+            };
+
+            for (index, expanded) in expansion.iter_mut().enumerate() {
+                expanded.line = token.line;
+                expanded.column = token.column;
+                if index == 0 {
+                    expanded.space_before = token.space_before;
+                }
+            }
+            for expanded in expansion.into_iter().rev() {
+                input.push_front(expanded);
+            }
+        }
+
+        Ok(output)
+    }
+
+    fn substitute(
+        &self,
+        definition: &Macro,
+        arguments: &[Vec<PPToken>],
+        hideset: &BTreeSet<String>,
+        invocation: &PPToken,
+        context: &FileContext,
+    ) -> Result<Vec<PPToken>, Diagnostic> {
+        let params = definition.params.as_deref().unwrap_or_default();
+        let param_index = |token: &PPToken| match token.kind {
+            PPTokenKind::Identifier => params.iter().position(|param| *param == token.text),
+            _ => None,
+        };
+        let body = &definition.body;
+        let mut output: Vec<PPToken> = Vec::new();
+        let mut index = 0;
+
+        while index < body.len() {
+            let token = &body[index];
+            let next = body.get(index + 1);
+
+            if definition.params.is_some() && token.text == "#" {
+                if let Some(param) = next.and_then(param_index) {
+                    output.push(stringize(&arguments[param], token));
+                    index += 2;
+                    continue;
+                }
+            }
+
+            if let Some(next) = next.filter(|_| token.text == "##") {
+                let right = match param_index(next) {
+                    Some(param) => arguments[param].clone(),
+                    None => vec![next.clone()],
+                };
+                index += 2;
+                let left = output.pop();
+                match (left, right.is_empty()) {
+                    // GNU extension: ', ## __VA_ARGS__' drops the comma when there are no variadic arguments
+                    // and does not paste otherwise
+                    (Some(left), true) if left.text == "," && next.text == "__VA_ARGS__" && definition.variadic => {}
+                    (Some(left), false) if left.text == "," && next.text == "__VA_ARGS__" && definition.variadic => {
+                        output.push(left);
+                        output.extend(right);
+                    }
+                    (Some(left), true) => output.push(left),
+                    (None, _) => output.extend(right),
+                    (Some(left), false) if left.kind == PPTokenKind::Placemarker => output.extend(right),
+                    (Some(left), false) => {
+                        output.push(paste(&left, &right[0]).ok_or_else(|| error_at(
+                            diagnostics::PREPROCESSOR_MACRO_INVOCATION,
+                            format!("Pasting \"{}\" and \"{}\" does not give a valid preprocessing token", left.text, right[0].text),
+                            context, invocation.line, invocation.column,
+                        ))?);
+                        output.extend(right[1..].iter().cloned());
+                    }
+                }
+                continue;
+            }
+
+            match param_index(token) {
+                Some(param) if next.is_some_and(|next| next.text == "##") => {
+                    // Operands of ## are not macro expanded, an empty one becomes a placemarker
+                    match arguments[param].is_empty() {
+                        true => output.push(PPToken { kind: PPTokenKind::Placemarker, text: String::new(), ..token.clone() }),
+                        false => output.extend(arguments[param].iter().cloned()),
+                    }
+                }
+                Some(param) => {
+                    let mut expanded = self.expand(arguments[param].clone(), context)?;
+                    if let Some(first) = expanded.first_mut() {
+                        first.space_before = token.space_before;
+                    }
+                    output.extend(expanded);
+                }
+                None => output.push(token.clone()),
+            }
+            index += 1;
+        }
+
+        Ok(output.into_iter()
+            .filter(|token| token.kind != PPTokenKind::Placemarker)
+            .map(|mut token| {
+                token.hideset.extend(hideset.iter().cloned());
+                token
+            })
+            .collect())
+    }
+
+    //
+    // Conditional expressions
+    //
+
+    fn evaluate_condition(&self, tokens: &[PPToken], context: &FileContext, hash: &PPToken) -> Result<bool, Diagnostic> {
+        // 'defined' is resolved before expansion so that its operand is not replaced
+        let mut resolved = Vec::new();
+        let mut index = 0;
+        while index < tokens.len() {
+            let token = &tokens[index];
+            if token.text != "defined" {
+                resolved.push(token.clone());
+                index += 1;
+                continue;
+            }
+            let (name, consumed) = match (tokens.get(index + 1), tokens.get(index + 2), tokens.get(index + 3)) {
+                (Some(open), Some(name), Some(close)) if open.text == "(" && close.text == ")" => (name, 4),
+                (Some(name), _, _) if name.kind == PPTokenKind::Identifier => (name, 2),
+                _ => return Err(error_at(
+                    diagnostics::PREPROCESSOR_INVALID_EXPRESSION,
+                    "Operator 'defined' requires an identifier".to_string(),
+                    context, token.line, token.column,
+                )),
+            };
+            let value = if self.macros.contains_key(&name.text) { "1" } else { "0" };
+            resolved.push(PPToken { kind: PPTokenKind::Number, text: value.to_string(), ..token.clone() });
+            index += consumed;
+        }
+
+        // Identifiers remaining after expansion evaluate to zero
+        let expanded = self.expand(resolved, context)?.into_iter()
+            .map(|token| match token.kind {
+                PPTokenKind::Identifier => PPToken { kind: PPTokenKind::Number, text: "0".to_string(), ..token },
+                _ => token,
+            })
+            .collect::<Vec<_>>();
+
+        let mut evaluator = ExpressionEvaluator { tokens: &expanded, position: 0 };
+        let value = evaluator.conditional(true)
+            .and_then(|value| match evaluator.tokens.get(evaluator.position) {
+                Some(token) => Err(format!("Unexpected token '{}' in preprocessor expression", token.text)),
+                None => Ok(value),
+            })
+            .map_err(|message| error_at(diagnostics::PREPROCESSOR_INVALID_EXPRESSION, message, context, hash.line, hash.column))?;
+        Ok(value != 0)
+    }
+}
+
+struct ExpressionEvaluator<'a> {
+    tokens: &'a [PPToken],
+    position: usize,
+}
+
+impl ExpressionEvaluator<'_> {
+    fn peek(&self) -> Option<&str> {
+        self.tokens.get(self.position).map(|token| token.text.as_str())
+    }
+
+    fn expect(&mut self, expected: &str) -> Result<(), String> {
+        match self.peek() {
+            Some(text) if text == expected => {
+                self.position += 1;
+                Ok(())
+            }
+            Some(text) => Err(format!("Expected '{}' in preprocessor expression, found '{}'", expected, text)),
+            None => Err(format!("Expected '{}' in preprocessor expression", expected)),
+        }
+    }
+
+    // Operands that are not evaluated (short-circuited) may divide by zero
+    fn conditional(&mut self, live: bool) -> Result<i64, String> {
+        let condition = self.binary(0, live)?;
+        if self.peek() != Some("?") {
+            return Ok(condition);
+        }
+        self.position += 1;
+        let when_true = self.conditional(live && condition != 0)?;
+        self.expect(":")?;
+        let when_false = self.conditional(live && condition == 0)?;
+        Ok(if condition != 0 { when_true } else { when_false })
+    }
+
+    fn binary(&mut self, min_precedence: u8, live: bool) -> Result<i64, String> {
+        let mut left = self.unary(live)?;
+        while let Some(operator) = self.peek() {
+            let precedence = match operator {
+                "||" => 1,
+                "&&" => 2,
+                "|" => 3,
+                "^" => 4,
+                "&" => 5,
+                "==" | "!=" => 6,
+                "<" | ">" | "<=" | ">=" => 7,
+                "<<" | ">>" => 8,
+                "+" | "-" => 9,
+                "*" | "/" | "%" => 10,
+                _ => break,
+            };
+            if precedence < min_precedence {
+                break;
+            }
+            let operator = operator.to_string();
+            self.position += 1;
+            let right_live = match operator.as_str() {
+                "&&" => live && left != 0,
+                "||" => live && left == 0,
+                _ => live,
+            };
+            let right = self.binary(precedence + 1, right_live)?;
+            left = match operator.as_str() {
+                "||" => (left != 0 || right != 0) as i64,
+                "&&" => (left != 0 && right != 0) as i64,
+                "|" => left | right,
+                "^" => left ^ right,
+                "&" => left & right,
+                "==" => (left == right) as i64,
+                "!=" => (left != right) as i64,
+                "<" => (left < right) as i64,
+                ">" => (left > right) as i64,
+                "<=" => (left <= right) as i64,
+                ">=" => (left >= right) as i64,
+                "<<" => left.wrapping_shl(right as u32),
+                ">>" => left.wrapping_shr(right as u32),
+                "+" => left.wrapping_add(right),
+                "-" => left.wrapping_sub(right),
+                "*" => left.wrapping_mul(right),
+                "/" | "%" if right == 0 => match live {
+                    true => return Err("Division by zero in preprocessor expression".to_string()),
+                    false => 0,
+                },
+                "/" => left.wrapping_div(right),
+                _ => left.wrapping_rem(right),
+            };
+        }
+        Ok(left)
+    }
+
+    fn unary(&mut self, live: bool) -> Result<i64, String> {
+        let token = self.tokens.get(self.position).ok_or("Missing operand in preprocessor expression")?;
+        self.position += 1;
+        match (token.kind, token.text.as_str()) {
+            (PPTokenKind::Punctuator, "+") => self.unary(live),
+            (PPTokenKind::Punctuator, "-") => self.unary(live).map(i64::wrapping_neg),
+            (PPTokenKind::Punctuator, "~") => self.unary(live).map(|value| !value),
+            (PPTokenKind::Punctuator, "!") => self.unary(live).map(|value| (value == 0) as i64),
+            (PPTokenKind::Punctuator, "(") => {
+                let value = self.conditional(live)?;
+                self.expect(")")?;
+                Ok(value)
+            }
+            (PPTokenKind::Number, text) => parse_integer(text),
+            (PPTokenKind::CharLiteral, text) => parse_char(text),
+            (_, text) => Err(format!("Invalid token '{}' in preprocessor expression", text)),
+        }
+    }
+}
+
+fn parse_integer(text: &str) -> Result<i64, String> {
+    let digits = text.trim_end_matches(['u', 'U', 'l', 'L']);
+    let parsed = if let Some(hex) = digits.strip_prefix("0x").or_else(|| digits.strip_prefix("0X")) {
+        u64::from_str_radix(hex, 16)
+    } else if let Some(binary) = digits.strip_prefix("0b").or_else(|| digits.strip_prefix("0B")) {
+        u64::from_str_radix(binary, 2)
+    } else if digits.len() > 1 && digits.starts_with('0') {
+        u64::from_str_radix(&digits[1..], 8)
+    } else {
+        digits.parse::<u64>()
+    };
+    parsed.map(|value| value as i64).map_err(|_| format!("Invalid integer constant '{}' in preprocessor expression", text))
+}
+
+fn parse_char(text: &str) -> Result<i64, String> {
+    let body = text.trim_start_matches(['L', 'u', 'U', '8']).trim_matches('\'');
+    let mut chars = body.chars();
+    let value = match (chars.next(), chars.next()) {
+        (Some('\\'), Some(escape)) => match escape {
+            'n' => 10,
+            't' => 9,
+            'r' => 13,
+            'a' => 7,
+            'b' => 8,
+            'f' => 12,
+            'v' => 11,
+            'x' => i64::from_str_radix(chars.as_str(), 16).map_err(|_| format!("Invalid character constant {}", text))?,
+            '0'..='7' => i64::from_str_radix(&body[1..], 8).map_err(|_| format!("Invalid character constant {}", text))?,
+            other => other as i64,
+        },
+        (Some(c), _) => c as i64,
+        (None, _) => return Err("Empty character constant".to_string()),
+    };
+    Ok(value)
+}
+
+//
+// Expansion helpers
+//
+
+// Splits the tokens following a '(' into arguments, returning them along with the closing parenthesis
+fn collect_arguments(input: &mut VecDeque<PPToken>, variadic_index: Option<usize>) -> Option<(Vec<Vec<PPToken>>, PPToken)> {
+    input.pop_front();
+    let mut arguments = vec![Vec::new()];
+    let mut depth = 0;
+    loop {
+        let token = input.pop_front()?;
+        match token.text.as_str() {
+            ")" if depth == 0 => return Some((arguments, token)),
+            "," if depth == 0 && variadic_index.is_none_or(|index| arguments.len() < index) => {
+                arguments.push(Vec::new());
+                continue;
+            }
+            "(" => depth += 1,
+            ")" => depth -= 1,
+            _ => {}
+        }
+        arguments.last_mut().unwrap().push(token);
+    }
+}
+
+fn match_arguments(mut arguments: Vec<Vec<PPToken>>, count: usize, variadic: bool) -> Option<Vec<Vec<PPToken>>> {
+    if count == 0 && arguments.len() == 1 && arguments[0].is_empty() {
+        arguments.clear();
+    }
+    if variadic && arguments.len() + 1 == count {
+        arguments.push(Vec::new());
+    }
+    (arguments.len() == count).then_some(arguments)
+}
+
+fn stringize(argument: &[PPToken], hash: &PPToken) -> PPToken {
+    let mut text = String::new();
+    for (index, token) in argument.iter().enumerate() {
+        if index > 0 && token.space_before {
+            text.push(' ');
+        }
+        match token.kind {
+            PPTokenKind::StringLiteral | PPTokenKind::CharLiteral => {
+                text.push_str(&token.text.replace('\\', "\\\\").replace('"', "\\\""));
+            }
+            _ => text.push_str(&token.text),
+        }
+    }
+    PPToken { kind: PPTokenKind::StringLiteral, text: format!("\"{}\"", text), hideset: BTreeSet::new(), ..hash.clone() }
+}
+
+fn paste(left: &PPToken, right: &PPToken) -> Option<PPToken> {
+    let text = format!("{}{}", left.text, right.text);
+    let mut tokens = tokenize_line(&text, left.line);
+    match tokens.len() {
+        1 => {
+            let token = tokens.pop().unwrap();
+            Some(PPToken { kind: token.kind, text: token.text, ..left.clone() })
+        }
+        _ => None,
+    }
+}
+
+fn expect_identifier(tokens: &[PPToken]) -> Option<&str> {
+    tokens.first().filter(|token| token.kind == PPTokenKind::Identifier).map(|token| token.text.as_str())
+}
+
+fn join_tokens(tokens: &[PPToken]) -> String {
+    tokens.iter().enumerate()
+        .map(|(index, token)| match index > 0 && token.space_before {
+            true => format!(" {}", token.text),
+            false => token.text.clone(),
+        })
+        .collect()
+}
+
+fn quote(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn unquote(text: &str) -> String {
+    text.trim_matches('"').replace("\\\"", "\"").replace("\\\\", "\\")
+}
+
+fn presumed_line(context: &FileContext, line: usize) -> usize {
+    (line as isize + context.line_offset).max(1) as usize
+}
+
+fn error_at(code: &'static str, message: String, context: &FileContext, line: usize, column: usize) -> Diagnostic {
+    Diagnostic::error(code, message).with_range(range_at(context, line, column))
+}
+
+fn range_at(context: &FileContext, line: usize, column: usize) -> SourceRange {
+    let start = SourcePosition { line: presumed_line(context, line), column };
+    let end = SourcePosition { line: start.line, column: column + 1 };
+    SourceRange { file: Some(context.name.clone()), start, end }
+}
+
+//
+// Output
+//
+
+impl Output {
+    fn emit(&mut self, token: &PPToken, file: &Rc<str>, line: usize) {
+        let same_file = self.file.as_ref() == Some(file);
+        if !same_file || line < self.line || line > self.line + 8 {
+            if self.column > 1 {
+                self.text.push('\n');
+            }
+            if self.line_markers {
+                self.text.push_str(&format!("# {} {}\n", line, quote(file)));
+            }
+            self.file = Some(file.clone());
+            self.line = line;
+            self.column = 1;
+            self.previous = None;
+        }
+        while self.line < line {
+            self.text.push('\n');
+            self.line += 1;
+            self.column = 1;
+            self.previous = None;
+        }
+
+        // Tokens are placed at their original column when possible so that diagnostics point at the right place,
+        // and tokens that were not adjacent in the source must not merge into a different token
+        let needs_space = match &self.previous {
+            Some((kind, text)) => token.space_before || would_merge(*kind, text, token),
+            None => false,
+        };
+        let padding = match token.column > self.column {
+            true => token.column - self.column,
+            false => needs_space as usize,
+        };
+        self.text.push_str(&" ".repeat(padding));
+        self.text.push_str(&token.text);
+        self.column += padding + token.text.chars().count();
+        self.previous = Some((token.kind, token.text.clone()));
+    }
+}
+
+fn would_merge(kind: PPTokenKind, text: &str, next: &PPToken) -> bool {
+    let word = |kind| matches!(kind, PPTokenKind::Identifier | PPTokenKind::Number);
+    match (kind, next.kind) {
+        (left, right) if word(left) && word(right) => true,
+        (PPTokenKind::Number, PPTokenKind::Punctuator) => next.text.starts_with('.'),
+        (PPTokenKind::Punctuator, PPTokenKind::Punctuator) => {
+            let joined = format!("{}{}", &text[text.len() - 1..], &next.text[..1]);
+            PUNCTUATORS.iter().any(|punctuator| punctuator.starts_with(&joined))
+        }
+        _ => false,
+    }
+}
+
+//
+// Tokenization
+//
+
+// Splits a source file into logical lines of tokens, after line splicing and comment removal
+fn tokenize_source(source: &str) -> Result<Vec<Vec<PPToken>>, (String, usize, usize)> {
+    let mut chars = Vec::with_capacity(source.len());
+    let (mut line, mut column) = (1, 1);
+    let mut iter = source.chars().peekable();
+    while let Some(c) = iter.next() {
+        if c == '\\' && matches!(iter.peek(), Some('\n')) {
+            iter.next();
+            line += 1;
+            column = 1;
+            continue;
+        }
+        if c == '\r' && matches!(iter.peek(), Some('\n')) {
+            continue;
+        }
+        chars.push((c, line, column));
+        if c == '\n' {
+            line += 1;
+            column = 1;
+        } else {
+            column += 1;
+        }
+    }
+
+    let mut lines = Vec::new();
+    let mut current = Vec::new();
+    let mut space_before = false;
+    let mut index = 0;
+    while index < chars.len() {
+        let (c, line, column) = chars[index];
+        let next = chars.get(index + 1).map(|(c, _, _)| *c);
+        match c {
+            '\n' => {
+                lines.push(std::mem::take(&mut current));
+                space_before = false;
+                index += 1;
+            }
+            _ if c.is_whitespace() => {
+                space_before = true;
+                index += 1;
+            }
+            '/' if next == Some('/') => {
+                while index < chars.len() && chars[index].0 != '\n' {
+                    index += 1;
+                }
+                space_before = true;
+            }
+            '/' if next == Some('*') => {
+                index += 2;
+                while index + 1 < chars.len() && !(chars[index].0 == '*' && chars[index + 1].0 == '/') {
+                    index += 1;
+                }
+                if index + 1 >= chars.len() {
+                    return Err(("Unterminated comment".to_string(), line, column));
+                }
+                index += 2;
+                space_before = true;
+            }
+            _ => {
+                let text = chars[index..].iter().map(|(c, _, _)| *c).take_while(|c| *c != '\n').collect::<String>();
+                let (kind, length) = next_token(&text);
+                current.push(PPToken {
+                    kind,
+                    text: text.chars().take(length).collect(),
+                    space_before,
+                    line,
+                    column,
+                    hideset: BTreeSet::new(),
+                });
+                space_before = false;
+                index += length;
+            }
+        }
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    Ok(lines)
+}
+
+fn tokenize_line(text: &str, line: usize) -> Vec<PPToken> {
+    let mut tokens = Vec::new();
+    let mut rest = text;
+    let mut space_before = false;
+    while let Some(c) = rest.chars().next() {
+        if c.is_whitespace() {
+            rest = &rest[c.len_utf8()..];
+            space_before = true;
+            continue;
+        }
+        let (kind, length) = next_token(rest);
+        let length = rest.char_indices().nth(length).map_or(rest.len(), |(offset, _)| offset);
+        tokens.push(PPToken {
+            kind,
+            text: rest[..length].to_string(),
+            space_before,
+            line,
+            column: 0,
+            hideset: BTreeSet::new(),
+        });
+        rest = &rest[length..];
+        space_before = false;
+    }
+    tokens
+}
+
+// Returns the kind and length in characters of the token at the start of the text, an unmatched
+// quote is left for the lexer to reject like any other stray character
+fn next_token(text: &str) -> (PPTokenKind, usize) {
+    let chars = text.chars().collect::<Vec<_>>();
+    let c = chars[0];
+
+    let literal_prefix = ["u8", "u", "U", "L"].iter()
+        .find(|prefix| text.starts_with(*prefix) && matches!(chars.get(prefix.len()), Some('"') | Some('\'')))
+        .map_or(0, |prefix| prefix.len());
+    if literal_prefix > 0 || c == '"' || c == '\'' {
+        let quote = chars[literal_prefix];
+        let mut index = literal_prefix + 1;
+        while index < chars.len() && chars[index] != quote {
+            index += if chars[index] == '\\' { 2 } else { 1 };
+        }
+        if index < chars.len() {
+            let kind = if quote == '"' { PPTokenKind::StringLiteral } else { PPTokenKind::CharLiteral };
+            return (kind, index + 1);
+        }
+        return (PPTokenKind::Other, 1);
+    }
+
+    if c.is_ascii_alphabetic() || c == '_' {
+        let length = chars.iter().take_while(|c| c.is_ascii_alphanumeric() || **c == '_').count();
+        return (PPTokenKind::Identifier, length);
+    }
+
+    if c.is_ascii_digit() || (c == '.' && chars.get(1).is_some_and(char::is_ascii_digit)) {
+        let mut length = 1;
+        while length < chars.len() {
+            let current = chars[length];
+            let exponent = matches!(chars[length - 1], 'e' | 'E' | 'p' | 'P') && matches!(current, '+' | '-');
+            if current.is_ascii_alphanumeric() || current == '_' || current == '.' || exponent {
+                length += 1;
+            } else {
+                break;
+            }
+        }
+        return (PPTokenKind::Number, length);
+    }
+
+    match PUNCTUATORS.iter().find(|punctuator| text.starts_with(*punctuator)) {
+        Some(punctuator) => (PPTokenKind::Punctuator, punctuator.len()),
+        None => (PPTokenKind::Other, 1),
+    }
+}
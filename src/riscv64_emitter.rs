@@ -0,0 +1,182 @@
+use std::fmt::{self, Write};
+
+use crate::riscv64_generator::{
+    RiscV64Address, RiscV64BinaryOperator, RiscV64Construct, RiscV64Function, RiscV64Global, RiscV64Instruction, RiscV64Register,
+    RiscV64UnaryOperator, RiscV64Width,
+};
+
+// Temporary the generator leaves free, holding addresses whose offset does not fit in an instruction
+const ADDRESS_REGISTER: &str = "t6";
+
+// Bytes of the frame the return address and the caller's frame pointer are saved in
+const SAVED_REGISTERS_SIZE: i32 = 16;
+
+// Prints the RISC-V assembly AST in GNU as syntax, instructions are written as whole indented lines
+// since some of them expand to several machine instructions
+pub fn emit(program: &RiscV64Construct) -> String {
+    let mut output = String::new();
+    // Writing to a String cannot fail
+    let _ = write_program(&mut output, program);
+    output
+}
+
+fn write_program(f: &mut dyn Write, program: &RiscV64Construct) -> fmt::Result {
+    let RiscV64Construct::Program { functions, globals } = program;
+    writeln!(f, ".text")?;
+    for function in functions {
+        write_function(f, function)?;
+    }
+    for global in globals {
+        write_global(f, global)?;
+    }
+    write!(f, "\n.section .note.GNU-stack,\"\",@progbits")
+}
+
+// Globals come after all functions, so switching sections does not affect the code. Zero-initialized
+// globals go to .bss, which takes no space in the object file.
+fn write_global(f: &mut dyn Write, global: &RiscV64Global) -> fmt::Result {
+    writeln!(f, ".globl {}", global.name)?;
+    writeln!(f, "{}", if global.initializer == 0 { ".bss" } else { ".data" })?;
+    writeln!(f, ".p2align 2")?;
+    writeln!(f, "{}:", global.name)?;
+    match global.initializer {
+        0 => writeln!(f, "    .zero 4"),
+        value => writeln!(f, "    .word {}", value),
+    }
+}
+
+// The return address and the caller's frame pointer are saved at the top of the frame, and the frame
+// pointer points just above them, where the caller's stack pointer was
+fn write_function(f: &mut dyn Write, function: &RiscV64Function) -> fmt::Result {
+    let RiscV64Function::Function { name, global, frame_size, instructions } = function;
+    if *global {
+        writeln!(f, ".globl {}", name)?;
+    }
+    writeln!(f, ".type {}, @function", name)?;
+    writeln!(f, ".p2align 2")?;
+    writeln!(f, "{}:", name)?;
+    writeln!(f, "    addi sp, sp, -{}", SAVED_REGISTERS_SIZE)?;
+    writeln!(f, "    sd ra, 8(sp)")?;
+    writeln!(f, "    sd s0, 0(sp)")?;
+    writeln!(f, "    addi s0, sp, {}", SAVED_REGISTERS_SIZE)?;
+    if *frame_size > 0 {
+        write_add_immediate(f, "sp", "sp", -frame_size)?;
+    }
+    for instruction in instructions {
+        write_instruction(f, instruction)?;
+    }
+    Ok(())
+}
+
+fn write_instruction(f: &mut dyn Write, instruction: &RiscV64Instruction) -> fmt::Result {
+    match instruction {
+        RiscV64Instruction::Li(dst, value) => writeln!(f, "    li {}, {}", register_name(dst), value),
+        RiscV64Instruction::La(dst, RiscV64Address::Global(name)) => writeln!(f, "    lla {}, {}", register_name(dst), name),
+        RiscV64Instruction::La(dst, address) => {
+            let (base, offset) = base_and_offset(address);
+            write_add_immediate(f, &register_name(dst), base, offset)
+        }
+        RiscV64Instruction::Load(width, dst, address) => write_access(f, load_mnemonic(width), dst, address),
+        RiscV64Instruction::Store(width, src, address) => write_access(f, store_mnemonic(width), src, address),
+        RiscV64Instruction::Unary(op, dst, src) => writeln!(f, "    {} {}, {}", unary_mnemonic(op), register_name(dst), register_name(src)),
+        RiscV64Instruction::Binary(op, dst, lhs, rhs) => {
+            writeln!(f, "    {} {}, {}, {}", binary_mnemonic(op), register_name(dst), register_name(lhs), register_name(rhs))
+        }
+        RiscV64Instruction::Addiw(dst, src, value) => writeln!(f, "    addiw {}, {}, {}", register_name(dst), register_name(src), value),
+        RiscV64Instruction::Xori(dst, src, value) => writeln!(f, "    xori {}, {}, {}", register_name(dst), register_name(src), value),
+        RiscV64Instruction::J(label) => writeln!(f, "    j {}", label),
+        RiscV64Instruction::Beqz(register, label) => writeln!(f, "    beqz {}, {}", register_name(register), label),
+        RiscV64Instruction::Label(label) => writeln!(f, "{}:", label),
+        RiscV64Instruction::Call(name) => writeln!(f, "    call {}", name),
+        RiscV64Instruction::Ret => {
+            writeln!(f, "    addi sp, s0, -{}", SAVED_REGISTERS_SIZE)?;
+            writeln!(f, "    ld ra, 8(sp)")?;
+            writeln!(f, "    ld s0, 0(sp)")?;
+            writeln!(f, "    addi sp, sp, {}", SAVED_REGISTERS_SIZE)?;
+            writeln!(f, "    ret")
+        }
+    }
+}
+
+// Loads, stores and addi take a signed 12-bit offset, larger ones are added to the base first
+fn write_access(f: &mut dyn Write, mnemonic: &str, register: &RiscV64Register, address: &RiscV64Address) -> fmt::Result {
+    let register = register_name(register);
+    if let RiscV64Address::Global(name) = address {
+        writeln!(f, "    lla {}, {}", ADDRESS_REGISTER, name)?;
+        return writeln!(f, "    {} {}, 0({})", mnemonic, register, ADDRESS_REGISTER);
+    }
+    let (base, offset) = base_and_offset(address);
+    if fits_immediate(offset) {
+        return writeln!(f, "    {} {}, {}({})", mnemonic, register, offset, base);
+    }
+    write_add_immediate(f, ADDRESS_REGISTER, base, offset)?;
+    writeln!(f, "    {} {}, 0({})", mnemonic, register, ADDRESS_REGISTER)
+}
+
+fn write_add_immediate(f: &mut dyn Write, dst: &str, src: &str, value: i32) -> fmt::Result {
+    if fits_immediate(value) {
+        return writeln!(f, "    addi {}, {}, {}", dst, src, value);
+    }
+    writeln!(f, "    li {}, {}", ADDRESS_REGISTER, value)?;
+    writeln!(f, "    add {}, {}, {}", dst, src, ADDRESS_REGISTER)
+}
+
+fn fits_immediate(value: i32) -> bool {
+    (-2048..=2047).contains(&value)
+}
+
+fn base_and_offset(address: &RiscV64Address) -> (&'static str, i32) {
+    match address {
+        RiscV64Address::Stack(offset) => ("sp", *offset),
+        RiscV64Address::Frame(offset) => ("s0", *offset),
+        RiscV64Address::Global(_) => unreachable!("globals are addressed through lla"),
+    }
+}
+
+fn register_name(register: &RiscV64Register) -> String {
+    match register {
+        RiscV64Register::A(number) => format!("a{}", number),
+        RiscV64Register::T(number) => format!("t{}", number),
+    }
+}
+
+fn load_mnemonic(width: &RiscV64Width) -> &'static str {
+    match width {
+        RiscV64Width::Word => "lw",
+        RiscV64Width::Double => "ld",
+    }
+}
+
+fn store_mnemonic(width: &RiscV64Width) -> &'static str {
+    match width {
+        RiscV64Width::Word => "sw",
+        RiscV64Width::Double => "sd",
+    }
+}
+
+fn unary_mnemonic(op: &RiscV64UnaryOperator) -> &'static str {
+    match op {
+        RiscV64UnaryOperator::Negw => "negw",
+        RiscV64UnaryOperator::Neg => "neg",
+        RiscV64UnaryOperator::Not => "not",
+        RiscV64UnaryOperator::Seqz => "seqz",
+        RiscV64UnaryOperator::Snez => "snez",
+    }
+}
+
+fn binary_mnemonic(op: &RiscV64BinaryOperator) -> &'static str {
+    match op {
+        RiscV64BinaryOperator::Addw => "addw",
+        RiscV64BinaryOperator::Subw => "subw",
+        RiscV64BinaryOperator::Mulw => "mulw",
+        RiscV64BinaryOperator::Divw => "divw",
+        RiscV64BinaryOperator::Divuw => "divuw",
+        RiscV64BinaryOperator::Remw => "remw",
+        RiscV64BinaryOperator::Remuw => "remuw",
+        RiscV64BinaryOperator::Sub => "sub",
+        RiscV64BinaryOperator::Xor => "xor",
+        RiscV64BinaryOperator::And => "and",
+        RiscV64BinaryOperator::Slt => "slt",
+        RiscV64BinaryOperator::Sltu => "sltu",
+    }
+}
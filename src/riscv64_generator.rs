@@ -0,0 +1,359 @@
+use crate::llvm_ir::{
+    IRType, LLVMBasicBlock, LLVMBinaryOp, LLVMComparison, LLVMConstruct, LLVMFunction, LLVMInstruction, LLVMUnaryOp, LLVMValue, Linkage,
+    ValueId,
+};
+use crate::stack_slots::{SlotLowering, StackFrame};
+
+// A straightforward RV64IM backend built like the AArch64 one: every value lives in a stack slot, and
+// each instruction loads its operands into temporaries, computes and stores its result. Functions follow
+// the LP64 calling convention, saving ra and the frame pointer s0 and only touching caller-saved
+// registers otherwise. 32-bit values are kept sign-extended to 64 bits, as the W instructions and lw
+// leave them.
+
+#[derive(Debug, Clone)]
+pub enum RiscV64Construct {
+    Program {
+        functions: Vec<RiscV64Function>,
+        globals: Vec<RiscV64Global>,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub enum RiscV64Function {
+    Function {
+        name: String,
+        // Whether the symbol is visible to other object files
+        global: bool,
+        // Bytes allocated below the saved registers, a multiple of 16 so that the stack stays aligned
+        frame_size: i32,
+        instructions: Vec<RiscV64Instruction>,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct RiscV64Global {
+    pub name: String,
+    pub initializer: i32,
+}
+
+#[derive(Debug, Clone)]
+pub enum RiscV64Instruction {
+    Li(RiscV64Register, i32),
+    // Address of a global or a stack slot
+    La(RiscV64Register, RiscV64Address),
+    Load(RiscV64Width, RiscV64Register, RiscV64Address),
+    Store(RiscV64Width, RiscV64Register, RiscV64Address),
+    Unary(RiscV64UnaryOperator, RiscV64Register, RiscV64Register),
+    // Destination, lhs and rhs
+    Binary(RiscV64BinaryOperator, RiscV64Register, RiscV64Register, RiscV64Register),
+    Addiw(RiscV64Register, RiscV64Register, i32),
+    Xori(RiscV64Register, RiscV64Register, i32),
+    J(String),
+    Beqz(RiscV64Register, String),
+    Label(String),
+    Call(String),
+    // Restores the saved registers before returning
+    Ret,
+}
+
+// Operators ending in w work on the low 32 bits and sign-extend their result, the others on all 64
+#[derive(Debug, Clone)]
+pub enum RiscV64UnaryOperator {
+    Negw,
+    Neg,
+    Not,
+    Seqz,
+    Snez,
+}
+
+#[derive(Debug, Clone)]
+pub enum RiscV64BinaryOperator {
+    Addw,
+    Subw,
+    Mulw,
+    Divw,
+    Divuw,
+    Remw,
+    Remuw,
+    Sub,
+    Xor,
+    And,
+    Slt,
+    Sltu,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RiscV64Width {
+    Word,
+    Double,
+}
+
+// Argument registers a0 to a7 and temporaries t0 to t6, by their ABI names
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RiscV64Register {
+    A(u8),
+    T(u8),
+}
+
+// Stack slots are addressed from the stack pointer, incoming stack arguments from the frame pointer
+#[derive(Debug, Clone)]
+pub enum RiscV64Address {
+    Stack(i32),
+    Frame(i32),
+    Global(String),
+}
+
+// LP64 passes the first eight integer arguments in a0 to a7, the others in 8-byte stack slots
+const ARGUMENT_REGISTERS: usize = 8;
+
+// Temporaries holding operands while an instruction is computed, t6 being left to the emitter
+const SCRATCH: [RiscV64Register; 3] = [RiscV64Register::T(0), RiscV64Register::T(1), RiscV64Register::T(2)];
+
+// Range of the signed 12-bit immediates of addiw
+const IMMEDIATES: std::ops::RangeInclusive<i32> = -2048..=2047;
+
+pub fn generate(llvm_ir: &LLVMConstruct) -> Result<RiscV64Construct, String> {
+    match llvm_ir {
+        LLVMConstruct::Module { globals, functions } => {
+            let functions = functions.iter()
+                .filter(|function| !function.is_declaration())
+                .map(generate_function)
+                .collect::<Result<Vec<_>, String>>()?;
+            let globals = globals.iter()
+                .map(|global| RiscV64Global { name: global.name.clone(), initializer: global.initializer })
+                .collect();
+            Ok(RiscV64Construct::Program { functions, globals })
+        }
+    }
+}
+
+struct FunctionGenerator<'a> {
+    frame: StackFrame<'a>,
+}
+
+fn generate_function(function: &LLVMFunction) -> Result<RiscV64Function, String> {
+    let LLVMFunction::Function { name, linkage, params, blocks, .. } = function;
+    let mut generator = FunctionGenerator { frame: StackFrame::new(function, ARGUMENT_REGISTERS) };
+
+    // Parameters beyond the eighth are where the caller's stack pointer was, which the frame pointer
+    // points to
+    let mut asm_instructions = Vec::new();
+    for (index, (id, ty)) in params.iter().enumerate() {
+        let register = if index < ARGUMENT_REGISTERS {
+            RiscV64Register::A(index as u8)
+        } else {
+            let offset = 8 * (index - ARGUMENT_REGISTERS) as i32;
+            asm_instructions.push(RiscV64Instruction::Load(width(*ty), SCRATCH[0], RiscV64Address::Frame(offset)));
+            SCRATCH[0]
+        };
+        asm_instructions.push(generator.store(*ty, register, *id));
+    }
+
+    asm_instructions.extend(generator.generate_blocks(blocks)?);
+
+    Ok(RiscV64Function::Function {
+        name: name.clone(),
+        global: *linkage == Linkage::External,
+        frame_size: generator.frame.size(),
+        instructions: asm_instructions,
+    })
+}
+
+impl<'a> SlotLowering<'a> for FunctionGenerator<'a> {
+    type Instruction = RiscV64Instruction;
+
+    fn frame(&mut self) -> &mut StackFrame<'a> {
+        &mut self.frame
+    }
+
+    fn label(label: String) -> RiscV64Instruction {
+        RiscV64Instruction::Label(label)
+    }
+
+    fn copy(&mut self, dst: ValueId, ty: IRType, value: &LLVMValue) -> Vec<RiscV64Instruction> {
+        let mut instructions = self.load(value, ty, SCRATCH[0]);
+        instructions.push(self.store(ty, SCRATCH[0], dst));
+        instructions
+    }
+
+    fn generate_instruction(&mut self, block: &LLVMBasicBlock, instruction: &LLVMInstruction) -> Result<Vec<RiscV64Instruction>, String> {
+        let [first, second, third] = SCRATCH;
+        match instruction {
+            LLVMInstruction::Return => Ok(vec![RiscV64Instruction::Ret]),
+            LLVMInstruction::ReturnValue(ty, value) => {
+                let mut instructions = self.load(value, *ty, RiscV64Register::A(0));
+                instructions.push(RiscV64Instruction::Ret);
+                Ok(instructions)
+            },
+            LLVMInstruction::Branch(target) => {
+                let mut instructions = self.edge_copies(&block.label, target);
+                instructions.push(RiscV64Instruction::J(self.frame.block_label(target)));
+                Ok(instructions)
+            },
+            // Conditional branches only reach 4 KiB away, so they skip over a jump to the block rather than
+            // going there themselves. Copies for phi nodes go on the edge they belong to, so that a value
+            // still live along the other edge is not overwritten.
+            LLVMInstruction::CondBranch(condition, then_target, else_target) => {
+                let then_copies = self.edge_copies(&block.label, then_target);
+                let else_copies = self.edge_copies(&block.label, else_target);
+                let else_edge = self.frame.new_label();
+                let mut instructions = self.load(condition, IRType::I1, first);
+                instructions.push(RiscV64Instruction::Beqz(first, else_edge.clone()));
+                instructions.extend(then_copies);
+                instructions.push(RiscV64Instruction::J(self.frame.block_label(then_target)));
+                instructions.push(RiscV64Instruction::Label(else_edge));
+                instructions.extend(else_copies);
+                instructions.push(RiscV64Instruction::J(self.frame.block_label(else_target)));
+                Ok(instructions)
+            },
+            LLVMInstruction::UnaryOp(dst, ty, op, value) => {
+                let asm_op = match op {
+                    LLVMUnaryOp::Not => RiscV64UnaryOperator::Not,
+                    LLVMUnaryOp::Neg => RiscV64UnaryOperator::Negw,
+                };
+                let mut instructions = self.load(value, *ty, first);
+                instructions.push(RiscV64Instruction::Unary(asm_op, first, first));
+                instructions.push(self.store(*ty, first, *dst));
+                Ok(instructions)
+            },
+            // Division by zero gives all ones and the remainder the dividend, rather than trapping
+            LLVMInstruction::BinaryOp(dst, ty, op, lhs, rhs) => {
+                let mut instructions = self.load(lhs, *ty, first);
+                match (op, rhs) {
+                    (LLVMBinaryOp::Add, LLVMValue::Immediate(value)) if IMMEDIATES.contains(value) => {
+                        instructions.push(RiscV64Instruction::Addiw(first, first, *value));
+                    }
+                    (LLVMBinaryOp::Subtract, LLVMValue::Immediate(value)) if IMMEDIATES.contains(&value.wrapping_neg()) => {
+                        instructions.push(RiscV64Instruction::Addiw(first, first, value.wrapping_neg()));
+                    }
+                    _ => {
+                        let asm_op = match op {
+                            LLVMBinaryOp::Add => RiscV64BinaryOperator::Addw,
+                            LLVMBinaryOp::Subtract => RiscV64BinaryOperator::Subw,
+                            LLVMBinaryOp::Multiply => RiscV64BinaryOperator::Mulw,
+                            LLVMBinaryOp::Divide => RiscV64BinaryOperator::Divw,
+                            LLVMBinaryOp::Remainder => RiscV64BinaryOperator::Remw,
+                            LLVMBinaryOp::UnsignedDivide => RiscV64BinaryOperator::Divuw,
+                            LLVMBinaryOp::UnsignedRemainder => RiscV64BinaryOperator::Remuw,
+                        };
+                        instructions.extend(self.load(rhs, *ty, second));
+                        instructions.push(RiscV64Instruction::Binary(asm_op, first, first, second));
+                    }
+                }
+                instructions.push(self.store(*ty, first, *dst));
+                Ok(instructions)
+            },
+            // Sign-extended values order the same way as their low 32 bits, signed or unsigned, so the
+            // comparisons use all 64 bits. There are only less-than comparisons, the others swap their
+            // operands or invert the result.
+            LLVMInstruction::ICmp(dst, comparison, ty, lhs, rhs) => {
+                let mut instructions = self.load(lhs, *ty, first);
+                instructions.extend(self.load(rhs, *ty, second));
+                let less = |unsigned: bool, lhs, rhs| {
+                    let op = if unsigned { RiscV64BinaryOperator::Sltu } else { RiscV64BinaryOperator::Slt };
+                    RiscV64Instruction::Binary(op, first, lhs, rhs)
+                };
+                let invert = RiscV64Instruction::Xori(first, first, 1);
+                let difference = RiscV64Instruction::Binary(RiscV64BinaryOperator::Sub, first, first, second);
+                instructions.extend(match comparison {
+                    LLVMComparison::Equal => vec![difference, RiscV64Instruction::Unary(RiscV64UnaryOperator::Seqz, first, first)],
+                    LLVMComparison::NotEqual => vec![difference, RiscV64Instruction::Unary(RiscV64UnaryOperator::Snez, first, first)],
+                    LLVMComparison::SignedLess => vec![less(false, first, second)],
+                    LLVMComparison::SignedGreater => vec![less(false, second, first)],
+                    LLVMComparison::SignedLessOrEqual => vec![less(false, second, first), invert],
+                    LLVMComparison::SignedGreaterOrEqual => vec![less(false, first, second), invert],
+                    LLVMComparison::UnsignedLess => vec![less(true, first, second)],
+                    LLVMComparison::UnsignedGreater => vec![less(true, second, first)],
+                    LLVMComparison::UnsignedLessOrEqual => vec![less(true, second, first), invert],
+                    LLVMComparison::UnsignedGreaterOrEqual => vec![less(true, first, second), invert],
+                });
+                instructions.push(self.store(IRType::I1, first, *dst));
+                Ok(instructions)
+            },
+            // Booleans are already kept as 0 or 1 in a full register
+            LLVMInstruction::ZExt(dst, ty, value, _) => {
+                let mut instructions = self.load(value, *ty, first);
+                instructions.push(self.store(IRType::I32, first, *dst));
+                Ok(instructions)
+            },
+            // Without a conditional move, the boolean is turned into a mask: rhs ^ ((lhs ^ rhs) & -condition)
+            LLVMInstruction::Select(dst, ty, condition, lhs, rhs) => {
+                let mut instructions = self.load(condition, IRType::I1, first);
+                instructions.extend(self.load(lhs, *ty, second));
+                instructions.extend(self.load(rhs, *ty, third));
+                instructions.extend([
+                    RiscV64Instruction::Unary(RiscV64UnaryOperator::Neg, first, first),
+                    RiscV64Instruction::Binary(RiscV64BinaryOperator::Xor, second, second, third),
+                    RiscV64Instruction::Binary(RiscV64BinaryOperator::And, second, second, first),
+                    RiscV64Instruction::Binary(RiscV64BinaryOperator::Xor, second, second, third),
+                ]);
+                instructions.push(self.store(*ty, second, *dst));
+                Ok(instructions)
+            },
+            LLVMInstruction::Call(dst, ty, callee, args) => Ok(self.generate_call(*dst, *ty, callee, args)),
+            LLVMInstruction::Store(ty, src, dst) => {
+                let mut instructions = self.load(src, *ty, first);
+                instructions.push(RiscV64Instruction::Store(width(*ty), first, self.pointer(dst)?));
+                Ok(instructions)
+            },
+            LLVMInstruction::Load(dst, ty, src) => {
+                Ok(vec![RiscV64Instruction::Load(width(*ty), first, self.pointer(src)?), self.store(*ty, first, *dst)])
+            },
+            LLVMInstruction::Alloca(..) => Ok(Vec::new()),
+            LLVMInstruction::Phi(..) => Ok(Vec::new()),
+        }
+    }
+}
+
+impl FunctionGenerator<'_> {
+    // Stack arguments are stored first, since loading them goes through a temporary rather than the
+    // argument registers
+    fn generate_call(&mut self, dst: Option<ValueId>, ty: IRType, callee: &str, args: &[(IRType, LLVMValue)]) -> Vec<RiscV64Instruction> {
+        let mut instructions = Vec::new();
+        for (index, (ty, value)) in args.iter().enumerate().skip(ARGUMENT_REGISTERS) {
+            instructions.extend(self.load(value, *ty, SCRATCH[0]));
+            let offset = 8 * (index - ARGUMENT_REGISTERS) as i32;
+            instructions.push(RiscV64Instruction::Store(RiscV64Width::Double, SCRATCH[0], RiscV64Address::Stack(offset)));
+        }
+        for (index, (ty, value)) in args.iter().take(ARGUMENT_REGISTERS).enumerate() {
+            instructions.extend(self.load(value, *ty, RiscV64Register::A(index as u8)));
+        }
+        instructions.push(RiscV64Instruction::Call(callee.to_string()));
+        if let Some(dst) = dst {
+            instructions.push(self.store(ty, RiscV64Register::A(0), dst));
+        }
+        instructions
+    }
+
+    // Brings a value into the given register, allocas and globals being their address
+    fn load(&mut self, value: &LLVMValue, ty: IRType, register: RiscV64Register) -> Vec<RiscV64Instruction> {
+        match value {
+            LLVMValue::Immediate(value) => vec![RiscV64Instruction::Li(register, *value)],
+            LLVMValue::Register(id) if self.frame.is_alloca(*id) => {
+                vec![RiscV64Instruction::La(register, RiscV64Address::Stack(self.frame.slot(*id)))]
+            }
+            LLVMValue::Register(id) => vec![RiscV64Instruction::Load(width(ty), register, RiscV64Address::Stack(self.frame.slot(*id)))],
+            LLVMValue::Global(name) => vec![RiscV64Instruction::La(register, RiscV64Address::Global(name.clone()))],
+        }
+    }
+
+    fn store(&mut self, ty: IRType, register: RiscV64Register, id: ValueId) -> RiscV64Instruction {
+        RiscV64Instruction::Store(width(ty), register, RiscV64Address::Stack(self.frame.slot(id)))
+    }
+
+    fn pointer(&mut self, pointer: &LLVMValue) -> Result<RiscV64Address, String> {
+        match pointer {
+            LLVMValue::Register(id) if self.frame.is_alloca(*id) => Ok(RiscV64Address::Stack(self.frame.slot(*id))),
+            LLVMValue::Global(name) => Ok(RiscV64Address::Global(name.clone())),
+            other => Err(format!("Memory access through {} is not supported by the RISC-V backend", other)),
+        }
+    }
+}
+
+// Pointers take a doubleword, other values a word
+fn width(ty: IRType) -> RiscV64Width {
+    match ty {
+        IRType::Ptr => RiscV64Width::Double,
+        _ => RiscV64Width::Word,
+    }
+}
@@ -0,0 +1,168 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::llvm_ir::{IRType, LLVMBasicBlock, LLVMFunction, LLVMInstruction, LLVMValue, ValueId};
+
+// Lowering shared by the backends that keep every value in a stack slot, AArch64 and RISC-V: each
+// instruction loads its operands into scratch registers, computes and stores its result. The frame lays
+// out the slots, and phi nodes become copies on the edges leading to their block.
+
+// Labels local to the object file, made unique across functions
+pub fn block_label(function: &str, label: &str) -> String {
+    format!(".L{}.{}", function, label)
+}
+
+// Incoming values of a phi node, with the label of the predecessor each flows from
+type Incoming = [(LLVMValue, String)];
+
+pub struct StackFrame<'a> {
+    name: &'a str,
+    // Allocas are stack slots themselves, so memory accesses through them use the slot directly
+    allocas: HashSet<ValueId>,
+    // Phi nodes of each block, turned into copies on the edges leading to it
+    phis: HashMap<&'a str, Vec<(ValueId, IRType, &'a Incoming)>>,
+    // Type of the value in each slot, the allocated type for allocas
+    types: HashMap<ValueId, IRType>,
+    // Offsets of the slots from the stack pointer, assigned as values are first stored or loaded
+    slots: HashMap<ValueId, i32>,
+    // End of the slots, which start above the outgoing stack arguments
+    frame_end: i32,
+    next_temporary: usize,
+    next_label: usize,
+}
+
+impl<'a> StackFrame<'a> {
+    // Outgoing stack arguments, those past the argument registers, are stored in 8-byte slots at the bottom
+    // of the frame, where the callee finds them
+    pub fn new(function: &'a LLVMFunction, argument_registers: usize) -> Self {
+        let LLVMFunction::Function { name, params, blocks, .. } = function;
+        let instructions = blocks.iter().flat_map(|block| &block.instructions);
+        let outgoing_arguments = instructions.clone()
+            .filter_map(|instruction| match instruction {
+                LLVMInstruction::Call(_, _, _, args) => Some(args.len().saturating_sub(argument_registers)),
+                _ => None,
+            })
+            .max()
+            .unwrap_or(0);
+        let phis = blocks.iter()
+            .map(|block| {
+                let phis = block.instructions.iter().filter_map(|instruction| match instruction {
+                    LLVMInstruction::Phi(dst, ty, incoming) => Some((*dst, *ty, incoming.as_slice())),
+                    _ => None,
+                });
+                (block.label.as_str(), phis.collect())
+            })
+            .collect();
+        StackFrame {
+            name,
+            allocas: instructions.clone()
+                .filter_map(|instruction| match instruction {
+                    LLVMInstruction::Alloca(slot, _) => Some(*slot),
+                    _ => None,
+                })
+                .collect(),
+            phis,
+            types: params.iter()
+                .copied()
+                .chain(instructions.filter_map(|instruction| match instruction {
+                    LLVMInstruction::Alloca(slot, ty) => Some((*slot, *ty)),
+                    other => other.result(),
+                }))
+                .collect(),
+            slots: HashMap::new(),
+            frame_end: 8 * outgoing_arguments as i32,
+            next_temporary: function.next_value_id().0,
+            next_label: 0,
+        }
+    }
+
+    pub fn is_alloca(&self, id: ValueId) -> bool {
+        self.allocas.contains(&id)
+    }
+
+    // Slots are aligned to their size, which loads and stores need to encode their offset. Booleans are
+    // kept zero-extended in a full 32-bit register, so they take as much room as an int.
+    pub fn slot(&mut self, id: ValueId) -> i32 {
+        if let Some(offset) = self.slots.get(&id) {
+            return *offset;
+        }
+        let size = match self.types.get(&id) {
+            Some(IRType::Ptr) => 8,
+            _ => 4,
+        };
+        let offset = (self.frame_end + size - 1) / size * size;
+        self.frame_end = offset + size;
+        self.slots.insert(id, offset);
+        offset
+    }
+
+    // Bytes taken by the slots and outgoing arguments, a multiple of 16 so that the stack stays aligned
+    pub fn size(&self) -> i32 {
+        (self.frame_end + 15) / 16 * 16
+    }
+
+    pub fn block_label(&self, label: &str) -> String {
+        block_label(self.name, label)
+    }
+
+    // Labels that do not come from a block, numbered so they cannot clash with block labels
+    pub fn new_label(&mut self) -> String {
+        self.next_label += 1;
+        block_label(self.name, &self.next_label.to_string())
+    }
+
+    // Phi nodes read their incoming values all at once, so the copies go through fresh slots when there
+    // are several of them. Each copy is a destination, its type and the value copied into it.
+    pub fn edge_copies(&mut self, from: &str, to: &str) -> Vec<(ValueId, IRType, LLVMValue)> {
+        let copies = self.phis.get(to).into_iter().flatten()
+            .filter_map(|(dst, ty, incoming)| {
+                let (value, _) = incoming.iter().find(|(_, label)| label == from)?;
+                Some((*dst, *ty, value.clone()))
+            })
+            .collect::<Vec<_>>();
+        if copies.len() < 2 {
+            return copies;
+        }
+
+        let temporaries = copies.iter()
+            .map(|(_, ty, _)| {
+                let id = ValueId(self.next_temporary);
+                self.next_temporary += 1;
+                self.types.insert(id, *ty);
+                id
+            })
+            .collect::<Vec<_>>();
+        let to_temporaries = copies.iter().zip(&temporaries).map(|((_, ty, value), temporary)| (*temporary, *ty, value.clone()));
+        let from_temporaries = copies.iter().zip(&temporaries).map(|((dst, ty, _), temporary)| (*dst, *ty, LLVMValue::Register(*temporary)));
+        to_temporaries.collect::<Vec<_>>().into_iter().chain(from_temporaries).collect()
+    }
+}
+
+// A backend lowering each instruction on its own, between loads and stores of the stack slots
+pub trait SlotLowering<'a> {
+    type Instruction;
+
+    fn frame(&mut self) -> &mut StackFrame<'a>;
+    fn label(label: String) -> Self::Instruction;
+    // Copies a value into the slot of another one
+    fn copy(&mut self, dst: ValueId, ty: IRType, value: &LLVMValue) -> Vec<Self::Instruction>;
+    fn generate_instruction(&mut self, block: &LLVMBasicBlock, instruction: &LLVMInstruction) -> Result<Vec<Self::Instruction>, String>;
+
+    // The entry block cannot be branched to, so it needs no label
+    fn generate_blocks(&mut self, blocks: &[LLVMBasicBlock]) -> Result<Vec<Self::Instruction>, String> {
+        let mut instructions = Vec::new();
+        for (index, block) in blocks.iter().enumerate() {
+            if index > 0 {
+                instructions.push(Self::label(self.frame().block_label(&block.label)));
+            }
+            for instruction in &block.instructions {
+                instructions.extend(self.generate_instruction(block, instruction)?);
+            }
+        }
+        Ok(instructions)
+    }
+
+    fn edge_copies(&mut self, from: &str, to: &str) -> Vec<Self::Instruction> {
+        let copies = self.frame().edge_copies(from, to);
+        copies.iter().flat_map(|(dst, ty, value)| self.copy(*dst, *ty, value)).collect()
+    }
+}
@@ -0,0 +1,136 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::cfg::ControlFlowGraph;
+use crate::dominators::DominatorTree;
+use crate::llvm_ir::{IRType, LLVMBasicBlock, LLVMBinaryOp, LLVMFunction, LLVMInstruction, LLVMValue, ValueId};
+use crate::loops::{self, NaturalLoop};
+
+// Induction variable strength reduction: a multiplication of a loop counter `i = i + step` by a value `c`
+// that does not change in the loop becomes a second counter starting at `init * c` and increased by
+// `step * c` on every iteration. Wrapping arithmetic makes both sequences equal even on overflow.
+pub fn run(function: &mut LLVMFunction) -> bool {
+    let mut next_value = function.next_value_id().0;
+    let mut changed = false;
+    loop {
+        let LLVMFunction::Function { blocks, .. } = &mut *function;
+        let Some(replaced) = reduce_first_multiplication(blocks, &mut next_value) else {
+            break;
+        };
+        function.replace_uses(&replaced);
+        changed = true;
+    }
+    changed
+}
+
+// A multiplication to rewrite, with the labels it is found by once a preheader shifts the blocks
+struct Candidate {
+    header: String,
+    latch: String,
+    counter: ValueId,
+    step: LLVMValue,
+    product: ValueId,
+    factor: LLVMValue,
+}
+
+// Rewrites one multiplication, returning the replacement of its result
+fn reduce_first_multiplication(blocks: &mut Vec<LLVMBasicBlock>, next_value: &mut usize) -> Option<HashMap<ValueId, LLVMValue>> {
+    let cfg = ControlFlowGraph::new(blocks);
+    let dominators = DominatorTree::new(&cfg);
+    let (natural_loop, candidate) = loops::find_loops(&cfg, &dominators)
+        .into_iter()
+        .find_map(|natural_loop| find_candidate(blocks, &natural_loop).map(|candidate| (natural_loop, candidate)))?;
+
+    let preheader = loops::insert_preheader(blocks, next_value, &cfg, &natural_loop);
+    let preheader_label = blocks[preheader].label.clone();
+    let mut new_value = || {
+        let id = ValueId(*next_value);
+        *next_value += 1;
+        id
+    };
+
+    // The counter's initial value, which goes through the preheader now
+    let header = blocks.iter().position(|block| block.label == candidate.header)?;
+    let init = blocks[header].instructions.iter().find_map(|instruction| match instruction {
+        LLVMInstruction::Phi(id, _, incoming) if *id == candidate.counter => {
+            incoming.iter().find(|(_, from)| *from == preheader_label).map(|(value, _)| value.clone())
+        }
+        _ => None,
+    })?;
+
+    let (start, increment, reduced, next) = (new_value(), new_value(), new_value(), new_value());
+    let instructions = &mut blocks[preheader].instructions;
+    let terminator = instructions.len() - 1;
+    instructions.splice(terminator..terminator, [
+        LLVMInstruction::BinaryOp(start, IRType::I32, LLVMBinaryOp::Multiply, init, candidate.factor.clone()),
+        LLVMInstruction::BinaryOp(increment, IRType::I32, LLVMBinaryOp::Multiply, candidate.step, candidate.factor),
+    ]);
+
+    blocks[header].instructions.insert(0, LLVMInstruction::Phi(reduced, IRType::I32, vec![
+        (LLVMValue::Register(start), preheader_label),
+        (LLVMValue::Register(next), candidate.latch.clone()),
+    ]));
+    let latch = blocks.iter().position(|block| block.label == candidate.latch)?;
+    let instructions = &mut blocks[latch].instructions;
+    let terminator = instructions.len() - 1;
+    instructions.insert(
+        terminator,
+        LLVMInstruction::BinaryOp(next, IRType::I32, LLVMBinaryOp::Add, LLVMValue::Register(reduced), LLVMValue::Register(increment)),
+    );
+
+    for block in blocks.iter_mut() {
+        block.instructions.retain(|instruction| instruction.result().is_none_or(|(id, _)| id != candidate.product));
+    }
+    Some(HashMap::from([(candidate.product, LLVMValue::Register(reduced))]))
+}
+
+fn find_candidate(blocks: &[LLVMBasicBlock], natural_loop: &NaturalLoop) -> Option<Candidate> {
+    // Only loops with a single back edge have a single place to increase the new counter
+    let [latch] = natural_loop.latches.as_slice() else {
+        return None;
+    };
+    let latch_label = &blocks[*latch].label;
+    let loop_instructions = || natural_loop.blocks.iter().flat_map(|block| &blocks[*block].instructions);
+    let defined_in_loop = loop_instructions().filter_map(|instruction| instruction.result()).map(|(id, _)| id).collect::<HashSet<_>>();
+    let invariant = |value: &LLVMValue| match value {
+        LLVMValue::Register(id) => !defined_in_loop.contains(id),
+        LLVMValue::Immediate(_) | LLVMValue::Global(_) => true,
+    };
+    let definition = |id: &ValueId| loop_instructions().find(|instruction| instruction.result().is_some_and(|(result, _)| result == *id));
+
+    // Counters are header phis whose value along the back edge is the phi plus an invariant step
+    let mut counters = HashMap::new();
+    for instruction in &blocks[natural_loop.header].instructions {
+        let LLVMInstruction::Phi(counter, IRType::I32, incoming) = instruction else {
+            continue;
+        };
+        let Some((LLVMValue::Register(next), _)) = incoming.iter().find(|(_, from)| from == latch_label) else {
+            continue;
+        };
+        let Some(LLVMInstruction::BinaryOp(_, IRType::I32, LLVMBinaryOp::Add, lhs, rhs)) = definition(next) else {
+            continue;
+        };
+        let step = match (lhs, rhs) {
+            (LLVMValue::Register(id), step) | (step, LLVMValue::Register(id)) if id == counter && invariant(step) => step,
+            _ => continue,
+        };
+        counters.insert(*counter, step.clone());
+    }
+
+    loop_instructions().find_map(|instruction| {
+        let LLVMInstruction::BinaryOp(product, IRType::I32, LLVMBinaryOp::Multiply, lhs, rhs) = instruction else {
+            return None;
+        };
+        let (counter, factor) = match (lhs, rhs) {
+            (LLVMValue::Register(id), factor) | (factor, LLVMValue::Register(id)) if counters.contains_key(id) && invariant(factor) => (id, factor),
+            _ => return None,
+        };
+        Some(Candidate {
+            header: blocks[natural_loop.header].label.clone(),
+            latch: latch_label.clone(),
+            counter: *counter,
+            step: counters[counter].clone(),
+            product: *product,
+            factor: factor.clone(),
+        })
+    })
+}
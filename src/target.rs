@@ -0,0 +1,187 @@
+use crate::aarch64_emitter;
+use crate::aarch64_generator::{self, AArch64Construct};
+use crate::elf_writer;
+use crate::llvm_ir::LLVMConstruct;
+use crate::riscv64_emitter;
+use crate::riscv64_generator::{self, RiscV64Construct};
+use crate::x86_64_emitter;
+use crate::x86_64_encoder;
+use crate::x86_64_generator::{self, AssemblyConstruct, GeneratorOptions};
+use crate::x86_64_register_allocator::RegisterAllocator;
+use crate::CompileOptions;
+
+// Architecture code is generated for, chosen with --target=
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum TargetArchitecture {
+    X86_64,
+    AArch64,
+    RiscV64,
+}
+
+impl TargetArchitecture {
+    pub fn from_name(name: &str) -> Option<TargetArchitecture> {
+        match name {
+            "x86_64-linux-gnu" => Some(TargetArchitecture::X86_64),
+            "aarch64-linux-gnu" => Some(TargetArchitecture::AArch64),
+            "riscv64" | "riscv64-linux-gnu" => Some(TargetArchitecture::RiscV64),
+            _ => None,
+        }
+    }
+
+    // Directories searched for system headers: the architecture's own headers come from the cross toolchain's
+    // sysroot or from Debian's multiarch layout, before the headers shared by every architecture
+    pub fn system_include_dirs(self) -> &'static [&'static str] {
+        match self {
+            TargetArchitecture::X86_64 => &["/usr/local/include", "/usr/include/x86_64-linux-gnu", "/usr/include"],
+            TargetArchitecture::AArch64 => {
+                &["/usr/local/include", "/usr/aarch64-linux-gnu/include", "/usr/include/aarch64-linux-gnu", "/usr/include"]
+            }
+            TargetArchitecture::RiscV64 => {
+                &["/usr/local/include", "/usr/riscv64-linux-gnu/include", "/usr/include/riscv64-linux-gnu", "/usr/include"]
+            }
+        }
+    }
+
+    // Macros the preprocessor defines to identify the architecture, and to describe the types its ABI leaves
+    // open: plain char is unsigned on AArch64 and RISC-V, and so is wchar_t on AArch64
+    pub fn predefined_macros(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            TargetArchitecture::X86_64 => &[
+                ("__x86_64__", "1"),
+                ("__WCHAR_TYPE__", "int"),
+                ("__WCHAR_MAX__", "0x7fffffff"),
+                ("__WCHAR_MIN__", "(-__WCHAR_MAX__ - 1)"),
+            ],
+            TargetArchitecture::AArch64 => &[
+                ("__aarch64__", "1"),
+                ("__CHAR_UNSIGNED__", "1"),
+                ("__WCHAR_TYPE__", "unsigned int"),
+                ("__WCHAR_MAX__", "0xffffffffU"),
+                ("__WCHAR_MIN__", "0U"),
+            ],
+            TargetArchitecture::RiscV64 => &[
+                ("__riscv", "1"),
+                ("__riscv_xlen", "64"),
+                ("__CHAR_UNSIGNED__", "1"),
+                ("__WCHAR_TYPE__", "int"),
+                ("__WCHAR_MAX__", "0x7fffffff"),
+                ("__WCHAR_MIN__", "(-__WCHAR_MAX__ - 1)"),
+            ],
+        }
+    }
+}
+
+// A backend lowering optimized IR to one architecture. Each one builds its own assembly AST, which the
+// rest of the compiler only sees through `Program`.
+pub trait Target {
+    // Prefix of the cross toolchain assembling and linking for the target, None for the host's own
+    fn toolchain_prefix(&self) -> Option<&'static str>;
+    // Whether objects can be emitted directly, without the system assembler
+    fn has_integrated_assembler(&self) -> bool;
+    fn generate(&self, module: &LLVMConstruct, options: &CompileOptions) -> Result<Box<dyn Program>, String>;
+}
+
+// Code generated for a target, ready to be printed or encoded
+pub trait Program {
+    fn assembly(&self, options: &CompileOptions) -> String;
+
+    fn object(&self) -> Result<Vec<u8>, String> {
+        Err("The integrated assembler does not support this target".to_string())
+    }
+}
+
+pub fn target(architecture: TargetArchitecture) -> &'static dyn Target {
+    match architecture {
+        TargetArchitecture::X86_64 => &X86_64Target,
+        TargetArchitecture::AArch64 => &AArch64Target,
+        TargetArchitecture::RiscV64 => &RiscV64Target,
+    }
+}
+
+//
+// x86-64
+//
+
+pub struct X86_64Target;
+
+impl Target for X86_64Target {
+    fn toolchain_prefix(&self) -> Option<&'static str> {
+        None
+    }
+
+    fn has_integrated_assembler(&self) -> bool {
+        true
+    }
+
+    fn generate(&self, module: &LLVMConstruct, options: &CompileOptions) -> Result<Box<dyn Program>, String> {
+        let generator_options = GeneratorOptions {
+            register_allocator: options.register_allocator
+                .unwrap_or_else(|| RegisterAllocator::for_optimization_level(options.optimization_level)),
+            red_zone: options.red_zone,
+            optimization_level: options.optimization_level,
+        };
+        Ok(Box::new(x86_64_generator::generate(module, &generator_options)?))
+    }
+}
+
+impl Program for AssemblyConstruct {
+    fn assembly(&self, options: &CompileOptions) -> String {
+        x86_64_emitter::emit(self, options.assembly_syntax)
+    }
+
+    fn object(&self) -> Result<Vec<u8>, String> {
+        x86_64_encoder::encode(self).and_then(|object| elf_writer::write(&object))
+    }
+}
+
+//
+// AArch64
+//
+
+pub struct AArch64Target;
+
+impl Target for AArch64Target {
+    fn toolchain_prefix(&self) -> Option<&'static str> {
+        Some("aarch64-linux-gnu-")
+    }
+
+    fn has_integrated_assembler(&self) -> bool {
+        false
+    }
+
+    fn generate(&self, module: &LLVMConstruct, _options: &CompileOptions) -> Result<Box<dyn Program>, String> {
+        Ok(Box::new(aarch64_generator::generate(module)?))
+    }
+}
+
+impl Program for AArch64Construct {
+    fn assembly(&self, _options: &CompileOptions) -> String {
+        aarch64_emitter::emit(self)
+    }
+}
+
+//
+// RISC-V
+//
+
+pub struct RiscV64Target;
+
+impl Target for RiscV64Target {
+    fn toolchain_prefix(&self) -> Option<&'static str> {
+        Some("riscv64-linux-gnu-")
+    }
+
+    fn has_integrated_assembler(&self) -> bool {
+        false
+    }
+
+    fn generate(&self, module: &LLVMConstruct, _options: &CompileOptions) -> Result<Box<dyn Program>, String> {
+        Ok(Box::new(riscv64_generator::generate(module)?))
+    }
+}
+
+impl Program for RiscV64Construct {
+    fn assembly(&self, _options: &CompileOptions) -> String {
+        riscv64_emitter::emit(self)
+    }
+}
@@ -0,0 +1,322 @@
+use std::fmt::{self, Write};
+
+use crate::x86_64_generator::{
+    AssemblyAddress, AssemblyBinaryOperator, AssemblyCondition, AssemblyConstruct, AssemblyFunction, AssemblyGlobal, AssemblyInstruction,
+    AssemblyOperand, AssemblyRegister, AssemblyUnaryOperator,
+};
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum AssemblySyntax {
+    Att,
+    Intel,
+}
+
+// Prints the assembly AST in one of the syntaxes GNU as understands, instructions are written as whole
+// indented lines since some of them expand to several machine instructions
+pub trait AssemblyEmitter {
+    // Directive switching the assembler to this syntax, when it is not the default one
+    fn syntax_directive(&self) -> Option<&'static str>;
+    fn write_prologue(&self, f: &mut dyn Write) -> fmt::Result;
+    fn write_instruction(&self, f: &mut dyn Write, instruction: &AssemblyInstruction) -> fmt::Result;
+
+    fn write_program(&self, f: &mut dyn Write, program: &AssemblyConstruct) -> fmt::Result {
+        if let Some(directive) = self.syntax_directive() {
+            writeln!(f, "{}", directive)?;
+        }
+        match program {
+            AssemblyConstruct::Program { functions, globals } => {
+                for function in functions {
+                    self.write_function(f, function)?;
+                }
+                for global in globals {
+                    self.write_global(f, global)?;
+                }
+            }
+        }
+        write!(f, "\n.section .note.GNU-stack,\"\",@progbits")
+    }
+
+    // Globals come after all functions, so switching sections does not affect the code. Zero-initialized
+    // globals go to .bss, which takes no space in the object file.
+    fn write_global(&self, f: &mut dyn Write, global: &AssemblyGlobal) -> fmt::Result {
+        writeln!(f, ".globl {}", global.name)?;
+        writeln!(f, "{}", if global.initializer == 0 { ".bss" } else { ".data" })?;
+        writeln!(f, ".align 4")?;
+        writeln!(f, "{}:", global.name)?;
+        match global.initializer {
+            0 => writeln!(f, "    .zero 4"),
+            value => writeln!(f, "    .long {}", value),
+        }
+    }
+
+    fn write_function(&self, f: &mut dyn Write, function: &AssemblyFunction) -> fmt::Result {
+        match function {
+            AssemblyFunction::Function { name, global, instructions } => {
+                if *global {
+                    writeln!(f, ".globl {}", name)?;
+                }
+                writeln!(f, "{}:", name)?;
+                self.write_prologue(f)?;
+                for instruction in instructions {
+                    self.write_instruction(f, instruction)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+pub fn emitter(syntax: AssemblySyntax) -> &'static dyn AssemblyEmitter {
+    match syntax {
+        AssemblySyntax::Att => &AttEmitter,
+        AssemblySyntax::Intel => &IntelEmitter,
+    }
+}
+
+pub fn emit(program: &AssemblyConstruct, syntax: AssemblySyntax) -> String {
+    let mut output = String::new();
+    // Writing to a String cannot fail
+    let _ = emitter(syntax).write_program(&mut output, program);
+    output
+}
+
+//
+// AT&T syntax
+//
+
+pub struct AttEmitter;
+
+impl AssemblyEmitter for AttEmitter {
+    fn syntax_directive(&self) -> Option<&'static str> {
+        None
+    }
+
+    fn write_prologue(&self, f: &mut dyn Write) -> fmt::Result {
+        writeln!(f, "    pushq %rbp")?;
+        writeln!(f, "    movq %rsp, %rbp")
+    }
+
+    fn write_instruction(&self, f: &mut dyn Write, instruction: &AssemblyInstruction) -> fmt::Result {
+        match instruction {
+            AssemblyInstruction::Mov(src, dst) => writeln!(f, "    movl {}, {}", att_operand(src), att_operand(dst)),
+            AssemblyInstruction::Unary(op, operand) => {
+                writeln!(f, "    {}l {}", unary_mnemonic(op), att_operand(operand))
+            }
+            AssemblyInstruction::Binary(op, src, dst) => {
+                writeln!(f, "    {}l {}, {}", binary_mnemonic(op), att_operand(src), att_operand(dst))
+            }
+            AssemblyInstruction::Cmp(src, dst) => writeln!(f, "    cmpl {}, {}", att_operand(src), att_operand(dst)),
+            AssemblyInstruction::Test(src, dst) => writeln!(f, "    testl {}, {}", att_operand(src), att_operand(dst)),
+            AssemblyInstruction::Cmov(condition, src, dst) => {
+                writeln!(f, "    cmov{}l {}, {}", condition_suffix(condition), att_operand(src), att_operand(dst))
+            }
+            AssemblyInstruction::Lea(address, dst) => writeln!(f, "    leal {}, {}", att_address(address), att_operand(dst)),
+            AssemblyInstruction::Imul(factor, src, dst) => {
+                writeln!(f, "    imull ${}, {}, {}", factor, att_operand(src), att_operand(dst))
+            }
+            AssemblyInstruction::Idiv(src) => writeln!(f, "    idivl {}", att_operand(src)),
+            AssemblyInstruction::Div(src) => writeln!(f, "    divl {}", att_operand(src)),
+            AssemblyInstruction::WideImul(src) => writeln!(f, "    imull {}", att_operand(src)),
+            AssemblyInstruction::WideMul(src) => writeln!(f, "    mull {}", att_operand(src)),
+            AssemblyInstruction::Cdq => writeln!(f, "    cdq"),
+            AssemblyInstruction::Jmp(label) => writeln!(f, "    jmp {}", label),
+            AssemblyInstruction::JmpCC(condition, label) => writeln!(f, "    j{} {}", condition_suffix(condition), label),
+            AssemblyInstruction::SetCC(condition, dst) => {
+                writeln!(f, "    set{} {}", condition_suffix(condition), att_sized_operand(dst, 1))
+            }
+            AssemblyInstruction::Label(label) => writeln!(f, "{}:", label),
+            AssemblyInstruction::AllocateStack(size) => writeln!(f, "    subq ${}, %rsp", size),
+            AssemblyInstruction::DeallocateStack(size) => writeln!(f, "    addq ${}, %rsp", size),
+            AssemblyInstruction::Push(src) => writeln!(f, "    pushq {}", att_sized_operand(src, 8)),
+            AssemblyInstruction::Pop(dst) => writeln!(f, "    popq %{}", register_name(dst, 8)),
+            AssemblyInstruction::Call(name, _) => writeln!(f, "    call {}", name),
+            AssemblyInstruction::Ret => {
+                writeln!(f, "    movq %rbp, %rsp")?;
+                writeln!(f, "    popq %rbp")?;
+                writeln!(f, "    ret")
+            }
+        }
+    }
+}
+
+fn att_operand(operand: &AssemblyOperand) -> String {
+    att_sized_operand(operand, 4)
+}
+
+fn att_sized_operand(operand: &AssemblyOperand, size: u8) -> String {
+    match operand {
+        AssemblyOperand::Register(reg) => format!("%{}", register_name(reg, size)),
+        AssemblyOperand::Immediate(value) => format!("${}", value),
+        AssemblyOperand::PseudoRegister(id) => format!("pseudo({})", id),
+        AssemblyOperand::StackPointer(offset) => format!("{}(%rbp)", offset),
+        AssemblyOperand::Data(name) => format!("{}(%rip)", name),
+    }
+}
+
+// Registers of an address are named in full
+fn att_address(address: &AssemblyAddress) -> String {
+    let displacement = if address.displacement != 0 { address.displacement.to_string() } else { String::new() };
+    match &address.index {
+        Some((index, scale)) => {
+            format!("{}({},{},{})", displacement, att_sized_operand(&address.base, 8), att_sized_operand(index, 8), scale)
+        }
+        None => format!("{}({})", displacement, att_sized_operand(&address.base, 8)),
+    }
+}
+
+//
+// Intel syntax
+//
+
+pub struct IntelEmitter;
+
+impl AssemblyEmitter for IntelEmitter {
+    fn syntax_directive(&self) -> Option<&'static str> {
+        Some(".intel_syntax noprefix")
+    }
+
+    fn write_prologue(&self, f: &mut dyn Write) -> fmt::Result {
+        writeln!(f, "    push rbp")?;
+        writeln!(f, "    mov rbp, rsp")
+    }
+
+    // Operands come destination first, memory operands carry their size since it cannot be inferred from
+    // a register or a mnemonic suffix
+    fn write_instruction(&self, f: &mut dyn Write, instruction: &AssemblyInstruction) -> fmt::Result {
+        match instruction {
+            AssemblyInstruction::Mov(src, dst) => writeln!(f, "    mov {}, {}", intel_operand(dst), intel_operand(src)),
+            AssemblyInstruction::Unary(op, operand) => {
+                writeln!(f, "    {} {}", unary_mnemonic(op), intel_operand(operand))
+            }
+            AssemblyInstruction::Binary(op, src, dst) => {
+                writeln!(f, "    {} {}, {}", binary_mnemonic(op), intel_operand(dst), intel_operand(src))
+            }
+            AssemblyInstruction::Cmp(src, dst) => writeln!(f, "    cmp {}, {}", intel_operand(dst), intel_operand(src)),
+            AssemblyInstruction::Test(src, dst) => writeln!(f, "    test {}, {}", intel_operand(dst), intel_operand(src)),
+            AssemblyInstruction::Cmov(condition, src, dst) => {
+                writeln!(f, "    cmov{} {}, {}", condition_suffix(condition), intel_operand(dst), intel_operand(src))
+            }
+            AssemblyInstruction::Lea(address, dst) => writeln!(f, "    lea {}, {}", intel_operand(dst), intel_address(address)),
+            AssemblyInstruction::Imul(factor, src, dst) => {
+                writeln!(f, "    imul {}, {}, {}", intel_operand(dst), intel_operand(src), factor)
+            }
+            AssemblyInstruction::Idiv(src) => writeln!(f, "    idiv {}", intel_operand(src)),
+            AssemblyInstruction::Div(src) => writeln!(f, "    div {}", intel_operand(src)),
+            AssemblyInstruction::WideImul(src) => writeln!(f, "    imul {}", intel_operand(src)),
+            AssemblyInstruction::WideMul(src) => writeln!(f, "    mul {}", intel_operand(src)),
+            AssemblyInstruction::Cdq => writeln!(f, "    cdq"),
+            AssemblyInstruction::Jmp(label) => writeln!(f, "    jmp {}", label),
+            AssemblyInstruction::JmpCC(condition, label) => writeln!(f, "    j{} {}", condition_suffix(condition), label),
+            AssemblyInstruction::SetCC(condition, dst) => {
+                writeln!(f, "    set{} {}", condition_suffix(condition), intel_sized_operand(dst, 1))
+            }
+            AssemblyInstruction::Label(label) => writeln!(f, "{}:", label),
+            AssemblyInstruction::AllocateStack(size) => writeln!(f, "    sub rsp, {}", size),
+            AssemblyInstruction::DeallocateStack(size) => writeln!(f, "    add rsp, {}", size),
+            AssemblyInstruction::Push(src) => writeln!(f, "    push {}", intel_sized_operand(src, 8)),
+            AssemblyInstruction::Pop(dst) => writeln!(f, "    pop {}", register_name(dst, 8)),
+            AssemblyInstruction::Call(name, _) => writeln!(f, "    call {}", name),
+            AssemblyInstruction::Ret => {
+                writeln!(f, "    mov rsp, rbp")?;
+                writeln!(f, "    pop rbp")?;
+                writeln!(f, "    ret")
+            }
+        }
+    }
+}
+
+fn intel_operand(operand: &AssemblyOperand) -> String {
+    intel_sized_operand(operand, 4)
+}
+
+fn intel_sized_operand(operand: &AssemblyOperand, size: u8) -> String {
+    let width = match size {
+        1 => "BYTE",
+        8 => "QWORD",
+        _ => "DWORD",
+    };
+    match operand {
+        AssemblyOperand::Register(reg) => register_name(reg, size).to_string(),
+        AssemblyOperand::Immediate(value) => value.to_string(),
+        AssemblyOperand::PseudoRegister(id) => format!("pseudo({})", id),
+        AssemblyOperand::StackPointer(offset) if *offset < 0 => format!("{} PTR [rbp-{}]", width, -(*offset as i64)),
+        AssemblyOperand::StackPointer(offset) => format!("{} PTR [rbp+{}]", width, offset),
+        AssemblyOperand::Data(name) => format!("{} PTR {}[rip]", width, name),
+    }
+}
+
+fn intel_address(address: &AssemblyAddress) -> String {
+    let mut terms = intel_sized_operand(&address.base, 8);
+    if let Some((index, scale)) = &address.index {
+        terms += &format!("+{}*{}", intel_sized_operand(index, 8), scale);
+    }
+    match address.displacement {
+        0 => format!("[{}]", terms),
+        displacement if displacement < 0 => format!("[{}-{}]", terms, -(displacement as i64)),
+        displacement => format!("[{}+{}]", terms, displacement),
+    }
+}
+
+//
+// Shared names
+//
+
+fn unary_mnemonic(op: &AssemblyUnaryOperator) -> &'static str {
+    match op {
+        AssemblyUnaryOperator::Neg => "neg",
+        AssemblyUnaryOperator::Not => "not",
+    }
+}
+
+fn binary_mnemonic(op: &AssemblyBinaryOperator) -> &'static str {
+    match op {
+        AssemblyBinaryOperator::Add => "add",
+        AssemblyBinaryOperator::Sub => "sub",
+        AssemblyBinaryOperator::Mult => "imul",
+        AssemblyBinaryOperator::Xor => "xor",
+        AssemblyBinaryOperator::And => "and",
+        AssemblyBinaryOperator::Shl => "shl",
+        AssemblyBinaryOperator::Sar => "sar",
+        AssemblyBinaryOperator::Shr => "shr",
+    }
+}
+
+fn condition_suffix(condition: &AssemblyCondition) -> &'static str {
+    match condition {
+        AssemblyCondition::E => "e",
+        AssemblyCondition::NE => "ne",
+        AssemblyCondition::G => "g",
+        AssemblyCondition::GE => "ge",
+        AssemblyCondition::L => "l",
+        AssemblyCondition::LE => "le",
+        AssemblyCondition::A => "a",
+        AssemblyCondition::AE => "ae",
+        AssemblyCondition::B => "b",
+        AssemblyCondition::BE => "be",
+    }
+}
+
+// Name of the low 1, 4 or 8 bytes of a register
+fn register_name(register: &AssemblyRegister, size: u8) -> &'static str {
+    let names = match register {
+        AssemblyRegister::AX => ["al", "eax", "rax"],
+        AssemblyRegister::BX => ["bl", "ebx", "rbx"],
+        AssemblyRegister::CX => ["cl", "ecx", "rcx"],
+        AssemblyRegister::DX => ["dl", "edx", "rdx"],
+        AssemblyRegister::DI => ["dil", "edi", "rdi"],
+        AssemblyRegister::SI => ["sil", "esi", "rsi"],
+        AssemblyRegister::R8 => ["r8b", "r8d", "r8"],
+        AssemblyRegister::R9 => ["r9b", "r9d", "r9"],
+        AssemblyRegister::R10 => ["r10b", "r10d", "r10"],
+        AssemblyRegister::R11 => ["r11b", "r11d", "r11"],
+        AssemblyRegister::R12 => ["r12b", "r12d", "r12"],
+        AssemblyRegister::R13 => ["r13b", "r13d", "r13"],
+        AssemblyRegister::R14 => ["r14b", "r14d", "r14"],
+        AssemblyRegister::R15 => ["r15b", "r15d", "r15"],
+    };
+    match size {
+        1 => names[0],
+        8 => names[2],
+        _ => names[1],
+    }
+}
@@ -0,0 +1,488 @@
+use std::collections::HashMap;
+
+use crate::elf_writer::{ObjectFile, Relocation, RelocationKind, SectionKind, Symbol, SymbolKind};
+use crate::x86_64_generator::{
+    AssemblyAddress, AssemblyBinaryOperator, AssemblyCondition, AssemblyConstruct, AssemblyFunction, AssemblyInstruction, AssemblyOperand,
+    AssemblyRegister, AssemblyUnaryOperator,
+};
+
+// Hardware register numbers, the fourth bit goes into the REX prefix
+const RAX: u8 = 0;
+const RCX: u8 = 1;
+const RDX: u8 = 2;
+const RBX: u8 = 3;
+const RSP: u8 = 4;
+const RBP: u8 = 5;
+const RSI: u8 = 6;
+const RDI: u8 = 7;
+const R8: u8 = 8;
+const R9: u8 = 9;
+const R10: u8 = 10;
+const R11: u8 = 11;
+const R12: u8 = 12;
+const R13: u8 = 13;
+const R14: u8 = 14;
+const R15: u8 = 15;
+
+#[derive(Debug, Clone, Copy)]
+enum RegisterOrMemory {
+    Register(u8),
+    Memory { base: u8, displacement: i32 },
+    // Scale is 1, 2, 4 or 8, and the index is never rsp
+    Indexed { base: u8, index: u8, scale: u8, displacement: i32 },
+    // The symbol is recorded by the encoder until the end of the instruction is known
+    RipRelative,
+}
+
+struct Encoder {
+    code: Vec<u8>,
+    relocations: Vec<Relocation>,
+    labels: HashMap<String, usize>,
+    // Offsets of 32-bit jump displacements, patched once every label is placed
+    jumps: Vec<(usize, String)>,
+    data_symbol: Option<String>,
+    data_displacement: Option<usize>,
+}
+
+pub fn encode(program: &AssemblyConstruct) -> Result<ObjectFile, String> {
+    let mut encoder = Encoder {
+        code: Vec::new(),
+        relocations: Vec::new(),
+        labels: HashMap::new(),
+        jumps: Vec::new(),
+        data_symbol: None,
+        data_displacement: None,
+    };
+    let mut object = ObjectFile::default();
+
+    match program {
+        AssemblyConstruct::Program { functions, globals } => {
+            for function in functions {
+                let AssemblyFunction::Function { name, global, .. } = function;
+                let offset = encoder.code.len() as u64;
+                encoder.encode_function(function)?;
+                object.symbols.push(Symbol {
+                    name: name.clone(),
+                    kind: SymbolKind::Function,
+                    global: *global,
+                    section: Some(SectionKind::Text),
+                    offset,
+                    size: encoder.code.len() as u64 - offset,
+                });
+            }
+
+            for global in globals {
+                let (section, offset) = match global.initializer {
+                    0 => {
+                        object.bss_size = object.bss_size.next_multiple_of(4);
+                        object.bss_size += 4;
+                        (SectionKind::Bss, object.bss_size - 4)
+                    }
+                    value => {
+                        object.data.resize(object.data.len().next_multiple_of(4), 0);
+                        object.data.extend_from_slice(&value.to_le_bytes());
+                        (SectionKind::Data, object.data.len() as u64 - 4)
+                    }
+                };
+                object.symbols.push(Symbol {
+                    name: global.name.clone(),
+                    kind: SymbolKind::Object,
+                    global: true,
+                    section: Some(section),
+                    offset,
+                    size: 4,
+                });
+            }
+        }
+    }
+
+    for (offset, label) in &encoder.jumps {
+        let target = encoder.labels.get(label).ok_or_else(|| format!("Undefined label {}", label))?;
+        let displacement = *target as i32 - (*offset as i32 + 4);
+        encoder.code[*offset..*offset + 4].copy_from_slice(&displacement.to_le_bytes());
+    }
+
+    // Symbols referenced but not defined here are left for the linker
+    for relocation in &encoder.relocations {
+        if !object.symbols.iter().any(|symbol| symbol.name == relocation.symbol) {
+            object.symbols.push(Symbol {
+                name: relocation.symbol.clone(),
+                kind: SymbolKind::NoType,
+                global: true,
+                section: None,
+                offset: 0,
+                size: 0,
+            });
+        }
+    }
+
+    object.text = encoder.code;
+    object.relocations = encoder.relocations;
+    Ok(object)
+}
+
+impl Encoder {
+    fn encode_function(&mut self, function: &AssemblyFunction) -> Result<(), String> {
+        match function {
+            AssemblyFunction::Function { instructions, .. } => {
+                // pushq %rbp; movq %rsp, %rbp
+                self.code.push(0x55);
+                self.emit(&[0x89], true, RSP, RegisterOrMemory::Register(RBP));
+                for instruction in instructions {
+                    self.encode_instruction(instruction)?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn encode_instruction(&mut self, instruction: &AssemblyInstruction) -> Result<(), String> {
+        self.encode_operation(instruction)?;
+
+        // RIP-relative displacements count from the end of the instruction, immediates included
+        if let (Some(offset), Some(symbol)) = (self.data_displacement.take(), self.data_symbol.take()) {
+            self.relocations.push(Relocation {
+                offset: offset as u64,
+                symbol,
+                kind: RelocationKind::Pc32,
+                addend: offset as i64 - self.code.len() as i64,
+            });
+        }
+        Ok(())
+    }
+
+    fn encode_operation(&mut self, instruction: &AssemblyInstruction) -> Result<(), String> {
+        match instruction {
+            AssemblyInstruction::Mov(src, dst) => match (src, self.operand(dst)?) {
+                (AssemblyOperand::Immediate(value), RegisterOrMemory::Register(register)) => {
+                    self.emit_rex(false, 0, 0, register);
+                    self.code.push(0xb8 + (register & 7));
+                    self.code.extend_from_slice(&value.to_le_bytes());
+                }
+                (AssemblyOperand::Immediate(value), dst) => {
+                    self.emit(&[0xc7], false, 0, dst);
+                    self.code.extend_from_slice(&value.to_le_bytes());
+                }
+                (src, dst) => match (self.operand(src)?, dst) {
+                    (RegisterOrMemory::Register(src), dst) => self.emit(&[0x89], false, src, dst),
+                    (src, RegisterOrMemory::Register(dst)) => self.emit(&[0x8b], false, dst, src),
+                    _ => return Err(format!("Cannot encode memory to memory move: {}", instruction.to_string().trim())),
+                },
+            },
+            AssemblyInstruction::Unary(op, dst) => {
+                let extension = match op {
+                    AssemblyUnaryOperator::Not => 2,
+                    AssemblyUnaryOperator::Neg => 3,
+                };
+                let dst = self.operand(dst)?;
+                self.emit(&[0xf7], false, extension, dst);
+            }
+            AssemblyInstruction::Binary(AssemblyBinaryOperator::Mult, src, dst) => {
+                let dst = match self.operand(dst)? {
+                    RegisterOrMemory::Register(register) => register,
+                    _ => return Err(format!("Multiplication destination must be a register: {}", instruction.to_string().trim())),
+                };
+                match src {
+                    AssemblyOperand::Immediate(value) => self.emit_immediate(&[0x6b], &[0x69], false, dst, RegisterOrMemory::Register(dst), *value),
+                    src => {
+                        let src = self.operand(src)?;
+                        self.emit(&[0x0f, 0xaf], false, dst, src);
+                    }
+                }
+            }
+            AssemblyInstruction::Binary(op @ (AssemblyBinaryOperator::Shl | AssemblyBinaryOperator::Sar | AssemblyBinaryOperator::Shr), src, dst) => match src {
+                AssemblyOperand::Immediate(count) => {
+                    let extension = match op {
+                        AssemblyBinaryOperator::Shl => 4,
+                        AssemblyBinaryOperator::Shr => 5,
+                        _ => 7,
+                    };
+                    let dst = self.operand(dst)?;
+                    // Shifts by one have their own opcode
+                    if *count == 1 {
+                        self.emit(&[0xd1], false, extension, dst);
+                    } else {
+                        self.emit(&[0xc1], false, extension, dst);
+                        self.code.push(*count as u8);
+                    }
+                }
+                _ => return Err(format!("Shift count must be an immediate: {}", instruction.to_string().trim())),
+            },
+            AssemblyInstruction::Binary(op, src, dst) => {
+                // Opcodes for 'r/m += reg' and 'reg += r/m', and the extension of the immediate form
+                let (to_memory, from_memory, extension) = match op {
+                    AssemblyBinaryOperator::Add => (0x01, 0x03, 0),
+                    AssemblyBinaryOperator::Sub => (0x29, 0x2b, 5),
+                    AssemblyBinaryOperator::Xor => (0x31, 0x33, 6),
+                    AssemblyBinaryOperator::And => (0x21, 0x23, 4),
+                    AssemblyBinaryOperator::Mult
+                    | AssemblyBinaryOperator::Shl
+                    | AssemblyBinaryOperator::Sar
+                    | AssemblyBinaryOperator::Shr => unreachable!(),
+                };
+                match (src, self.operand(dst)?) {
+                    (AssemblyOperand::Immediate(value), dst) => self.emit_immediate(&[0x83], &[0x81], false, extension, dst, *value),
+                    (src, dst) => match (self.operand(src)?, dst) {
+                        (RegisterOrMemory::Register(src), dst) => self.emit(&[to_memory], false, src, dst),
+                        (src, RegisterOrMemory::Register(dst)) => self.emit(&[from_memory], false, dst, src),
+                        _ => return Err(format!("Cannot encode memory to memory operation: {}", instruction.to_string().trim())),
+                    },
+                }
+            }
+            AssemblyInstruction::Cmp(src, dst) => match (src, self.operand(dst)?) {
+                (AssemblyOperand::Immediate(value), dst) => self.emit_immediate(&[0x83], &[0x81], false, 7, dst, *value),
+                (src, dst) => match (self.operand(src)?, dst) {
+                    (RegisterOrMemory::Register(src), dst) => self.emit(&[0x39], false, src, dst),
+                    (src, RegisterOrMemory::Register(dst)) => self.emit(&[0x3b], false, dst, src),
+                    _ => return Err(format!("Cannot encode memory to memory comparison: {}", instruction.to_string().trim())),
+                },
+            },
+            AssemblyInstruction::Test(src, dst) => match (self.operand(src)?, self.operand(dst)?) {
+                (RegisterOrMemory::Register(src), dst) | (dst, RegisterOrMemory::Register(src)) => self.emit(&[0x85], false, src, dst),
+                _ => return Err(format!("Cannot encode memory to memory test: {}", instruction.to_string().trim())),
+            },
+            AssemblyInstruction::Cmov(condition, src, dst) => match self.operand(dst)? {
+                RegisterOrMemory::Register(dst) => {
+                    let src = self.operand(src)?;
+                    self.emit(&[0x0f, 0x40 + condition_number(condition)], false, dst, src);
+                }
+                _ => return Err(format!("Conditional move destination must be a register: {}", instruction.to_string().trim())),
+            },
+            AssemblyInstruction::Lea(address, dst) => match self.operand(dst)? {
+                RegisterOrMemory::Register(dst) => {
+                    let address = self.address(address)?;
+                    self.emit(&[0x8d], false, dst, address);
+                }
+                _ => return Err(format!("Address destination must be a register: {}", instruction.to_string().trim())),
+            },
+            AssemblyInstruction::Imul(factor, src, dst) => match self.operand(dst)? {
+                RegisterOrMemory::Register(dst) => {
+                    let src = self.operand(src)?;
+                    self.emit_immediate(&[0x6b], &[0x69], false, dst, src, *factor);
+                }
+                _ => return Err(format!("Multiplication destination must be a register: {}", instruction.to_string().trim())),
+            },
+            // Extensions of the 0xf7 group for mul, imul, div and idiv
+            AssemblyInstruction::WideMul(src)
+            | AssemblyInstruction::WideImul(src)
+            | AssemblyInstruction::Div(src)
+            | AssemblyInstruction::Idiv(src) => match src {
+                AssemblyOperand::Immediate(_) => return Err(format!("Operand cannot be an immediate: {}", instruction.to_string().trim())),
+                src => {
+                    let extension = match instruction {
+                        AssemblyInstruction::WideMul(_) => 4,
+                        AssemblyInstruction::WideImul(_) => 5,
+                        AssemblyInstruction::Div(_) => 6,
+                        _ => 7,
+                    };
+                    let src = self.operand(src)?;
+                    self.emit(&[0xf7], false, extension, src);
+                }
+            },
+            AssemblyInstruction::Cdq => self.code.push(0x99),
+            AssemblyInstruction::Jmp(label) => {
+                self.code.push(0xe9);
+                self.emit_jump_target(label);
+            }
+            AssemblyInstruction::JmpCC(condition, label) => {
+                self.code.extend_from_slice(&[0x0f, 0x80 + condition_number(condition)]);
+                self.emit_jump_target(label);
+            }
+            // Byte registers 4 to 7 only name sil and dil when a REX prefix is present
+            AssemblyInstruction::SetCC(condition, dst) => {
+                let dst = self.operand(dst)?;
+                if let RegisterOrMemory::Register(4..=7) = dst {
+                    self.code.push(0x40);
+                }
+                self.emit(&[0x0f, 0x90 + condition_number(condition)], false, 0, dst);
+            }
+            AssemblyInstruction::Label(label) => {
+                if self.labels.insert(label.clone(), self.code.len()).is_some() {
+                    return Err(format!("Label {} is defined more than once", label));
+                }
+            }
+            AssemblyInstruction::AllocateStack(size) => {
+                self.emit_immediate(&[0x83], &[0x81], true, 5, RegisterOrMemory::Register(RSP), *size);
+            }
+            AssemblyInstruction::DeallocateStack(size) => {
+                self.emit_immediate(&[0x83], &[0x81], true, 0, RegisterOrMemory::Register(RSP), *size);
+            }
+            AssemblyInstruction::Push(src) => match src {
+                AssemblyOperand::Immediate(value) => match i8::try_from(*value) {
+                    Ok(byte) => self.code.extend_from_slice(&[0x6a, byte as u8]),
+                    Err(_) => {
+                        self.code.push(0x68);
+                        self.code.extend_from_slice(&value.to_le_bytes());
+                    }
+                },
+                src => match self.operand(src)? {
+                    RegisterOrMemory::Register(register) => {
+                        self.emit_rex(false, 0, 0, register);
+                        self.code.push(0x50 + (register & 7));
+                    }
+                    src => self.emit(&[0xff], false, 6, src),
+                },
+            },
+            AssemblyInstruction::Pop(dst) => {
+                let register = register_number(dst);
+                self.emit_rex(false, 0, 0, register);
+                self.code.push(0x58 + (register & 7));
+            }
+            AssemblyInstruction::Call(name, _) => {
+                self.code.push(0xe8);
+                self.relocations.push(Relocation {
+                    offset: self.code.len() as u64,
+                    symbol: name.clone(),
+                    kind: RelocationKind::Plt32,
+                    addend: -4,
+                });
+                self.code.extend_from_slice(&[0; 4]);
+            }
+            AssemblyInstruction::Ret => {
+                // movq %rbp, %rsp; popq %rbp; ret
+                self.emit(&[0x89], true, RBP, RegisterOrMemory::Register(RSP));
+                self.code.push(0x5d);
+                self.code.push(0xc3);
+            }
+        }
+        Ok(())
+    }
+
+    fn emit_jump_target(&mut self, label: &str) {
+        self.jumps.push((self.code.len(), label.to_string()));
+        self.code.extend_from_slice(&[0; 4]);
+    }
+
+    fn operand(&mut self, operand: &AssemblyOperand) -> Result<RegisterOrMemory, String> {
+        match operand {
+            AssemblyOperand::Register(register) => Ok(RegisterOrMemory::Register(register_number(register))),
+            AssemblyOperand::StackPointer(offset) => Ok(RegisterOrMemory::Memory { base: RBP, displacement: *offset }),
+            AssemblyOperand::Data(name) => {
+                self.data_symbol = Some(name.clone());
+                Ok(RegisterOrMemory::RipRelative)
+            }
+            AssemblyOperand::Immediate(value) => Err(format!("Unexpected immediate operand ${}", value)),
+            AssemblyOperand::PseudoRegister(name) => Err(format!("Unallocated pseudo register {}", name)),
+        }
+    }
+
+    // Registers of an address are used in full, the instruction only keeps the low bits of the sum
+    fn address(&mut self, address: &AssemblyAddress) -> Result<RegisterOrMemory, String> {
+        let register = |operand: &AssemblyOperand| match operand {
+            AssemblyOperand::Register(register) => Ok(register_number(register)),
+            other => Err(format!("Address operand must be a register: {:?}", other)),
+        };
+        let base = register(&address.base)?;
+        Ok(match &address.index {
+            Some((index, scale)) => RegisterOrMemory::Indexed { base, index: register(index)?, scale: *scale, displacement: address.displacement },
+            None => RegisterOrMemory::Memory { base, displacement: address.displacement },
+        })
+    }
+
+    // Uses the sign-extended 8-bit immediate form when the value fits
+    fn emit_immediate(&mut self, short: &[u8], long: &[u8], wide: bool, reg: u8, rm: RegisterOrMemory, value: i32) {
+        match i8::try_from(value) {
+            Ok(byte) => {
+                self.emit(short, wide, reg, rm);
+                self.code.push(byte as u8);
+            }
+            Err(_) => {
+                self.emit(long, wide, reg, rm);
+                self.code.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+    }
+
+    fn emit(&mut self, opcode: &[u8], wide: bool, reg: u8, rm: RegisterOrMemory) {
+        let (index_register, rm_register) = match rm {
+            RegisterOrMemory::Register(register) => (0, register),
+            RegisterOrMemory::Memory { base, .. } => (0, base),
+            RegisterOrMemory::Indexed { base, index, .. } => (index, base),
+            RegisterOrMemory::RipRelative => (0, 0),
+        };
+        self.emit_rex(wide, reg, index_register, rm_register);
+        self.code.extend_from_slice(opcode);
+
+        match rm {
+            RegisterOrMemory::Register(register) => self.code.push(0xc0 | ((reg & 7) << 3) | (register & 7)),
+            RegisterOrMemory::Memory { base, displacement } => {
+                // [rbp] and [r13] have no displacement-free form, [rsp] and [r12] need a SIB byte
+                let mode = match (displacement, base & 7) {
+                    (0, low) if low != RBP => 0x00,
+                    (d, _) if i8::try_from(d).is_ok() => 0x40,
+                    _ => 0x80,
+                };
+                self.code.push(mode | ((reg & 7) << 3) | (base & 7));
+                if base & 7 == RSP {
+                    self.code.push(0x24);
+                }
+                match mode {
+                    0x40 => self.code.push(displacement as i8 as u8),
+                    0x80 => self.code.extend_from_slice(&displacement.to_le_bytes()),
+                    _ => {}
+                }
+            }
+            // The SIB byte holds the scale as a power of two
+            RegisterOrMemory::Indexed { base, index, scale, displacement } => {
+                let mode = match (displacement, base & 7) {
+                    (0, low) if low != RBP => 0x00,
+                    (d, _) if i8::try_from(d).is_ok() => 0x40,
+                    _ => 0x80,
+                };
+                self.code.push(mode | ((reg & 7) << 3) | RSP);
+                self.code.push(((scale.trailing_zeros() as u8) << 6) | ((index & 7) << 3) | (base & 7));
+                match mode {
+                    0x40 => self.code.push(displacement as i8 as u8),
+                    0x80 => self.code.extend_from_slice(&displacement.to_le_bytes()),
+                    _ => {}
+                }
+            }
+            RegisterOrMemory::RipRelative => {
+                self.code.push(((reg & 7) << 3) | RBP);
+                self.data_displacement = Some(self.code.len());
+                self.code.extend_from_slice(&[0; 4]);
+            }
+        }
+    }
+
+    fn emit_rex(&mut self, wide: bool, reg: u8, index: u8, rm: u8) {
+        let rex = 0x40 | ((wide as u8) << 3) | (((reg >> 3) & 1) << 2) | (((index >> 3) & 1) << 1) | ((rm >> 3) & 1);
+        if rex != 0x40 {
+            self.code.push(rex);
+        }
+    }
+}
+
+fn register_number(register: &AssemblyRegister) -> u8 {
+    match register {
+        AssemblyRegister::AX => RAX,
+        AssemblyRegister::BX => RBX,
+        AssemblyRegister::CX => RCX,
+        AssemblyRegister::DX => RDX,
+        AssemblyRegister::DI => RDI,
+        AssemblyRegister::SI => RSI,
+        AssemblyRegister::R8 => R8,
+        AssemblyRegister::R9 => R9,
+        AssemblyRegister::R10 => R10,
+        AssemblyRegister::R11 => R11,
+        AssemblyRegister::R12 => R12,
+        AssemblyRegister::R13 => R13,
+        AssemblyRegister::R14 => R14,
+        AssemblyRegister::R15 => R15,
+    }
+}
+
+fn condition_number(condition: &AssemblyCondition) -> u8 {
+    match condition {
+        AssemblyCondition::B => 0x2,
+        AssemblyCondition::AE => 0x3,
+        AssemblyCondition::E => 0x4,
+        AssemblyCondition::NE => 0x5,
+        AssemblyCondition::BE => 0x6,
+        AssemblyCondition::A => 0x7,
+        AssemblyCondition::L => 0xc,
+        AssemblyCondition::GE => 0xd,
+        AssemblyCondition::LE => 0xe,
+        AssemblyCondition::G => 0xf,
+    }
+}
@@ -1,27 +1,70 @@
+use std::collections::{HashMap, HashSet};
 use std::fmt;
-use crate::llvm_ir_generator::{LLVMConstruct, LLVMFunction, LLVMInstruction, LLVMValue, LLVMUnaryOp, LLVMBinaryOp};
+use crate::llvm_ir::{
+    IRType, LLVMBasicBlock, LLVMComparison, LLVMConstruct, LLVMFunction, LLVMInstruction, LLVMValue, LLVMUnaryOp, LLVMBinaryOp,
+    Linkage, ValueId,
+};
+use crate::magic_division;
+use crate::x86_64_emitter::{AssemblyEmitter, AttEmitter};
+use crate::x86_64_instruction_selection::{self, Tiling};
+use crate::x86_64_peephole;
+use crate::x86_64_register_allocator::{self, RegisterAllocator};
+use crate::x86_64_scheduler;
 
 #[derive(Debug, Clone)]
 pub enum AssemblyConstruct {
-    Program(AssemblyFunction),
+    Program {
+        functions: Vec<AssemblyFunction>,
+        globals: Vec<AssemblyGlobal>,
+    },
 }
 
 #[derive(Debug, Clone)]
 pub enum AssemblyFunction {
     Function {
         name: String,
+        // Whether the symbol is visible to other object files
+        global: bool,
         instructions: Vec<AssemblyInstruction>,
     },
 }
 
+#[derive(Debug, Clone)]
+pub struct AssemblyGlobal {
+    pub name: String,
+    pub initializer: i32,
+}
+
 #[derive(Debug, Clone)]
 pub enum AssemblyInstruction {
     Mov(AssemblyOperand, AssemblyOperand),
     Unary(AssemblyUnaryOperator, AssemblyOperand),
     Binary(AssemblyBinaryOperator, AssemblyOperand, AssemblyOperand),
+    Cmp(AssemblyOperand, AssemblyOperand),
+    // Sets the flags from the bitwise and of its operands
+    Test(AssemblyOperand, AssemblyOperand),
+    // Moves the source to the destination only when the condition holds
+    Cmov(AssemblyCondition, AssemblyOperand, AssemblyOperand),
+    Lea(AssemblyAddress, AssemblyOperand),
+    // Three-operand multiplication, destination = source * immediate
+    Imul(i32, AssemblyOperand, AssemblyOperand),
     Idiv(AssemblyOperand),
+    // Unsigned division of edx:eax
+    Div(AssemblyOperand),
+    // edx:eax = eax * operand, signed and unsigned
+    WideImul(AssemblyOperand),
+    WideMul(AssemblyOperand),
     Cdq,
+    Jmp(String),
+    JmpCC(AssemblyCondition, String),
+    SetCC(AssemblyCondition, AssemblyOperand),
+    Label(String),
     AllocateStack(i32),
+    DeallocateStack(i32),
+    Push(AssemblyOperand),
+    Pop(AssemblyRegister),
+    // The number of arguments passed in registers, which the call reads
+    Call(String, usize),
     Ret,
 }
 
@@ -35,201 +78,791 @@ pub enum AssemblyUnaryOperator {
 pub enum AssemblyBinaryOperator {
     Add,
     Sub,
-    Mult
+    Mult,
+    Xor,
+    And,
+    // Only shifts by an immediate count
+    Shl,
+    Sar,
+    Shr,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AssemblyCondition {
+    E,
+    NE,
+    G,
+    GE,
+    L,
+    LE,
+    A,
+    AE,
+    B,
+    BE,
+}
+
+// Data operands are globals, addressed relative to the instruction pointer
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum AssemblyOperand {
     Register(AssemblyRegister),
     Immediate(i32),
-    PseudoRegister(String),
+    PseudoRegister(ValueId),
     StackPointer(i32),
+    Data(String),
 }
 
-#[derive(Debug, Clone)]
+// base + index * scale + displacement. Only the low 32 bits of the sum are kept, so the registers may
+// hold 32-bit values.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AssemblyAddress {
+    pub base: AssemblyOperand,
+    pub index: Option<(AssemblyOperand, u8)>,
+    pub displacement: i32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum AssemblyRegister {
     AX,
+    BX,
+    CX,
     DX,
+    DI,
+    SI,
+    R8,
+    R9,
     R10,
-    R11
+    R11,
+    R12,
+    R13,
+    R14,
+    R15,
+}
+
+// System V integer argument registers, in order
+pub const ARGUMENT_REGISTERS: [AssemblyRegister; 6] = [
+    AssemblyRegister::DI,
+    AssemblyRegister::SI,
+    AssemblyRegister::DX,
+    AssemblyRegister::CX,
+    AssemblyRegister::R8,
+    AssemblyRegister::R9,
+];
+
+// Registers a call may overwrite, the other ones keep their value across calls
+pub const CALLER_SAVED_REGISTERS: [AssemblyRegister; 9] = [
+    AssemblyRegister::AX,
+    AssemblyRegister::CX,
+    AssemblyRegister::DX,
+    AssemblyRegister::SI,
+    AssemblyRegister::DI,
+    AssemblyRegister::R8,
+    AssemblyRegister::R9,
+    AssemblyRegister::R10,
+    AssemblyRegister::R11,
+];
+
+pub const CALLEE_SAVED_REGISTERS: [AssemblyRegister; 5] = [
+    AssemblyRegister::BX,
+    AssemblyRegister::R12,
+    AssemblyRegister::R13,
+    AssemblyRegister::R14,
+    AssemblyRegister::R15,
+];
+
+impl AssemblyOperand {
+    pub fn is_memory(&self) -> bool {
+        matches!(self, AssemblyOperand::StackPointer(_) | AssemblyOperand::Data(_))
+    }
+}
+
+impl AssemblyCondition {
+    // The condition holding when this one does not
+    pub fn negated(self) -> AssemblyCondition {
+        match self {
+            AssemblyCondition::E => AssemblyCondition::NE,
+            AssemblyCondition::NE => AssemblyCondition::E,
+            AssemblyCondition::G => AssemblyCondition::LE,
+            AssemblyCondition::GE => AssemblyCondition::L,
+            AssemblyCondition::L => AssemblyCondition::GE,
+            AssemblyCondition::LE => AssemblyCondition::G,
+            AssemblyCondition::A => AssemblyCondition::BE,
+            AssemblyCondition::AE => AssemblyCondition::B,
+            AssemblyCondition::B => AssemblyCondition::AE,
+            AssemblyCondition::BE => AssemblyCondition::A,
+        }
+    }
+
+    // The condition holding for the operands compared the other way around
+    fn swapped(self) -> AssemblyCondition {
+        match self {
+            AssemblyCondition::E | AssemblyCondition::NE => self,
+            AssemblyCondition::G => AssemblyCondition::L,
+            AssemblyCondition::GE => AssemblyCondition::LE,
+            AssemblyCondition::L => AssemblyCondition::G,
+            AssemblyCondition::LE => AssemblyCondition::GE,
+            AssemblyCondition::A => AssemblyCondition::B,
+            AssemblyCondition::AE => AssemblyCondition::BE,
+            AssemblyCondition::B => AssemblyCondition::A,
+            AssemblyCondition::BE => AssemblyCondition::AE,
+        }
+    }
+}
+
+impl AssemblyInstruction {
+    pub fn operands_mut(&mut self) -> Vec<&mut AssemblyOperand> {
+        match self {
+            AssemblyInstruction::Mov(src, dst)
+            | AssemblyInstruction::Binary(_, src, dst)
+            | AssemblyInstruction::Cmp(src, dst)
+            | AssemblyInstruction::Test(src, dst)
+            | AssemblyInstruction::Cmov(_, src, dst)
+            | AssemblyInstruction::Imul(_, src, dst) => vec![src, dst],
+            AssemblyInstruction::Lea(AssemblyAddress { base, index, .. }, dst) => {
+                let mut operands = vec![base];
+                operands.extend(index.as_mut().map(|(index, _)| index));
+                operands.push(dst);
+                operands
+            }
+            AssemblyInstruction::Unary(_, operand)
+            | AssemblyInstruction::Idiv(operand)
+            | AssemblyInstruction::Div(operand)
+            | AssemblyInstruction::WideImul(operand)
+            | AssemblyInstruction::WideMul(operand)
+            | AssemblyInstruction::SetCC(_, operand)
+            | AssemblyInstruction::Push(operand) => vec![operand],
+            _ => Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct GeneratorOptions {
+    pub register_allocator: RegisterAllocator,
+    // Let leaf functions keep their frame below the stack pointer instead of allocating it
+    pub red_zone: bool,
+    // 0 for -O0, 1 for -O1 and 2 for -O2 and above
+    pub optimization_level: u8,
 }
 
-pub fn generate(llvm_ir: &LLVMConstruct) -> Result<AssemblyConstruct, String> {
+// Bytes below the stack pointer that System V guarantees signal handlers leave alone
+const RED_ZONE_SIZE: i32 = 128;
+
+pub fn generate(llvm_ir: &LLVMConstruct, options: &GeneratorOptions) -> Result<AssemblyConstruct, String> {
     match llvm_ir {
-        LLVMConstruct::Module(function) => {
-            let initial_asm = generate_function(function)?;
-            let (stack_allocated_asm, offset) = compute_stack_allocation(&initial_asm)?;
-            let fixed_asm = fix_memory_constraints(AssemblyConstruct::Program(stack_allocated_asm), offset.abs())?;
-            Ok(fixed_asm)
+        LLVMConstruct::Module { globals, functions } => {
+            let functions = functions.iter()
+                .filter(|function| !function.is_declaration())
+                .map(|function| {
+                    let (initial_asm, pseudo_types) = generate_function(function)?;
+                    // Scheduling before allocation has the most freedom, scheduling again afterwards takes spill
+                    // code and the registers actually used into account
+                    let schedule = options.optimization_level >= 2;
+                    let initial_asm = if schedule { x86_64_scheduler::schedule(initial_asm) } else { initial_asm };
+                    let (allocated_asm, callee_saved) = x86_64_register_allocator::allocate(initial_asm, options.register_allocator);
+                    let (stack_allocated_asm, stack_size) = compute_stack_allocation(&allocated_asm, &pseudo_types)?;
+
+                    // Pushing callee-saved registers would overwrite a frame left in the red zone
+                    let AssemblyFunction::Function { instructions, .. } = &stack_allocated_asm;
+                    let is_leaf = !instructions.iter().any(|instruction| matches!(instruction, AssemblyInstruction::Call(..)));
+                    let in_red_zone = options.red_zone && is_leaf && callee_saved.is_empty() && stack_size <= RED_ZONE_SIZE;
+                    let frame_size = if in_red_zone { 0 } else { stack_size };
+                    let final_asm = fix_instructions_operands(stack_allocated_asm, frame_size, &callee_saved)?;
+                    let final_asm = if options.optimization_level >= 1 { x86_64_peephole::optimize(final_asm) } else { final_asm };
+                    Ok(if schedule { x86_64_scheduler::schedule(final_asm) } else { final_asm })
+                })
+                .collect::<Result<Vec<_>, String>>()?;
+            let globals = globals.iter()
+                .map(|global| AssemblyGlobal { name: global.name.clone(), initializer: global.initializer })
+                .collect();
+            Ok(AssemblyConstruct::Program { functions, globals })
         }
     }
 }
 
-fn generate_function(function: &LLVMFunction) -> Result<AssemblyFunction, String> {
+// Labels local to the object file, made unique across functions
+pub fn block_label(function: &str, label: &str) -> String {
+    format!(".L{}.{}", function, label)
+}
+
+// Incoming values of a phi node, with the label of the predecessor each flows from
+type Incoming = [(LLVMValue, String)];
+
+struct FunctionGenerator<'a> {
+    name: &'a str,
+    // Stack slots are pseudo registers themselves, so memory accesses through them become plain moves
+    allocas: HashSet<ValueId>,
+    // Phi nodes of each block, turned into copies on the edges leading to it
+    phis: HashMap<&'a str, Vec<(ValueId, &'a Incoming)>>,
+    // Type of the value each pseudo register holds, which sizes its stack slot if it ends up in one
+    pseudo_types: HashMap<ValueId, IRType>,
+    tiling: Tiling<'a>,
+    next_pseudo: usize,
+    next_label: usize,
+}
+
+fn generate_function(function: &LLVMFunction) -> Result<(AssemblyFunction, HashMap<ValueId, IRType>), String> {
     match function {
-        LLVMFunction::Function { name, instructions } => {
-            let asm_instructions = instructions.iter()
-                .filter_map(|inst| generate_instruction(inst).transpose())
-                .collect::<Result<Vec<_>, String>>()?
-                .into_iter()
-                .flatten()
-                .collect::<Vec<_>>();
-
-            Ok(AssemblyFunction::Function {
+        LLVMFunction::Function { name, linkage, params, blocks, .. } => {
+            let instructions = blocks.iter().flat_map(|block| &block.instructions);
+            let mut generator = FunctionGenerator {
+                name,
+                allocas: instructions.clone()
+                    .filter_map(|instruction| match instruction {
+                        LLVMInstruction::Alloca(slot, _) => Some(*slot),
+                        _ => None,
+                    })
+                    .collect(),
+                phis: HashMap::new(),
+                // Stack slots of allocas hold the allocated type rather than a pointer
+                pseudo_types: params.iter()
+                    .copied()
+                    .chain(instructions.clone().filter_map(|instruction| match instruction {
+                        LLVMInstruction::Alloca(slot, ty) => Some((*slot, *ty)),
+                        other => other.result(),
+                    }))
+                    .collect(),
+                tiling: x86_64_instruction_selection::select(function),
+                next_pseudo: function.next_value_id().0,
+                next_label: 0,
+            };
+            for block in blocks {
+                let phis = block.instructions.iter().filter_map(|instruction| match instruction {
+                    LLVMInstruction::Phi(dst, _, incoming) => Some((*dst, incoming.as_slice())),
+                    _ => None,
+                });
+                generator.phis.insert(&block.label, phis.collect());
+            }
+
+            // Parameters beyond the sixth are on the stack, above the return address and the saved frame pointer
+            let mut asm_instructions = Vec::new();
+            for (index, (id, _)) in params.iter().enumerate() {
+                let source = match ARGUMENT_REGISTERS.get(index) {
+                    Some(register) => AssemblyOperand::Register(*register),
+                    None => AssemblyOperand::StackPointer(16 + 8 * (index as i32 - 6)),
+                };
+                asm_instructions.push(AssemblyInstruction::Mov(source, AssemblyOperand::PseudoRegister(*id)));
+            }
+
+            // The entry block cannot be branched to, so it needs no label
+            for (index, block) in blocks.iter().enumerate() {
+                if index > 0 {
+                    asm_instructions.push(AssemblyInstruction::Label(block_label(name, &block.label)));
+                }
+                for instruction in &block.instructions {
+                    if instruction.result().is_some_and(|(id, _)| generator.tiling.folded.contains(&id)) {
+                        continue;
+                    }
+                    asm_instructions.extend(generator.generate_instruction(block, instruction)?);
+                }
+            }
+
+            let function = AssemblyFunction::Function {
                 name: name.clone(),
-                instructions: [asm_instructions, vec![AssemblyInstruction::Ret]].concat(),
-            })
+                global: *linkage == Linkage::External,
+                instructions: asm_instructions,
+            };
+            Ok((function, generator.pseudo_types))
         }
     }
 }
 
-fn generate_instruction(instruction: &LLVMInstruction) -> Result<Option<Vec<AssemblyInstruction>>, String> {
-    match instruction {
-        LLVMInstruction::ReturnValue(value) => {
-            Ok(Some(vec![AssemblyInstruction::Mov(
-                generate_value(value),
-                AssemblyOperand::Register(AssemblyRegister::AX)
-            )]))
-        },
-        LLVMInstruction::UnaryOp(dst, _ty, op, value) => {
-            let asm_op = match op {
-                LLVMUnaryOp::Not => AssemblyUnaryOperator::Not,
-                LLVMUnaryOp::Neg => AssemblyUnaryOperator::Neg,
+impl FunctionGenerator<'_> {
+    fn generate_instruction(&mut self, block: &LLVMBasicBlock, instruction: &LLVMInstruction) -> Result<Vec<AssemblyInstruction>, String> {
+        match instruction {
+            LLVMInstruction::Return => Ok(vec![AssemblyInstruction::Ret]),
+            LLVMInstruction::ReturnValue(_, value) => {
+                Ok(vec![
+                    AssemblyInstruction::Mov(
+                        generate_value(value),
+                        AssemblyOperand::Register(AssemblyRegister::AX)
+                    ),
+                    AssemblyInstruction::Ret,
+                ])
+            },
+            LLVMInstruction::Branch(target) => {
+                let mut instructions = self.edge_copies(&block.label, target);
+                instructions.push(AssemblyInstruction::Jmp(block_label(self.name, target)));
+                Ok(instructions)
+            },
+            // Copies for phi nodes go on the edge they belong to, so that a value still live along the
+            // other edge is not overwritten
+            LLVMInstruction::CondBranch(condition, then_target, else_target) => {
+                let then_copies = self.edge_copies(&block.label, then_target);
+                let else_copies = self.edge_copies(&block.label, else_target);
+                let (flags, condition) = self.condition_flags(condition);
+                let mut instructions = vec![flags];
+                if then_copies.is_empty() {
+                    instructions.push(AssemblyInstruction::JmpCC(condition, block_label(self.name, then_target)));
+                } else {
+                    let else_edge = self.new_label();
+                    instructions.push(AssemblyInstruction::JmpCC(condition.negated(), else_edge.clone()));
+                    instructions.extend(then_copies);
+                    instructions.push(AssemblyInstruction::Jmp(block_label(self.name, then_target)));
+                    instructions.push(AssemblyInstruction::Label(else_edge));
+                }
+                instructions.extend(else_copies);
+                instructions.push(AssemblyInstruction::Jmp(block_label(self.name, else_target)));
+                Ok(instructions)
+            },
+            LLVMInstruction::UnaryOp(dst, _ty, op, value) => {
+                let asm_op = match op {
+                    LLVMUnaryOp::Not => AssemblyUnaryOperator::Not,
+                    LLVMUnaryOp::Neg => AssemblyUnaryOperator::Neg,
+                };
+
+                Ok(vec![
+                    AssemblyInstruction::Mov(
+                        generate_value(value),
+                        AssemblyOperand::PseudoRegister(*dst)
+                    ),
+                    AssemblyInstruction::Unary(
+                        asm_op,
+                        AssemblyOperand::PseudoRegister(*dst)
+                    )
+                ])
+            },
+            LLVMInstruction::BinaryOp(dst, _, _, _, _) if self.tiling.addresses.contains_key(dst) => {
+                let address = &self.tiling.addresses[dst];
+                let index = address.index.map(|(index, scale)| (AssemblyOperand::PseudoRegister(index), scale));
+                let address = AssemblyAddress { base: AssemblyOperand::PseudoRegister(address.base), index, displacement: address.displacement };
+                Ok(vec![AssemblyInstruction::Lea(address, AssemblyOperand::PseudoRegister(*dst))])
+            },
+            // Powers of two are left to the peephole pass, which shifts instead
+            LLVMInstruction::BinaryOp(dst, _, LLVMBinaryOp::Multiply, LLVMValue::Register(src), LLVMValue::Immediate(factor))
+            | LLVMInstruction::BinaryOp(dst, _, LLVMBinaryOp::Multiply, LLVMValue::Immediate(factor), LLVMValue::Register(src))
+                if factor.count_ones() != 1 =>
+            {
+                Ok(vec![AssemblyInstruction::Imul(*factor, AssemblyOperand::PseudoRegister(*src), AssemblyOperand::PseudoRegister(*dst))])
+            },
+            LLVMInstruction::BinaryOp(dst, _, op, lhs, rhs) => {
+                let instructions = match op {
+                    LLVMBinaryOp::Add | LLVMBinaryOp::Subtract | LLVMBinaryOp::Multiply => {
+                        let asm_op = match op {
+                            LLVMBinaryOp::Add => AssemblyBinaryOperator::Add,
+                            LLVMBinaryOp::Subtract => AssemblyBinaryOperator::Sub,
+                            LLVMBinaryOp::Multiply => AssemblyBinaryOperator::Mult,
+                            _ => unreachable!(),
+                        };
+                        vec![
+                            AssemblyInstruction::Mov(
+                                generate_value(lhs),
+                                AssemblyOperand::PseudoRegister(*dst)
+                            ),
+                            AssemblyInstruction::Binary(
+                                asm_op,
+                                generate_value(rhs),
+                                AssemblyOperand::PseudoRegister(*dst)
+                            ),
+                        ]
+                    },
+                    LLVMBinaryOp::Divide
+                    | LLVMBinaryOp::Remainder
+                    | LLVMBinaryOp::UnsignedDivide
+                    | LLVMBinaryOp::UnsignedRemainder => self.generate_division(*dst, op, lhs, rhs),
+                };
+                Ok(instructions)
+            },
+            // cmp computes lhs - rhs, the result is zeroed first since setcc only writes a byte
+            LLVMInstruction::ICmp(dst, comparison, _, lhs, rhs) => {
+                let (flags, condition) = compare(comparison, lhs, rhs);
+                Ok(vec![
+                    flags,
+                    AssemblyInstruction::Mov(AssemblyOperand::Immediate(0), AssemblyOperand::PseudoRegister(*dst)),
+                    AssemblyInstruction::SetCC(condition, AssemblyOperand::PseudoRegister(*dst)),
+                ])
+            },
+            // Booleans are already kept as 0 or 1 in a full register
+            LLVMInstruction::ZExt(dst, _, value, _) => {
+                Ok(vec![AssemblyInstruction::Mov(generate_value(value), AssemblyOperand::PseudoRegister(*dst))])
+            },
+            // cmov cannot take an immediate, and the moves go before the flags are set since moving zero may
+            // become a xor
+            LLVMInstruction::Select(dst, ty, condition, lhs, rhs) => {
+                let mut instructions = vec![AssemblyInstruction::Mov(generate_value(rhs), AssemblyOperand::PseudoRegister(*dst))];
+                let lhs = match generate_value(lhs) {
+                    immediate @ AssemblyOperand::Immediate(_) => {
+                        let temporary = self.new_pseudo(*ty);
+                        instructions.push(AssemblyInstruction::Mov(immediate, temporary.clone()));
+                        temporary
+                    }
+                    lhs => lhs,
+                };
+                let (flags, condition) = self.condition_flags(condition);
+                instructions.push(flags);
+                instructions.push(AssemblyInstruction::Cmov(condition, lhs, AssemblyOperand::PseudoRegister(*dst)));
+                Ok(instructions)
+            },
+            LLVMInstruction::Call(dst, _, callee, args) => Ok(generate_call(*dst, callee, args)),
+            LLVMInstruction::Store(_, src, dst) => {
+                Ok(vec![AssemblyInstruction::Mov(
+                    generate_value(src),
+                    self.generate_pointer(dst)?
+                )])
+            },
+            LLVMInstruction::Load(dst, _, src) => {
+                Ok(vec![AssemblyInstruction::Mov(
+                    self.generate_pointer(src)?,
+                    AssemblyOperand::PseudoRegister(*dst)
+                )])
+            },
+            LLVMInstruction::Alloca(_dst, _ty) => Ok(Vec::new()),
+            LLVMInstruction::Phi(..) => Ok(Vec::new()),
+        }
+    }
+
+    // Sets the flags for a boolean, comparing directly when the comparison producing it was folded into
+    // its user
+    fn condition_flags(&self, condition: &LLVMValue) -> (AssemblyInstruction, AssemblyCondition) {
+        if let LLVMValue::Register(id) = condition {
+            if let Some((comparison, lhs, rhs)) = self.tiling.comparisons.get(id) {
+                return compare(comparison, lhs, rhs);
+            }
+        }
+        compare(&LLVMComparison::NotEqual, condition, &LLVMValue::Immediate(0))
+    }
+
+    // Dividing by a constant multiplies by its reciprocal instead, which takes a few cycles where idiv takes
+    // tens. Remainders are computed from the quotient. Division by zero is left to the hardware, which
+    // traps as C programs expect.
+    fn generate_division(&mut self, dst: ValueId, op: &LLVMBinaryOp, lhs: &LLVMValue, rhs: &LLVMValue) -> Vec<AssemblyInstruction> {
+        let signed = matches!(op, LLVMBinaryOp::Divide | LLVMBinaryOp::Remainder);
+        let remainder = matches!(op, LLVMBinaryOp::Remainder | LLVMBinaryOp::UnsignedRemainder);
+        let dividend = generate_value(lhs);
+        let dst = AssemblyOperand::PseudoRegister(dst);
+        let divisor = match rhs {
+            LLVMValue::Immediate(divisor) if *divisor != 0 => *divisor,
+            _ => return hardware_division(signed, remainder, dividend, generate_value(rhs), dst),
+        };
+        let absolute = if signed { divisor.unsigned_abs() } else { divisor as u32 };
+
+        if absolute == 1 {
+            return match (remainder, divisor) {
+                (true, _) => vec![AssemblyInstruction::Mov(AssemblyOperand::Immediate(0), dst)],
+                (false, -1) => vec![AssemblyInstruction::Mov(dividend, dst.clone()), AssemblyInstruction::Unary(AssemblyUnaryOperator::Neg, dst)],
+                (false, _) => vec![AssemblyInstruction::Mov(dividend, dst)],
             };
+        }
+        if magic_division::is_power_of_two(absolute) {
+            return self.power_of_two_division(signed, remainder, divisor, dividend, dst);
+        }
+
+        let quotient = if remainder { self.new_pseudo(IRType::I32) } else { dst.clone() };
+        let mut instructions = if signed {
+            signed_quotient(divisor, &dividend, &quotient)
+        } else {
+            unsigned_quotient(divisor as u32, &dividend, &quotient)
+        };
+        if remainder {
+            let product = self.new_pseudo(IRType::I32);
+            instructions.extend([
+                AssemblyInstruction::Imul(divisor, quotient, product.clone()),
+                AssemblyInstruction::Mov(dividend, dst.clone()),
+                AssemblyInstruction::Binary(AssemblyBinaryOperator::Sub, product, dst),
+            ]);
+        }
+        instructions
+    }
 
-            Ok(Some(vec![
-                AssemblyInstruction::Mov(
-                    generate_value(value),
-                    AssemblyOperand::PseudoRegister(dst.clone())
-                ),
-                AssemblyInstruction::Unary(
-                    asm_op,
-                    AssemblyOperand::PseudoRegister(dst.clone())
-                )
-            ]))
-        },
-        LLVMInstruction::BinaryOp(dst, _, op, lhs, rhs) => {
-            let instructions = match op {
-                LLVMBinaryOp::Add | LLVMBinaryOp::Subtract | LLVMBinaryOp::Multiply => {
-                    let asm_op = match op {
-                        LLVMBinaryOp::Add => AssemblyBinaryOperator::Add,
-                        LLVMBinaryOp::Subtract => AssemblyBinaryOperator::Sub,
-                        LLVMBinaryOp::Multiply => AssemblyBinaryOperator::Mult,
-                        _ => unreachable!(),
-                    };
-                    vec![
-                        AssemblyInstruction::Mov(
-                            generate_value(lhs),
-                            AssemblyOperand::PseudoRegister(dst.clone())
-                        ),
-                        AssemblyInstruction::Binary(
-                            asm_op,
-                            generate_value(rhs),
-                            AssemblyOperand::PseudoRegister(dst.clone())
-                        ),
-                    ]
-                },
-                LLVMBinaryOp::Divide | LLVMBinaryOp::Remainder => {
-                    let result_reg = match op {
-                        LLVMBinaryOp::Divide => AssemblyRegister::AX,
-                        LLVMBinaryOp::Remainder => AssemblyRegister::DX,
-                        _ => unreachable!(),
-                    };
-                    vec![
-                        AssemblyInstruction::Mov(
-                            generate_value(lhs),
-                            AssemblyOperand::Register(AssemblyRegister::AX)
-                        ),
-                        AssemblyInstruction::Cdq,
-                        AssemblyInstruction::Idiv(generate_value(rhs)),
-                        AssemblyInstruction::Mov(
-                            AssemblyOperand::Register(result_reg),
-                            AssemblyOperand::PseudoRegister(dst.clone())
-                        ),
-                    ]
-                },
+    // Shifting rounds toward negative infinity, so negative dividends are first biased by the divisor
+    // minus one to round toward zero as C does. The bias is the sign mask shifted right logically.
+    fn power_of_two_division(
+        &mut self,
+        signed: bool,
+        remainder: bool,
+        divisor: i32,
+        dividend: AssemblyOperand,
+        dst: AssemblyOperand,
+    ) -> Vec<AssemblyInstruction> {
+        let absolute = if signed { divisor.unsigned_abs() } else { divisor as u32 };
+        let shift = absolute.trailing_zeros() as i32;
+        if !signed {
+            let operation = match remainder {
+                true => AssemblyInstruction::Binary(AssemblyBinaryOperator::And, AssemblyOperand::Immediate((absolute - 1) as i32), dst.clone()),
+                false => AssemblyInstruction::Binary(AssemblyBinaryOperator::Shr, AssemblyOperand::Immediate(shift), dst.clone()),
             };
-            Ok(Some(instructions))
-        },
-        LLVMInstruction::Store(src, dst) => {
-            Ok(Some(vec![AssemblyInstruction::Mov(
-                generate_value(src),
-                AssemblyOperand::PseudoRegister(dst.to_string())
-            )]))
-        },
-        LLVMInstruction::Load(dst, src) => {
-            Ok(Some(vec![AssemblyInstruction::Mov(
-                AssemblyOperand::PseudoRegister(src.to_string()),
-                AssemblyOperand::PseudoRegister(dst.clone())
-            )]))
-        },
-        LLVMInstruction::Alloca(_dst, _ty) => Ok(None),
+            return vec![AssemblyInstruction::Mov(dividend, dst), operation];
+        }
+
+        let biased = self.new_pseudo(IRType::I32);
+        let mut instructions = vec![AssemblyInstruction::Mov(dividend.clone(), biased.clone())];
+        if shift > 1 {
+            instructions.push(AssemblyInstruction::Binary(AssemblyBinaryOperator::Sar, AssemblyOperand::Immediate(31), biased.clone()));
+        }
+        instructions.extend([
+            AssemblyInstruction::Binary(AssemblyBinaryOperator::Shr, AssemblyOperand::Immediate(32 - shift), biased.clone()),
+            AssemblyInstruction::Binary(AssemblyBinaryOperator::Add, dividend.clone(), biased.clone()),
+        ]);
+        if remainder {
+            // The remainder is what rounding the biased dividend down to a multiple of the divisor removes
+            let mask = AssemblyOperand::Immediate(absolute.wrapping_neg() as i32);
+            instructions.extend([
+                AssemblyInstruction::Binary(AssemblyBinaryOperator::And, mask, biased.clone()),
+                AssemblyInstruction::Mov(dividend, dst.clone()),
+                AssemblyInstruction::Binary(AssemblyBinaryOperator::Sub, biased, dst),
+            ]);
+        } else {
+            instructions.extend([
+                AssemblyInstruction::Mov(biased, dst.clone()),
+                AssemblyInstruction::Binary(AssemblyBinaryOperator::Sar, AssemblyOperand::Immediate(shift), dst.clone()),
+            ]);
+            if divisor < 0 {
+                instructions.push(AssemblyInstruction::Unary(AssemblyUnaryOperator::Neg, dst));
+            }
+        }
+        instructions
+    }
+
+    fn generate_pointer(&self, pointer: &LLVMValue) -> Result<AssemblyOperand, String> {
+        match pointer {
+            LLVMValue::Register(id) if self.allocas.contains(id) => Ok(AssemblyOperand::PseudoRegister(*id)),
+            LLVMValue::Global(name) => Ok(AssemblyOperand::Data(name.clone())),
+            other => Err(format!("Memory access through {} is not supported by the x86-64 backend", other)),
+        }
+    }
+
+    // Phi nodes read their incoming values all at once, so the copies go through fresh pseudo registers
+    // when there are several of them
+    fn edge_copies(&mut self, from: &str, to: &str) -> Vec<AssemblyInstruction> {
+        let copies = self.phis.get(to).into_iter().flatten()
+            .filter_map(|(dst, incoming)| {
+                let (value, _) = incoming.iter().find(|(_, label)| label == from)?;
+                Some((*dst, generate_value(value)))
+            })
+            .collect::<Vec<_>>();
+        if copies.len() < 2 {
+            return copies.into_iter()
+                .map(|(dst, value)| AssemblyInstruction::Mov(value, AssemblyOperand::PseudoRegister(dst)))
+                .collect();
+        }
+
+        let temporaries = copies.iter()
+            .map(|(dst, _)| {
+                let ty = self.pseudo_types.get(dst).copied().unwrap_or(IRType::I32);
+                self.new_pseudo(ty)
+            })
+            .collect::<Vec<_>>();
+        let to_temporaries = copies.iter().zip(&temporaries)
+            .map(|((_, value), temporary)| AssemblyInstruction::Mov(value.clone(), temporary.clone()));
+        let from_temporaries = copies.iter().zip(&temporaries)
+            .map(|((dst, _), temporary)| AssemblyInstruction::Mov(temporary.clone(), AssemblyOperand::PseudoRegister(*dst)));
+        to_temporaries.chain(from_temporaries).collect()
+    }
+
+    fn new_pseudo(&mut self, ty: IRType) -> AssemblyOperand {
+        let id = ValueId(self.next_pseudo);
+        self.next_pseudo += 1;
+        self.pseudo_types.insert(id, ty);
+        AssemblyOperand::PseudoRegister(id)
+    }
+
+    // Labels that do not come from a block, numbered so they cannot clash with block labels
+    fn new_label(&mut self) -> String {
+        self.next_label += 1;
+        block_label(self.name, &self.next_label.to_string())
+    }
+}
+
+// Stack arguments are pushed in reverse order, with padding so that the stack stays 16-byte aligned at the call
+fn generate_call(dst: Option<ValueId>, callee: &str, args: &[(IRType, LLVMValue)]) -> Vec<AssemblyInstruction> {
+    let mut instructions = Vec::new();
+    let stack_args = args.len().saturating_sub(ARGUMENT_REGISTERS.len());
+    let padding = if stack_args % 2 == 1 { 8 } else { 0 };
+    if padding > 0 {
+        instructions.push(AssemblyInstruction::AllocateStack(padding));
+    }
+
+    for (register, (_, value)) in ARGUMENT_REGISTERS.iter().zip(args) {
+        instructions.push(AssemblyInstruction::Mov(generate_value(value), AssemblyOperand::Register(*register)));
+    }
+    for (_, value) in args.iter().skip(ARGUMENT_REGISTERS.len()).rev() {
+        instructions.push(AssemblyInstruction::Push(generate_value(value)));
+    }
+
+    instructions.push(AssemblyInstruction::Call(callee.to_string(), args.len().min(ARGUMENT_REGISTERS.len())));
+    let deallocation = 8 * stack_args as i32 + padding;
+    if deallocation > 0 {
+        instructions.push(AssemblyInstruction::DeallocateStack(deallocation));
+    }
+    if let Some(dst) = dst {
+        instructions.push(AssemblyInstruction::Mov(
+            AssemblyOperand::Register(AssemblyRegister::AX),
+            AssemblyOperand::PseudoRegister(dst)
+        ));
+    }
+    instructions
+}
+
+// cmp computes lhs - rhs and needs its immediate on the right, so an immediate lhs swaps the operands.
+// Comparing against zero tests the value against itself instead, which sets the same flags.
+fn compare(comparison: &LLVMComparison, lhs: &LLVMValue, rhs: &LLVMValue) -> (AssemblyInstruction, AssemblyCondition) {
+    let condition = condition_code(comparison);
+    match (generate_value(lhs), generate_value(rhs)) {
+        (lhs, AssemblyOperand::Immediate(0)) if !matches!(lhs, AssemblyOperand::Immediate(_)) => {
+            (AssemblyInstruction::Test(lhs.clone(), lhs), condition)
+        }
+        (AssemblyOperand::Immediate(0), rhs) if !matches!(rhs, AssemblyOperand::Immediate(_)) => {
+            (AssemblyInstruction::Test(rhs.clone(), rhs), condition.swapped())
+        }
+        (lhs @ AssemblyOperand::Immediate(_), rhs) if !matches!(rhs, AssemblyOperand::Immediate(_)) => {
+            (AssemblyInstruction::Cmp(lhs, rhs), condition.swapped())
+        }
+        (lhs, rhs) => (AssemblyInstruction::Cmp(rhs, lhs), condition),
+    }
+}
+
+// Signed division goes through cdq and idiv, unsigned division zeroes the high half and uses div. Both
+// leave the quotient in eax and the remainder in edx.
+fn hardware_division(signed: bool, remainder: bool, dividend: AssemblyOperand, divisor: AssemblyOperand, dst: AssemblyOperand) -> Vec<AssemblyInstruction> {
+    let result = if remainder { AssemblyRegister::DX } else { AssemblyRegister::AX };
+    let mut instructions = vec![AssemblyInstruction::Mov(dividend, AssemblyOperand::Register(AssemblyRegister::AX))];
+    if signed {
+        instructions.extend([AssemblyInstruction::Cdq, AssemblyInstruction::Idiv(divisor)]);
+    } else {
+        instructions.extend([
+            AssemblyInstruction::Mov(AssemblyOperand::Immediate(0), AssemblyOperand::Register(AssemblyRegister::DX)),
+            AssemblyInstruction::Div(divisor),
+        ]);
+    }
+    instructions.push(AssemblyInstruction::Mov(AssemblyOperand::Register(result), dst));
+    instructions
+}
+
+// The high half of the product lands in edx, the quotient is rounded toward zero by adding one when it
+// is negative
+fn signed_quotient(divisor: i32, dividend: &AssemblyOperand, quotient: &AssemblyOperand) -> Vec<AssemblyInstruction> {
+    let magic = magic_division::signed_magic(divisor);
+    let high = AssemblyOperand::Register(AssemblyRegister::DX);
+    let mut instructions = vec![
+        AssemblyInstruction::Mov(AssemblyOperand::Immediate(magic.multiplier), AssemblyOperand::Register(AssemblyRegister::AX)),
+        AssemblyInstruction::WideImul(dividend.clone()),
+    ];
+    if divisor > 0 && magic.multiplier < 0 {
+        instructions.push(AssemblyInstruction::Binary(AssemblyBinaryOperator::Add, dividend.clone(), high.clone()));
+    } else if divisor < 0 && magic.multiplier > 0 {
+        instructions.push(AssemblyInstruction::Binary(AssemblyBinaryOperator::Sub, dividend.clone(), high.clone()));
+    }
+    if magic.shift > 0 {
+        instructions.push(AssemblyInstruction::Binary(AssemblyBinaryOperator::Sar, AssemblyOperand::Immediate(magic.shift as i32), high.clone()));
+    }
+    instructions.extend([
+        AssemblyInstruction::Mov(high.clone(), quotient.clone()),
+        AssemblyInstruction::Binary(AssemblyBinaryOperator::Shr, AssemblyOperand::Immediate(31), high.clone()),
+        AssemblyInstruction::Binary(AssemblyBinaryOperator::Add, high, quotient.clone()),
+    ]);
+    instructions
+}
+
+// A multiplier needing 33 bits is applied as its low 32 bits plus the dividend, halving before adding so
+// that the sum cannot overflow
+fn unsigned_quotient(divisor: u32, dividend: &AssemblyOperand, quotient: &AssemblyOperand) -> Vec<AssemblyInstruction> {
+    let magic = magic_division::unsigned_magic(divisor);
+    let high = AssemblyOperand::Register(AssemblyRegister::DX);
+    let mut instructions = vec![
+        AssemblyInstruction::Mov(AssemblyOperand::Immediate(magic.multiplier as i32), AssemblyOperand::Register(AssemblyRegister::AX)),
+        AssemblyInstruction::WideMul(dividend.clone()),
+    ];
+    let shift = if magic.add {
+        instructions.extend([
+            AssemblyInstruction::Mov(dividend.clone(), quotient.clone()),
+            AssemblyInstruction::Binary(AssemblyBinaryOperator::Sub, high.clone(), quotient.clone()),
+            AssemblyInstruction::Binary(AssemblyBinaryOperator::Shr, AssemblyOperand::Immediate(1), quotient.clone()),
+            AssemblyInstruction::Binary(AssemblyBinaryOperator::Add, high, quotient.clone()),
+        ]);
+        magic.shift - 1
+    } else {
+        instructions.push(AssemblyInstruction::Mov(high, quotient.clone()));
+        magic.shift
+    };
+    if shift > 0 {
+        instructions.push(AssemblyInstruction::Binary(AssemblyBinaryOperator::Shr, AssemblyOperand::Immediate(shift as i32), quotient.clone()));
+    }
+    instructions
+}
+
+fn condition_code(comparison: &LLVMComparison) -> AssemblyCondition {
+    match comparison {
+        LLVMComparison::Equal => AssemblyCondition::E,
+        LLVMComparison::NotEqual => AssemblyCondition::NE,
+        LLVMComparison::SignedGreater => AssemblyCondition::G,
+        LLVMComparison::SignedGreaterOrEqual => AssemblyCondition::GE,
+        LLVMComparison::SignedLess => AssemblyCondition::L,
+        LLVMComparison::SignedLessOrEqual => AssemblyCondition::LE,
+        LLVMComparison::UnsignedGreater => AssemblyCondition::A,
+        LLVMComparison::UnsignedGreaterOrEqual => AssemblyCondition::AE,
+        LLVMComparison::UnsignedLess => AssemblyCondition::B,
+        LLVMComparison::UnsignedLessOrEqual => AssemblyCondition::BE,
     }
 }
 
 fn generate_value(value: &LLVMValue) -> AssemblyOperand {
     match value {
-        LLVMValue::Register(reg) => AssemblyOperand::PseudoRegister(reg.clone()),
+        LLVMValue::Register(id) => AssemblyOperand::PseudoRegister(*id),
         LLVMValue::Immediate(val) => AssemblyOperand::Immediate(*val),
+        LLVMValue::Global(name) => AssemblyOperand::Data(name.clone()),
     }
 }
 
-fn replace_pseudo(operand: &AssemblyOperand) -> AssemblyOperand {
-    match operand {
-        AssemblyOperand::PseudoRegister(reg) => {
-            let num = reg.trim_start_matches('%').parse::<i32>().unwrap();
-            AssemblyOperand::StackPointer(-4 * (num + 1))
-        },
-        other => other.clone(),
+// Bytes a stack slot for a value of the type takes, which is also its alignment. Booleans are kept
+// zero-extended in a full 32-bit register, so they take as much room as an int.
+fn slot_size(ty: IRType) -> i32 {
+    match ty {
+        IRType::Ptr => 8,
+        IRType::Void | IRType::I1 | IRType::I32 => 4,
     }
 }
 
-fn compute_stack_allocation(func: &AssemblyFunction) -> Result<(AssemblyFunction, i32), String> {
+// Pseudo registers left by the register allocator each get their own slot below the frame pointer, the
+// most aligned ones first so that no padding is needed between slots. Returns the size of the frame,
+// rounded up to keep the stack 16-byte aligned at calls.
+fn compute_stack_allocation(func: &AssemblyFunction, pseudo_types: &HashMap<ValueId, IRType>) -> Result<(AssemblyFunction, i32), String> {
     match func {
-        AssemblyFunction::Function { name, instructions } => {
-            let new_instructions = instructions.iter().map(|inst| match inst {
-                AssemblyInstruction::Mov(src, dst) => AssemblyInstruction::Mov(
-                    replace_pseudo(src),
-                    replace_pseudo(dst)
-                ),
-                AssemblyInstruction::Unary(op, operand) => AssemblyInstruction::Unary(
-                    op.clone(),
-                    replace_pseudo(operand)
-                ),
-                AssemblyInstruction::Binary(op, src, dst) => AssemblyInstruction::Binary(
-                    op.clone(),
-                    replace_pseudo(src),
-                    replace_pseudo(dst)
-                ),
-                AssemblyInstruction::Idiv(src) => AssemblyInstruction::Idiv(
-                    replace_pseudo(src)
-                ),
-                other => other.clone(),
-            }).collect();
-
-            Ok((AssemblyFunction::Function { name: name.clone(), instructions: new_instructions }, -16))
-        }
-    }
-}
+        AssemblyFunction::Function { name, global, instructions } => {
+            let mut new_instructions = instructions.clone();
+            let mut pseudos = Vec::new();
+            for operand in new_instructions.iter_mut().flat_map(AssemblyInstruction::operands_mut) {
+                if let AssemblyOperand::PseudoRegister(id) = operand {
+                    if !pseudos.contains(id) {
+                        pseudos.push(*id);
+                    }
+                }
+            }
+            let size = |id: &ValueId| slot_size(pseudo_types.get(id).copied().unwrap_or(IRType::I32));
+            pseudos.sort_by_key(|id| -size(id));
 
-fn fix_memory_constraints(program: AssemblyConstruct, stack_size: i32) -> Result<AssemblyConstruct, String> {
-    match program {
-        AssemblyConstruct::Program(function) => {
-            Ok(AssemblyConstruct::Program(fix_instructions_operands(function, stack_size)?))
+            let mut offset = 0;
+            let mut slots = HashMap::new();
+            for id in pseudos {
+                offset -= size(&id);
+                slots.insert(id, offset);
+            }
+            for operand in new_instructions.iter_mut().flat_map(AssemblyInstruction::operands_mut) {
+                if let AssemblyOperand::PseudoRegister(id) = operand {
+                    *operand = AssemblyOperand::StackPointer(slots[id]);
+                }
+            }
+
+            let stack_size = (-offset + 15) / 16 * 16;
+            Ok((AssemblyFunction::Function { name: name.clone(), global: *global, instructions: new_instructions }, stack_size))
         }
     }
 }
 
-fn fix_instructions_operands(function: AssemblyFunction, stack_size: i32) -> Result<AssemblyFunction, String> {
+// Callee-saved registers are pushed below the frame and popped before each return, the frame grows by 8
+// bytes when needed to keep the stack aligned
+fn fix_instructions_operands(function: AssemblyFunction, stack_size: i32, callee_saved: &[AssemblyRegister]) -> Result<AssemblyFunction, String> {
     match function {
-        AssemblyFunction::Function { name, instructions } => {
+        AssemblyFunction::Function { name, global, instructions } => {
             let mut new_instructions = Vec::new();
-            new_instructions.push(AssemblyInstruction::AllocateStack(stack_size));
+            let padding = if callee_saved.len() % 2 == 1 { 8 } else { 0 };
+            if stack_size + padding > 0 {
+                new_instructions.push(AssemblyInstruction::AllocateStack(stack_size + padding));
+            }
+            new_instructions.extend(callee_saved.iter().map(|register| AssemblyInstruction::Push(AssemblyOperand::Register(*register))));
 
             for inst in instructions {
                 match inst {
                     AssemblyInstruction::Mov(src, dst) => {
-                        if let (AssemblyOperand::StackPointer(_), AssemblyOperand::StackPointer(_)) = (&src, &dst) {
+                        if src.is_memory() && dst.is_memory() {
                             new_instructions.extend([
                                 AssemblyInstruction::Mov(src, AssemblyOperand::Register(AssemblyRegister::R10)),
                                 AssemblyInstruction::Mov(AssemblyOperand::Register(AssemblyRegister::R10), dst),
@@ -238,122 +871,106 @@ fn fix_instructions_operands(function: AssemblyFunction, stack_size: i32) -> Res
                             new_instructions.push(AssemblyInstruction::Mov(src, dst));
                         }
                     },
-                    AssemblyInstruction::Idiv(src) => {
-                        match src {
-                            AssemblyOperand::Immediate(_) => new_instructions.extend([
-                                AssemblyInstruction::Mov(src, AssemblyOperand::Register(AssemblyRegister::R10)),
-                                AssemblyInstruction::Idiv(AssemblyOperand::Register(AssemblyRegister::R10)),
-                            ]),
-                            _ => new_instructions.push(AssemblyInstruction::Idiv(src)),
-                        }
+                    // Divisions and widening multiplications cannot take an immediate
+                    mut instruction @ (AssemblyInstruction::Idiv(AssemblyOperand::Immediate(_))
+                    | AssemblyInstruction::Div(AssemblyOperand::Immediate(_))
+                    | AssemblyInstruction::WideImul(AssemblyOperand::Immediate(_))
+                    | AssemblyInstruction::WideMul(AssemblyOperand::Immediate(_))) => {
+                        let src = std::mem::replace(instruction.operands_mut()[0], AssemblyOperand::Register(AssemblyRegister::R10));
+                        new_instructions.extend([AssemblyInstruction::Mov(src, AssemblyOperand::Register(AssemblyRegister::R10)), instruction]);
                     },
                     AssemblyInstruction::Binary(op, src, dst) => {
                         match op {
-                            AssemblyBinaryOperator::Mult => new_instructions.extend([
+                            AssemblyBinaryOperator::Mult if dst.is_memory() => new_instructions.extend([
                                 AssemblyInstruction::Mov(dst.clone(), AssemblyOperand::Register(AssemblyRegister::R11)),
                                 AssemblyInstruction::Binary(op, src, AssemblyOperand::Register(AssemblyRegister::R11)),
                                 AssemblyInstruction::Mov(AssemblyOperand::Register(AssemblyRegister::R11), dst),
                             ]),
-                            _ => match src {
-                                AssemblyOperand::StackPointer(_) => new_instructions.extend([
-                                    AssemblyInstruction::Mov(src, AssemblyOperand::Register(AssemblyRegister::R10)),
-                                    AssemblyInstruction::Binary(op, AssemblyOperand::Register(AssemblyRegister::R10), dst),
-                                ]),
-                                _ => new_instructions.push(AssemblyInstruction::Binary(op, src, dst)),
-                            },
+                            _ if src.is_memory() && dst.is_memory() => new_instructions.extend([
+                                AssemblyInstruction::Mov(src, AssemblyOperand::Register(AssemblyRegister::R10)),
+                                AssemblyInstruction::Binary(op, AssemblyOperand::Register(AssemblyRegister::R10), dst),
+                            ]),
+                            _ => new_instructions.push(AssemblyInstruction::Binary(op, src, dst)),
+                        }
+                    },
+                    // The second operand of cmp cannot be an immediate
+                    AssemblyInstruction::Cmp(src, dst) => {
+                        let src = if src.is_memory() && dst.is_memory() {
+                            new_instructions.push(AssemblyInstruction::Mov(src, AssemblyOperand::Register(AssemblyRegister::R10)));
+                            AssemblyOperand::Register(AssemblyRegister::R10)
+                        } else {
+                            src
+                        };
+                        match dst {
+                            AssemblyOperand::Immediate(_) => new_instructions.extend([
+                                AssemblyInstruction::Mov(dst, AssemblyOperand::Register(AssemblyRegister::R11)),
+                                AssemblyInstruction::Cmp(src, AssemblyOperand::Register(AssemblyRegister::R11)),
+                            ]),
+                            _ => new_instructions.push(AssemblyInstruction::Cmp(src, dst)),
+                        }
+                    },
+                    // test is commutative, and testing a value against itself compares it with zero
+                    AssemblyInstruction::Test(src, dst) if src.is_memory() && dst.is_memory() => {
+                        if src == dst {
+                            new_instructions.push(AssemblyInstruction::Cmp(AssemblyOperand::Immediate(0), dst));
+                        } else {
+                            new_instructions.extend([
+                                AssemblyInstruction::Mov(src, AssemblyOperand::Register(AssemblyRegister::R10)),
+                                AssemblyInstruction::Test(AssemblyOperand::Register(AssemblyRegister::R10), dst),
+                            ]);
+                        }
+                    },
+                    AssemblyInstruction::Cmov(condition, src, dst) if dst.is_memory() => new_instructions.extend([
+                        AssemblyInstruction::Mov(dst.clone(), AssemblyOperand::Register(AssemblyRegister::R11)),
+                        AssemblyInstruction::Cmov(condition, src, AssemblyOperand::Register(AssemblyRegister::R11)),
+                        AssemblyInstruction::Mov(AssemblyOperand::Register(AssemblyRegister::R11), dst),
+                    ]),
+                    AssemblyInstruction::Imul(factor, src, dst) if dst.is_memory() => new_instructions.extend([
+                        AssemblyInstruction::Imul(factor, src, AssemblyOperand::Register(AssemblyRegister::R11)),
+                        AssemblyInstruction::Mov(AssemblyOperand::Register(AssemblyRegister::R11), dst),
+                    ]),
+                    // The address is read before the destination is written, so R10 can hold both the base and the result
+                    AssemblyInstruction::Lea(AssemblyAddress { base, index, displacement }, dst) => {
+                        let base = if base.is_memory() {
+                            new_instructions.push(AssemblyInstruction::Mov(base, AssemblyOperand::Register(AssemblyRegister::R10)));
+                            AssemblyOperand::Register(AssemblyRegister::R10)
+                        } else {
+                            base
+                        };
+                        let index = index.map(|(index, scale)| {
+                            if index.is_memory() {
+                                new_instructions.push(AssemblyInstruction::Mov(index, AssemblyOperand::Register(AssemblyRegister::R11)));
+                                (AssemblyOperand::Register(AssemblyRegister::R11), scale)
+                            } else {
+                                (index, scale)
+                            }
+                        });
+                        let address = AssemblyAddress { base, index, displacement };
+                        if dst.is_memory() {
+                            new_instructions.extend([
+                                AssemblyInstruction::Lea(address, AssemblyOperand::Register(AssemblyRegister::R10)),
+                                AssemblyInstruction::Mov(AssemblyOperand::Register(AssemblyRegister::R10), dst),
+                            ]);
+                        } else {
+                            new_instructions.push(AssemblyInstruction::Lea(address, dst));
                         }
                     },
+                    AssemblyInstruction::Ret => {
+                        new_instructions.extend(callee_saved.iter().rev().map(|register| AssemblyInstruction::Pop(*register)));
+                        new_instructions.push(AssemblyInstruction::Ret);
+                    },
                     other => new_instructions.push(other),
                 }
             }
 
-            Ok(AssemblyFunction::Function { name, instructions: new_instructions })
-        }
-    }
-}
-
-impl fmt::Display for AssemblyConstruct {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            AssemblyConstruct::Program(func) => {
-                write!(f, "{}\n.section .note.GNU-stack,\"\",@progbits", func)
-            }
-        }
-    }
-}
-
-impl fmt::Display for AssemblyFunction {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            AssemblyFunction::Function { name, instructions } => {
-                writeln!(f, ".globl {}", name)?;
-                writeln!(f, "{}:", name)?;
-                writeln!(f, "    pushq %rbp")?;
-                writeln!(f, "    movq %rsp, %rbp")?;
-                for instruction in instructions {
-                    write!(f, "    {}", instruction)?;
-                }
-                Ok(())
-            }
+            Ok(AssemblyFunction::Function { name, global, instructions: new_instructions })
         }
     }
 }
 
+// Instructions print in AT&T syntax when they show up in messages
 impl fmt::Display for AssemblyInstruction {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            AssemblyInstruction::Mov(src, dst) => writeln!(f, "movl {}, {}", src, dst),
-            AssemblyInstruction::Unary(op, operand) => writeln!(f, "{} {}", op, operand),
-            AssemblyInstruction::Binary(op, src, dst) => writeln!(f, "{} {}, {}", op, src, dst),
-            AssemblyInstruction::Idiv(src) => writeln!(f, "idivl {}", src),
-            AssemblyInstruction::Cdq => writeln!(f, "cdq"),
-            AssemblyInstruction::AllocateStack(size) => writeln!(f, "subq ${}, %rsp", size),
-            AssemblyInstruction::Ret => {
-                writeln!(f, "movq %rbp, %rsp")?;
-                writeln!(f, "    popq %rbp")?;
-                writeln!(f, "    ret")
-            }
-        }
+        AttEmitter.write_instruction(f, self)
     }
 }
-
-impl fmt::Display for AssemblyUnaryOperator {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            AssemblyUnaryOperator::Neg => write!(f, "negl"),
-            AssemblyUnaryOperator::Not => write!(f, "notl"),
-        }
-    }
-}
-
-impl fmt::Display for AssemblyBinaryOperator {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            AssemblyBinaryOperator::Add => write!(f, "addl"),
-            AssemblyBinaryOperator::Sub => write!(f, "subl"),
-            AssemblyBinaryOperator::Mult => write!(f, "imull"),
-        }
-    }
-}
-
-impl fmt::Display for AssemblyOperand {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            AssemblyOperand::Register(reg) => write!(f, "{}", reg),
-            AssemblyOperand::Immediate(value) => write!(f, "${}", value),
-            AssemblyOperand::PseudoRegister(id) => write!(f, "pseudo({})", id),
-            AssemblyOperand::StackPointer(offset) => write!(f, "{}(%rbp)", offset),
-        }
-    }
-}
-
-impl fmt::Display for AssemblyRegister {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            AssemblyRegister::AX => write!(f, "%eax"),
-            AssemblyRegister::DX => write!(f, "%edx"),
-            AssemblyRegister::R10 => write!(f, "%r10d"),
-            AssemblyRegister::R11 => write!(f, "%r11d"),
-        }
-    }
-}
\ No newline at end of file
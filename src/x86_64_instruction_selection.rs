@@ -0,0 +1,191 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::llvm_ir::{IRType, LLVMBasicBlock, LLVMBinaryOp, LLVMComparison, LLVMFunction, LLVMInstruction, LLVMValue, ValueId};
+
+// base + index * scale + displacement, computed by a single lea
+#[derive(Debug, Clone)]
+pub struct Address {
+    pub base: ValueId,
+    pub index: Option<(ValueId, u8)>,
+    pub displacement: i32,
+}
+
+// How the instructions of a function are covered by x86 instructions. An instruction whose result has a
+// single use, in the same block, can be folded into the instruction using it, which then computes the
+// whole tree at once. The other instructions go through the generator's own templates.
+#[derive(Default)]
+pub struct Tiling<'a> {
+    // Instructions computed as part of the instruction using their result, which emit nothing themselves
+    pub folded: HashSet<ValueId>,
+    // Additions, subtractions and multiplications done by a lea
+    pub addresses: HashMap<ValueId, Address>,
+    // Comparisons done where the branch or select using them tests the flags
+    pub comparisons: HashMap<ValueId, (&'a LLVMComparison, &'a LLVMValue, &'a LLVMValue)>,
+}
+
+pub fn select(function: &LLVMFunction) -> Tiling<'_> {
+    let LLVMFunction::Function { blocks, .. } = function;
+    let mut use_counts = HashMap::new();
+    for instruction in blocks.iter().flat_map(|block| &block.instructions) {
+        for operand in instruction.operands() {
+            if let LLVMValue::Register(id) = operand {
+                *use_counts.entry(*id).or_insert(0) += 1;
+            }
+        }
+    }
+
+    let mut tiling = Tiling::default();
+    for block in blocks {
+        let positions = block.instructions.iter()
+            .enumerate()
+            .filter_map(|(position, instruction)| Some((instruction.result()?.0, position)))
+            .collect();
+        BlockSelector { block, positions: &positions, use_counts: &use_counts, tiling: &mut tiling }.select();
+    }
+    tiling
+}
+
+// Partial match of an address, whose base may still be missing
+#[derive(Default)]
+struct AddressPattern {
+    base: Option<ValueId>,
+    index: Option<(ValueId, u8)>,
+    displacement: i32,
+    folded: Vec<ValueId>,
+}
+
+struct BlockSelector<'a, 'b> {
+    block: &'a LLVMBasicBlock,
+    // Where each value of the block is defined
+    positions: &'b HashMap<ValueId, usize>,
+    use_counts: &'b HashMap<ValueId, usize>,
+    tiling: &'b mut Tiling<'a>,
+}
+
+impl<'a> BlockSelector<'a, '_> {
+    // Trees are matched from their root, so the instructions are visited last to first and those folded
+    // into an instruction already visited are skipped
+    fn select(&mut self) {
+        for (position, instruction) in self.block.instructions.iter().enumerate().rev() {
+            if instruction.result().is_some_and(|(id, _)| self.tiling.folded.contains(&id)) {
+                continue;
+            }
+            match instruction {
+                LLVMInstruction::CondBranch(LLVMValue::Register(condition), ..)
+                | LLVMInstruction::Select(_, _, LLVMValue::Register(condition), ..) => self.fold_comparison(*condition, position),
+                LLVMInstruction::BinaryOp(dst, IRType::I32, LLVMBinaryOp::Add | LLVMBinaryOp::Subtract | LLVMBinaryOp::Multiply, ..) => {
+                    self.select_address(*dst, instruction, position);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    // The instruction defining a value used once, earlier in the block than `user`
+    fn foldable_definition(&self, id: ValueId, user: usize) -> Option<(usize, &'a LLVMInstruction)> {
+        if self.use_counts.get(&id) != Some(&1) || self.tiling.folded.contains(&id) {
+            return None;
+        }
+        let position = *self.positions.get(&id).filter(|position| **position < user)?;
+        Some((position, &self.block.instructions[position]))
+    }
+
+    // The comparison's operands are read at the user instead, so nothing in between may write memory
+    fn fold_comparison(&mut self, condition: ValueId, user: usize) {
+        let Some((position, LLVMInstruction::ICmp(_, comparison, _, lhs, rhs))) = self.foldable_definition(condition, user) else {
+            return;
+        };
+        let writes_memory = self.block.instructions[position..user]
+            .iter()
+            .any(|instruction| matches!(instruction, LLVMInstruction::Store(..) | LLVMInstruction::Call(..)));
+        if !writes_memory {
+            self.tiling.folded.insert(condition);
+            self.tiling.comparisons.insert(condition, (comparison, lhs, rhs));
+        }
+    }
+
+    // A lea is worth it when it saves more than a move, so it needs a base and something to add to it.
+    // Doubling takes the base as index too.
+    fn select_address(&mut self, dst: ValueId, instruction: &'a LLVMInstruction, position: usize) {
+        let Some(mut pattern) = self.expand(instruction, position) else {
+            return;
+        };
+        if let (None, Some((index, 2))) = (pattern.base, pattern.index) {
+            pattern.base = Some(index);
+            pattern.index = Some((index, 1));
+        }
+        let Some(base) = pattern.base else {
+            return;
+        };
+        if pattern.index.is_none() && pattern.displacement == 0 {
+            return;
+        }
+        self.tiling.folded.extend(pattern.folded);
+        self.tiling.addresses.insert(dst, Address { base, index: pattern.index, displacement: pattern.displacement });
+    }
+
+    // Matches an instruction as an address, folding in the operands that can be
+    fn expand(&self, instruction: &LLVMInstruction, position: usize) -> Option<AddressPattern> {
+        match instruction {
+            LLVMInstruction::BinaryOp(_, IRType::I32, LLVMBinaryOp::Add, lhs, rhs) => {
+                combine(self.operand(lhs, position)?, self.operand(rhs, position)?)
+            }
+            LLVMInstruction::BinaryOp(_, IRType::I32, LLVMBinaryOp::Subtract, lhs, LLVMValue::Immediate(value)) => {
+                let mut pattern = self.operand(lhs, position)?;
+                pattern.displacement = pattern.displacement.wrapping_sub(*value);
+                Some(pattern)
+            }
+            LLVMInstruction::BinaryOp(_, IRType::I32, LLVMBinaryOp::Multiply, LLVMValue::Register(id), LLVMValue::Immediate(scale))
+            | LLVMInstruction::BinaryOp(_, IRType::I32, LLVMBinaryOp::Multiply, LLVMValue::Immediate(scale), LLVMValue::Register(id)) => {
+                match scale {
+                    1 => Some(AddressPattern { base: Some(*id), ..Default::default() }),
+                    2 | 4 | 8 => Some(AddressPattern { index: Some((*id, *scale as u8)), ..Default::default() }),
+                    3 | 5 | 9 => Some(AddressPattern { base: Some(*id), index: Some((*id, *scale as u8 - 1)), ..Default::default() }),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    // Operands defined by a foldable instruction are expanded if they match, otherwise they are used as is
+    fn operand(&self, value: &LLVMValue, user: usize) -> Option<AddressPattern> {
+        match value {
+            LLVMValue::Immediate(value) => Some(AddressPattern { displacement: *value, ..Default::default() }),
+            LLVMValue::Register(id) => {
+                let expanded = self.foldable_definition(*id, user).and_then(|(position, definition)| {
+                    let mut pattern = self.expand(definition, position)?;
+                    pattern.folded.push(*id);
+                    Some(pattern)
+                });
+                Some(expanded.unwrap_or(AddressPattern { base: Some(*id), ..Default::default() }))
+            }
+            LLVMValue::Global(_) => None,
+        }
+    }
+}
+
+// Sum of two patterns, if it still fits in a base and a scaled index
+fn combine(first: AddressPattern, second: AddressPattern) -> Option<AddressPattern> {
+    let mut unscaled = Vec::new();
+    let mut scaled = Vec::new();
+    for pattern in [&first, &second] {
+        unscaled.extend(pattern.base);
+        match pattern.index {
+            Some((index, 1)) => unscaled.push(index),
+            Some(index) => scaled.push(index),
+            None => {}
+        }
+    }
+    let (base, index) = match (unscaled.as_slice(), scaled.as_slice()) {
+        ([], []) => (None, None),
+        ([base], []) => (Some(*base), None),
+        ([base, index], []) => (Some(*base), Some((*index, 1))),
+        ([], [index]) => (None, Some(*index)),
+        ([base], [index]) => (Some(*base), Some(*index)),
+        _ => return None,
+    };
+    let displacement = first.displacement.wrapping_add(second.displacement);
+    let folded = first.folded.into_iter().chain(second.folded).collect();
+    Some(AddressPattern { base, index, displacement, folded })
+}
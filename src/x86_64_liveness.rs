@@ -0,0 +1,151 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::llvm_ir::ValueId;
+use crate::x86_64_generator::{
+    AssemblyAddress, AssemblyInstruction, AssemblyOperand, AssemblyRegister, ARGUMENT_REGISTERS, CALLER_SAVED_REGISTERS,
+};
+
+// Where a value is kept before register allocation, either a hardware register an instruction requires
+// or a pseudo register
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Location {
+    Register(AssemblyRegister),
+    Pseudo(ValueId),
+}
+
+impl Location {
+    // Memory and immediate operands hold no value the allocator deals with
+    pub fn of(operand: &AssemblyOperand) -> Option<Location> {
+        match operand {
+            AssemblyOperand::Register(register) => Some(Location::Register(*register)),
+            AssemblyOperand::PseudoRegister(id) => Some(Location::Pseudo(*id)),
+            _ => None,
+        }
+    }
+
+    pub fn operand(self) -> AssemblyOperand {
+        match self {
+            Location::Register(register) => AssemblyOperand::Register(register),
+            Location::Pseudo(id) => AssemblyOperand::PseudoRegister(id),
+        }
+    }
+}
+
+// Operands an instruction reads, and the ones it writes, leaving out the registers it uses implicitly.
+// setcc only writes the low byte and cmov may not write at all, so they read their destination too.
+pub fn operand_accesses(instruction: &AssemblyInstruction) -> (Vec<&AssemblyOperand>, Vec<&AssemblyOperand>) {
+    match instruction {
+        AssemblyInstruction::Mov(src, dst) | AssemblyInstruction::Imul(_, src, dst) => (vec![src], vec![dst]),
+        AssemblyInstruction::Unary(_, dst) | AssemblyInstruction::SetCC(_, dst) => (vec![dst], vec![dst]),
+        AssemblyInstruction::Binary(_, src, dst) | AssemblyInstruction::Cmov(_, src, dst) => (vec![src, dst], vec![dst]),
+        AssemblyInstruction::Cmp(lhs, rhs) | AssemblyInstruction::Test(lhs, rhs) => (vec![lhs, rhs], Vec::new()),
+        AssemblyInstruction::Lea(AssemblyAddress { base, index, .. }, dst) => {
+            let mut reads = vec![base];
+            reads.extend(index.iter().map(|(index, _)| index));
+            (reads, vec![dst])
+        }
+        AssemblyInstruction::Idiv(src)
+        | AssemblyInstruction::Div(src)
+        | AssemblyInstruction::WideImul(src)
+        | AssemblyInstruction::WideMul(src)
+        | AssemblyInstruction::Push(src) => (vec![src], Vec::new()),
+        _ => (Vec::new(), Vec::new()),
+    }
+}
+
+// Locations an instruction reads, and the ones it writes
+pub fn uses_and_defs(instruction: &AssemblyInstruction) -> (Vec<Location>, Vec<Location>) {
+    let (reads, writes) = operand_accesses(instruction);
+    let mut uses = reads.into_iter().filter_map(Location::of).collect::<Vec<_>>();
+    let mut defs = writes.into_iter().filter_map(Location::of).collect::<Vec<_>>();
+    let (implicit_uses, implicit_defs): (&[AssemblyRegister], &[AssemblyRegister]) = match instruction {
+        AssemblyInstruction::Idiv(_) | AssemblyInstruction::Div(_) => {
+            (&[AssemblyRegister::AX, AssemblyRegister::DX], &[AssemblyRegister::AX, AssemblyRegister::DX])
+        }
+        AssemblyInstruction::WideImul(_) | AssemblyInstruction::WideMul(_) => {
+            (&[AssemblyRegister::AX], &[AssemblyRegister::AX, AssemblyRegister::DX])
+        }
+        AssemblyInstruction::Cdq => (&[AssemblyRegister::AX], &[AssemblyRegister::DX]),
+        AssemblyInstruction::Call(_, register_args) => (&ARGUMENT_REGISTERS[..*register_args], &CALLER_SAVED_REGISTERS),
+        AssemblyInstruction::Ret => (&[AssemblyRegister::AX], &[]),
+        _ => (&[], &[]),
+    };
+    uses.extend(implicit_uses.iter().map(|register| Location::Register(*register)));
+    defs.extend(implicit_defs.iter().map(|register| Location::Register(*register)));
+    (uses, defs)
+}
+
+// Locations whose value is still needed right after each instruction, found by the usual backward
+// dataflow over the basic blocks of a function
+pub fn live_after(instructions: &[AssemblyInstruction]) -> Vec<HashSet<Location>> {
+    // Blocks start at labels and after jumps
+    let mut starts = vec![0];
+    for (index, instruction) in instructions.iter().enumerate() {
+        match instruction {
+            AssemblyInstruction::Label(_) => starts.push(index),
+            AssemblyInstruction::Jmp(_) | AssemblyInstruction::JmpCC(..) | AssemblyInstruction::Ret => starts.push(index + 1),
+            _ => {}
+        }
+    }
+    starts.retain(|start| *start < instructions.len());
+    starts.sort_unstable();
+    starts.dedup();
+    let blocks = starts.iter()
+        .enumerate()
+        .map(|(block, start)| *start..starts.get(block + 1).copied().unwrap_or(instructions.len()))
+        .collect::<Vec<_>>();
+
+    let labels = blocks.iter()
+        .enumerate()
+        .filter_map(|(block, range)| match &instructions[range.start] {
+            AssemblyInstruction::Label(label) => Some((label.as_str(), block)),
+            _ => None,
+        })
+        .collect::<HashMap<_, _>>();
+    let successors = blocks.iter()
+        .enumerate()
+        .map(|(block, range)| {
+            let fallthrough = (block + 1 < blocks.len()).then_some(block + 1);
+            match &instructions[range.end - 1] {
+                AssemblyInstruction::Jmp(label) => labels.get(label.as_str()).copied().into_iter().collect(),
+                AssemblyInstruction::JmpCC(_, label) => labels.get(label.as_str()).copied().into_iter().chain(fallthrough).collect(),
+                AssemblyInstruction::Ret => Vec::new(),
+                _ => fallthrough.into_iter().collect::<Vec<_>>(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let transfer = |live: &mut HashSet<Location>, instruction: &AssemblyInstruction| {
+        let (uses, defs) = uses_and_defs(instruction);
+        for def in defs {
+            live.remove(&def);
+        }
+        live.extend(uses);
+    };
+
+    let mut live_in = vec![HashSet::new(); blocks.len()];
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for (block, range) in blocks.iter().enumerate().rev() {
+            let mut live = successors[block].iter().flat_map(|successor| live_in[*successor].iter().copied()).collect::<HashSet<_>>();
+            for instruction in instructions[range.clone()].iter().rev() {
+                transfer(&mut live, instruction);
+            }
+            if live != live_in[block] {
+                live_in[block] = live;
+                changed = true;
+            }
+        }
+    }
+
+    let mut live_after = vec![HashSet::new(); instructions.len()];
+    for (block, range) in blocks.iter().enumerate() {
+        let mut live = successors[block].iter().flat_map(|successor| live_in[*successor].iter().copied()).collect::<HashSet<_>>();
+        for index in range.clone().rev() {
+            live_after[index] = live.clone();
+            transfer(&mut live, &instructions[index]);
+        }
+    }
+    live_after
+}
@@ -0,0 +1,143 @@
+use crate::x86_64_generator::{
+    AssemblyBinaryOperator, AssemblyFunction, AssemblyInstruction, AssemblyOperand, AssemblyUnaryOperator,
+};
+
+// Cleans up the final instructions of a function by rewriting short sequences into cheaper ones. Each
+// rewrite either removes instructions or produces one no other rewrite applies to, so repeating them until
+// nothing changes terminates.
+pub fn optimize(function: AssemblyFunction) -> AssemblyFunction {
+    let AssemblyFunction::Function { name, global, mut instructions } = function;
+    let mut changed = true;
+    while changed {
+        changed = false;
+        let mut index = 0;
+        while index < instructions.len() {
+            match rewrite(&instructions, index) {
+                Some((consumed, replacement)) => {
+                    instructions.splice(index..index + consumed, replacement);
+                    changed = true;
+                }
+                None => index += 1,
+            }
+        }
+    }
+    AssemblyFunction::Function { name, global, instructions }
+}
+
+// The number of instructions starting at `index` a rewrite replaces, and what it replaces them with
+fn rewrite(instructions: &[AssemblyInstruction], index: usize) -> Option<(usize, Vec<AssemblyInstruction>)> {
+    // Whether the flags the first `count` instructions leave behind can change
+    let flags_dead = |count: usize| flags_dead_after(instructions, index + count);
+    // Whether the first `count` instructions are followed by the label `target`
+    let falls_through = |count: usize, target: &String| {
+        instructions[(index + count).min(instructions.len())..].iter()
+            .map_while(|instruction| match instruction {
+                AssemblyInstruction::Label(label) => Some(label),
+                _ => None,
+            })
+            .any(|label| label == target)
+    };
+    let rewritten = match (&instructions[index], instructions.get(index + 1)) {
+        // Self-moves
+        (AssemblyInstruction::Mov(src, dst), _) if src == dst => (1, vec![]),
+        // Moving a value back where it came from
+        (AssemblyInstruction::Mov(src, dst), Some(AssemblyInstruction::Mov(back_src, back_dst))) if back_src == dst && back_dst == src => {
+            (2, vec![instructions[index].clone()])
+        }
+        // Reloading a value just stored, from the register it came from
+        (AssemblyInstruction::Mov(src @ AssemblyOperand::Register(_), dst), Some(AssemblyInstruction::Mov(next_src, next_dst)))
+            if dst.is_memory() && next_src == dst && !next_dst.is_memory() =>
+        {
+            (2, vec![instructions[index].clone(), AssemblyInstruction::Mov(src.clone(), next_dst.clone())])
+        }
+        // A store overwritten before being read
+        (AssemblyInstruction::Mov(_, dst), Some(AssemblyInstruction::Mov(src, next_dst))) if next_dst == dst && src != dst => {
+            (2, vec![instructions[index + 1].clone()])
+        }
+
+        // Immediate operations on the same destination, which change the flags the two leave behind
+        (
+            AssemblyInstruction::Binary(op @ (AssemblyBinaryOperator::Add | AssemblyBinaryOperator::Sub), AssemblyOperand::Immediate(first), dst),
+            Some(AssemblyInstruction::Binary(
+                next_op @ (AssemblyBinaryOperator::Add | AssemblyBinaryOperator::Sub),
+                AssemblyOperand::Immediate(second),
+                next_dst,
+            )),
+        ) if next_dst == dst && flags_dead(2) => {
+            let signed = |op: &AssemblyBinaryOperator, value: i32| match op {
+                AssemblyBinaryOperator::Sub => value.wrapping_neg(),
+                _ => value,
+            };
+            match signed(op, *first).wrapping_add(signed(next_op, *second)) {
+                0 => (2, vec![]),
+                total => (2, vec![AssemblyInstruction::Binary(AssemblyBinaryOperator::Add, AssemblyOperand::Immediate(total), dst.clone())]),
+            }
+        }
+        (AssemblyInstruction::Mov(AssemblyOperand::Immediate(value), dst), next) => {
+            match next.and_then(|next| fold(*value, dst, next)) {
+                Some(folded) if flags_dead(2) => (2, vec![AssemblyInstruction::Mov(AssemblyOperand::Immediate(folded), dst.clone())]),
+                _ if *value == 0 && matches!(dst, AssemblyOperand::Register(_)) && flags_dead(1) => {
+                    (1, vec![AssemblyInstruction::Binary(AssemblyBinaryOperator::Xor, dst.clone(), dst.clone())])
+                }
+                _ => return None,
+            }
+        }
+
+        // Multiplications by powers of two
+        (AssemblyInstruction::Binary(AssemblyBinaryOperator::Mult, AssemblyOperand::Immediate(1), _), _) if flags_dead(1) => (1, vec![]),
+        (AssemblyInstruction::Binary(AssemblyBinaryOperator::Mult, AssemblyOperand::Immediate(factor), dst), _)
+            if factor.count_ones() == 1 && flags_dead(1) =>
+        {
+            let shift = AssemblyOperand::Immediate(factor.trailing_zeros() as i32);
+            (1, vec![AssemblyInstruction::Binary(AssemblyBinaryOperator::Shl, shift, dst.clone())])
+        }
+
+        // Conditional jumps over an unconditional one
+        (AssemblyInstruction::JmpCC(condition, skipped), Some(AssemblyInstruction::Jmp(target))) if falls_through(2, skipped) => {
+            (2, vec![AssemblyInstruction::JmpCC(condition.negated(), target.clone())])
+        }
+        // Jumps to the next instruction
+        (AssemblyInstruction::Jmp(target) | AssemblyInstruction::JmpCC(_, target), _) if falls_through(1, target) => (1, vec![]),
+        _ => return None,
+    };
+    Some(rewritten)
+}
+
+// The value an operation on an immediate just moved to its destination computes
+fn fold(value: i32, dst: &AssemblyOperand, next: &AssemblyInstruction) -> Option<i32> {
+    match next {
+        AssemblyInstruction::Binary(op, AssemblyOperand::Immediate(other), next_dst) if next_dst == dst => Some(match op {
+            AssemblyBinaryOperator::Add => value.wrapping_add(*other),
+            AssemblyBinaryOperator::Sub => value.wrapping_sub(*other),
+            AssemblyBinaryOperator::Mult => value.wrapping_mul(*other),
+            AssemblyBinaryOperator::Xor => value ^ other,
+            AssemblyBinaryOperator::And => value & other,
+            AssemblyBinaryOperator::Shl => value.wrapping_shl(*other as u32),
+            AssemblyBinaryOperator::Sar => value.wrapping_shr(*other as u32),
+            AssemblyBinaryOperator::Shr => (value as u32).wrapping_shr(*other as u32) as i32,
+        }),
+        AssemblyInstruction::Unary(op, next_dst) if next_dst == dst => Some(match op {
+            AssemblyUnaryOperator::Neg => value.wrapping_neg(),
+            AssemblyUnaryOperator::Not => !value,
+        }),
+        _ => None,
+    }
+}
+
+// Whether the flags are overwritten before anything from `index` on reads them. The generated code only
+// reads flags right after setting them, never across labels, jumps or calls.
+fn flags_dead_after(instructions: &[AssemblyInstruction], index: usize) -> bool {
+    for instruction in &instructions[index.min(instructions.len())..] {
+        match instruction {
+            AssemblyInstruction::JmpCC(..) | AssemblyInstruction::SetCC(..) | AssemblyInstruction::Cmov(..) => return false,
+            AssemblyInstruction::Mov(..)
+            | AssemblyInstruction::Lea(..)
+            | AssemblyInstruction::Unary(AssemblyUnaryOperator::Not, _)
+            | AssemblyInstruction::Cdq
+            | AssemblyInstruction::Push(_)
+            | AssemblyInstruction::Pop(_) => continue,
+            _ => return true,
+        }
+    }
+    true
+}
@@ -0,0 +1,453 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use crate::llvm_ir::ValueId;
+use crate::x86_64_generator::{AssemblyFunction, AssemblyInstruction, AssemblyOperand, AssemblyRegister, CALLEE_SAVED_REGISTERS};
+use crate::x86_64_liveness::{self, Location};
+
+// Registers pseudo registers can be assigned, caller-saved ones first so that callee-saved registers,
+// which cost a save and a restore, are only used for values live across calls or when the others run
+// out. r10 and r11 are left for the operand fixups of pseudo registers spilled to the stack.
+const ALLOCATABLE_REGISTERS: [AssemblyRegister; 12] = [
+    AssemblyRegister::AX,
+    AssemblyRegister::CX,
+    AssemblyRegister::DX,
+    AssemblyRegister::SI,
+    AssemblyRegister::DI,
+    AssemblyRegister::R8,
+    AssemblyRegister::R9,
+    AssemblyRegister::BX,
+    AssemblyRegister::R12,
+    AssemblyRegister::R13,
+    AssemblyRegister::R14,
+    AssemblyRegister::R15,
+];
+
+const K: usize = ALLOCATABLE_REGISTERS.len();
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum RegisterAllocator {
+    // Linear scan over live intervals, fast but coarse
+    Linear,
+    // Graph coloring, slower to run but better at keeping values in registers
+    Graph,
+    // Every pseudo register lives on the stack, which is handy when debugging the allocators
+    SpillAll,
+}
+
+impl RegisterAllocator {
+    // Compile time matters more than code quality below -O2
+    pub fn for_optimization_level(level: u8) -> RegisterAllocator {
+        if level >= 2 { RegisterAllocator::Graph } else { RegisterAllocator::Linear }
+    }
+}
+
+// Assigns hardware registers to pseudo registers. Pseudo registers left without one stay in place to be
+// given a stack slot. Returns the callee-saved registers the function now uses, which the prologue must
+// save.
+pub fn allocate(function: AssemblyFunction, allocator: RegisterAllocator) -> (AssemblyFunction, Vec<AssemblyRegister>) {
+    let AssemblyFunction::Function { name, global, mut instructions } = function;
+    let colors = match allocator {
+        RegisterAllocator::Linear => linear_scan(&instructions),
+        // Chaitin-Briggs: moves are coalesced conservatively, then pseudo registers are colored optimistically
+        RegisterAllocator::Graph => {
+            let mut graph = InterferenceGraph::build(&instructions);
+            let renaming = coalesce(&mut graph, &instructions);
+            instructions = rewrite(instructions, |id| Some(find(&renaming, Location::Pseudo(id))));
+            graph.color(&spill_costs(&instructions))
+        }
+        RegisterAllocator::SpillAll => HashMap::new(),
+    };
+    let instructions = rewrite(instructions, |id| colors.get(&id).map(|register| Location::Register(*register)));
+
+    let callee_saved = CALLEE_SAVED_REGISTERS.iter()
+        .filter(|register| colors.values().any(|color| color == *register))
+        .copied()
+        .collect();
+    (AssemblyFunction::Function { name, global, instructions }, callee_saved)
+}
+
+//
+// Interference graph
+//
+
+// Nodes are allocatable hardware registers, numbered first, then pseudo registers. Hardware registers are
+// precolored and all interfere with each other without it being recorded. Nodes merged away by coalescing
+// stay in the adjacency lists of their neighbors and are skipped.
+struct InterferenceGraph {
+    locations: Vec<Location>,
+    indices: HashMap<Location, usize>,
+    // Lower triangle of the adjacency matrix, one bit per pair of nodes
+    matrix: Vec<u64>,
+    adjacency: Vec<Vec<usize>>,
+    degrees: Vec<usize>,
+    merged: Vec<bool>,
+}
+
+fn pair(a: usize, b: usize) -> usize {
+    let (low, high) = (a.min(b), a.max(b));
+    high * (high - 1) / 2 + low
+}
+
+fn is_node(location: Location) -> bool {
+    match location {
+        Location::Register(register) => ALLOCATABLE_REGISTERS.contains(&register),
+        Location::Pseudo(_) => true,
+    }
+}
+
+impl InterferenceGraph {
+    // A location written by an instruction interferes with every location live after it, except the source
+    // of a move, which holds the same value
+    fn build(instructions: &[AssemblyInstruction]) -> Self {
+        let mut graph = InterferenceGraph {
+            locations: Vec::new(),
+            indices: HashMap::new(),
+            matrix: Vec::new(),
+            adjacency: Vec::new(),
+            degrees: Vec::new(),
+            merged: Vec::new(),
+        };
+        for register in ALLOCATABLE_REGISTERS {
+            graph.add_node(Location::Register(register));
+        }
+        for instruction in instructions {
+            let (uses, defs) = x86_64_liveness::uses_and_defs(instruction);
+            for location in uses.iter().chain(&defs).filter(|location| is_node(**location)) {
+                graph.add_node(*location);
+            }
+        }
+
+        let nodes = graph.locations.len();
+        graph.matrix = vec![0; (nodes * (nodes - 1) / 2).div_ceil(64)];
+        let live_after = x86_64_liveness::live_after(instructions);
+        for (instruction, live) in instructions.iter().zip(&live_after) {
+            let (_, defs) = x86_64_liveness::uses_and_defs(instruction);
+            let move_source = match instruction {
+                AssemblyInstruction::Mov(src, _) => Location::of(src),
+                _ => None,
+            };
+            let defs = defs.iter().filter_map(|def| graph.indices.get(def).copied()).collect::<Vec<_>>();
+            let live = live.iter()
+                .filter(|other| Some(**other) != move_source)
+                .filter_map(|other| graph.indices.get(other).copied())
+                .collect::<Vec<_>>();
+            for def in defs {
+                for other in &live {
+                    graph.add_edge(def, *other);
+                }
+            }
+        }
+        // Liveness sets are unordered, neighbors are sorted so that allocation is deterministic
+        for neighbors in &mut graph.adjacency {
+            neighbors.sort_unstable();
+        }
+        graph
+    }
+
+    fn add_node(&mut self, location: Location) {
+        if !self.indices.contains_key(&location) {
+            self.indices.insert(location, self.locations.len());
+            self.locations.push(location);
+            self.adjacency.push(Vec::new());
+            self.degrees.push(0);
+            self.merged.push(false);
+        }
+    }
+
+    fn add_edge(&mut self, a: usize, b: usize) {
+        if a == b || (a < K && b < K) || self.interferes(a, b) {
+            return;
+        }
+        let bit = pair(a, b);
+        self.matrix[bit / 64] |= 1 << (bit % 64);
+        self.adjacency[a].push(b);
+        self.adjacency[b].push(a);
+        self.degrees[a] += 1;
+        self.degrees[b] += 1;
+    }
+
+    fn interferes(&self, a: usize, b: usize) -> bool {
+        if a < K && b < K {
+            a != b
+        } else {
+            let bit = pair(a, b);
+            a != b && self.matrix[bit / 64] & 1 << (bit % 64) != 0
+        }
+    }
+
+    fn neighbors(&self, node: usize) -> impl Iterator<Item = usize> + '_ {
+        self.adjacency[node].iter().copied().filter(|neighbor| !self.merged[*neighbor])
+    }
+
+    // Hardware registers are treated as having infinite degree, they can never be simplified
+    fn degree(&self, node: usize) -> usize {
+        if node < K { usize::MAX } else { self.degrees[node] }
+    }
+
+    fn merge(&mut self, keep: usize, merged: usize) {
+        self.merged[merged] = true;
+        for neighbor in self.adjacency[merged].clone() {
+            if !self.merged[neighbor] {
+                self.degrees[neighbor] -= 1;
+                self.add_edge(keep, neighbor);
+            }
+        }
+    }
+
+    // Briggs: the merged node has fewer than K neighbors of significant degree, so it can still be
+    // simplified. A neighbor of both nodes loses one edge in the merge.
+    fn briggs_test(&self, a: usize, b: usize) -> bool {
+        let significant = self.neighbors(a)
+            .filter(|neighbor| self.degree(*neighbor).saturating_sub(self.interferes(*neighbor, b) as usize) >= K)
+            .count()
+            + self.neighbors(b)
+                .filter(|neighbor| !self.interferes(*neighbor, a) && self.degree(*neighbor) >= K)
+                .count();
+        significant < K
+    }
+
+    // George: every neighbor of the pseudo register either already interferes with the hardware register or
+    // has insignificant degree, so the merge adds no constraint that could make coloring fail
+    fn george_test(&self, register: usize, pseudo: usize) -> bool {
+        self.neighbors(pseudo).all(|neighbor| self.interferes(neighbor, register) || self.degree(neighbor) < K)
+    }
+
+    // Pseudo registers are removed one at a time, those with fewer than K remaining neighbors first since
+    // they are sure to get a register. When none is left, the cheapest one to spill goes next, and only
+    // spills if its neighbors really take every register once colored. Each pseudo register keeps a count
+    // of its remaining neighbors, and moves to the simplify worklist when a removal takes it below K.
+    fn color(&self, spill_costs: &HashMap<ValueId, f64>) -> HashMap<ValueId, AssemblyRegister> {
+        let mut degrees = self.degrees.clone();
+        let mut removed = self.merged.clone();
+        let (mut simplify_worklist, spill_worklist): (Vec<_>, Vec<_>) = (K..self.locations.len())
+            .filter(|node| !removed[*node])
+            .partition(|node| degrees[*node] < K);
+        let mut spill_worklist = spill_worklist.into_iter().collect::<BTreeSet<_>>();
+
+        let mut stack = Vec::new();
+        loop {
+            let next = match simplify_worklist.pop() {
+                Some(node) => node,
+                None => {
+                    let cost = |node: usize| match self.locations[node] {
+                        Location::Pseudo(id) => spill_costs.get(&id).copied().unwrap_or(0.0) / degrees[node] as f64,
+                        Location::Register(_) => f64::INFINITY,
+                    };
+                    let Some(node) = spill_worklist.iter().copied().min_by(|a, b| cost(*a).total_cmp(&cost(*b))) else {
+                        break;
+                    };
+                    spill_worklist.remove(&node);
+                    node
+                }
+            };
+            removed[next] = true;
+            stack.push(next);
+            for neighbor in self.neighbors(next).filter(|neighbor| *neighbor >= K && !removed[*neighbor]) {
+                degrees[neighbor] -= 1;
+                if degrees[neighbor] == K - 1 && spill_worklist.remove(&neighbor) {
+                    simplify_worklist.push(neighbor);
+                }
+            }
+        }
+
+        let mut colors = vec![None; self.locations.len()];
+        for (node, register) in ALLOCATABLE_REGISTERS.iter().enumerate() {
+            colors[node] = Some(*register);
+        }
+        while let Some(node) = stack.pop() {
+            let taken = self.neighbors(node).filter_map(|neighbor| colors[neighbor]).collect::<BTreeSet<_>>();
+            colors[node] = ALLOCATABLE_REGISTERS.iter().find(|register| !taken.contains(register)).copied();
+        }
+        self.locations.iter().zip(colors)
+            .filter_map(|(location, color)| match (location, color) {
+                (Location::Pseudo(id), Some(register)) => Some((*id, register)),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+//
+// Coalescing
+//
+
+// Moves between locations that do not interfere are removed by merging the two in the graph, as long as
+// the merge passes the Briggs or George test. Merges lower the degree of shared neighbors, so moves failing
+// the tests are retried until a round merges nothing. Returns what each merged pseudo register became.
+fn coalesce(graph: &mut InterferenceGraph, instructions: &[AssemblyInstruction]) -> HashMap<ValueId, Location> {
+    let mut moves = instructions.iter()
+        .filter_map(|instruction| match instruction {
+            AssemblyInstruction::Mov(src, dst) => Some((Location::of(src)?, Location::of(dst)?)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let mut renaming = HashMap::new();
+    loop {
+        let mut merged_any = false;
+        // Moves that can never be coalesced, because their locations interfere or are already the same, are
+        // dropped, interference only grows with merges
+        moves.retain(|(src, dst)| {
+            let (src, dst) = (find(&renaming, *src), find(&renaming, *dst));
+            let (Some(src_node), Some(dst_node)) = (graph.indices.get(&src).copied(), graph.indices.get(&dst).copied()) else {
+                return false;
+            };
+            if src_node == dst_node || graph.interferes(src_node, dst_node) {
+                return false;
+            }
+
+            // A hardware register is kept when merging with one
+            let (keep, merged) = match (src, dst) {
+                (_, Location::Pseudo(_)) => ((src, src_node), (dst, dst_node)),
+                (Location::Pseudo(_), _) => ((dst, dst_node), (src, src_node)),
+                _ => return false,
+            };
+            let conservative = match keep.0 {
+                Location::Register(_) => graph.george_test(keep.1, merged.1),
+                Location::Pseudo(_) => graph.briggs_test(keep.1, merged.1),
+            };
+            match (conservative, merged.0) {
+                (true, Location::Pseudo(id)) => {
+                    graph.merge(keep.1, merged.1);
+                    renaming.insert(id, keep.0);
+                    merged_any = true;
+                    false
+                }
+                _ => true,
+            }
+        });
+        if !merged_any {
+            return renaming;
+        }
+    }
+}
+
+fn find(renaming: &HashMap<ValueId, Location>, mut location: Location) -> Location {
+    while let Location::Pseudo(id) = location {
+        match renaming.get(&id) {
+            Some(renamed) => location = *renamed,
+            None => break,
+        }
+    }
+    location
+}
+
+//
+// Linear scan
+//
+
+// Each pseudo register is given the span of instructions from its first to its last appearance in
+// liveness, which covers whole loops it is live around. Spans are visited by start, and get a register
+// free for their whole length, both from other spans and from instructions using it directly. When there is
+// none, the span ending last is spilled.
+fn linear_scan(instructions: &[AssemblyInstruction]) -> HashMap<ValueId, AssemblyRegister> {
+    let live_after = x86_64_liveness::live_after(instructions);
+    let mut intervals = BTreeMap::<ValueId, (usize, usize)>::new();
+    let mut busy = HashMap::<AssemblyRegister, Vec<usize>>::new();
+    for (index, (instruction, live)) in instructions.iter().zip(&live_after).enumerate() {
+        let (uses, defs) = x86_64_liveness::uses_and_defs(instruction);
+        for location in uses.iter().chain(&defs).chain(live) {
+            match location {
+                Location::Pseudo(id) => {
+                    let interval = intervals.entry(*id).or_insert((index, index));
+                    interval.0 = interval.0.min(index);
+                    interval.1 = interval.1.max(index);
+                }
+                Location::Register(register) => busy.entry(*register).or_default().push(index),
+            }
+        }
+    }
+    for positions in busy.values_mut() {
+        positions.sort_unstable();
+        positions.dedup();
+    }
+    let free_of_fixed_uses = |register: &AssemblyRegister, (start, end): (usize, usize)| {
+        let positions = busy.get(register).map_or(&[][..], Vec::as_slice);
+        let first_after_start = positions.partition_point(|position| *position < start);
+        positions.get(first_after_start).is_none_or(|position| *position > end)
+    };
+
+    let mut order = intervals.iter().map(|(id, interval)| (*id, *interval)).collect::<Vec<_>>();
+    order.sort_by_key(|(id, (start, _))| (*start, *id));
+    let mut colors = HashMap::new();
+    let mut active: Vec<(ValueId, (usize, usize), AssemblyRegister)> = Vec::new();
+    for (id, interval) in order {
+        active.retain(|(_, (_, end), _)| *end >= interval.0);
+        let free = ALLOCATABLE_REGISTERS.iter()
+            .find(|register| active.iter().all(|(_, _, taken)| taken != *register) && free_of_fixed_uses(register, interval));
+        if let Some(register) = free {
+            colors.insert(id, *register);
+            active.push((id, interval, *register));
+            continue;
+        }
+
+        let longest = active.iter()
+            .enumerate()
+            .filter(|(_, (_, (_, end), register))| *end > interval.1 && free_of_fixed_uses(register, interval))
+            .max_by_key(|(_, (_, (_, end), _))| *end)
+            .map(|(position, _)| position);
+        if let Some(position) = longest {
+            let (spilled, _, register) = active.remove(position);
+            colors.remove(&spilled);
+            colors.insert(id, register);
+            active.push((id, interval, register));
+        }
+    }
+    colors
+}
+
+//
+// Spill costs and rewriting
+//
+
+// Uses and definitions of each pseudo register, those in loops weighing more. Loops are found as the
+// instructions between a label and a jump back to it.
+fn spill_costs(instructions: &[AssemblyInstruction]) -> HashMap<ValueId, f64> {
+    let labels = instructions.iter()
+        .enumerate()
+        .filter_map(|(index, instruction)| match instruction {
+            AssemblyInstruction::Label(label) => Some((label.as_str(), index)),
+            _ => None,
+        })
+        .collect::<HashMap<_, _>>();
+    let mut depth = vec![0; instructions.len()];
+    for (index, instruction) in instructions.iter().enumerate() {
+        if let AssemblyInstruction::Jmp(label) | AssemblyInstruction::JmpCC(_, label) = instruction {
+            if let Some(&target) = labels.get(label.as_str()).filter(|target| **target < index) {
+                for nesting in &mut depth[target..=index] {
+                    *nesting += 1;
+                }
+            }
+        }
+    }
+
+    let mut costs = HashMap::new();
+    for (instruction, nesting) in instructions.iter().zip(depth) {
+        let (uses, defs) = x86_64_liveness::uses_and_defs(instruction);
+        for location in uses.into_iter().chain(defs) {
+            if let Location::Pseudo(id) = location {
+                *costs.entry(id).or_insert(0.0) += 10f64.powi(nesting.min(8));
+            }
+        }
+    }
+    costs
+}
+
+// Replaces pseudo registers with the location they were given, if any, dropping the moves that end up
+// copying a location to itself
+fn rewrite(instructions: Vec<AssemblyInstruction>, location: impl Fn(ValueId) -> Option<Location>) -> Vec<AssemblyInstruction> {
+    instructions.into_iter()
+        .filter_map(|mut instruction| {
+            for operand in instruction.operands_mut() {
+                if let AssemblyOperand::PseudoRegister(id) = operand {
+                    if let Some(location) = location(*id) {
+                        *operand = location.operand();
+                    }
+                }
+            }
+            match &instruction {
+                AssemblyInstruction::Mov(src, dst) if Location::of(src).is_some() && Location::of(src) == Location::of(dst) => None,
+                _ => Some(instruction),
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,220 @@
+use std::collections::HashMap;
+
+use crate::x86_64_generator::{AssemblyBinaryOperator, AssemblyFunction, AssemblyInstruction, AssemblyOperand, AssemblyUnaryOperator};
+use crate::x86_64_liveness::{self, Location};
+
+// What an instruction waits on, which sets how long its result takes
+#[derive(Debug, Clone, Copy)]
+enum Unit {
+    Alu,
+    Load,
+    Multiply,
+    Divide,
+}
+
+// Cycles before the result of each unit can be used, roughly those of recent x86-64 cores
+const LATENCIES: [u32; 4] = [1, 4, 3, 26];
+
+// Values an instruction reads or writes, each of which orders the instructions accessing it. Memory
+// operands name a stack slot or a global, which never overlap.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Resource {
+    Location(Location),
+    Memory(AssemblyOperand),
+}
+
+// Reorders the instructions of each basic block so that those waiting on a slow result come as late as
+// their dependencies allow, keeping the processor busy with independent work in between. Labels, jumps,
+// calls and stack adjustments stay where they are and split blocks further.
+pub fn schedule(function: AssemblyFunction) -> AssemblyFunction {
+    let AssemblyFunction::Function { name, global, instructions } = function;
+    let mut scheduled = Vec::with_capacity(instructions.len());
+    let mut region = Vec::new();
+    for instruction in instructions {
+        if is_barrier(&instruction) {
+            let flags_live_out = reads_flags(&instruction);
+            scheduled.extend(schedule_region(std::mem::take(&mut region), flags_live_out));
+            scheduled.push(instruction);
+        } else {
+            region.push(instruction);
+        }
+    }
+    scheduled.extend(schedule_region(region, false));
+    AssemblyFunction::Function { name, global, instructions: scheduled }
+}
+
+fn is_barrier(instruction: &AssemblyInstruction) -> bool {
+    matches!(
+        instruction,
+        AssemblyInstruction::Label(_)
+            | AssemblyInstruction::Jmp(_)
+            | AssemblyInstruction::JmpCC(..)
+            | AssemblyInstruction::Ret
+            | AssemblyInstruction::Call(..)
+            | AssemblyInstruction::Push(_)
+            | AssemblyInstruction::Pop(_)
+            | AssemblyInstruction::AllocateStack(_)
+            | AssemblyInstruction::DeallocateStack(_)
+    )
+}
+
+fn reads_flags(instruction: &AssemblyInstruction) -> bool {
+    matches!(instruction, AssemblyInstruction::JmpCC(..) | AssemblyInstruction::SetCC(..) | AssemblyInstruction::Cmov(..))
+}
+
+fn writes_flags(instruction: &AssemblyInstruction) -> bool {
+    match instruction {
+        AssemblyInstruction::Binary(..)
+        | AssemblyInstruction::Cmp(..)
+        | AssemblyInstruction::Test(..)
+        | AssemblyInstruction::Imul(..)
+        | AssemblyInstruction::Idiv(_)
+        | AssemblyInstruction::Div(_)
+        | AssemblyInstruction::WideImul(_)
+        | AssemblyInstruction::WideMul(_) => true,
+        AssemblyInstruction::Unary(op, _) => matches!(op, AssemblyUnaryOperator::Neg),
+        _ => false,
+    }
+}
+
+fn unit(instruction: &AssemblyInstruction) -> Unit {
+    let (reads, _) = x86_64_liveness::operand_accesses(instruction);
+    match instruction {
+        AssemblyInstruction::Idiv(_) | AssemblyInstruction::Div(_) => Unit::Divide,
+        AssemblyInstruction::Imul(..)
+        | AssemblyInstruction::WideImul(_)
+        | AssemblyInstruction::WideMul(_)
+        | AssemblyInstruction::Binary(AssemblyBinaryOperator::Mult, ..) => Unit::Multiply,
+        _ if reads.iter().any(|operand| operand.is_memory()) => Unit::Load,
+        _ => Unit::Alu,
+    }
+}
+
+fn latency(instruction: &AssemblyInstruction) -> u32 {
+    LATENCIES[unit(instruction) as usize]
+}
+
+fn resources(instruction: &AssemblyInstruction) -> (Vec<Resource>, Vec<Resource>) {
+    let (uses, defs) = x86_64_liveness::uses_and_defs(instruction);
+    let (reads, writes) = x86_64_liveness::operand_accesses(instruction);
+    let memory = |operands: Vec<&AssemblyOperand>| {
+        operands.into_iter().filter(|operand| operand.is_memory()).map(|operand| Resource::Memory(operand.clone())).collect::<Vec<_>>()
+    };
+    let mut read = uses.into_iter().map(Resource::Location).collect::<Vec<_>>();
+    read.extend(memory(reads));
+    let mut written = defs.into_iter().map(Resource::Location).collect::<Vec<_>>();
+    written.extend(memory(writes));
+    (read, written)
+}
+
+// Edges of the dependency graph, from each instruction to those that must come after it and the
+// cycles they have to wait
+struct DependencyGraph {
+    successors: Vec<Vec<(usize, u32)>>,
+    predecessor_counts: Vec<usize>,
+}
+
+impl DependencyGraph {
+    fn add_edge(&mut self, from: usize, to: usize, delay: u32) {
+        self.successors[from].push((to, delay));
+        self.predecessor_counts[to] += 1;
+    }
+
+    // A read waits for the latest write before it, a write for the reads and the write before it. Only
+    // true dependencies carry the latency of their producer.
+    fn build(region: &[AssemblyInstruction], flags_live_out: bool) -> DependencyGraph {
+        let mut graph = DependencyGraph { successors: vec![Vec::new(); region.len()], predecessor_counts: vec![0; region.len()] };
+        let mut last_write: HashMap<Resource, usize> = HashMap::new();
+        let mut reads_since_write: HashMap<Resource, Vec<usize>> = HashMap::new();
+        for (index, instruction) in region.iter().enumerate() {
+            let (read, written) = resources(instruction);
+            for resource in read {
+                if let Some(writer) = last_write.get(&resource) {
+                    graph.add_edge(*writer, index, latency(&region[*writer]));
+                }
+                reads_since_write.entry(resource).or_default().push(index);
+            }
+            for resource in written {
+                for reader in reads_since_write.remove(&resource).unwrap_or_default() {
+                    if reader != index {
+                        graph.add_edge(reader, index, 0);
+                    }
+                }
+                if let Some(writer) = last_write.insert(resource, index) {
+                    graph.add_edge(writer, index, 0);
+                }
+            }
+        }
+        graph.add_flags_edges(region, flags_live_out);
+        graph
+    }
+
+    // Nearly every instruction writes the flags, so they are only ordered where they are read: no other
+    // writer may come between a reader and the write it reads
+    fn add_flags_edges(&mut self, region: &[AssemblyInstruction], flags_live_out: bool) {
+        let writers = (0..region.len()).filter(|index| writes_flags(&region[*index])).collect::<Vec<_>>();
+        let readers = (0..region.len())
+            .filter(|index| reads_flags(&region[*index]))
+            .map(Some)
+            .chain(flags_live_out.then_some(None));
+        for reader in readers {
+            let end = reader.unwrap_or(region.len());
+            let producer = writers.iter().rev().copied().find(|writer| *writer < end);
+            if let (Some(producer), Some(reader)) = (producer, reader) {
+                self.add_edge(producer, reader, 1);
+            }
+            for writer in writers.iter().copied() {
+                if let Some(producer) = producer.filter(|producer| writer < *producer) {
+                    self.add_edge(writer, producer, 0);
+                } else if let Some(reader) = reader.filter(|reader| writer > *reader) {
+                    self.add_edge(reader, writer, 0);
+                }
+            }
+        }
+    }
+
+    // Length of the longest path from each instruction to the end of the block, counting latencies
+    fn critical_paths(&self, region: &[AssemblyInstruction]) -> Vec<u32> {
+        let mut lengths = vec![0; region.len()];
+        // Edges always go forward in the original order
+        for index in (0..region.len()).rev() {
+            let longest = self.successors[index].iter().map(|(successor, delay)| delay + lengths[*successor]).max().unwrap_or(0);
+            lengths[index] = longest.max(latency(&region[index]));
+        }
+        lengths
+    }
+}
+
+// List scheduling issuing one instruction per cycle: among the instructions whose dependencies are
+// done, the one on the longest path to the end of the block goes first. When none is ready yet, the
+// first one to become ready is issued, the processor stalling in between.
+fn schedule_region(region: Vec<AssemblyInstruction>, flags_live_out: bool) -> Vec<AssemblyInstruction> {
+    if region.len() < 2 {
+        return region;
+    }
+    let mut graph = DependencyGraph::build(&region, flags_live_out);
+    let priorities = graph.critical_paths(&region);
+    let mut earliest = vec![0; region.len()];
+    let mut candidates = (0..region.len()).filter(|index| graph.predecessor_counts[*index] == 0).collect::<Vec<_>>();
+    let mut order = Vec::with_capacity(region.len());
+    let mut cycle = 0;
+    while !candidates.is_empty() {
+        let ready = candidates.iter().copied().filter(|candidate| earliest[*candidate] <= cycle);
+        let chosen = ready
+            .max_by_key(|candidate| (priorities[*candidate], std::cmp::Reverse(*candidate)))
+            .unwrap_or_else(|| *candidates.iter().min_by_key(|candidate| (earliest[**candidate], **candidate)).unwrap());
+        cycle = cycle.max(earliest[chosen]) + 1;
+        candidates.retain(|candidate| *candidate != chosen);
+        order.push(chosen);
+        for (successor, delay) in std::mem::take(&mut graph.successors[chosen]) {
+            earliest[successor] = earliest[successor].max(cycle - 1 + delay);
+            graph.predecessor_counts[successor] -= 1;
+            if graph.predecessor_counts[successor] == 0 {
+                candidates.push(successor);
+            }
+        }
+    }
+
+    let mut slots = region.into_iter().map(Some).collect::<Vec<_>>();
+    order.into_iter().map(|index| slots[index].take().unwrap()).collect()
+}
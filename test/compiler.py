@@ -1,5 +1,7 @@
 import subprocess
 import os
+import json
+import re
 from typing import List, Tuple, Optional
 from config import BRITTEN_PATH
 from utils import run_subprocess, get_executable_return_code
@@ -40,7 +42,7 @@ def run_llvm_ir_generator(file_path: str) -> Optional[int]:
         print(f"No reference executable found: {file_path}")
         return None
         
-    success, error = run_subprocess([BRITTEN_PATH, "--llvm", file_path])
+    success, error = run_subprocess([BRITTEN_PATH, "--llvm", file_path, "-o", britten_output])
     if not success:
         print(error)
         return 1
@@ -78,29 +80,27 @@ def run_full_compiler(file_path: str) -> Optional[int]:
         
     britten_exe = file_path.replace('.i', '.britten')
     clang_exe = file_path.replace('.i', '.clang')
-    assembly_file = file_path.replace('.i', '.s')
     
+    # Britten drives the system assembler and linker itself
+    return compare_with_clang(file_path, [BRITTEN_PATH, file_path, '-o', britten_exe], britten_exe, clang_exe)
+
+def compare_with_clang(file_path: str, command: List[str], britten_exe: str, clang_exe: str,
+                       runner: List[str] = []) -> Optional[int]:
     if not os.path.exists(clang_exe):
         print(f"No reference executable found: {file_path}")
         return None
         
-    success, error = run_subprocess([BRITTEN_PATH, file_path])
+    success, error = run_subprocess(command)
     if not success:
         print(error)
         return 1
 
-    if not os.path.exists(assembly_file):
-        print(f"No assembly file generated for {file_path}")
-        return 1
-    
-    # Assemble and link britten-generated x86_64 asm using clang
-    success, error = run_subprocess(['clang', assembly_file, '-o', britten_exe])
-    if not success:
-        print(error)
+    if not os.path.exists(britten_exe):
+        print(f"No executable generated for {file_path}")
         return 1
     
     clang_code = get_executable_return_code(clang_exe)
-    britten_code = get_executable_return_code(britten_exe)
+    britten_code = get_executable_return_code(britten_exe, runner)
     
     if clang_code is None or britten_code is None:
         return None
@@ -109,4 +109,188 @@ def run_full_compiler(file_path: str) -> Optional[int]:
         print(f"Compilation error for {file_path}: Britten-compiled exe returned {britten_code}, while clang-compiled exe returned {clang_code}")
         return 1
     
-    return 0
\ No newline at end of file
+    return 0
+
+# Textual IR samples need no preprocessing, clang compiles the valid ones for reference
+def prepare_llvm_folder(folder_name: str) -> List[str]:
+    folder_path = os.path.join(os.path.dirname(os.path.abspath(__file__)), folder_name)
+    ll_files = [os.path.join(folder_path, f) for f in os.listdir(folder_path) if f.endswith('.ll')]
+    if "invalid" not in folder_name:
+        for ll_file in ll_files:
+            compile_with_clang(ll_file, ll_file.replace('.ll', '.clang'))
+    return ll_files
+
+# Optimization levels the valid IR samples are compiled at
+LLVM_INPUT_LEVELS = ['-O0', '-O2']
+
+def run_llvm_input(file_path: str) -> Optional[int]:
+    # Invalid IR must be rejected by the parser or the verifier
+    if "invalid" in file_path:
+        success, error = run_subprocess([BRITTEN_PATH, "--from-llvm", "--parse", file_path])
+        return 1 if not success else 0
+
+    clang_exe = file_path.replace('.ll', '.clang')
+    for level in LLVM_INPUT_LEVELS:
+        britten_exe = file_path.replace('.ll', f'.{level[1:]}.britten')
+        command = [BRITTEN_PATH, "--from-llvm", level, file_path, '-o', britten_exe]
+        result = compare_with_clang(f"{file_path} at {level}", command, britten_exe, clang_exe)
+        if result != 0:
+            return result
+    return 0
+
+# Every optimization level is compiled with every register allocator
+OPTIMIZATION_MATRIX = [(level, allocator) for level in ['-O1', '-O2'] for allocator in ['graph', 'linear', 'spill-all']]
+
+def run_optimized_compiler(file_path: str) -> Optional[int]:
+    if "invalid" in file_path:
+        return 1
+
+    base, extension = os.path.splitext(file_path)
+    input_flags = ["--from-llvm"] if extension == '.ll' else []
+    clang_exe = f"{base}.clang"
+    for level, allocator in OPTIMIZATION_MATRIX:
+        britten_exe = f"{base}.{level[1:]}.{allocator}.britten"
+        command = [BRITTEN_PATH, *input_flags, level, f"--regalloc={allocator}", file_path, '-o', britten_exe]
+        result = compare_with_clang(f"{file_path} at {level} with --regalloc={allocator}", command, britten_exe, clang_exe)
+        if result != 0:
+            return result
+    return 0
+
+# Executables for another architecture run under qemu's user mode, against that target's libraries
+def cross_runner(target: str) -> List[str]:
+    architecture = target.split('-')[0]
+    return [f"qemu-{architecture}", "-L", f"/usr/{architecture}-linux-gnu"]
+
+def run_cross_compiler(file_path: str, target: str) -> Optional[int]:
+    if "invalid" in file_path:
+        return 1
+
+    base, extension = os.path.splitext(file_path)
+    input_flags = ["--from-llvm"] if extension == '.ll' else []
+    britten_exe = f"{base}.{target.split('-')[0]}.britten"
+    command = [BRITTEN_PATH, *input_flags, f"--target={target}", file_path, '-o', britten_exe]
+    return compare_with_clang(f"{file_path} for {target}", command, britten_exe, f"{base}.clang", cross_runner(target))
+
+# Every diagnostic names the input, a location, its severity and a code. Only the verifier has no location to
+# report, since it checks IR after parsing.
+DIAGNOSTIC_FIELDS = {'file', 'range', 'severity', 'code', 'message'}
+DIAGNOSTIC_CODE = re.compile(r'^E\d{4}$')
+UNLOCATED_CODES = {'E3002'}
+
+def check_diagnostic(file_path: str, diagnostic: dict) -> Optional[str]:
+    if set(diagnostic) != DIAGNOSTIC_FIELDS:
+        return f"unexpected fields {sorted(diagnostic)}"
+    if diagnostic['file'] != file_path:
+        return f"file is {diagnostic['file']}"
+    if diagnostic['severity'] not in ('error', 'warning'):
+        return f"severity is {diagnostic['severity']}"
+    if not DIAGNOSTIC_CODE.match(diagnostic['code']):
+        return f"code is {diagnostic['code']}"
+    diagnostic_range = diagnostic['range']
+    if diagnostic_range is None:
+        return None if diagnostic['code'] in UNLOCATED_CODES else "range is missing"
+    positions = [diagnostic_range['start']['line'], diagnostic_range['start']['column'],
+                 diagnostic_range['end']['line'], diagnostic_range['end']['column']]
+    if not all(isinstance(position, int) and position >= 1 for position in positions):
+        return f"range is {diagnostic_range}"
+    return None
+
+# SARIF results are converted back to JSON records, so that both formats are held to the same checks
+def sarif_diagnostics(log: dict) -> List[dict]:
+    if log.get('version') != '2.1.0' or 'sarif-2.1.0' not in log.get('$schema', ''):
+        raise ValueError("not a SARIF 2.1.0 log")
+    runs = log['runs']
+    if len(runs) != 1 or runs[0]['tool']['driver']['name'] != 'britten':
+        raise ValueError("expected a single run of britten")
+    diagnostics = []
+    for result in runs[0]['results']:
+        location = result.get('locations', [{}])[0].get('physicalLocation', {})
+        region = location.get('region')
+        diagnostic_range = region and {
+            'start': {'line': region['startLine'], 'column': region['startColumn']},
+            'end': {'line': region['endLine'], 'column': region['endColumn']},
+        }
+        diagnostics.append({
+            'file': location.get('artifactLocation', {}).get('uri'),
+            'range': diagnostic_range,
+            'severity': result['level'],
+            'code': result['ruleId'],
+            'message': result['message']['text'],
+        })
+    return diagnostics
+
+def run_diagnostics(file_path: str) -> Optional[int]:
+    input_flags = ["--from-llvm"] if file_path.endswith('.ll') else []
+    codes = {}
+    for diagnostics_format in ['json', 'sarif']:
+        command = [BRITTEN_PATH, *input_flags, "--parse", f"--diagnostics-format={diagnostics_format}", file_path]
+        result = subprocess.run(command, capture_output=True, text=True)
+        if result.returncode == 0:
+            print(f"{file_path} was accepted with --diagnostics-format={diagnostics_format}")
+            return 1
+        try:
+            if diagnostics_format == 'json':
+                diagnostics = [json.loads(line) for line in result.stderr.splitlines() if line]
+            else:
+                diagnostics = sarif_diagnostics(json.loads(result.stderr))
+        except (ValueError, KeyError, IndexError, TypeError) as e:
+            print(f"Malformed {diagnostics_format} diagnostics for {file_path}: {e}\n{result.stderr}")
+            return 1
+        if not any(diagnostic['severity'] == 'error' for diagnostic in diagnostics):
+            print(f"No error reported in {diagnostics_format} for {file_path}:\n{result.stderr}")
+            return 1
+        for diagnostic in diagnostics:
+            problem = check_diagnostic(file_path, diagnostic)
+            if problem:
+                print(f"Invalid {diagnostics_format} diagnostic for {file_path}: {problem}\n{result.stderr}")
+                return 1
+        codes[diagnostics_format] = [diagnostic['code'] for diagnostic in diagnostics]
+    if codes['json'] != codes['sarif']:
+        print(f"JSON and SARIF report different diagnostics for {file_path}: {codes['json']} and {codes['sarif']}")
+        return 1
+    return 0
+
+# The built-in linker links no libraries, so IR samples declaring functions must be rejected for the first of
+# them instead. Any sample linked together with itself must be rejected for defining its functions twice.
+DECLARATION = re.compile(r'^declare [^@]*@([\w.]+)\(', re.MULTILINE)
+
+def expect_link_error(file_path: str, command: List[str], expected: str) -> Optional[int]:
+    result = subprocess.run(command, capture_output=True, text=True)
+    if result.returncode == 0 or expected not in result.stderr:
+        print(f"Built-in linker did not report \"{expected}\" for {file_path}:\n{result.stderr}")
+        return 1
+    return 0
+
+def run_builtin_linker(file_path: str) -> Optional[int]:
+    base, extension = os.path.splitext(file_path)
+    input_flags = ["--from-llvm"] if extension == '.ll' else []
+    britten_exe = f"{base}.builtin.britten"
+
+    command = [BRITTEN_PATH, *input_flags, "--linker=builtin", file_path, file_path, '-o', britten_exe]
+    if expect_link_error(file_path, command, "Multiple definition of '") != 0:
+        return 1
+
+    command = [BRITTEN_PATH, *input_flags, "--linker=builtin", file_path, '-o', britten_exe]
+    declared = DECLARATION.findall(open(file_path).read()) if extension == '.ll' else []
+    if declared:
+        return expect_link_error(file_path, command, f"Undefined reference to '{declared[0]}'")
+    return compare_with_clang(f"{file_path} with --linker=builtin", command, britten_exe, f"{base}.clang")
+
+# Intel syntax output goes through the system assembler, which must accept it and build the same program
+INTEL_SYNTAX_LEVELS = ['-O0', '-O2']
+
+def run_intel_syntax(file_path: str) -> Optional[int]:
+    base, extension = os.path.splitext(file_path)
+    input_flags = ["--from-llvm"] if extension == '.ll' else []
+    for level in INTEL_SYNTAX_LEVELS:
+        assembly = f"{base}.{level[1:]}.intel.s"
+        britten_exe = f"{base}.{level[1:]}.intel.britten"
+        success, error = run_subprocess([BRITTEN_PATH, *input_flags, level, "-masm=intel", "-S", file_path, '-o', assembly])
+        if not success:
+            print(error)
+            return 1
+        command = [BRITTEN_PATH, assembly, '-o', britten_exe]
+        result = compare_with_clang(f"{file_path} at {level} with -masm=intel", command, britten_exe, f"{base}.clang")
+        if result != 0:
+            return result
+    return 0
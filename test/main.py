@@ -1,10 +1,13 @@
 from concurrent.futures import ThreadPoolExecutor
 import multiprocessing
 import argparse
+from functools import partial
 import sys
 from typing import List, Tuple, Callable, Optional
-from preprocessor import preprocess_folder
-from compiler import (run_lexer, run_parser, run_llvm_ir_generator, run_x86_64_generator, run_full_compiler)
+from preprocessor import preprocess_folder, run_britten_preprocessor, run_system_headers
+from compiler import (run_lexer, run_parser, run_llvm_ir_generator, run_x86_64_generator, run_full_compiler,
+                      prepare_llvm_folder, run_llvm_input, run_optimized_compiler, run_cross_compiler, run_diagnostics,
+                      run_builtin_linker, run_intel_syntax)
 from test_runner import run_tests, TestResult
 from reporter import print_results, print_summary
 from utils import cleanup_samples_directory
@@ -17,32 +20,56 @@ def run_stage(stage_info: Tuple[int, str, Callable, List[str], List[str]]) -> Tu
     stage_results = run_tests(test_func, invalid_files, valid_files)
     return (stage_order, stage_name, stage_results)
 
-def parse_args() -> Optional[str]:
+def parse_args() -> argparse.Namespace:
     parser = argparse.ArgumentParser(description='Run compiler tests')
-    parser.add_argument('--stage', type=str, choices=['lexer', 'parser', 'llvm', 'asm', 'full'], help='Run only a specific test stage')
-    args = parser.parse_args()
-    return args.stage
+    parser.add_argument('--stage', type=str, choices=['preprocessor', 'lexer', 'parser', 'llvm', 'asm', 'full', 'llvm-input', 'optimized', 'headers', 'diagnostics', 'builtin-linker', 'intel'], help='Run only a specific test stage')
+    parser.add_argument('--target', type=str, choices=['aarch64-linux-gnu', 'riscv64-linux-gnu'], help='Only compile the valid samples for another architecture and run them under qemu')
+    return parser.parse_args()
 
 def get_test_stages(valid_preprocessed: List[str], 
                    invalid_lex_preprocessed: List[str],
                    invalid_parse_preprocessed: List[str],
-                   selected_stage: Optional[str] = None) -> List[Tuple]:
+                   valid_llvm: List[str],
+                   invalid_llvm: List[str],
+                   system_headers: List[str],
+                   selected_stage: Optional[str] = None,
+                   target: Optional[str] = None) -> List[Tuple]:
+    if target:
+        return [(12, f"Cross Compilation ({target})", partial(run_cross_compiler, target=target), [],
+                 valid_preprocessed + valid_llvm)]
+
     all_stages = [
+        (0, "Preprocessor", run_britten_preprocessor, [],
+         invalid_lex_preprocessed + invalid_parse_preprocessed + valid_preprocessed),
         (1, "Lexer", run_lexer, invalid_lex_preprocessed, 
          invalid_parse_preprocessed + valid_preprocessed),
         (2, "Parser", run_parser, invalid_parse_preprocessed, valid_preprocessed),
         (3, "LLVM IR Generation", run_llvm_ir_generator, [], valid_preprocessed),
         (4, "Assembly Generation", run_x86_64_generator, [], valid_preprocessed),
         (5, "Full Compilation", run_full_compiler, [], valid_preprocessed),
+        (6, "LLVM IR Input", run_llvm_input, invalid_llvm, valid_llvm),
+        (7, "Optimized Compilation", run_optimized_compiler, [], valid_preprocessed + valid_llvm),
+        (8, "System Headers", run_system_headers, [], system_headers),
+        # Rejecting the invalid samples with well-formed diagnostics is what passes here
+        (9, "Diagnostics", run_diagnostics, [], invalid_lex_preprocessed + invalid_parse_preprocessed + invalid_llvm),
+        (10, "Built-in Linker", run_builtin_linker, [], valid_preprocessed + valid_llvm),
+        (11, "Intel Syntax", run_intel_syntax, [], valid_preprocessed + valid_llvm),
     ]
     
     if selected_stage:
         stage_map = {
+            'preprocessor': 0,
             'lexer': 1,
             'parser': 2,
             'llvm': 3,
             'asm': 4,
-            'full': 5
+            'full': 5,
+            'llvm-input': 6,
+            'optimized': 7,
+            'headers': 8,
+            'diagnostics': 9,
+            'builtin-linker': 10,
+            'intel': 11
         }
         stage_num = stage_map[selected_stage]
         return [stage for stage in all_stages if stage[0] == stage_num]
@@ -50,11 +77,12 @@ def get_test_stages(valid_preprocessed: List[str],
     return all_stages
 
 def main():
-    selected_stage = parse_args()
+    args = parse_args()
+    selected_stage = args.stage
     
     num_cores = multiprocessing.cpu_count()
-    num_threads = 1 if selected_stage else num_cores * 2
-    thread_info = "1 thread" if selected_stage else f"{num_threads} threads ({num_cores} CPU cores)"
+    num_threads = 1 if selected_stage or args.target else num_cores * 2
+    thread_info = "1 thread" if selected_stage or args.target else f"{num_threads} threads ({num_cores} CPU cores)"
     print(f"Running tests using {thread_info}")
     print("-" * 80)
 
@@ -62,18 +90,28 @@ def main():
         preprocess_futures = [
             executor.submit(preprocess_folder, "samples/valid"),
             executor.submit(preprocess_folder, "samples/invalid_lex"),
-            executor.submit(preprocess_folder, "samples/invalid_parse")
+            executor.submit(preprocess_folder, "samples/invalid_parse"),
+            executor.submit(prepare_llvm_folder, "samples/valid_llvm"),
+            executor.submit(prepare_llvm_folder, "samples/invalid_llvm"),
+            executor.submit(preprocess_folder, "samples/valid_headers")
         ]
         
     valid_preprocessed = preprocess_futures[0].result()
     invalid_lex_preprocessed = preprocess_futures[1].result()
     invalid_parse_preprocessed = preprocess_futures[2].result()
+    valid_llvm = preprocess_futures[3].result()
+    invalid_llvm = preprocess_futures[4].result()
+    system_headers = preprocess_futures[5].result()
 
     test_stages = get_test_stages(
         valid_preprocessed,
         invalid_lex_preprocessed,
         invalid_parse_preprocessed,
-        selected_stage
+        valid_llvm,
+        invalid_llvm,
+        system_headers,
+        selected_stage,
+        args.target
     )
 
     stage_results = []
@@ -93,7 +131,7 @@ def main():
     with print_lock:
         print("\nCleaning up samples directory...")
         files_removed = cleanup_samples_directory()
-        print(f"Cleanup completed. Removed {files_removed} non-'.c' files")
+        print(f"Cleanup completed. Removed {files_removed} generated files")
         print_summary(sorted(stage_results, key=lambda x: x[0]))
         
         if not all(passed for _, _, passed in stage_results):
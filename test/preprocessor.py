@@ -1,7 +1,8 @@
 import subprocess
 import os
 from typing import List, Optional
-from compiler import compile_with_clang
+from compiler import compile_with_clang, compare_with_clang
+from config import BRITTEN_PATH
 from utils import run_subprocess
 from concurrent.futures import ThreadPoolExecutor
 
@@ -20,6 +21,43 @@ def run_preprocessor(input_file: str, is_valid: bool) -> Optional[str]:
             
     return output_file
 
+# Whitespace is not significant between tokens, so both outputs are compared with it collapsed
+def normalize_preprocessed(text: str) -> str:
+    return ' '.join(text.split())
+
+# Compares britten's preprocessor against clang's output, which later stages use as their input
+def run_britten_preprocessor(file_path: str) -> Optional[int]:
+    input_file = file_path.replace('.i', '.c')
+    britten_output = file_path.replace('.i', '.britten.i')
+
+    success, error = run_subprocess([BRITTEN_PATH, '-E', '-P', input_file, '-o', britten_output])
+    if not success:
+        print(error)
+        return 1
+
+    with open(file_path) as clang_file, open(britten_output) as britten_file:
+        clang_text = normalize_preprocessed(clang_file.read())
+        britten_text = normalize_preprocessed(britten_file.read())
+    if clang_text != britten_text:
+        print(f"Preprocessor output differs for {input_file}:\n  clang:   {clang_text}\n  britten: {britten_text}")
+        return 1
+    return 0
+
+# Sources including system headers preprocess differently with each compiler's own headers, so britten's
+# output is compiled with clang and must behave like clang's own build instead
+def run_system_headers(file_path: str) -> Optional[int]:
+    input_file = file_path.replace('.i', '.c')
+    britten_output = file_path.replace('.i', '.britten.i')
+    britten_exe = file_path.replace('.i', '.britten')
+
+    success, error = run_subprocess([BRITTEN_PATH, '-E', '-P', input_file, '-o', britten_output])
+    if not success:
+        print(error)
+        return 1
+
+    command = ['clang', britten_output, '-o', britten_exe]
+    return compare_with_clang(input_file, command, britten_exe, file_path.replace('.i', '.clang'))
+
 def preprocess_folder(folder_name: str) -> List[str]:
     folder_path = os.path.join(os.path.dirname(os.path.abspath(__file__)), folder_name)
     c_files = [f for f in os.listdir(folder_path) if f.endswith('.c')]
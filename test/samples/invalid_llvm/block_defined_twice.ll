@@ -0,0 +1,10 @@
+define i32 @main() {
+entry:
+  br label %next
+next:
+  br label %next2
+next:
+  ret i32 0
+next2:
+  ret i32 1
+}
@@ -0,0 +1,9 @@
+define i32 @f(i32 %a, i32 %b) {
+  %r = add i32 %a, %b
+  ret i32 %r
+}
+
+define i32 @main() {
+  %r = call i32 @f(i32 1)
+  ret i32 %r
+}
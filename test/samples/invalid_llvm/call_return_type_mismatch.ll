@@ -0,0 +1,8 @@
+define i1 @f() {
+  ret i1 true
+}
+
+define i32 @main() {
+  %r = call i32 @f()
+  ret i32 %r
+}
@@ -0,0 +1,4 @@
+define i32 @main() {
+  %r = call i32 @missing(i32 1)
+  ret i32 %r
+}
@@ -0,0 +1,4 @@
+define i32 @main() {
+  %1 = add i32 1, 5000000000
+  ret i32 %1
+}
@@ -0,0 +1,5 @@
+define i32 @main() {
+entry:
+  %i = add i32 1, 2
+  br label %entry
+}
@@ -0,0 +1,12 @@
+define i32 @f() {
+  ret i32 1
+}
+
+define i32 @f() {
+  ret i32 2
+}
+
+define i32 @main() {
+  %r = call i32 @f()
+  ret i32 %r
+}
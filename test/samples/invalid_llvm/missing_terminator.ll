@@ -0,0 +1,6 @@
+define i32 @main() {
+entry:
+  %x = add i32 1, 2
+next:
+  ret i32 %x
+}
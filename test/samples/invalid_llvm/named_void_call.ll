@@ -0,0 +1,8 @@
+define void @nothing() {
+  ret void
+}
+
+define i32 @main() {
+  %r = call void @nothing()
+  ret i32 0
+}
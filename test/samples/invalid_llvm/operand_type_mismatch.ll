@@ -0,0 +1,5 @@
+define i32 @main() {
+  %c = icmp eq i32 1, 2
+  %x = add i32 %c, 1
+  ret i32 %x
+}
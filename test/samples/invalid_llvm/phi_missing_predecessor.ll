@@ -0,0 +1,12 @@
+define i32 @main() {
+entry:
+  %c = icmp eq i32 1, 2
+  br i1 %c, label %a, label %b
+a:
+  br label %join
+b:
+  br label %join
+join:
+  %p = phi i32 [ 1, %a ]
+  ret i32 %p
+}
@@ -0,0 +1,6 @@
+@g = global i32 0
+
+define i32 @main() {
+  %p = add ptr @g, 4
+  ret i32 0
+}
@@ -0,0 +1,5 @@
+@p = global ptr 0
+
+define i32 @main() {
+  ret i32 0
+}
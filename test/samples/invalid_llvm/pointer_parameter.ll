@@ -0,0 +1,8 @@
+define i32 @load(ptr %p) {
+  %v = load i32, ptr %p
+  ret i32 %v
+}
+
+define i32 @main() {
+  ret i32 0
+}
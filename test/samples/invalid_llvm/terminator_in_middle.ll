@@ -0,0 +1,5 @@
+define i32 @main() {
+  ret i32 0
+  %x = add i32 1, 2
+  ret i32 %x
+}
@@ -0,0 +1,4 @@
+define i32 @main() {
+  %v = load i32, ptr @missing
+  ret i32 %v
+}
@@ -0,0 +1,4 @@
+define i32 @main() {
+  %1 = add i32 %2, 1
+  ret i32 %1
+}
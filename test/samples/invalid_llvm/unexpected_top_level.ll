@@ -0,0 +1 @@
+ret i32 0
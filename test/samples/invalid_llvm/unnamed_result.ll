@@ -0,0 +1,4 @@
+define i32 @main() {
+  add i32 1, 2
+  ret i32 0
+}
@@ -0,0 +1,4 @@
+define i32 @main() {
+  %1 = fadd float 1.0, 2.0
+  ret i32 0
+}
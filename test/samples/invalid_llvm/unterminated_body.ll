@@ -0,0 +1,3 @@
+define i32 @main() {
+  %1 = add i32 1, 2
+  ret i32 %1
@@ -0,0 +1,11 @@
+define i32 @main() {
+entry:
+  %c = icmp eq i32 1, 2
+  br i1 %c, label %a, label %join
+a:
+  %x = add i32 1, 2
+  br label %join
+join:
+  %y = add i32 %x, 1
+  ret i32 %y
+}
@@ -0,0 +1,5 @@
+define i32 @main() {
+  %x = add i32 1, 2
+  %x = add i32 3, 4
+  ret i32 %x
+}
@@ -0,0 +1,5 @@
+declare i32 @printf(ptr, ...)
+
+define i32 @main() {
+  ret i32 0
+}
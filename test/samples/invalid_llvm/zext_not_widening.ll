@@ -0,0 +1,4 @@
+define i32 @main() {
+  %x = zext i32 1 to i32
+  ret i32 %x
+}
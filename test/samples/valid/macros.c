@@ -0,0 +1,22 @@
+/* Exercise the built-in preprocessor: the whole program reduces to a single return statement */
+#define TWICE(x) ((x) + (x))
+#define CAT(a, b) a ## b
+#define NEG -1
+#define LIMIT 100
+
+#if defined(TWICE) && (1 << 2) == 4 && !defined(UNDEFINED)
+#define RESULT TWICE(CAT(1, 0)) - -NEG
+#elif LIMIT / 0
+#error "unreachable"
+#else
+#define RESULT 0
+#endif
+
+#undef LIMIT
+#ifdef LIMIT
+#error "LIMIT should be undefined"
+#endif
+
+int main(void) {
+    return RESULT;
+}
@@ -0,0 +1,31 @@
+#include <stddef.h>
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <limits.h>
+#include <string.h>
+
+struct pair {
+    int first;
+    int second;
+};
+
+static int sum(int count, ...) {
+    va_list args;
+    va_start(args, count);
+    int total = 0;
+    for (int i = 0; i < count; i++) {
+        total += va_arg(args, int);
+    }
+    va_end(args);
+    return total;
+}
+
+int main(void) {
+    const char *text = "britten";
+    size_t length = strlen(text);
+    bool fits = INT_MAX > SHRT_MAX && UINT8_MAX == 255 && SIZE_MAX > UINT32_MAX && CHAR_BIT == 8;
+    ptrdiff_t offset = (ptrdiff_t)offsetof(struct pair, second);
+    int same = memcmp(text, "brit", 4) == 0 && NULL == (void *)0;
+    return sum(3, (int)length, (int)offset, fits) + same;
+}
@@ -0,0 +1,56 @@
+; ModuleID = 'fib.c'
+source_filename = "fib.c"
+target datalayout = "e-m:e-p270:32:32-p271:32:32-p272:64:64-i64:64-f80:128-n8:16:32:64-S128"
+target triple = "x86_64-pc-linux-gnu"
+
+@start = dso_local global i32 3, align 4
+
+; Function Attrs: noinline nounwind optnone uwtable
+define dso_local i32 @fib(i32 noundef %0) #0 {
+  %2 = alloca i32, align 4
+  %3 = alloca i32, align 4
+  store i32 %0, ptr %3, align 4
+  %4 = load i32, ptr %3, align 4
+  %5 = icmp slt i32 %4, 2
+  br i1 %5, label %6, label %8
+
+6:                                                ; preds = %1
+  %7 = load i32, ptr %3, align 4
+  store i32 %7, ptr %2, align 4
+  br label %16
+
+8:                                                ; preds = %1
+  %9 = load i32, ptr %3, align 4
+  %10 = sub nsw i32 %9, 1
+  %11 = call i32 @fib(i32 noundef %10)
+  %12 = load i32, ptr %3, align 4
+  %13 = sub nsw i32 %12, 2
+  %14 = call i32 @fib(i32 noundef %13)
+  %15 = add nsw i32 %11, %14
+  store i32 %15, ptr %2, align 4
+  br label %16
+
+16:                                               ; preds = %8, %6
+  %17 = load i32, ptr %2, align 4
+  ret i32 %17
+}
+
+; Function Attrs: noinline nounwind optnone uwtable
+define dso_local i32 @main() #0 {
+  %1 = alloca i32, align 4
+  store i32 0, ptr %1, align 4
+  %2 = load i32, ptr @start, align 4, !noundef !3
+  %3 = add nsw i32 %2, 7
+  %4 = call i32 @fib(i32 noundef %3)
+  ret i32 %4
+}
+
+attributes #0 = { noinline nounwind optnone uwtable "frame-pointer"="all" "min-legal-vector-width"="0" "no-trapping-math"="true" "stack-protector-buffer-size"="8" "target-cpu"="x86-64" }
+
+!llvm.module.flags = !{!0, !1}
+!llvm.ident = !{!2}
+
+!0 = !{i32 1, !"wchar_size", i32 4}
+!1 = !{i32 7, !"PIC Level", i32 2}
+!2 = !{!"clang version 18.1.3"}
+!3 = !{}
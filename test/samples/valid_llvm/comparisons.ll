@@ -0,0 +1,65 @@
+; Every comparison, as branches, selects and zero extensions, along with negation and bitwise not
+define i32 @bit(i1 %c, i32 %shift) {
+  %v = select i1 %c, i32 %shift, i32 0
+  %one = icmp ne i32 %v, 0
+  %w = zext i1 %one to i32
+  ret i32 %w
+}
+
+define i32 @compare(i32 %a, i32 %b) {
+entry:
+  %eq = icmp eq i32 %a, %b
+  %ne = icmp ne i32 %a, %b
+  %sgt = icmp sgt i32 %a, %b
+  %sge = icmp sge i32 %a, %b
+  %slt = icmp slt i32 %a, %b
+  %sle = icmp sle i32 %a, %b
+  %ugt = icmp ugt i32 %a, %b
+  %uge = icmp uge i32 %a, %b
+  %ult = icmp ult i32 %a, %b
+  %ule = icmp ule i32 %a, %b
+  %r0 = select i1 %eq, i32 1, i32 0
+  %r1 = select i1 %ne, i32 2, i32 0
+  %r2 = select i1 %sgt, i32 4, i32 0
+  %r3 = select i1 %sge, i32 8, i32 0
+  %r4 = select i1 %slt, i32 16, i32 0
+  %r5 = zext i1 %sle to i32
+  %r6 = zext i1 %ugt to i32
+  %r7 = zext i1 %uge to i32
+  %r8 = zext i1 %ult to i32
+  %s0 = add i32 %r0, %r1
+  %s1 = add i32 %s0, %r2
+  %s2 = add i32 %s1, %r3
+  %s3 = add i32 %s2, %r4
+  %s4 = mul i32 %r5, 32
+  %s5 = add i32 %s3, %s4
+  %s6 = mul i32 %r6, 64
+  %s7 = add i32 %s5, %s6
+  %s8 = add i32 %r7, %r8
+  %s9 = add i32 %s7, %s8
+  br i1 %ule, label %small, label %done
+small:
+  %s10 = add i32 %s9, 128
+  br label %done
+done:
+  %s = phi i32 [ %s9, %entry ], [ %s10, %small ]
+  ret i32 %s
+}
+
+define i32 @main() {
+  %a = call i32 @compare(i32 -3, i32 5)
+  %b = call i32 @compare(i32 5, i32 5)
+  %c = call i32 @compare(i32 9, i32 -1)
+  %neg = sub i32 0, %a
+  %not = xor i32 %b, -1
+  %x = add i32 %neg, %not
+  %y = add i32 %x, %c
+  %t = call i32 @bit(i1 true, i32 3)
+  %f = call i32 @bit(i1 false, i32 3)
+  %z = add i32 %y, %t
+  %w = add i32 %z, %f
+  %m = srem i32 %w, 256
+  %r = add i32 %m, 256
+  %u = srem i32 %r, 256
+  ret i32 %u
+}
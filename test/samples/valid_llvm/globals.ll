@@ -0,0 +1,34 @@
+; Globals of every supported kind, read and written through pointers
+@counter = global i32 0
+@base = internal global i32 -7, align 4
+@limit = constant i32 40
+@zero = dso_local global i32 zeroinitializer
+@flag = global i1 true
+
+declare i32 @abs(i32)
+
+define internal void @bump(i32 %by) {
+  %v = load i32, ptr @counter
+  %w = add i32 %v, %by
+  store i32 %w, ptr @counter
+  ret void
+}
+
+define i32 @main() {
+entry:
+  call void @bump(i32 5)
+  call void @bump(i32 6)
+  store i32 2, ptr @zero
+  %c = load i32, ptr @counter
+  %b = load i32, ptr @base
+  %a = call i32 @abs(i32 %b)
+  %l = load i32, ptr @limit
+  %z = load i32, ptr @zero
+  %f = load i1, ptr @flag
+  %fz = zext i1 %f to i32
+  %s1 = add i32 %c, %a
+  %s2 = add i32 %s1, %l
+  %s3 = add i32 %s2, %z
+  %s4 = add i32 %s3, %fz
+  ret i32 %s4
+}
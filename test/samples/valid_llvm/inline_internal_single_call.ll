@@ -0,0 +1,48 @@
+; An internal function called once is inlined whatever its size and then removed, while a recursive one
+; is kept
+@seed = global i32 7
+
+define internal i32 @mix(i32 %x) {
+entry:
+  %a = mul i32 %x, 31
+  %b = add i32 %a, 17
+  %c = srem i32 %b, 101
+  %d = mul i32 %c, %c
+  %e = sub i32 %d, %x
+  %f = srem i32 %e, 97
+  %g = add i32 %f, %a
+  %h = mul i32 %g, 3
+  %i = srem i32 %h, 89
+  %j = add i32 %i, %c
+  %k = mul i32 %j, %j
+  %l = srem i32 %k, 83
+  %m = add i32 %l, %f
+  %n = icmp slt i32 %m, 0
+  br i1 %n, label %negative, label %done
+negative:
+  %p = sub i32 0, %m
+  ret i32 %p
+done:
+  ret i32 %m
+}
+
+define internal i32 @fact(i32 %n) {
+entry:
+  %z = icmp sle i32 %n, 1
+  br i1 %z, label %base, label %recurse
+base:
+  ret i32 1
+recurse:
+  %m = sub i32 %n, 1
+  %f = call i32 @fact(i32 %m)
+  %p = mul i32 %n, %f
+  ret i32 %p
+}
+
+define i32 @main() {
+  %s = load i32, ptr @seed
+  %x = call i32 @mix(i32 %s)
+  %f = call i32 @fact(i32 5)
+  %r = add i32 %x, %f
+  ret i32 %r
+}
@@ -0,0 +1,33 @@
+; Each return of the inlined body becomes a branch to the rest of the caller, where a phi joins the values
+@a = global i32 23
+@b = global i32 30
+
+define i32 @absdiff(i32 %x, i32 %y) {
+entry:
+  %c = icmp sgt i32 %x, %y
+  br i1 %c, label %greater, label %other
+greater:
+  %d = sub i32 %x, %y
+  ret i32 %d
+other:
+  %e = icmp eq i32 %x, %y
+  br i1 %e, label %equal, label %less
+equal:
+  ret i32 0
+less:
+  %f = sub i32 %y, %x
+  ret i32 %f
+}
+
+define i32 @main() {
+entry:
+  %a = load i32, ptr @a
+  %b = load i32, ptr @b
+  %r1 = call i32 @absdiff(i32 %a, i32 %b)
+  %r2 = call i32 @absdiff(i32 %b, i32 %a)
+  %r3 = call i32 @absdiff(i32 %a, i32 %a)
+  %m = mul i32 %r1, 10
+  %s = add i32 %m, %r2
+  %t = add i32 %s, %r3
+  ret i32 %t
+}
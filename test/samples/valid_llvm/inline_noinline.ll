@@ -0,0 +1,26 @@
+; Functions marked noinline keep their calls, whether the attribute is written inline or in a group
+@calls = global i32 0
+
+define i32 @count(i32 %x) noinline {
+  %n = load i32, ptr @calls
+  %m = add i32 %n, 1
+  store i32 %m, ptr @calls
+  %y = add i32 %x, %m
+  ret i32 %y
+}
+
+define i32 @twice(i32 %x) #0 {
+  %y = mul i32 %x, 2
+  ret i32 %y
+}
+
+define i32 @main() {
+  %a = call i32 @count(i32 10)
+  %b = call i32 @count(i32 %a)
+  %c = call i32 @twice(i32 %b)
+  %n = load i32, ptr @calls
+  %r = add i32 %c, %n
+  ret i32 %r
+}
+
+attributes #0 = { noinline nounwind }
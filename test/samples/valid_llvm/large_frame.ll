@@ -0,0 +1,4418 @@
+; Enough values live in the frame that the later ones sit past the largest immediate offset a load or
+; store can encode on AArch64 and RISC-V
+define i32 @chain(i32 %a) noinline {
+entry:
+  %p = alloca i32
+  store i32 %a, ptr %p
+  %v0 = add i32 %a, 0
+  %v1 = add i32 %v0, 1
+  %v2 = add i32 %v1, 2
+  %v3 = add i32 %v2, 3
+  %v4 = add i32 %v3, 4
+  %v5 = add i32 %v4, 5
+  %v6 = add i32 %v5, 6
+  %v7 = add i32 %v6, 0
+  %v8 = add i32 %v7, 1
+  %v9 = add i32 %v8, 2
+  %v10 = add i32 %v9, 3
+  %v11 = add i32 %v10, 4
+  %v12 = add i32 %v11, 5
+  %v13 = add i32 %v12, 6
+  %v14 = add i32 %v13, 0
+  %v15 = add i32 %v14, 1
+  %v16 = add i32 %v15, 2
+  %v17 = add i32 %v16, 3
+  %v18 = add i32 %v17, 4
+  %v19 = add i32 %v18, 5
+  %v20 = add i32 %v19, 6
+  %v21 = add i32 %v20, 0
+  %v22 = add i32 %v21, 1
+  %v23 = add i32 %v22, 2
+  %v24 = add i32 %v23, 3
+  %v25 = add i32 %v24, 4
+  %v26 = add i32 %v25, 5
+  %v27 = add i32 %v26, 6
+  %v28 = add i32 %v27, 0
+  %v29 = add i32 %v28, 1
+  %v30 = add i32 %v29, 2
+  %v31 = add i32 %v30, 3
+  %v32 = add i32 %v31, 4
+  %v33 = add i32 %v32, 5
+  %v34 = add i32 %v33, 6
+  %v35 = add i32 %v34, 0
+  %v36 = add i32 %v35, 1
+  %v37 = add i32 %v36, 2
+  %v38 = add i32 %v37, 3
+  %v39 = add i32 %v38, 4
+  %v40 = add i32 %v39, 5
+  %v41 = add i32 %v40, 6
+  %v42 = add i32 %v41, 0
+  %v43 = add i32 %v42, 1
+  %v44 = add i32 %v43, 2
+  %v45 = add i32 %v44, 3
+  %v46 = add i32 %v45, 4
+  %v47 = add i32 %v46, 5
+  %v48 = add i32 %v47, 6
+  %v49 = add i32 %v48, 0
+  %v50 = add i32 %v49, 1
+  %v51 = add i32 %v50, 2
+  %v52 = add i32 %v51, 3
+  %v53 = add i32 %v52, 4
+  %v54 = add i32 %v53, 5
+  %v55 = add i32 %v54, 6
+  %v56 = add i32 %v55, 0
+  %v57 = add i32 %v56, 1
+  %v58 = add i32 %v57, 2
+  %v59 = add i32 %v58, 3
+  %v60 = add i32 %v59, 4
+  %v61 = add i32 %v60, 5
+  %v62 = add i32 %v61, 6
+  %v63 = add i32 %v62, 0
+  %v64 = add i32 %v63, 1
+  %v65 = add i32 %v64, 2
+  %v66 = add i32 %v65, 3
+  %v67 = add i32 %v66, 4
+  %v68 = add i32 %v67, 5
+  %v69 = add i32 %v68, 6
+  %v70 = add i32 %v69, 0
+  %v71 = add i32 %v70, 1
+  %v72 = add i32 %v71, 2
+  %v73 = add i32 %v72, 3
+  %v74 = add i32 %v73, 4
+  %v75 = add i32 %v74, 5
+  %v76 = add i32 %v75, 6
+  %v77 = add i32 %v76, 0
+  %v78 = add i32 %v77, 1
+  %v79 = add i32 %v78, 2
+  %v80 = add i32 %v79, 3
+  %v81 = add i32 %v80, 4
+  %v82 = add i32 %v81, 5
+  %v83 = add i32 %v82, 6
+  %v84 = add i32 %v83, 0
+  %v85 = add i32 %v84, 1
+  %v86 = add i32 %v85, 2
+  %v87 = add i32 %v86, 3
+  %v88 = add i32 %v87, 4
+  %v89 = add i32 %v88, 5
+  %v90 = add i32 %v89, 6
+  %v91 = add i32 %v90, 0
+  %v92 = add i32 %v91, 1
+  %v93 = add i32 %v92, 2
+  %v94 = add i32 %v93, 3
+  %v95 = add i32 %v94, 4
+  %v96 = add i32 %v95, 5
+  %v97 = add i32 %v96, 6
+  %v98 = add i32 %v97, 0
+  %v99 = add i32 %v98, 1
+  %v100 = add i32 %v99, 2
+  %v101 = add i32 %v100, 3
+  %v102 = add i32 %v101, 4
+  %v103 = add i32 %v102, 5
+  %v104 = add i32 %v103, 6
+  %v105 = add i32 %v104, 0
+  %v106 = add i32 %v105, 1
+  %v107 = add i32 %v106, 2
+  %v108 = add i32 %v107, 3
+  %v109 = add i32 %v108, 4
+  %v110 = add i32 %v109, 5
+  %v111 = add i32 %v110, 6
+  %v112 = add i32 %v111, 0
+  %v113 = add i32 %v112, 1
+  %v114 = add i32 %v113, 2
+  %v115 = add i32 %v114, 3
+  %v116 = add i32 %v115, 4
+  %v117 = add i32 %v116, 5
+  %v118 = add i32 %v117, 6
+  %v119 = add i32 %v118, 0
+  %v120 = add i32 %v119, 1
+  %v121 = add i32 %v120, 2
+  %v122 = add i32 %v121, 3
+  %v123 = add i32 %v122, 4
+  %v124 = add i32 %v123, 5
+  %v125 = add i32 %v124, 6
+  %v126 = add i32 %v125, 0
+  %v127 = add i32 %v126, 1
+  %v128 = add i32 %v127, 2
+  %v129 = add i32 %v128, 3
+  %v130 = add i32 %v129, 4
+  %v131 = add i32 %v130, 5
+  %v132 = add i32 %v131, 6
+  %v133 = add i32 %v132, 0
+  %v134 = add i32 %v133, 1
+  %v135 = add i32 %v134, 2
+  %v136 = add i32 %v135, 3
+  %v137 = add i32 %v136, 4
+  %v138 = add i32 %v137, 5
+  %v139 = add i32 %v138, 6
+  %v140 = add i32 %v139, 0
+  %v141 = add i32 %v140, 1
+  %v142 = add i32 %v141, 2
+  %v143 = add i32 %v142, 3
+  %v144 = add i32 %v143, 4
+  %v145 = add i32 %v144, 5
+  %v146 = add i32 %v145, 6
+  %v147 = add i32 %v146, 0
+  %v148 = add i32 %v147, 1
+  %v149 = add i32 %v148, 2
+  %v150 = add i32 %v149, 3
+  %v151 = add i32 %v150, 4
+  %v152 = add i32 %v151, 5
+  %v153 = add i32 %v152, 6
+  %v154 = add i32 %v153, 0
+  %v155 = add i32 %v154, 1
+  %v156 = add i32 %v155, 2
+  %v157 = add i32 %v156, 3
+  %v158 = add i32 %v157, 4
+  %v159 = add i32 %v158, 5
+  %v160 = add i32 %v159, 6
+  %v161 = add i32 %v160, 0
+  %v162 = add i32 %v161, 1
+  %v163 = add i32 %v162, 2
+  %v164 = add i32 %v163, 3
+  %v165 = add i32 %v164, 4
+  %v166 = add i32 %v165, 5
+  %v167 = add i32 %v166, 6
+  %v168 = add i32 %v167, 0
+  %v169 = add i32 %v168, 1
+  %v170 = add i32 %v169, 2
+  %v171 = add i32 %v170, 3
+  %v172 = add i32 %v171, 4
+  %v173 = add i32 %v172, 5
+  %v174 = add i32 %v173, 6
+  %v175 = add i32 %v174, 0
+  %v176 = add i32 %v175, 1
+  %v177 = add i32 %v176, 2
+  %v178 = add i32 %v177, 3
+  %v179 = add i32 %v178, 4
+  %v180 = add i32 %v179, 5
+  %v181 = add i32 %v180, 6
+  %v182 = add i32 %v181, 0
+  %v183 = add i32 %v182, 1
+  %v184 = add i32 %v183, 2
+  %v185 = add i32 %v184, 3
+  %v186 = add i32 %v185, 4
+  %v187 = add i32 %v186, 5
+  %v188 = add i32 %v187, 6
+  %v189 = add i32 %v188, 0
+  %v190 = add i32 %v189, 1
+  %v191 = add i32 %v190, 2
+  %v192 = add i32 %v191, 3
+  %v193 = add i32 %v192, 4
+  %v194 = add i32 %v193, 5
+  %v195 = add i32 %v194, 6
+  %v196 = add i32 %v195, 0
+  %v197 = add i32 %v196, 1
+  %v198 = add i32 %v197, 2
+  %v199 = add i32 %v198, 3
+  %v200 = add i32 %v199, 4
+  %v201 = add i32 %v200, 5
+  %v202 = add i32 %v201, 6
+  %v203 = add i32 %v202, 0
+  %v204 = add i32 %v203, 1
+  %v205 = add i32 %v204, 2
+  %v206 = add i32 %v205, 3
+  %v207 = add i32 %v206, 4
+  %v208 = add i32 %v207, 5
+  %v209 = add i32 %v208, 6
+  %v210 = add i32 %v209, 0
+  %v211 = add i32 %v210, 1
+  %v212 = add i32 %v211, 2
+  %v213 = add i32 %v212, 3
+  %v214 = add i32 %v213, 4
+  %v215 = add i32 %v214, 5
+  %v216 = add i32 %v215, 6
+  %v217 = add i32 %v216, 0
+  %v218 = add i32 %v217, 1
+  %v219 = add i32 %v218, 2
+  %v220 = add i32 %v219, 3
+  %v221 = add i32 %v220, 4
+  %v222 = add i32 %v221, 5
+  %v223 = add i32 %v222, 6
+  %v224 = add i32 %v223, 0
+  %v225 = add i32 %v224, 1
+  %v226 = add i32 %v225, 2
+  %v227 = add i32 %v226, 3
+  %v228 = add i32 %v227, 4
+  %v229 = add i32 %v228, 5
+  %v230 = add i32 %v229, 6
+  %v231 = add i32 %v230, 0
+  %v232 = add i32 %v231, 1
+  %v233 = add i32 %v232, 2
+  %v234 = add i32 %v233, 3
+  %v235 = add i32 %v234, 4
+  %v236 = add i32 %v235, 5
+  %v237 = add i32 %v236, 6
+  %v238 = add i32 %v237, 0
+  %v239 = add i32 %v238, 1
+  %v240 = add i32 %v239, 2
+  %v241 = add i32 %v240, 3
+  %v242 = add i32 %v241, 4
+  %v243 = add i32 %v242, 5
+  %v244 = add i32 %v243, 6
+  %v245 = add i32 %v244, 0
+  %v246 = add i32 %v245, 1
+  %v247 = add i32 %v246, 2
+  %v248 = add i32 %v247, 3
+  %v249 = add i32 %v248, 4
+  %v250 = add i32 %v249, 5
+  %v251 = add i32 %v250, 6
+  %v252 = add i32 %v251, 0
+  %v253 = add i32 %v252, 1
+  %v254 = add i32 %v253, 2
+  %v255 = add i32 %v254, 3
+  %v256 = add i32 %v255, 4
+  %v257 = add i32 %v256, 5
+  %v258 = add i32 %v257, 6
+  %v259 = add i32 %v258, 0
+  %v260 = add i32 %v259, 1
+  %v261 = add i32 %v260, 2
+  %v262 = add i32 %v261, 3
+  %v263 = add i32 %v262, 4
+  %v264 = add i32 %v263, 5
+  %v265 = add i32 %v264, 6
+  %v266 = add i32 %v265, 0
+  %v267 = add i32 %v266, 1
+  %v268 = add i32 %v267, 2
+  %v269 = add i32 %v268, 3
+  %v270 = add i32 %v269, 4
+  %v271 = add i32 %v270, 5
+  %v272 = add i32 %v271, 6
+  %v273 = add i32 %v272, 0
+  %v274 = add i32 %v273, 1
+  %v275 = add i32 %v274, 2
+  %v276 = add i32 %v275, 3
+  %v277 = add i32 %v276, 4
+  %v278 = add i32 %v277, 5
+  %v279 = add i32 %v278, 6
+  %v280 = add i32 %v279, 0
+  %v281 = add i32 %v280, 1
+  %v282 = add i32 %v281, 2
+  %v283 = add i32 %v282, 3
+  %v284 = add i32 %v283, 4
+  %v285 = add i32 %v284, 5
+  %v286 = add i32 %v285, 6
+  %v287 = add i32 %v286, 0
+  %v288 = add i32 %v287, 1
+  %v289 = add i32 %v288, 2
+  %v290 = add i32 %v289, 3
+  %v291 = add i32 %v290, 4
+  %v292 = add i32 %v291, 5
+  %v293 = add i32 %v292, 6
+  %v294 = add i32 %v293, 0
+  %v295 = add i32 %v294, 1
+  %v296 = add i32 %v295, 2
+  %v297 = add i32 %v296, 3
+  %v298 = add i32 %v297, 4
+  %v299 = add i32 %v298, 5
+  %v300 = add i32 %v299, 6
+  %v301 = add i32 %v300, 0
+  %v302 = add i32 %v301, 1
+  %v303 = add i32 %v302, 2
+  %v304 = add i32 %v303, 3
+  %v305 = add i32 %v304, 4
+  %v306 = add i32 %v305, 5
+  %v307 = add i32 %v306, 6
+  %v308 = add i32 %v307, 0
+  %v309 = add i32 %v308, 1
+  %v310 = add i32 %v309, 2
+  %v311 = add i32 %v310, 3
+  %v312 = add i32 %v311, 4
+  %v313 = add i32 %v312, 5
+  %v314 = add i32 %v313, 6
+  %v315 = add i32 %v314, 0
+  %v316 = add i32 %v315, 1
+  %v317 = add i32 %v316, 2
+  %v318 = add i32 %v317, 3
+  %v319 = add i32 %v318, 4
+  %v320 = add i32 %v319, 5
+  %v321 = add i32 %v320, 6
+  %v322 = add i32 %v321, 0
+  %v323 = add i32 %v322, 1
+  %v324 = add i32 %v323, 2
+  %v325 = add i32 %v324, 3
+  %v326 = add i32 %v325, 4
+  %v327 = add i32 %v326, 5
+  %v328 = add i32 %v327, 6
+  %v329 = add i32 %v328, 0
+  %v330 = add i32 %v329, 1
+  %v331 = add i32 %v330, 2
+  %v332 = add i32 %v331, 3
+  %v333 = add i32 %v332, 4
+  %v334 = add i32 %v333, 5
+  %v335 = add i32 %v334, 6
+  %v336 = add i32 %v335, 0
+  %v337 = add i32 %v336, 1
+  %v338 = add i32 %v337, 2
+  %v339 = add i32 %v338, 3
+  %v340 = add i32 %v339, 4
+  %v341 = add i32 %v340, 5
+  %v342 = add i32 %v341, 6
+  %v343 = add i32 %v342, 0
+  %v344 = add i32 %v343, 1
+  %v345 = add i32 %v344, 2
+  %v346 = add i32 %v345, 3
+  %v347 = add i32 %v346, 4
+  %v348 = add i32 %v347, 5
+  %v349 = add i32 %v348, 6
+  %v350 = add i32 %v349, 0
+  %v351 = add i32 %v350, 1
+  %v352 = add i32 %v351, 2
+  %v353 = add i32 %v352, 3
+  %v354 = add i32 %v353, 4
+  %v355 = add i32 %v354, 5
+  %v356 = add i32 %v355, 6
+  %v357 = add i32 %v356, 0
+  %v358 = add i32 %v357, 1
+  %v359 = add i32 %v358, 2
+  %v360 = add i32 %v359, 3
+  %v361 = add i32 %v360, 4
+  %v362 = add i32 %v361, 5
+  %v363 = add i32 %v362, 6
+  %v364 = add i32 %v363, 0
+  %v365 = add i32 %v364, 1
+  %v366 = add i32 %v365, 2
+  %v367 = add i32 %v366, 3
+  %v368 = add i32 %v367, 4
+  %v369 = add i32 %v368, 5
+  %v370 = add i32 %v369, 6
+  %v371 = add i32 %v370, 0
+  %v372 = add i32 %v371, 1
+  %v373 = add i32 %v372, 2
+  %v374 = add i32 %v373, 3
+  %v375 = add i32 %v374, 4
+  %v376 = add i32 %v375, 5
+  %v377 = add i32 %v376, 6
+  %v378 = add i32 %v377, 0
+  %v379 = add i32 %v378, 1
+  %v380 = add i32 %v379, 2
+  %v381 = add i32 %v380, 3
+  %v382 = add i32 %v381, 4
+  %v383 = add i32 %v382, 5
+  %v384 = add i32 %v383, 6
+  %v385 = add i32 %v384, 0
+  %v386 = add i32 %v385, 1
+  %v387 = add i32 %v386, 2
+  %v388 = add i32 %v387, 3
+  %v389 = add i32 %v388, 4
+  %v390 = add i32 %v389, 5
+  %v391 = add i32 %v390, 6
+  %v392 = add i32 %v391, 0
+  %v393 = add i32 %v392, 1
+  %v394 = add i32 %v393, 2
+  %v395 = add i32 %v394, 3
+  %v396 = add i32 %v395, 4
+  %v397 = add i32 %v396, 5
+  %v398 = add i32 %v397, 6
+  %v399 = add i32 %v398, 0
+  %v400 = add i32 %v399, 1
+  %v401 = add i32 %v400, 2
+  %v402 = add i32 %v401, 3
+  %v403 = add i32 %v402, 4
+  %v404 = add i32 %v403, 5
+  %v405 = add i32 %v404, 6
+  %v406 = add i32 %v405, 0
+  %v407 = add i32 %v406, 1
+  %v408 = add i32 %v407, 2
+  %v409 = add i32 %v408, 3
+  %v410 = add i32 %v409, 4
+  %v411 = add i32 %v410, 5
+  %v412 = add i32 %v411, 6
+  %v413 = add i32 %v412, 0
+  %v414 = add i32 %v413, 1
+  %v415 = add i32 %v414, 2
+  %v416 = add i32 %v415, 3
+  %v417 = add i32 %v416, 4
+  %v418 = add i32 %v417, 5
+  %v419 = add i32 %v418, 6
+  %v420 = add i32 %v419, 0
+  %v421 = add i32 %v420, 1
+  %v422 = add i32 %v421, 2
+  %v423 = add i32 %v422, 3
+  %v424 = add i32 %v423, 4
+  %v425 = add i32 %v424, 5
+  %v426 = add i32 %v425, 6
+  %v427 = add i32 %v426, 0
+  %v428 = add i32 %v427, 1
+  %v429 = add i32 %v428, 2
+  %v430 = add i32 %v429, 3
+  %v431 = add i32 %v430, 4
+  %v432 = add i32 %v431, 5
+  %v433 = add i32 %v432, 6
+  %v434 = add i32 %v433, 0
+  %v435 = add i32 %v434, 1
+  %v436 = add i32 %v435, 2
+  %v437 = add i32 %v436, 3
+  %v438 = add i32 %v437, 4
+  %v439 = add i32 %v438, 5
+  %v440 = add i32 %v439, 6
+  %v441 = add i32 %v440, 0
+  %v442 = add i32 %v441, 1
+  %v443 = add i32 %v442, 2
+  %v444 = add i32 %v443, 3
+  %v445 = add i32 %v444, 4
+  %v446 = add i32 %v445, 5
+  %v447 = add i32 %v446, 6
+  %v448 = add i32 %v447, 0
+  %v449 = add i32 %v448, 1
+  %v450 = add i32 %v449, 2
+  %v451 = add i32 %v450, 3
+  %v452 = add i32 %v451, 4
+  %v453 = add i32 %v452, 5
+  %v454 = add i32 %v453, 6
+  %v455 = add i32 %v454, 0
+  %v456 = add i32 %v455, 1
+  %v457 = add i32 %v456, 2
+  %v458 = add i32 %v457, 3
+  %v459 = add i32 %v458, 4
+  %v460 = add i32 %v459, 5
+  %v461 = add i32 %v460, 6
+  %v462 = add i32 %v461, 0
+  %v463 = add i32 %v462, 1
+  %v464 = add i32 %v463, 2
+  %v465 = add i32 %v464, 3
+  %v466 = add i32 %v465, 4
+  %v467 = add i32 %v466, 5
+  %v468 = add i32 %v467, 6
+  %v469 = add i32 %v468, 0
+  %v470 = add i32 %v469, 1
+  %v471 = add i32 %v470, 2
+  %v472 = add i32 %v471, 3
+  %v473 = add i32 %v472, 4
+  %v474 = add i32 %v473, 5
+  %v475 = add i32 %v474, 6
+  %v476 = add i32 %v475, 0
+  %v477 = add i32 %v476, 1
+  %v478 = add i32 %v477, 2
+  %v479 = add i32 %v478, 3
+  %v480 = add i32 %v479, 4
+  %v481 = add i32 %v480, 5
+  %v482 = add i32 %v481, 6
+  %v483 = add i32 %v482, 0
+  %v484 = add i32 %v483, 1
+  %v485 = add i32 %v484, 2
+  %v486 = add i32 %v485, 3
+  %v487 = add i32 %v486, 4
+  %v488 = add i32 %v487, 5
+  %v489 = add i32 %v488, 6
+  %v490 = add i32 %v489, 0
+  %v491 = add i32 %v490, 1
+  %v492 = add i32 %v491, 2
+  %v493 = add i32 %v492, 3
+  %v494 = add i32 %v493, 4
+  %v495 = add i32 %v494, 5
+  %v496 = add i32 %v495, 6
+  %v497 = add i32 %v496, 0
+  %v498 = add i32 %v497, 1
+  %v499 = add i32 %v498, 2
+  %v500 = add i32 %v499, 3
+  %v501 = add i32 %v500, 4
+  %v502 = add i32 %v501, 5
+  %v503 = add i32 %v502, 6
+  %v504 = add i32 %v503, 0
+  %v505 = add i32 %v504, 1
+  %v506 = add i32 %v505, 2
+  %v507 = add i32 %v506, 3
+  %v508 = add i32 %v507, 4
+  %v509 = add i32 %v508, 5
+  %v510 = add i32 %v509, 6
+  %v511 = add i32 %v510, 0
+  %v512 = add i32 %v511, 1
+  %v513 = add i32 %v512, 2
+  %v514 = add i32 %v513, 3
+  %v515 = add i32 %v514, 4
+  %v516 = add i32 %v515, 5
+  %v517 = add i32 %v516, 6
+  %v518 = add i32 %v517, 0
+  %v519 = add i32 %v518, 1
+  %v520 = add i32 %v519, 2
+  %v521 = add i32 %v520, 3
+  %v522 = add i32 %v521, 4
+  %v523 = add i32 %v522, 5
+  %v524 = add i32 %v523, 6
+  %v525 = add i32 %v524, 0
+  %v526 = add i32 %v525, 1
+  %v527 = add i32 %v526, 2
+  %v528 = add i32 %v527, 3
+  %v529 = add i32 %v528, 4
+  %v530 = add i32 %v529, 5
+  %v531 = add i32 %v530, 6
+  %v532 = add i32 %v531, 0
+  %v533 = add i32 %v532, 1
+  %v534 = add i32 %v533, 2
+  %v535 = add i32 %v534, 3
+  %v536 = add i32 %v535, 4
+  %v537 = add i32 %v536, 5
+  %v538 = add i32 %v537, 6
+  %v539 = add i32 %v538, 0
+  %v540 = add i32 %v539, 1
+  %v541 = add i32 %v540, 2
+  %v542 = add i32 %v541, 3
+  %v543 = add i32 %v542, 4
+  %v544 = add i32 %v543, 5
+  %v545 = add i32 %v544, 6
+  %v546 = add i32 %v545, 0
+  %v547 = add i32 %v546, 1
+  %v548 = add i32 %v547, 2
+  %v549 = add i32 %v548, 3
+  %v550 = add i32 %v549, 4
+  %v551 = add i32 %v550, 5
+  %v552 = add i32 %v551, 6
+  %v553 = add i32 %v552, 0
+  %v554 = add i32 %v553, 1
+  %v555 = add i32 %v554, 2
+  %v556 = add i32 %v555, 3
+  %v557 = add i32 %v556, 4
+  %v558 = add i32 %v557, 5
+  %v559 = add i32 %v558, 6
+  %v560 = add i32 %v559, 0
+  %v561 = add i32 %v560, 1
+  %v562 = add i32 %v561, 2
+  %v563 = add i32 %v562, 3
+  %v564 = add i32 %v563, 4
+  %v565 = add i32 %v564, 5
+  %v566 = add i32 %v565, 6
+  %v567 = add i32 %v566, 0
+  %v568 = add i32 %v567, 1
+  %v569 = add i32 %v568, 2
+  %v570 = add i32 %v569, 3
+  %v571 = add i32 %v570, 4
+  %v572 = add i32 %v571, 5
+  %v573 = add i32 %v572, 6
+  %v574 = add i32 %v573, 0
+  %v575 = add i32 %v574, 1
+  %v576 = add i32 %v575, 2
+  %v577 = add i32 %v576, 3
+  %v578 = add i32 %v577, 4
+  %v579 = add i32 %v578, 5
+  %v580 = add i32 %v579, 6
+  %v581 = add i32 %v580, 0
+  %v582 = add i32 %v581, 1
+  %v583 = add i32 %v582, 2
+  %v584 = add i32 %v583, 3
+  %v585 = add i32 %v584, 4
+  %v586 = add i32 %v585, 5
+  %v587 = add i32 %v586, 6
+  %v588 = add i32 %v587, 0
+  %v589 = add i32 %v588, 1
+  %v590 = add i32 %v589, 2
+  %v591 = add i32 %v590, 3
+  %v592 = add i32 %v591, 4
+  %v593 = add i32 %v592, 5
+  %v594 = add i32 %v593, 6
+  %v595 = add i32 %v594, 0
+  %v596 = add i32 %v595, 1
+  %v597 = add i32 %v596, 2
+  %v598 = add i32 %v597, 3
+  %v599 = add i32 %v598, 4
+  %v600 = add i32 %v599, 5
+  %v601 = add i32 %v600, 6
+  %v602 = add i32 %v601, 0
+  %v603 = add i32 %v602, 1
+  %v604 = add i32 %v603, 2
+  %v605 = add i32 %v604, 3
+  %v606 = add i32 %v605, 4
+  %v607 = add i32 %v606, 5
+  %v608 = add i32 %v607, 6
+  %v609 = add i32 %v608, 0
+  %v610 = add i32 %v609, 1
+  %v611 = add i32 %v610, 2
+  %v612 = add i32 %v611, 3
+  %v613 = add i32 %v612, 4
+  %v614 = add i32 %v613, 5
+  %v615 = add i32 %v614, 6
+  %v616 = add i32 %v615, 0
+  %v617 = add i32 %v616, 1
+  %v618 = add i32 %v617, 2
+  %v619 = add i32 %v618, 3
+  %v620 = add i32 %v619, 4
+  %v621 = add i32 %v620, 5
+  %v622 = add i32 %v621, 6
+  %v623 = add i32 %v622, 0
+  %v624 = add i32 %v623, 1
+  %v625 = add i32 %v624, 2
+  %v626 = add i32 %v625, 3
+  %v627 = add i32 %v626, 4
+  %v628 = add i32 %v627, 5
+  %v629 = add i32 %v628, 6
+  %v630 = add i32 %v629, 0
+  %v631 = add i32 %v630, 1
+  %v632 = add i32 %v631, 2
+  %v633 = add i32 %v632, 3
+  %v634 = add i32 %v633, 4
+  %v635 = add i32 %v634, 5
+  %v636 = add i32 %v635, 6
+  %v637 = add i32 %v636, 0
+  %v638 = add i32 %v637, 1
+  %v639 = add i32 %v638, 2
+  %v640 = add i32 %v639, 3
+  %v641 = add i32 %v640, 4
+  %v642 = add i32 %v641, 5
+  %v643 = add i32 %v642, 6
+  %v644 = add i32 %v643, 0
+  %v645 = add i32 %v644, 1
+  %v646 = add i32 %v645, 2
+  %v647 = add i32 %v646, 3
+  %v648 = add i32 %v647, 4
+  %v649 = add i32 %v648, 5
+  %v650 = add i32 %v649, 6
+  %v651 = add i32 %v650, 0
+  %v652 = add i32 %v651, 1
+  %v653 = add i32 %v652, 2
+  %v654 = add i32 %v653, 3
+  %v655 = add i32 %v654, 4
+  %v656 = add i32 %v655, 5
+  %v657 = add i32 %v656, 6
+  %v658 = add i32 %v657, 0
+  %v659 = add i32 %v658, 1
+  %v660 = add i32 %v659, 2
+  %v661 = add i32 %v660, 3
+  %v662 = add i32 %v661, 4
+  %v663 = add i32 %v662, 5
+  %v664 = add i32 %v663, 6
+  %v665 = add i32 %v664, 0
+  %v666 = add i32 %v665, 1
+  %v667 = add i32 %v666, 2
+  %v668 = add i32 %v667, 3
+  %v669 = add i32 %v668, 4
+  %v670 = add i32 %v669, 5
+  %v671 = add i32 %v670, 6
+  %v672 = add i32 %v671, 0
+  %v673 = add i32 %v672, 1
+  %v674 = add i32 %v673, 2
+  %v675 = add i32 %v674, 3
+  %v676 = add i32 %v675, 4
+  %v677 = add i32 %v676, 5
+  %v678 = add i32 %v677, 6
+  %v679 = add i32 %v678, 0
+  %v680 = add i32 %v679, 1
+  %v681 = add i32 %v680, 2
+  %v682 = add i32 %v681, 3
+  %v683 = add i32 %v682, 4
+  %v684 = add i32 %v683, 5
+  %v685 = add i32 %v684, 6
+  %v686 = add i32 %v685, 0
+  %v687 = add i32 %v686, 1
+  %v688 = add i32 %v687, 2
+  %v689 = add i32 %v688, 3
+  %v690 = add i32 %v689, 4
+  %v691 = add i32 %v690, 5
+  %v692 = add i32 %v691, 6
+  %v693 = add i32 %v692, 0
+  %v694 = add i32 %v693, 1
+  %v695 = add i32 %v694, 2
+  %v696 = add i32 %v695, 3
+  %v697 = add i32 %v696, 4
+  %v698 = add i32 %v697, 5
+  %v699 = add i32 %v698, 6
+  %v700 = add i32 %v699, 0
+  %v701 = add i32 %v700, 1
+  %v702 = add i32 %v701, 2
+  %v703 = add i32 %v702, 3
+  %v704 = add i32 %v703, 4
+  %v705 = add i32 %v704, 5
+  %v706 = add i32 %v705, 6
+  %v707 = add i32 %v706, 0
+  %v708 = add i32 %v707, 1
+  %v709 = add i32 %v708, 2
+  %v710 = add i32 %v709, 3
+  %v711 = add i32 %v710, 4
+  %v712 = add i32 %v711, 5
+  %v713 = add i32 %v712, 6
+  %v714 = add i32 %v713, 0
+  %v715 = add i32 %v714, 1
+  %v716 = add i32 %v715, 2
+  %v717 = add i32 %v716, 3
+  %v718 = add i32 %v717, 4
+  %v719 = add i32 %v718, 5
+  %v720 = add i32 %v719, 6
+  %v721 = add i32 %v720, 0
+  %v722 = add i32 %v721, 1
+  %v723 = add i32 %v722, 2
+  %v724 = add i32 %v723, 3
+  %v725 = add i32 %v724, 4
+  %v726 = add i32 %v725, 5
+  %v727 = add i32 %v726, 6
+  %v728 = add i32 %v727, 0
+  %v729 = add i32 %v728, 1
+  %v730 = add i32 %v729, 2
+  %v731 = add i32 %v730, 3
+  %v732 = add i32 %v731, 4
+  %v733 = add i32 %v732, 5
+  %v734 = add i32 %v733, 6
+  %v735 = add i32 %v734, 0
+  %v736 = add i32 %v735, 1
+  %v737 = add i32 %v736, 2
+  %v738 = add i32 %v737, 3
+  %v739 = add i32 %v738, 4
+  %v740 = add i32 %v739, 5
+  %v741 = add i32 %v740, 6
+  %v742 = add i32 %v741, 0
+  %v743 = add i32 %v742, 1
+  %v744 = add i32 %v743, 2
+  %v745 = add i32 %v744, 3
+  %v746 = add i32 %v745, 4
+  %v747 = add i32 %v746, 5
+  %v748 = add i32 %v747, 6
+  %v749 = add i32 %v748, 0
+  %v750 = add i32 %v749, 1
+  %v751 = add i32 %v750, 2
+  %v752 = add i32 %v751, 3
+  %v753 = add i32 %v752, 4
+  %v754 = add i32 %v753, 5
+  %v755 = add i32 %v754, 6
+  %v756 = add i32 %v755, 0
+  %v757 = add i32 %v756, 1
+  %v758 = add i32 %v757, 2
+  %v759 = add i32 %v758, 3
+  %v760 = add i32 %v759, 4
+  %v761 = add i32 %v760, 5
+  %v762 = add i32 %v761, 6
+  %v763 = add i32 %v762, 0
+  %v764 = add i32 %v763, 1
+  %v765 = add i32 %v764, 2
+  %v766 = add i32 %v765, 3
+  %v767 = add i32 %v766, 4
+  %v768 = add i32 %v767, 5
+  %v769 = add i32 %v768, 6
+  %v770 = add i32 %v769, 0
+  %v771 = add i32 %v770, 1
+  %v772 = add i32 %v771, 2
+  %v773 = add i32 %v772, 3
+  %v774 = add i32 %v773, 4
+  %v775 = add i32 %v774, 5
+  %v776 = add i32 %v775, 6
+  %v777 = add i32 %v776, 0
+  %v778 = add i32 %v777, 1
+  %v779 = add i32 %v778, 2
+  %v780 = add i32 %v779, 3
+  %v781 = add i32 %v780, 4
+  %v782 = add i32 %v781, 5
+  %v783 = add i32 %v782, 6
+  %v784 = add i32 %v783, 0
+  %v785 = add i32 %v784, 1
+  %v786 = add i32 %v785, 2
+  %v787 = add i32 %v786, 3
+  %v788 = add i32 %v787, 4
+  %v789 = add i32 %v788, 5
+  %v790 = add i32 %v789, 6
+  %v791 = add i32 %v790, 0
+  %v792 = add i32 %v791, 1
+  %v793 = add i32 %v792, 2
+  %v794 = add i32 %v793, 3
+  %v795 = add i32 %v794, 4
+  %v796 = add i32 %v795, 5
+  %v797 = add i32 %v796, 6
+  %v798 = add i32 %v797, 0
+  %v799 = add i32 %v798, 1
+  %v800 = add i32 %v799, 2
+  %v801 = add i32 %v800, 3
+  %v802 = add i32 %v801, 4
+  %v803 = add i32 %v802, 5
+  %v804 = add i32 %v803, 6
+  %v805 = add i32 %v804, 0
+  %v806 = add i32 %v805, 1
+  %v807 = add i32 %v806, 2
+  %v808 = add i32 %v807, 3
+  %v809 = add i32 %v808, 4
+  %v810 = add i32 %v809, 5
+  %v811 = add i32 %v810, 6
+  %v812 = add i32 %v811, 0
+  %v813 = add i32 %v812, 1
+  %v814 = add i32 %v813, 2
+  %v815 = add i32 %v814, 3
+  %v816 = add i32 %v815, 4
+  %v817 = add i32 %v816, 5
+  %v818 = add i32 %v817, 6
+  %v819 = add i32 %v818, 0
+  %v820 = add i32 %v819, 1
+  %v821 = add i32 %v820, 2
+  %v822 = add i32 %v821, 3
+  %v823 = add i32 %v822, 4
+  %v824 = add i32 %v823, 5
+  %v825 = add i32 %v824, 6
+  %v826 = add i32 %v825, 0
+  %v827 = add i32 %v826, 1
+  %v828 = add i32 %v827, 2
+  %v829 = add i32 %v828, 3
+  %v830 = add i32 %v829, 4
+  %v831 = add i32 %v830, 5
+  %v832 = add i32 %v831, 6
+  %v833 = add i32 %v832, 0
+  %v834 = add i32 %v833, 1
+  %v835 = add i32 %v834, 2
+  %v836 = add i32 %v835, 3
+  %v837 = add i32 %v836, 4
+  %v838 = add i32 %v837, 5
+  %v839 = add i32 %v838, 6
+  %v840 = add i32 %v839, 0
+  %v841 = add i32 %v840, 1
+  %v842 = add i32 %v841, 2
+  %v843 = add i32 %v842, 3
+  %v844 = add i32 %v843, 4
+  %v845 = add i32 %v844, 5
+  %v846 = add i32 %v845, 6
+  %v847 = add i32 %v846, 0
+  %v848 = add i32 %v847, 1
+  %v849 = add i32 %v848, 2
+  %v850 = add i32 %v849, 3
+  %v851 = add i32 %v850, 4
+  %v852 = add i32 %v851, 5
+  %v853 = add i32 %v852, 6
+  %v854 = add i32 %v853, 0
+  %v855 = add i32 %v854, 1
+  %v856 = add i32 %v855, 2
+  %v857 = add i32 %v856, 3
+  %v858 = add i32 %v857, 4
+  %v859 = add i32 %v858, 5
+  %v860 = add i32 %v859, 6
+  %v861 = add i32 %v860, 0
+  %v862 = add i32 %v861, 1
+  %v863 = add i32 %v862, 2
+  %v864 = add i32 %v863, 3
+  %v865 = add i32 %v864, 4
+  %v866 = add i32 %v865, 5
+  %v867 = add i32 %v866, 6
+  %v868 = add i32 %v867, 0
+  %v869 = add i32 %v868, 1
+  %v870 = add i32 %v869, 2
+  %v871 = add i32 %v870, 3
+  %v872 = add i32 %v871, 4
+  %v873 = add i32 %v872, 5
+  %v874 = add i32 %v873, 6
+  %v875 = add i32 %v874, 0
+  %v876 = add i32 %v875, 1
+  %v877 = add i32 %v876, 2
+  %v878 = add i32 %v877, 3
+  %v879 = add i32 %v878, 4
+  %v880 = add i32 %v879, 5
+  %v881 = add i32 %v880, 6
+  %v882 = add i32 %v881, 0
+  %v883 = add i32 %v882, 1
+  %v884 = add i32 %v883, 2
+  %v885 = add i32 %v884, 3
+  %v886 = add i32 %v885, 4
+  %v887 = add i32 %v886, 5
+  %v888 = add i32 %v887, 6
+  %v889 = add i32 %v888, 0
+  %v890 = add i32 %v889, 1
+  %v891 = add i32 %v890, 2
+  %v892 = add i32 %v891, 3
+  %v893 = add i32 %v892, 4
+  %v894 = add i32 %v893, 5
+  %v895 = add i32 %v894, 6
+  %v896 = add i32 %v895, 0
+  %v897 = add i32 %v896, 1
+  %v898 = add i32 %v897, 2
+  %v899 = add i32 %v898, 3
+  %v900 = add i32 %v899, 4
+  %v901 = add i32 %v900, 5
+  %v902 = add i32 %v901, 6
+  %v903 = add i32 %v902, 0
+  %v904 = add i32 %v903, 1
+  %v905 = add i32 %v904, 2
+  %v906 = add i32 %v905, 3
+  %v907 = add i32 %v906, 4
+  %v908 = add i32 %v907, 5
+  %v909 = add i32 %v908, 6
+  %v910 = add i32 %v909, 0
+  %v911 = add i32 %v910, 1
+  %v912 = add i32 %v911, 2
+  %v913 = add i32 %v912, 3
+  %v914 = add i32 %v913, 4
+  %v915 = add i32 %v914, 5
+  %v916 = add i32 %v915, 6
+  %v917 = add i32 %v916, 0
+  %v918 = add i32 %v917, 1
+  %v919 = add i32 %v918, 2
+  %v920 = add i32 %v919, 3
+  %v921 = add i32 %v920, 4
+  %v922 = add i32 %v921, 5
+  %v923 = add i32 %v922, 6
+  %v924 = add i32 %v923, 0
+  %v925 = add i32 %v924, 1
+  %v926 = add i32 %v925, 2
+  %v927 = add i32 %v926, 3
+  %v928 = add i32 %v927, 4
+  %v929 = add i32 %v928, 5
+  %v930 = add i32 %v929, 6
+  %v931 = add i32 %v930, 0
+  %v932 = add i32 %v931, 1
+  %v933 = add i32 %v932, 2
+  %v934 = add i32 %v933, 3
+  %v935 = add i32 %v934, 4
+  %v936 = add i32 %v935, 5
+  %v937 = add i32 %v936, 6
+  %v938 = add i32 %v937, 0
+  %v939 = add i32 %v938, 1
+  %v940 = add i32 %v939, 2
+  %v941 = add i32 %v940, 3
+  %v942 = add i32 %v941, 4
+  %v943 = add i32 %v942, 5
+  %v944 = add i32 %v943, 6
+  %v945 = add i32 %v944, 0
+  %v946 = add i32 %v945, 1
+  %v947 = add i32 %v946, 2
+  %v948 = add i32 %v947, 3
+  %v949 = add i32 %v948, 4
+  %v950 = add i32 %v949, 5
+  %v951 = add i32 %v950, 6
+  %v952 = add i32 %v951, 0
+  %v953 = add i32 %v952, 1
+  %v954 = add i32 %v953, 2
+  %v955 = add i32 %v954, 3
+  %v956 = add i32 %v955, 4
+  %v957 = add i32 %v956, 5
+  %v958 = add i32 %v957, 6
+  %v959 = add i32 %v958, 0
+  %v960 = add i32 %v959, 1
+  %v961 = add i32 %v960, 2
+  %v962 = add i32 %v961, 3
+  %v963 = add i32 %v962, 4
+  %v964 = add i32 %v963, 5
+  %v965 = add i32 %v964, 6
+  %v966 = add i32 %v965, 0
+  %v967 = add i32 %v966, 1
+  %v968 = add i32 %v967, 2
+  %v969 = add i32 %v968, 3
+  %v970 = add i32 %v969, 4
+  %v971 = add i32 %v970, 5
+  %v972 = add i32 %v971, 6
+  %v973 = add i32 %v972, 0
+  %v974 = add i32 %v973, 1
+  %v975 = add i32 %v974, 2
+  %v976 = add i32 %v975, 3
+  %v977 = add i32 %v976, 4
+  %v978 = add i32 %v977, 5
+  %v979 = add i32 %v978, 6
+  %v980 = add i32 %v979, 0
+  %v981 = add i32 %v980, 1
+  %v982 = add i32 %v981, 2
+  %v983 = add i32 %v982, 3
+  %v984 = add i32 %v983, 4
+  %v985 = add i32 %v984, 5
+  %v986 = add i32 %v985, 6
+  %v987 = add i32 %v986, 0
+  %v988 = add i32 %v987, 1
+  %v989 = add i32 %v988, 2
+  %v990 = add i32 %v989, 3
+  %v991 = add i32 %v990, 4
+  %v992 = add i32 %v991, 5
+  %v993 = add i32 %v992, 6
+  %v994 = add i32 %v993, 0
+  %v995 = add i32 %v994, 1
+  %v996 = add i32 %v995, 2
+  %v997 = add i32 %v996, 3
+  %v998 = add i32 %v997, 4
+  %v999 = add i32 %v998, 5
+  %v1000 = add i32 %v999, 6
+  %v1001 = add i32 %v1000, 0
+  %v1002 = add i32 %v1001, 1
+  %v1003 = add i32 %v1002, 2
+  %v1004 = add i32 %v1003, 3
+  %v1005 = add i32 %v1004, 4
+  %v1006 = add i32 %v1005, 5
+  %v1007 = add i32 %v1006, 6
+  %v1008 = add i32 %v1007, 0
+  %v1009 = add i32 %v1008, 1
+  %v1010 = add i32 %v1009, 2
+  %v1011 = add i32 %v1010, 3
+  %v1012 = add i32 %v1011, 4
+  %v1013 = add i32 %v1012, 5
+  %v1014 = add i32 %v1013, 6
+  %v1015 = add i32 %v1014, 0
+  %v1016 = add i32 %v1015, 1
+  %v1017 = add i32 %v1016, 2
+  %v1018 = add i32 %v1017, 3
+  %v1019 = add i32 %v1018, 4
+  %v1020 = add i32 %v1019, 5
+  %v1021 = add i32 %v1020, 6
+  %v1022 = add i32 %v1021, 0
+  %v1023 = add i32 %v1022, 1
+  %v1024 = add i32 %v1023, 2
+  %v1025 = add i32 %v1024, 3
+  %v1026 = add i32 %v1025, 4
+  %v1027 = add i32 %v1026, 5
+  %v1028 = add i32 %v1027, 6
+  %v1029 = add i32 %v1028, 0
+  %v1030 = add i32 %v1029, 1
+  %v1031 = add i32 %v1030, 2
+  %v1032 = add i32 %v1031, 3
+  %v1033 = add i32 %v1032, 4
+  %v1034 = add i32 %v1033, 5
+  %v1035 = add i32 %v1034, 6
+  %v1036 = add i32 %v1035, 0
+  %v1037 = add i32 %v1036, 1
+  %v1038 = add i32 %v1037, 2
+  %v1039 = add i32 %v1038, 3
+  %v1040 = add i32 %v1039, 4
+  %v1041 = add i32 %v1040, 5
+  %v1042 = add i32 %v1041, 6
+  %v1043 = add i32 %v1042, 0
+  %v1044 = add i32 %v1043, 1
+  %v1045 = add i32 %v1044, 2
+  %v1046 = add i32 %v1045, 3
+  %v1047 = add i32 %v1046, 4
+  %v1048 = add i32 %v1047, 5
+  %v1049 = add i32 %v1048, 6
+  %v1050 = add i32 %v1049, 0
+  %v1051 = add i32 %v1050, 1
+  %v1052 = add i32 %v1051, 2
+  %v1053 = add i32 %v1052, 3
+  %v1054 = add i32 %v1053, 4
+  %v1055 = add i32 %v1054, 5
+  %v1056 = add i32 %v1055, 6
+  %v1057 = add i32 %v1056, 0
+  %v1058 = add i32 %v1057, 1
+  %v1059 = add i32 %v1058, 2
+  %v1060 = add i32 %v1059, 3
+  %v1061 = add i32 %v1060, 4
+  %v1062 = add i32 %v1061, 5
+  %v1063 = add i32 %v1062, 6
+  %v1064 = add i32 %v1063, 0
+  %v1065 = add i32 %v1064, 1
+  %v1066 = add i32 %v1065, 2
+  %v1067 = add i32 %v1066, 3
+  %v1068 = add i32 %v1067, 4
+  %v1069 = add i32 %v1068, 5
+  %v1070 = add i32 %v1069, 6
+  %v1071 = add i32 %v1070, 0
+  %v1072 = add i32 %v1071, 1
+  %v1073 = add i32 %v1072, 2
+  %v1074 = add i32 %v1073, 3
+  %v1075 = add i32 %v1074, 4
+  %v1076 = add i32 %v1075, 5
+  %v1077 = add i32 %v1076, 6
+  %v1078 = add i32 %v1077, 0
+  %v1079 = add i32 %v1078, 1
+  %v1080 = add i32 %v1079, 2
+  %v1081 = add i32 %v1080, 3
+  %v1082 = add i32 %v1081, 4
+  %v1083 = add i32 %v1082, 5
+  %v1084 = add i32 %v1083, 6
+  %v1085 = add i32 %v1084, 0
+  %v1086 = add i32 %v1085, 1
+  %v1087 = add i32 %v1086, 2
+  %v1088 = add i32 %v1087, 3
+  %v1089 = add i32 %v1088, 4
+  %v1090 = add i32 %v1089, 5
+  %v1091 = add i32 %v1090, 6
+  %v1092 = add i32 %v1091, 0
+  %v1093 = add i32 %v1092, 1
+  %v1094 = add i32 %v1093, 2
+  %v1095 = add i32 %v1094, 3
+  %v1096 = add i32 %v1095, 4
+  %v1097 = add i32 %v1096, 5
+  %v1098 = add i32 %v1097, 6
+  %v1099 = add i32 %v1098, 0
+  %v1100 = add i32 %v1099, 1
+  %v1101 = add i32 %v1100, 2
+  %v1102 = add i32 %v1101, 3
+  %v1103 = add i32 %v1102, 4
+  %v1104 = add i32 %v1103, 5
+  %v1105 = add i32 %v1104, 6
+  %v1106 = add i32 %v1105, 0
+  %v1107 = add i32 %v1106, 1
+  %v1108 = add i32 %v1107, 2
+  %v1109 = add i32 %v1108, 3
+  %v1110 = add i32 %v1109, 4
+  %v1111 = add i32 %v1110, 5
+  %v1112 = add i32 %v1111, 6
+  %v1113 = add i32 %v1112, 0
+  %v1114 = add i32 %v1113, 1
+  %v1115 = add i32 %v1114, 2
+  %v1116 = add i32 %v1115, 3
+  %v1117 = add i32 %v1116, 4
+  %v1118 = add i32 %v1117, 5
+  %v1119 = add i32 %v1118, 6
+  %v1120 = add i32 %v1119, 0
+  %v1121 = add i32 %v1120, 1
+  %v1122 = add i32 %v1121, 2
+  %v1123 = add i32 %v1122, 3
+  %v1124 = add i32 %v1123, 4
+  %v1125 = add i32 %v1124, 5
+  %v1126 = add i32 %v1125, 6
+  %v1127 = add i32 %v1126, 0
+  %v1128 = add i32 %v1127, 1
+  %v1129 = add i32 %v1128, 2
+  %v1130 = add i32 %v1129, 3
+  %v1131 = add i32 %v1130, 4
+  %v1132 = add i32 %v1131, 5
+  %v1133 = add i32 %v1132, 6
+  %v1134 = add i32 %v1133, 0
+  %v1135 = add i32 %v1134, 1
+  %v1136 = add i32 %v1135, 2
+  %v1137 = add i32 %v1136, 3
+  %v1138 = add i32 %v1137, 4
+  %v1139 = add i32 %v1138, 5
+  %v1140 = add i32 %v1139, 6
+  %v1141 = add i32 %v1140, 0
+  %v1142 = add i32 %v1141, 1
+  %v1143 = add i32 %v1142, 2
+  %v1144 = add i32 %v1143, 3
+  %v1145 = add i32 %v1144, 4
+  %v1146 = add i32 %v1145, 5
+  %v1147 = add i32 %v1146, 6
+  %v1148 = add i32 %v1147, 0
+  %v1149 = add i32 %v1148, 1
+  %v1150 = add i32 %v1149, 2
+  %v1151 = add i32 %v1150, 3
+  %v1152 = add i32 %v1151, 4
+  %v1153 = add i32 %v1152, 5
+  %v1154 = add i32 %v1153, 6
+  %v1155 = add i32 %v1154, 0
+  %v1156 = add i32 %v1155, 1
+  %v1157 = add i32 %v1156, 2
+  %v1158 = add i32 %v1157, 3
+  %v1159 = add i32 %v1158, 4
+  %v1160 = add i32 %v1159, 5
+  %v1161 = add i32 %v1160, 6
+  %v1162 = add i32 %v1161, 0
+  %v1163 = add i32 %v1162, 1
+  %v1164 = add i32 %v1163, 2
+  %v1165 = add i32 %v1164, 3
+  %v1166 = add i32 %v1165, 4
+  %v1167 = add i32 %v1166, 5
+  %v1168 = add i32 %v1167, 6
+  %v1169 = add i32 %v1168, 0
+  %v1170 = add i32 %v1169, 1
+  %v1171 = add i32 %v1170, 2
+  %v1172 = add i32 %v1171, 3
+  %v1173 = add i32 %v1172, 4
+  %v1174 = add i32 %v1173, 5
+  %v1175 = add i32 %v1174, 6
+  %v1176 = add i32 %v1175, 0
+  %v1177 = add i32 %v1176, 1
+  %v1178 = add i32 %v1177, 2
+  %v1179 = add i32 %v1178, 3
+  %v1180 = add i32 %v1179, 4
+  %v1181 = add i32 %v1180, 5
+  %v1182 = add i32 %v1181, 6
+  %v1183 = add i32 %v1182, 0
+  %v1184 = add i32 %v1183, 1
+  %v1185 = add i32 %v1184, 2
+  %v1186 = add i32 %v1185, 3
+  %v1187 = add i32 %v1186, 4
+  %v1188 = add i32 %v1187, 5
+  %v1189 = add i32 %v1188, 6
+  %v1190 = add i32 %v1189, 0
+  %v1191 = add i32 %v1190, 1
+  %v1192 = add i32 %v1191, 2
+  %v1193 = add i32 %v1192, 3
+  %v1194 = add i32 %v1193, 4
+  %v1195 = add i32 %v1194, 5
+  %v1196 = add i32 %v1195, 6
+  %v1197 = add i32 %v1196, 0
+  %v1198 = add i32 %v1197, 1
+  %v1199 = add i32 %v1198, 2
+  %v1200 = add i32 %v1199, 3
+  %v1201 = add i32 %v1200, 4
+  %v1202 = add i32 %v1201, 5
+  %v1203 = add i32 %v1202, 6
+  %v1204 = add i32 %v1203, 0
+  %v1205 = add i32 %v1204, 1
+  %v1206 = add i32 %v1205, 2
+  %v1207 = add i32 %v1206, 3
+  %v1208 = add i32 %v1207, 4
+  %v1209 = add i32 %v1208, 5
+  %v1210 = add i32 %v1209, 6
+  %v1211 = add i32 %v1210, 0
+  %v1212 = add i32 %v1211, 1
+  %v1213 = add i32 %v1212, 2
+  %v1214 = add i32 %v1213, 3
+  %v1215 = add i32 %v1214, 4
+  %v1216 = add i32 %v1215, 5
+  %v1217 = add i32 %v1216, 6
+  %v1218 = add i32 %v1217, 0
+  %v1219 = add i32 %v1218, 1
+  %v1220 = add i32 %v1219, 2
+  %v1221 = add i32 %v1220, 3
+  %v1222 = add i32 %v1221, 4
+  %v1223 = add i32 %v1222, 5
+  %v1224 = add i32 %v1223, 6
+  %v1225 = add i32 %v1224, 0
+  %v1226 = add i32 %v1225, 1
+  %v1227 = add i32 %v1226, 2
+  %v1228 = add i32 %v1227, 3
+  %v1229 = add i32 %v1228, 4
+  %v1230 = add i32 %v1229, 5
+  %v1231 = add i32 %v1230, 6
+  %v1232 = add i32 %v1231, 0
+  %v1233 = add i32 %v1232, 1
+  %v1234 = add i32 %v1233, 2
+  %v1235 = add i32 %v1234, 3
+  %v1236 = add i32 %v1235, 4
+  %v1237 = add i32 %v1236, 5
+  %v1238 = add i32 %v1237, 6
+  %v1239 = add i32 %v1238, 0
+  %v1240 = add i32 %v1239, 1
+  %v1241 = add i32 %v1240, 2
+  %v1242 = add i32 %v1241, 3
+  %v1243 = add i32 %v1242, 4
+  %v1244 = add i32 %v1243, 5
+  %v1245 = add i32 %v1244, 6
+  %v1246 = add i32 %v1245, 0
+  %v1247 = add i32 %v1246, 1
+  %v1248 = add i32 %v1247, 2
+  %v1249 = add i32 %v1248, 3
+  %v1250 = add i32 %v1249, 4
+  %v1251 = add i32 %v1250, 5
+  %v1252 = add i32 %v1251, 6
+  %v1253 = add i32 %v1252, 0
+  %v1254 = add i32 %v1253, 1
+  %v1255 = add i32 %v1254, 2
+  %v1256 = add i32 %v1255, 3
+  %v1257 = add i32 %v1256, 4
+  %v1258 = add i32 %v1257, 5
+  %v1259 = add i32 %v1258, 6
+  %v1260 = add i32 %v1259, 0
+  %v1261 = add i32 %v1260, 1
+  %v1262 = add i32 %v1261, 2
+  %v1263 = add i32 %v1262, 3
+  %v1264 = add i32 %v1263, 4
+  %v1265 = add i32 %v1264, 5
+  %v1266 = add i32 %v1265, 6
+  %v1267 = add i32 %v1266, 0
+  %v1268 = add i32 %v1267, 1
+  %v1269 = add i32 %v1268, 2
+  %v1270 = add i32 %v1269, 3
+  %v1271 = add i32 %v1270, 4
+  %v1272 = add i32 %v1271, 5
+  %v1273 = add i32 %v1272, 6
+  %v1274 = add i32 %v1273, 0
+  %v1275 = add i32 %v1274, 1
+  %v1276 = add i32 %v1275, 2
+  %v1277 = add i32 %v1276, 3
+  %v1278 = add i32 %v1277, 4
+  %v1279 = add i32 %v1278, 5
+  %v1280 = add i32 %v1279, 6
+  %v1281 = add i32 %v1280, 0
+  %v1282 = add i32 %v1281, 1
+  %v1283 = add i32 %v1282, 2
+  %v1284 = add i32 %v1283, 3
+  %v1285 = add i32 %v1284, 4
+  %v1286 = add i32 %v1285, 5
+  %v1287 = add i32 %v1286, 6
+  %v1288 = add i32 %v1287, 0
+  %v1289 = add i32 %v1288, 1
+  %v1290 = add i32 %v1289, 2
+  %v1291 = add i32 %v1290, 3
+  %v1292 = add i32 %v1291, 4
+  %v1293 = add i32 %v1292, 5
+  %v1294 = add i32 %v1293, 6
+  %v1295 = add i32 %v1294, 0
+  %v1296 = add i32 %v1295, 1
+  %v1297 = add i32 %v1296, 2
+  %v1298 = add i32 %v1297, 3
+  %v1299 = add i32 %v1298, 4
+  %v1300 = add i32 %v1299, 5
+  %v1301 = add i32 %v1300, 6
+  %v1302 = add i32 %v1301, 0
+  %v1303 = add i32 %v1302, 1
+  %v1304 = add i32 %v1303, 2
+  %v1305 = add i32 %v1304, 3
+  %v1306 = add i32 %v1305, 4
+  %v1307 = add i32 %v1306, 5
+  %v1308 = add i32 %v1307, 6
+  %v1309 = add i32 %v1308, 0
+  %v1310 = add i32 %v1309, 1
+  %v1311 = add i32 %v1310, 2
+  %v1312 = add i32 %v1311, 3
+  %v1313 = add i32 %v1312, 4
+  %v1314 = add i32 %v1313, 5
+  %v1315 = add i32 %v1314, 6
+  %v1316 = add i32 %v1315, 0
+  %v1317 = add i32 %v1316, 1
+  %v1318 = add i32 %v1317, 2
+  %v1319 = add i32 %v1318, 3
+  %v1320 = add i32 %v1319, 4
+  %v1321 = add i32 %v1320, 5
+  %v1322 = add i32 %v1321, 6
+  %v1323 = add i32 %v1322, 0
+  %v1324 = add i32 %v1323, 1
+  %v1325 = add i32 %v1324, 2
+  %v1326 = add i32 %v1325, 3
+  %v1327 = add i32 %v1326, 4
+  %v1328 = add i32 %v1327, 5
+  %v1329 = add i32 %v1328, 6
+  %v1330 = add i32 %v1329, 0
+  %v1331 = add i32 %v1330, 1
+  %v1332 = add i32 %v1331, 2
+  %v1333 = add i32 %v1332, 3
+  %v1334 = add i32 %v1333, 4
+  %v1335 = add i32 %v1334, 5
+  %v1336 = add i32 %v1335, 6
+  %v1337 = add i32 %v1336, 0
+  %v1338 = add i32 %v1337, 1
+  %v1339 = add i32 %v1338, 2
+  %v1340 = add i32 %v1339, 3
+  %v1341 = add i32 %v1340, 4
+  %v1342 = add i32 %v1341, 5
+  %v1343 = add i32 %v1342, 6
+  %v1344 = add i32 %v1343, 0
+  %v1345 = add i32 %v1344, 1
+  %v1346 = add i32 %v1345, 2
+  %v1347 = add i32 %v1346, 3
+  %v1348 = add i32 %v1347, 4
+  %v1349 = add i32 %v1348, 5
+  %v1350 = add i32 %v1349, 6
+  %v1351 = add i32 %v1350, 0
+  %v1352 = add i32 %v1351, 1
+  %v1353 = add i32 %v1352, 2
+  %v1354 = add i32 %v1353, 3
+  %v1355 = add i32 %v1354, 4
+  %v1356 = add i32 %v1355, 5
+  %v1357 = add i32 %v1356, 6
+  %v1358 = add i32 %v1357, 0
+  %v1359 = add i32 %v1358, 1
+  %v1360 = add i32 %v1359, 2
+  %v1361 = add i32 %v1360, 3
+  %v1362 = add i32 %v1361, 4
+  %v1363 = add i32 %v1362, 5
+  %v1364 = add i32 %v1363, 6
+  %v1365 = add i32 %v1364, 0
+  %v1366 = add i32 %v1365, 1
+  %v1367 = add i32 %v1366, 2
+  %v1368 = add i32 %v1367, 3
+  %v1369 = add i32 %v1368, 4
+  %v1370 = add i32 %v1369, 5
+  %v1371 = add i32 %v1370, 6
+  %v1372 = add i32 %v1371, 0
+  %v1373 = add i32 %v1372, 1
+  %v1374 = add i32 %v1373, 2
+  %v1375 = add i32 %v1374, 3
+  %v1376 = add i32 %v1375, 4
+  %v1377 = add i32 %v1376, 5
+  %v1378 = add i32 %v1377, 6
+  %v1379 = add i32 %v1378, 0
+  %v1380 = add i32 %v1379, 1
+  %v1381 = add i32 %v1380, 2
+  %v1382 = add i32 %v1381, 3
+  %v1383 = add i32 %v1382, 4
+  %v1384 = add i32 %v1383, 5
+  %v1385 = add i32 %v1384, 6
+  %v1386 = add i32 %v1385, 0
+  %v1387 = add i32 %v1386, 1
+  %v1388 = add i32 %v1387, 2
+  %v1389 = add i32 %v1388, 3
+  %v1390 = add i32 %v1389, 4
+  %v1391 = add i32 %v1390, 5
+  %v1392 = add i32 %v1391, 6
+  %v1393 = add i32 %v1392, 0
+  %v1394 = add i32 %v1393, 1
+  %v1395 = add i32 %v1394, 2
+  %v1396 = add i32 %v1395, 3
+  %v1397 = add i32 %v1396, 4
+  %v1398 = add i32 %v1397, 5
+  %v1399 = add i32 %v1398, 6
+  %v1400 = add i32 %v1399, 0
+  %v1401 = add i32 %v1400, 1
+  %v1402 = add i32 %v1401, 2
+  %v1403 = add i32 %v1402, 3
+  %v1404 = add i32 %v1403, 4
+  %v1405 = add i32 %v1404, 5
+  %v1406 = add i32 %v1405, 6
+  %v1407 = add i32 %v1406, 0
+  %v1408 = add i32 %v1407, 1
+  %v1409 = add i32 %v1408, 2
+  %v1410 = add i32 %v1409, 3
+  %v1411 = add i32 %v1410, 4
+  %v1412 = add i32 %v1411, 5
+  %v1413 = add i32 %v1412, 6
+  %v1414 = add i32 %v1413, 0
+  %v1415 = add i32 %v1414, 1
+  %v1416 = add i32 %v1415, 2
+  %v1417 = add i32 %v1416, 3
+  %v1418 = add i32 %v1417, 4
+  %v1419 = add i32 %v1418, 5
+  %v1420 = add i32 %v1419, 6
+  %v1421 = add i32 %v1420, 0
+  %v1422 = add i32 %v1421, 1
+  %v1423 = add i32 %v1422, 2
+  %v1424 = add i32 %v1423, 3
+  %v1425 = add i32 %v1424, 4
+  %v1426 = add i32 %v1425, 5
+  %v1427 = add i32 %v1426, 6
+  %v1428 = add i32 %v1427, 0
+  %v1429 = add i32 %v1428, 1
+  %v1430 = add i32 %v1429, 2
+  %v1431 = add i32 %v1430, 3
+  %v1432 = add i32 %v1431, 4
+  %v1433 = add i32 %v1432, 5
+  %v1434 = add i32 %v1433, 6
+  %v1435 = add i32 %v1434, 0
+  %v1436 = add i32 %v1435, 1
+  %v1437 = add i32 %v1436, 2
+  %v1438 = add i32 %v1437, 3
+  %v1439 = add i32 %v1438, 4
+  %v1440 = add i32 %v1439, 5
+  %v1441 = add i32 %v1440, 6
+  %v1442 = add i32 %v1441, 0
+  %v1443 = add i32 %v1442, 1
+  %v1444 = add i32 %v1443, 2
+  %v1445 = add i32 %v1444, 3
+  %v1446 = add i32 %v1445, 4
+  %v1447 = add i32 %v1446, 5
+  %v1448 = add i32 %v1447, 6
+  %v1449 = add i32 %v1448, 0
+  %v1450 = add i32 %v1449, 1
+  %v1451 = add i32 %v1450, 2
+  %v1452 = add i32 %v1451, 3
+  %v1453 = add i32 %v1452, 4
+  %v1454 = add i32 %v1453, 5
+  %v1455 = add i32 %v1454, 6
+  %v1456 = add i32 %v1455, 0
+  %v1457 = add i32 %v1456, 1
+  %v1458 = add i32 %v1457, 2
+  %v1459 = add i32 %v1458, 3
+  %v1460 = add i32 %v1459, 4
+  %v1461 = add i32 %v1460, 5
+  %v1462 = add i32 %v1461, 6
+  %v1463 = add i32 %v1462, 0
+  %v1464 = add i32 %v1463, 1
+  %v1465 = add i32 %v1464, 2
+  %v1466 = add i32 %v1465, 3
+  %v1467 = add i32 %v1466, 4
+  %v1468 = add i32 %v1467, 5
+  %v1469 = add i32 %v1468, 6
+  %v1470 = add i32 %v1469, 0
+  %v1471 = add i32 %v1470, 1
+  %v1472 = add i32 %v1471, 2
+  %v1473 = add i32 %v1472, 3
+  %v1474 = add i32 %v1473, 4
+  %v1475 = add i32 %v1474, 5
+  %v1476 = add i32 %v1475, 6
+  %v1477 = add i32 %v1476, 0
+  %v1478 = add i32 %v1477, 1
+  %v1479 = add i32 %v1478, 2
+  %v1480 = add i32 %v1479, 3
+  %v1481 = add i32 %v1480, 4
+  %v1482 = add i32 %v1481, 5
+  %v1483 = add i32 %v1482, 6
+  %v1484 = add i32 %v1483, 0
+  %v1485 = add i32 %v1484, 1
+  %v1486 = add i32 %v1485, 2
+  %v1487 = add i32 %v1486, 3
+  %v1488 = add i32 %v1487, 4
+  %v1489 = add i32 %v1488, 5
+  %v1490 = add i32 %v1489, 6
+  %v1491 = add i32 %v1490, 0
+  %v1492 = add i32 %v1491, 1
+  %v1493 = add i32 %v1492, 2
+  %v1494 = add i32 %v1493, 3
+  %v1495 = add i32 %v1494, 4
+  %v1496 = add i32 %v1495, 5
+  %v1497 = add i32 %v1496, 6
+  %v1498 = add i32 %v1497, 0
+  %v1499 = add i32 %v1498, 1
+  %v1500 = add i32 %v1499, 2
+  %v1501 = add i32 %v1500, 3
+  %v1502 = add i32 %v1501, 4
+  %v1503 = add i32 %v1502, 5
+  %v1504 = add i32 %v1503, 6
+  %v1505 = add i32 %v1504, 0
+  %v1506 = add i32 %v1505, 1
+  %v1507 = add i32 %v1506, 2
+  %v1508 = add i32 %v1507, 3
+  %v1509 = add i32 %v1508, 4
+  %v1510 = add i32 %v1509, 5
+  %v1511 = add i32 %v1510, 6
+  %v1512 = add i32 %v1511, 0
+  %v1513 = add i32 %v1512, 1
+  %v1514 = add i32 %v1513, 2
+  %v1515 = add i32 %v1514, 3
+  %v1516 = add i32 %v1515, 4
+  %v1517 = add i32 %v1516, 5
+  %v1518 = add i32 %v1517, 6
+  %v1519 = add i32 %v1518, 0
+  %v1520 = add i32 %v1519, 1
+  %v1521 = add i32 %v1520, 2
+  %v1522 = add i32 %v1521, 3
+  %v1523 = add i32 %v1522, 4
+  %v1524 = add i32 %v1523, 5
+  %v1525 = add i32 %v1524, 6
+  %v1526 = add i32 %v1525, 0
+  %v1527 = add i32 %v1526, 1
+  %v1528 = add i32 %v1527, 2
+  %v1529 = add i32 %v1528, 3
+  %v1530 = add i32 %v1529, 4
+  %v1531 = add i32 %v1530, 5
+  %v1532 = add i32 %v1531, 6
+  %v1533 = add i32 %v1532, 0
+  %v1534 = add i32 %v1533, 1
+  %v1535 = add i32 %v1534, 2
+  %v1536 = add i32 %v1535, 3
+  %v1537 = add i32 %v1536, 4
+  %v1538 = add i32 %v1537, 5
+  %v1539 = add i32 %v1538, 6
+  %v1540 = add i32 %v1539, 0
+  %v1541 = add i32 %v1540, 1
+  %v1542 = add i32 %v1541, 2
+  %v1543 = add i32 %v1542, 3
+  %v1544 = add i32 %v1543, 4
+  %v1545 = add i32 %v1544, 5
+  %v1546 = add i32 %v1545, 6
+  %v1547 = add i32 %v1546, 0
+  %v1548 = add i32 %v1547, 1
+  %v1549 = add i32 %v1548, 2
+  %v1550 = add i32 %v1549, 3
+  %v1551 = add i32 %v1550, 4
+  %v1552 = add i32 %v1551, 5
+  %v1553 = add i32 %v1552, 6
+  %v1554 = add i32 %v1553, 0
+  %v1555 = add i32 %v1554, 1
+  %v1556 = add i32 %v1555, 2
+  %v1557 = add i32 %v1556, 3
+  %v1558 = add i32 %v1557, 4
+  %v1559 = add i32 %v1558, 5
+  %v1560 = add i32 %v1559, 6
+  %v1561 = add i32 %v1560, 0
+  %v1562 = add i32 %v1561, 1
+  %v1563 = add i32 %v1562, 2
+  %v1564 = add i32 %v1563, 3
+  %v1565 = add i32 %v1564, 4
+  %v1566 = add i32 %v1565, 5
+  %v1567 = add i32 %v1566, 6
+  %v1568 = add i32 %v1567, 0
+  %v1569 = add i32 %v1568, 1
+  %v1570 = add i32 %v1569, 2
+  %v1571 = add i32 %v1570, 3
+  %v1572 = add i32 %v1571, 4
+  %v1573 = add i32 %v1572, 5
+  %v1574 = add i32 %v1573, 6
+  %v1575 = add i32 %v1574, 0
+  %v1576 = add i32 %v1575, 1
+  %v1577 = add i32 %v1576, 2
+  %v1578 = add i32 %v1577, 3
+  %v1579 = add i32 %v1578, 4
+  %v1580 = add i32 %v1579, 5
+  %v1581 = add i32 %v1580, 6
+  %v1582 = add i32 %v1581, 0
+  %v1583 = add i32 %v1582, 1
+  %v1584 = add i32 %v1583, 2
+  %v1585 = add i32 %v1584, 3
+  %v1586 = add i32 %v1585, 4
+  %v1587 = add i32 %v1586, 5
+  %v1588 = add i32 %v1587, 6
+  %v1589 = add i32 %v1588, 0
+  %v1590 = add i32 %v1589, 1
+  %v1591 = add i32 %v1590, 2
+  %v1592 = add i32 %v1591, 3
+  %v1593 = add i32 %v1592, 4
+  %v1594 = add i32 %v1593, 5
+  %v1595 = add i32 %v1594, 6
+  %v1596 = add i32 %v1595, 0
+  %v1597 = add i32 %v1596, 1
+  %v1598 = add i32 %v1597, 2
+  %v1599 = add i32 %v1598, 3
+  %v1600 = add i32 %v1599, 4
+  %v1601 = add i32 %v1600, 5
+  %v1602 = add i32 %v1601, 6
+  %v1603 = add i32 %v1602, 0
+  %v1604 = add i32 %v1603, 1
+  %v1605 = add i32 %v1604, 2
+  %v1606 = add i32 %v1605, 3
+  %v1607 = add i32 %v1606, 4
+  %v1608 = add i32 %v1607, 5
+  %v1609 = add i32 %v1608, 6
+  %v1610 = add i32 %v1609, 0
+  %v1611 = add i32 %v1610, 1
+  %v1612 = add i32 %v1611, 2
+  %v1613 = add i32 %v1612, 3
+  %v1614 = add i32 %v1613, 4
+  %v1615 = add i32 %v1614, 5
+  %v1616 = add i32 %v1615, 6
+  %v1617 = add i32 %v1616, 0
+  %v1618 = add i32 %v1617, 1
+  %v1619 = add i32 %v1618, 2
+  %v1620 = add i32 %v1619, 3
+  %v1621 = add i32 %v1620, 4
+  %v1622 = add i32 %v1621, 5
+  %v1623 = add i32 %v1622, 6
+  %v1624 = add i32 %v1623, 0
+  %v1625 = add i32 %v1624, 1
+  %v1626 = add i32 %v1625, 2
+  %v1627 = add i32 %v1626, 3
+  %v1628 = add i32 %v1627, 4
+  %v1629 = add i32 %v1628, 5
+  %v1630 = add i32 %v1629, 6
+  %v1631 = add i32 %v1630, 0
+  %v1632 = add i32 %v1631, 1
+  %v1633 = add i32 %v1632, 2
+  %v1634 = add i32 %v1633, 3
+  %v1635 = add i32 %v1634, 4
+  %v1636 = add i32 %v1635, 5
+  %v1637 = add i32 %v1636, 6
+  %v1638 = add i32 %v1637, 0
+  %v1639 = add i32 %v1638, 1
+  %v1640 = add i32 %v1639, 2
+  %v1641 = add i32 %v1640, 3
+  %v1642 = add i32 %v1641, 4
+  %v1643 = add i32 %v1642, 5
+  %v1644 = add i32 %v1643, 6
+  %v1645 = add i32 %v1644, 0
+  %v1646 = add i32 %v1645, 1
+  %v1647 = add i32 %v1646, 2
+  %v1648 = add i32 %v1647, 3
+  %v1649 = add i32 %v1648, 4
+  %v1650 = add i32 %v1649, 5
+  %v1651 = add i32 %v1650, 6
+  %v1652 = add i32 %v1651, 0
+  %v1653 = add i32 %v1652, 1
+  %v1654 = add i32 %v1653, 2
+  %v1655 = add i32 %v1654, 3
+  %v1656 = add i32 %v1655, 4
+  %v1657 = add i32 %v1656, 5
+  %v1658 = add i32 %v1657, 6
+  %v1659 = add i32 %v1658, 0
+  %v1660 = add i32 %v1659, 1
+  %v1661 = add i32 %v1660, 2
+  %v1662 = add i32 %v1661, 3
+  %v1663 = add i32 %v1662, 4
+  %v1664 = add i32 %v1663, 5
+  %v1665 = add i32 %v1664, 6
+  %v1666 = add i32 %v1665, 0
+  %v1667 = add i32 %v1666, 1
+  %v1668 = add i32 %v1667, 2
+  %v1669 = add i32 %v1668, 3
+  %v1670 = add i32 %v1669, 4
+  %v1671 = add i32 %v1670, 5
+  %v1672 = add i32 %v1671, 6
+  %v1673 = add i32 %v1672, 0
+  %v1674 = add i32 %v1673, 1
+  %v1675 = add i32 %v1674, 2
+  %v1676 = add i32 %v1675, 3
+  %v1677 = add i32 %v1676, 4
+  %v1678 = add i32 %v1677, 5
+  %v1679 = add i32 %v1678, 6
+  %v1680 = add i32 %v1679, 0
+  %v1681 = add i32 %v1680, 1
+  %v1682 = add i32 %v1681, 2
+  %v1683 = add i32 %v1682, 3
+  %v1684 = add i32 %v1683, 4
+  %v1685 = add i32 %v1684, 5
+  %v1686 = add i32 %v1685, 6
+  %v1687 = add i32 %v1686, 0
+  %v1688 = add i32 %v1687, 1
+  %v1689 = add i32 %v1688, 2
+  %v1690 = add i32 %v1689, 3
+  %v1691 = add i32 %v1690, 4
+  %v1692 = add i32 %v1691, 5
+  %v1693 = add i32 %v1692, 6
+  %v1694 = add i32 %v1693, 0
+  %v1695 = add i32 %v1694, 1
+  %v1696 = add i32 %v1695, 2
+  %v1697 = add i32 %v1696, 3
+  %v1698 = add i32 %v1697, 4
+  %v1699 = add i32 %v1698, 5
+  %v1700 = add i32 %v1699, 6
+  %v1701 = add i32 %v1700, 0
+  %v1702 = add i32 %v1701, 1
+  %v1703 = add i32 %v1702, 2
+  %v1704 = add i32 %v1703, 3
+  %v1705 = add i32 %v1704, 4
+  %v1706 = add i32 %v1705, 5
+  %v1707 = add i32 %v1706, 6
+  %v1708 = add i32 %v1707, 0
+  %v1709 = add i32 %v1708, 1
+  %v1710 = add i32 %v1709, 2
+  %v1711 = add i32 %v1710, 3
+  %v1712 = add i32 %v1711, 4
+  %v1713 = add i32 %v1712, 5
+  %v1714 = add i32 %v1713, 6
+  %v1715 = add i32 %v1714, 0
+  %v1716 = add i32 %v1715, 1
+  %v1717 = add i32 %v1716, 2
+  %v1718 = add i32 %v1717, 3
+  %v1719 = add i32 %v1718, 4
+  %v1720 = add i32 %v1719, 5
+  %v1721 = add i32 %v1720, 6
+  %v1722 = add i32 %v1721, 0
+  %v1723 = add i32 %v1722, 1
+  %v1724 = add i32 %v1723, 2
+  %v1725 = add i32 %v1724, 3
+  %v1726 = add i32 %v1725, 4
+  %v1727 = add i32 %v1726, 5
+  %v1728 = add i32 %v1727, 6
+  %v1729 = add i32 %v1728, 0
+  %v1730 = add i32 %v1729, 1
+  %v1731 = add i32 %v1730, 2
+  %v1732 = add i32 %v1731, 3
+  %v1733 = add i32 %v1732, 4
+  %v1734 = add i32 %v1733, 5
+  %v1735 = add i32 %v1734, 6
+  %v1736 = add i32 %v1735, 0
+  %v1737 = add i32 %v1736, 1
+  %v1738 = add i32 %v1737, 2
+  %v1739 = add i32 %v1738, 3
+  %v1740 = add i32 %v1739, 4
+  %v1741 = add i32 %v1740, 5
+  %v1742 = add i32 %v1741, 6
+  %v1743 = add i32 %v1742, 0
+  %v1744 = add i32 %v1743, 1
+  %v1745 = add i32 %v1744, 2
+  %v1746 = add i32 %v1745, 3
+  %v1747 = add i32 %v1746, 4
+  %v1748 = add i32 %v1747, 5
+  %v1749 = add i32 %v1748, 6
+  %v1750 = add i32 %v1749, 0
+  %v1751 = add i32 %v1750, 1
+  %v1752 = add i32 %v1751, 2
+  %v1753 = add i32 %v1752, 3
+  %v1754 = add i32 %v1753, 4
+  %v1755 = add i32 %v1754, 5
+  %v1756 = add i32 %v1755, 6
+  %v1757 = add i32 %v1756, 0
+  %v1758 = add i32 %v1757, 1
+  %v1759 = add i32 %v1758, 2
+  %v1760 = add i32 %v1759, 3
+  %v1761 = add i32 %v1760, 4
+  %v1762 = add i32 %v1761, 5
+  %v1763 = add i32 %v1762, 6
+  %v1764 = add i32 %v1763, 0
+  %v1765 = add i32 %v1764, 1
+  %v1766 = add i32 %v1765, 2
+  %v1767 = add i32 %v1766, 3
+  %v1768 = add i32 %v1767, 4
+  %v1769 = add i32 %v1768, 5
+  %v1770 = add i32 %v1769, 6
+  %v1771 = add i32 %v1770, 0
+  %v1772 = add i32 %v1771, 1
+  %v1773 = add i32 %v1772, 2
+  %v1774 = add i32 %v1773, 3
+  %v1775 = add i32 %v1774, 4
+  %v1776 = add i32 %v1775, 5
+  %v1777 = add i32 %v1776, 6
+  %v1778 = add i32 %v1777, 0
+  %v1779 = add i32 %v1778, 1
+  %v1780 = add i32 %v1779, 2
+  %v1781 = add i32 %v1780, 3
+  %v1782 = add i32 %v1781, 4
+  %v1783 = add i32 %v1782, 5
+  %v1784 = add i32 %v1783, 6
+  %v1785 = add i32 %v1784, 0
+  %v1786 = add i32 %v1785, 1
+  %v1787 = add i32 %v1786, 2
+  %v1788 = add i32 %v1787, 3
+  %v1789 = add i32 %v1788, 4
+  %v1790 = add i32 %v1789, 5
+  %v1791 = add i32 %v1790, 6
+  %v1792 = add i32 %v1791, 0
+  %v1793 = add i32 %v1792, 1
+  %v1794 = add i32 %v1793, 2
+  %v1795 = add i32 %v1794, 3
+  %v1796 = add i32 %v1795, 4
+  %v1797 = add i32 %v1796, 5
+  %v1798 = add i32 %v1797, 6
+  %v1799 = add i32 %v1798, 0
+  %v1800 = add i32 %v1799, 1
+  %v1801 = add i32 %v1800, 2
+  %v1802 = add i32 %v1801, 3
+  %v1803 = add i32 %v1802, 4
+  %v1804 = add i32 %v1803, 5
+  %v1805 = add i32 %v1804, 6
+  %v1806 = add i32 %v1805, 0
+  %v1807 = add i32 %v1806, 1
+  %v1808 = add i32 %v1807, 2
+  %v1809 = add i32 %v1808, 3
+  %v1810 = add i32 %v1809, 4
+  %v1811 = add i32 %v1810, 5
+  %v1812 = add i32 %v1811, 6
+  %v1813 = add i32 %v1812, 0
+  %v1814 = add i32 %v1813, 1
+  %v1815 = add i32 %v1814, 2
+  %v1816 = add i32 %v1815, 3
+  %v1817 = add i32 %v1816, 4
+  %v1818 = add i32 %v1817, 5
+  %v1819 = add i32 %v1818, 6
+  %v1820 = add i32 %v1819, 0
+  %v1821 = add i32 %v1820, 1
+  %v1822 = add i32 %v1821, 2
+  %v1823 = add i32 %v1822, 3
+  %v1824 = add i32 %v1823, 4
+  %v1825 = add i32 %v1824, 5
+  %v1826 = add i32 %v1825, 6
+  %v1827 = add i32 %v1826, 0
+  %v1828 = add i32 %v1827, 1
+  %v1829 = add i32 %v1828, 2
+  %v1830 = add i32 %v1829, 3
+  %v1831 = add i32 %v1830, 4
+  %v1832 = add i32 %v1831, 5
+  %v1833 = add i32 %v1832, 6
+  %v1834 = add i32 %v1833, 0
+  %v1835 = add i32 %v1834, 1
+  %v1836 = add i32 %v1835, 2
+  %v1837 = add i32 %v1836, 3
+  %v1838 = add i32 %v1837, 4
+  %v1839 = add i32 %v1838, 5
+  %v1840 = add i32 %v1839, 6
+  %v1841 = add i32 %v1840, 0
+  %v1842 = add i32 %v1841, 1
+  %v1843 = add i32 %v1842, 2
+  %v1844 = add i32 %v1843, 3
+  %v1845 = add i32 %v1844, 4
+  %v1846 = add i32 %v1845, 5
+  %v1847 = add i32 %v1846, 6
+  %v1848 = add i32 %v1847, 0
+  %v1849 = add i32 %v1848, 1
+  %v1850 = add i32 %v1849, 2
+  %v1851 = add i32 %v1850, 3
+  %v1852 = add i32 %v1851, 4
+  %v1853 = add i32 %v1852, 5
+  %v1854 = add i32 %v1853, 6
+  %v1855 = add i32 %v1854, 0
+  %v1856 = add i32 %v1855, 1
+  %v1857 = add i32 %v1856, 2
+  %v1858 = add i32 %v1857, 3
+  %v1859 = add i32 %v1858, 4
+  %v1860 = add i32 %v1859, 5
+  %v1861 = add i32 %v1860, 6
+  %v1862 = add i32 %v1861, 0
+  %v1863 = add i32 %v1862, 1
+  %v1864 = add i32 %v1863, 2
+  %v1865 = add i32 %v1864, 3
+  %v1866 = add i32 %v1865, 4
+  %v1867 = add i32 %v1866, 5
+  %v1868 = add i32 %v1867, 6
+  %v1869 = add i32 %v1868, 0
+  %v1870 = add i32 %v1869, 1
+  %v1871 = add i32 %v1870, 2
+  %v1872 = add i32 %v1871, 3
+  %v1873 = add i32 %v1872, 4
+  %v1874 = add i32 %v1873, 5
+  %v1875 = add i32 %v1874, 6
+  %v1876 = add i32 %v1875, 0
+  %v1877 = add i32 %v1876, 1
+  %v1878 = add i32 %v1877, 2
+  %v1879 = add i32 %v1878, 3
+  %v1880 = add i32 %v1879, 4
+  %v1881 = add i32 %v1880, 5
+  %v1882 = add i32 %v1881, 6
+  %v1883 = add i32 %v1882, 0
+  %v1884 = add i32 %v1883, 1
+  %v1885 = add i32 %v1884, 2
+  %v1886 = add i32 %v1885, 3
+  %v1887 = add i32 %v1886, 4
+  %v1888 = add i32 %v1887, 5
+  %v1889 = add i32 %v1888, 6
+  %v1890 = add i32 %v1889, 0
+  %v1891 = add i32 %v1890, 1
+  %v1892 = add i32 %v1891, 2
+  %v1893 = add i32 %v1892, 3
+  %v1894 = add i32 %v1893, 4
+  %v1895 = add i32 %v1894, 5
+  %v1896 = add i32 %v1895, 6
+  %v1897 = add i32 %v1896, 0
+  %v1898 = add i32 %v1897, 1
+  %v1899 = add i32 %v1898, 2
+  %v1900 = add i32 %v1899, 3
+  %v1901 = add i32 %v1900, 4
+  %v1902 = add i32 %v1901, 5
+  %v1903 = add i32 %v1902, 6
+  %v1904 = add i32 %v1903, 0
+  %v1905 = add i32 %v1904, 1
+  %v1906 = add i32 %v1905, 2
+  %v1907 = add i32 %v1906, 3
+  %v1908 = add i32 %v1907, 4
+  %v1909 = add i32 %v1908, 5
+  %v1910 = add i32 %v1909, 6
+  %v1911 = add i32 %v1910, 0
+  %v1912 = add i32 %v1911, 1
+  %v1913 = add i32 %v1912, 2
+  %v1914 = add i32 %v1913, 3
+  %v1915 = add i32 %v1914, 4
+  %v1916 = add i32 %v1915, 5
+  %v1917 = add i32 %v1916, 6
+  %v1918 = add i32 %v1917, 0
+  %v1919 = add i32 %v1918, 1
+  %v1920 = add i32 %v1919, 2
+  %v1921 = add i32 %v1920, 3
+  %v1922 = add i32 %v1921, 4
+  %v1923 = add i32 %v1922, 5
+  %v1924 = add i32 %v1923, 6
+  %v1925 = add i32 %v1924, 0
+  %v1926 = add i32 %v1925, 1
+  %v1927 = add i32 %v1926, 2
+  %v1928 = add i32 %v1927, 3
+  %v1929 = add i32 %v1928, 4
+  %v1930 = add i32 %v1929, 5
+  %v1931 = add i32 %v1930, 6
+  %v1932 = add i32 %v1931, 0
+  %v1933 = add i32 %v1932, 1
+  %v1934 = add i32 %v1933, 2
+  %v1935 = add i32 %v1934, 3
+  %v1936 = add i32 %v1935, 4
+  %v1937 = add i32 %v1936, 5
+  %v1938 = add i32 %v1937, 6
+  %v1939 = add i32 %v1938, 0
+  %v1940 = add i32 %v1939, 1
+  %v1941 = add i32 %v1940, 2
+  %v1942 = add i32 %v1941, 3
+  %v1943 = add i32 %v1942, 4
+  %v1944 = add i32 %v1943, 5
+  %v1945 = add i32 %v1944, 6
+  %v1946 = add i32 %v1945, 0
+  %v1947 = add i32 %v1946, 1
+  %v1948 = add i32 %v1947, 2
+  %v1949 = add i32 %v1948, 3
+  %v1950 = add i32 %v1949, 4
+  %v1951 = add i32 %v1950, 5
+  %v1952 = add i32 %v1951, 6
+  %v1953 = add i32 %v1952, 0
+  %v1954 = add i32 %v1953, 1
+  %v1955 = add i32 %v1954, 2
+  %v1956 = add i32 %v1955, 3
+  %v1957 = add i32 %v1956, 4
+  %v1958 = add i32 %v1957, 5
+  %v1959 = add i32 %v1958, 6
+  %v1960 = add i32 %v1959, 0
+  %v1961 = add i32 %v1960, 1
+  %v1962 = add i32 %v1961, 2
+  %v1963 = add i32 %v1962, 3
+  %v1964 = add i32 %v1963, 4
+  %v1965 = add i32 %v1964, 5
+  %v1966 = add i32 %v1965, 6
+  %v1967 = add i32 %v1966, 0
+  %v1968 = add i32 %v1967, 1
+  %v1969 = add i32 %v1968, 2
+  %v1970 = add i32 %v1969, 3
+  %v1971 = add i32 %v1970, 4
+  %v1972 = add i32 %v1971, 5
+  %v1973 = add i32 %v1972, 6
+  %v1974 = add i32 %v1973, 0
+  %v1975 = add i32 %v1974, 1
+  %v1976 = add i32 %v1975, 2
+  %v1977 = add i32 %v1976, 3
+  %v1978 = add i32 %v1977, 4
+  %v1979 = add i32 %v1978, 5
+  %v1980 = add i32 %v1979, 6
+  %v1981 = add i32 %v1980, 0
+  %v1982 = add i32 %v1981, 1
+  %v1983 = add i32 %v1982, 2
+  %v1984 = add i32 %v1983, 3
+  %v1985 = add i32 %v1984, 4
+  %v1986 = add i32 %v1985, 5
+  %v1987 = add i32 %v1986, 6
+  %v1988 = add i32 %v1987, 0
+  %v1989 = add i32 %v1988, 1
+  %v1990 = add i32 %v1989, 2
+  %v1991 = add i32 %v1990, 3
+  %v1992 = add i32 %v1991, 4
+  %v1993 = add i32 %v1992, 5
+  %v1994 = add i32 %v1993, 6
+  %v1995 = add i32 %v1994, 0
+  %v1996 = add i32 %v1995, 1
+  %v1997 = add i32 %v1996, 2
+  %v1998 = add i32 %v1997, 3
+  %v1999 = add i32 %v1998, 4
+  %v2000 = add i32 %v1999, 5
+  %v2001 = add i32 %v2000, 6
+  %v2002 = add i32 %v2001, 0
+  %v2003 = add i32 %v2002, 1
+  %v2004 = add i32 %v2003, 2
+  %v2005 = add i32 %v2004, 3
+  %v2006 = add i32 %v2005, 4
+  %v2007 = add i32 %v2006, 5
+  %v2008 = add i32 %v2007, 6
+  %v2009 = add i32 %v2008, 0
+  %v2010 = add i32 %v2009, 1
+  %v2011 = add i32 %v2010, 2
+  %v2012 = add i32 %v2011, 3
+  %v2013 = add i32 %v2012, 4
+  %v2014 = add i32 %v2013, 5
+  %v2015 = add i32 %v2014, 6
+  %v2016 = add i32 %v2015, 0
+  %v2017 = add i32 %v2016, 1
+  %v2018 = add i32 %v2017, 2
+  %v2019 = add i32 %v2018, 3
+  %v2020 = add i32 %v2019, 4
+  %v2021 = add i32 %v2020, 5
+  %v2022 = add i32 %v2021, 6
+  %v2023 = add i32 %v2022, 0
+  %v2024 = add i32 %v2023, 1
+  %v2025 = add i32 %v2024, 2
+  %v2026 = add i32 %v2025, 3
+  %v2027 = add i32 %v2026, 4
+  %v2028 = add i32 %v2027, 5
+  %v2029 = add i32 %v2028, 6
+  %v2030 = add i32 %v2029, 0
+  %v2031 = add i32 %v2030, 1
+  %v2032 = add i32 %v2031, 2
+  %v2033 = add i32 %v2032, 3
+  %v2034 = add i32 %v2033, 4
+  %v2035 = add i32 %v2034, 5
+  %v2036 = add i32 %v2035, 6
+  %v2037 = add i32 %v2036, 0
+  %v2038 = add i32 %v2037, 1
+  %v2039 = add i32 %v2038, 2
+  %v2040 = add i32 %v2039, 3
+  %v2041 = add i32 %v2040, 4
+  %v2042 = add i32 %v2041, 5
+  %v2043 = add i32 %v2042, 6
+  %v2044 = add i32 %v2043, 0
+  %v2045 = add i32 %v2044, 1
+  %v2046 = add i32 %v2045, 2
+  %v2047 = add i32 %v2046, 3
+  %v2048 = add i32 %v2047, 4
+  %v2049 = add i32 %v2048, 5
+  %v2050 = add i32 %v2049, 6
+  %v2051 = add i32 %v2050, 0
+  %v2052 = add i32 %v2051, 1
+  %v2053 = add i32 %v2052, 2
+  %v2054 = add i32 %v2053, 3
+  %v2055 = add i32 %v2054, 4
+  %v2056 = add i32 %v2055, 5
+  %v2057 = add i32 %v2056, 6
+  %v2058 = add i32 %v2057, 0
+  %v2059 = add i32 %v2058, 1
+  %v2060 = add i32 %v2059, 2
+  %v2061 = add i32 %v2060, 3
+  %v2062 = add i32 %v2061, 4
+  %v2063 = add i32 %v2062, 5
+  %v2064 = add i32 %v2063, 6
+  %v2065 = add i32 %v2064, 0
+  %v2066 = add i32 %v2065, 1
+  %v2067 = add i32 %v2066, 2
+  %v2068 = add i32 %v2067, 3
+  %v2069 = add i32 %v2068, 4
+  %v2070 = add i32 %v2069, 5
+  %v2071 = add i32 %v2070, 6
+  %v2072 = add i32 %v2071, 0
+  %v2073 = add i32 %v2072, 1
+  %v2074 = add i32 %v2073, 2
+  %v2075 = add i32 %v2074, 3
+  %v2076 = add i32 %v2075, 4
+  %v2077 = add i32 %v2076, 5
+  %v2078 = add i32 %v2077, 6
+  %v2079 = add i32 %v2078, 0
+  %v2080 = add i32 %v2079, 1
+  %v2081 = add i32 %v2080, 2
+  %v2082 = add i32 %v2081, 3
+  %v2083 = add i32 %v2082, 4
+  %v2084 = add i32 %v2083, 5
+  %v2085 = add i32 %v2084, 6
+  %v2086 = add i32 %v2085, 0
+  %v2087 = add i32 %v2086, 1
+  %v2088 = add i32 %v2087, 2
+  %v2089 = add i32 %v2088, 3
+  %v2090 = add i32 %v2089, 4
+  %v2091 = add i32 %v2090, 5
+  %v2092 = add i32 %v2091, 6
+  %v2093 = add i32 %v2092, 0
+  %v2094 = add i32 %v2093, 1
+  %v2095 = add i32 %v2094, 2
+  %v2096 = add i32 %v2095, 3
+  %v2097 = add i32 %v2096, 4
+  %v2098 = add i32 %v2097, 5
+  %v2099 = add i32 %v2098, 6
+  %v2100 = add i32 %v2099, 0
+  %v2101 = add i32 %v2100, 1
+  %v2102 = add i32 %v2101, 2
+  %v2103 = add i32 %v2102, 3
+  %v2104 = add i32 %v2103, 4
+  %v2105 = add i32 %v2104, 5
+  %v2106 = add i32 %v2105, 6
+  %v2107 = add i32 %v2106, 0
+  %v2108 = add i32 %v2107, 1
+  %v2109 = add i32 %v2108, 2
+  %v2110 = add i32 %v2109, 3
+  %v2111 = add i32 %v2110, 4
+  %v2112 = add i32 %v2111, 5
+  %v2113 = add i32 %v2112, 6
+  %v2114 = add i32 %v2113, 0
+  %v2115 = add i32 %v2114, 1
+  %v2116 = add i32 %v2115, 2
+  %v2117 = add i32 %v2116, 3
+  %v2118 = add i32 %v2117, 4
+  %v2119 = add i32 %v2118, 5
+  %v2120 = add i32 %v2119, 6
+  %v2121 = add i32 %v2120, 0
+  %v2122 = add i32 %v2121, 1
+  %v2123 = add i32 %v2122, 2
+  %v2124 = add i32 %v2123, 3
+  %v2125 = add i32 %v2124, 4
+  %v2126 = add i32 %v2125, 5
+  %v2127 = add i32 %v2126, 6
+  %v2128 = add i32 %v2127, 0
+  %v2129 = add i32 %v2128, 1
+  %v2130 = add i32 %v2129, 2
+  %v2131 = add i32 %v2130, 3
+  %v2132 = add i32 %v2131, 4
+  %v2133 = add i32 %v2132, 5
+  %v2134 = add i32 %v2133, 6
+  %v2135 = add i32 %v2134, 0
+  %v2136 = add i32 %v2135, 1
+  %v2137 = add i32 %v2136, 2
+  %v2138 = add i32 %v2137, 3
+  %v2139 = add i32 %v2138, 4
+  %v2140 = add i32 %v2139, 5
+  %v2141 = add i32 %v2140, 6
+  %v2142 = add i32 %v2141, 0
+  %v2143 = add i32 %v2142, 1
+  %v2144 = add i32 %v2143, 2
+  %v2145 = add i32 %v2144, 3
+  %v2146 = add i32 %v2145, 4
+  %v2147 = add i32 %v2146, 5
+  %v2148 = add i32 %v2147, 6
+  %v2149 = add i32 %v2148, 0
+  %v2150 = add i32 %v2149, 1
+  %v2151 = add i32 %v2150, 2
+  %v2152 = add i32 %v2151, 3
+  %v2153 = add i32 %v2152, 4
+  %v2154 = add i32 %v2153, 5
+  %v2155 = add i32 %v2154, 6
+  %v2156 = add i32 %v2155, 0
+  %v2157 = add i32 %v2156, 1
+  %v2158 = add i32 %v2157, 2
+  %v2159 = add i32 %v2158, 3
+  %v2160 = add i32 %v2159, 4
+  %v2161 = add i32 %v2160, 5
+  %v2162 = add i32 %v2161, 6
+  %v2163 = add i32 %v2162, 0
+  %v2164 = add i32 %v2163, 1
+  %v2165 = add i32 %v2164, 2
+  %v2166 = add i32 %v2165, 3
+  %v2167 = add i32 %v2166, 4
+  %v2168 = add i32 %v2167, 5
+  %v2169 = add i32 %v2168, 6
+  %v2170 = add i32 %v2169, 0
+  %v2171 = add i32 %v2170, 1
+  %v2172 = add i32 %v2171, 2
+  %v2173 = add i32 %v2172, 3
+  %v2174 = add i32 %v2173, 4
+  %v2175 = add i32 %v2174, 5
+  %v2176 = add i32 %v2175, 6
+  %v2177 = add i32 %v2176, 0
+  %v2178 = add i32 %v2177, 1
+  %v2179 = add i32 %v2178, 2
+  %v2180 = add i32 %v2179, 3
+  %v2181 = add i32 %v2180, 4
+  %v2182 = add i32 %v2181, 5
+  %v2183 = add i32 %v2182, 6
+  %v2184 = add i32 %v2183, 0
+  %v2185 = add i32 %v2184, 1
+  %v2186 = add i32 %v2185, 2
+  %v2187 = add i32 %v2186, 3
+  %v2188 = add i32 %v2187, 4
+  %v2189 = add i32 %v2188, 5
+  %v2190 = add i32 %v2189, 6
+  %v2191 = add i32 %v2190, 0
+  %v2192 = add i32 %v2191, 1
+  %v2193 = add i32 %v2192, 2
+  %v2194 = add i32 %v2193, 3
+  %v2195 = add i32 %v2194, 4
+  %v2196 = add i32 %v2195, 5
+  %v2197 = add i32 %v2196, 6
+  %v2198 = add i32 %v2197, 0
+  %v2199 = add i32 %v2198, 1
+  %v2200 = add i32 %v2199, 2
+  %v2201 = add i32 %v2200, 3
+  %v2202 = add i32 %v2201, 4
+  %v2203 = add i32 %v2202, 5
+  %v2204 = add i32 %v2203, 6
+  %v2205 = add i32 %v2204, 0
+  %v2206 = add i32 %v2205, 1
+  %v2207 = add i32 %v2206, 2
+  %v2208 = add i32 %v2207, 3
+  %v2209 = add i32 %v2208, 4
+  %v2210 = add i32 %v2209, 5
+  %v2211 = add i32 %v2210, 6
+  %v2212 = add i32 %v2211, 0
+  %v2213 = add i32 %v2212, 1
+  %v2214 = add i32 %v2213, 2
+  %v2215 = add i32 %v2214, 3
+  %v2216 = add i32 %v2215, 4
+  %v2217 = add i32 %v2216, 5
+  %v2218 = add i32 %v2217, 6
+  %v2219 = add i32 %v2218, 0
+  %v2220 = add i32 %v2219, 1
+  %v2221 = add i32 %v2220, 2
+  %v2222 = add i32 %v2221, 3
+  %v2223 = add i32 %v2222, 4
+  %v2224 = add i32 %v2223, 5
+  %v2225 = add i32 %v2224, 6
+  %v2226 = add i32 %v2225, 0
+  %v2227 = add i32 %v2226, 1
+  %v2228 = add i32 %v2227, 2
+  %v2229 = add i32 %v2228, 3
+  %v2230 = add i32 %v2229, 4
+  %v2231 = add i32 %v2230, 5
+  %v2232 = add i32 %v2231, 6
+  %v2233 = add i32 %v2232, 0
+  %v2234 = add i32 %v2233, 1
+  %v2235 = add i32 %v2234, 2
+  %v2236 = add i32 %v2235, 3
+  %v2237 = add i32 %v2236, 4
+  %v2238 = add i32 %v2237, 5
+  %v2239 = add i32 %v2238, 6
+  %v2240 = add i32 %v2239, 0
+  %v2241 = add i32 %v2240, 1
+  %v2242 = add i32 %v2241, 2
+  %v2243 = add i32 %v2242, 3
+  %v2244 = add i32 %v2243, 4
+  %v2245 = add i32 %v2244, 5
+  %v2246 = add i32 %v2245, 6
+  %v2247 = add i32 %v2246, 0
+  %v2248 = add i32 %v2247, 1
+  %v2249 = add i32 %v2248, 2
+  %v2250 = add i32 %v2249, 3
+  %v2251 = add i32 %v2250, 4
+  %v2252 = add i32 %v2251, 5
+  %v2253 = add i32 %v2252, 6
+  %v2254 = add i32 %v2253, 0
+  %v2255 = add i32 %v2254, 1
+  %v2256 = add i32 %v2255, 2
+  %v2257 = add i32 %v2256, 3
+  %v2258 = add i32 %v2257, 4
+  %v2259 = add i32 %v2258, 5
+  %v2260 = add i32 %v2259, 6
+  %v2261 = add i32 %v2260, 0
+  %v2262 = add i32 %v2261, 1
+  %v2263 = add i32 %v2262, 2
+  %v2264 = add i32 %v2263, 3
+  %v2265 = add i32 %v2264, 4
+  %v2266 = add i32 %v2265, 5
+  %v2267 = add i32 %v2266, 6
+  %v2268 = add i32 %v2267, 0
+  %v2269 = add i32 %v2268, 1
+  %v2270 = add i32 %v2269, 2
+  %v2271 = add i32 %v2270, 3
+  %v2272 = add i32 %v2271, 4
+  %v2273 = add i32 %v2272, 5
+  %v2274 = add i32 %v2273, 6
+  %v2275 = add i32 %v2274, 0
+  %v2276 = add i32 %v2275, 1
+  %v2277 = add i32 %v2276, 2
+  %v2278 = add i32 %v2277, 3
+  %v2279 = add i32 %v2278, 4
+  %v2280 = add i32 %v2279, 5
+  %v2281 = add i32 %v2280, 6
+  %v2282 = add i32 %v2281, 0
+  %v2283 = add i32 %v2282, 1
+  %v2284 = add i32 %v2283, 2
+  %v2285 = add i32 %v2284, 3
+  %v2286 = add i32 %v2285, 4
+  %v2287 = add i32 %v2286, 5
+  %v2288 = add i32 %v2287, 6
+  %v2289 = add i32 %v2288, 0
+  %v2290 = add i32 %v2289, 1
+  %v2291 = add i32 %v2290, 2
+  %v2292 = add i32 %v2291, 3
+  %v2293 = add i32 %v2292, 4
+  %v2294 = add i32 %v2293, 5
+  %v2295 = add i32 %v2294, 6
+  %v2296 = add i32 %v2295, 0
+  %v2297 = add i32 %v2296, 1
+  %v2298 = add i32 %v2297, 2
+  %v2299 = add i32 %v2298, 3
+  %v2300 = add i32 %v2299, 4
+  %v2301 = add i32 %v2300, 5
+  %v2302 = add i32 %v2301, 6
+  %v2303 = add i32 %v2302, 0
+  %v2304 = add i32 %v2303, 1
+  %v2305 = add i32 %v2304, 2
+  %v2306 = add i32 %v2305, 3
+  %v2307 = add i32 %v2306, 4
+  %v2308 = add i32 %v2307, 5
+  %v2309 = add i32 %v2308, 6
+  %v2310 = add i32 %v2309, 0
+  %v2311 = add i32 %v2310, 1
+  %v2312 = add i32 %v2311, 2
+  %v2313 = add i32 %v2312, 3
+  %v2314 = add i32 %v2313, 4
+  %v2315 = add i32 %v2314, 5
+  %v2316 = add i32 %v2315, 6
+  %v2317 = add i32 %v2316, 0
+  %v2318 = add i32 %v2317, 1
+  %v2319 = add i32 %v2318, 2
+  %v2320 = add i32 %v2319, 3
+  %v2321 = add i32 %v2320, 4
+  %v2322 = add i32 %v2321, 5
+  %v2323 = add i32 %v2322, 6
+  %v2324 = add i32 %v2323, 0
+  %v2325 = add i32 %v2324, 1
+  %v2326 = add i32 %v2325, 2
+  %v2327 = add i32 %v2326, 3
+  %v2328 = add i32 %v2327, 4
+  %v2329 = add i32 %v2328, 5
+  %v2330 = add i32 %v2329, 6
+  %v2331 = add i32 %v2330, 0
+  %v2332 = add i32 %v2331, 1
+  %v2333 = add i32 %v2332, 2
+  %v2334 = add i32 %v2333, 3
+  %v2335 = add i32 %v2334, 4
+  %v2336 = add i32 %v2335, 5
+  %v2337 = add i32 %v2336, 6
+  %v2338 = add i32 %v2337, 0
+  %v2339 = add i32 %v2338, 1
+  %v2340 = add i32 %v2339, 2
+  %v2341 = add i32 %v2340, 3
+  %v2342 = add i32 %v2341, 4
+  %v2343 = add i32 %v2342, 5
+  %v2344 = add i32 %v2343, 6
+  %v2345 = add i32 %v2344, 0
+  %v2346 = add i32 %v2345, 1
+  %v2347 = add i32 %v2346, 2
+  %v2348 = add i32 %v2347, 3
+  %v2349 = add i32 %v2348, 4
+  %v2350 = add i32 %v2349, 5
+  %v2351 = add i32 %v2350, 6
+  %v2352 = add i32 %v2351, 0
+  %v2353 = add i32 %v2352, 1
+  %v2354 = add i32 %v2353, 2
+  %v2355 = add i32 %v2354, 3
+  %v2356 = add i32 %v2355, 4
+  %v2357 = add i32 %v2356, 5
+  %v2358 = add i32 %v2357, 6
+  %v2359 = add i32 %v2358, 0
+  %v2360 = add i32 %v2359, 1
+  %v2361 = add i32 %v2360, 2
+  %v2362 = add i32 %v2361, 3
+  %v2363 = add i32 %v2362, 4
+  %v2364 = add i32 %v2363, 5
+  %v2365 = add i32 %v2364, 6
+  %v2366 = add i32 %v2365, 0
+  %v2367 = add i32 %v2366, 1
+  %v2368 = add i32 %v2367, 2
+  %v2369 = add i32 %v2368, 3
+  %v2370 = add i32 %v2369, 4
+  %v2371 = add i32 %v2370, 5
+  %v2372 = add i32 %v2371, 6
+  %v2373 = add i32 %v2372, 0
+  %v2374 = add i32 %v2373, 1
+  %v2375 = add i32 %v2374, 2
+  %v2376 = add i32 %v2375, 3
+  %v2377 = add i32 %v2376, 4
+  %v2378 = add i32 %v2377, 5
+  %v2379 = add i32 %v2378, 6
+  %v2380 = add i32 %v2379, 0
+  %v2381 = add i32 %v2380, 1
+  %v2382 = add i32 %v2381, 2
+  %v2383 = add i32 %v2382, 3
+  %v2384 = add i32 %v2383, 4
+  %v2385 = add i32 %v2384, 5
+  %v2386 = add i32 %v2385, 6
+  %v2387 = add i32 %v2386, 0
+  %v2388 = add i32 %v2387, 1
+  %v2389 = add i32 %v2388, 2
+  %v2390 = add i32 %v2389, 3
+  %v2391 = add i32 %v2390, 4
+  %v2392 = add i32 %v2391, 5
+  %v2393 = add i32 %v2392, 6
+  %v2394 = add i32 %v2393, 0
+  %v2395 = add i32 %v2394, 1
+  %v2396 = add i32 %v2395, 2
+  %v2397 = add i32 %v2396, 3
+  %v2398 = add i32 %v2397, 4
+  %v2399 = add i32 %v2398, 5
+  %v2400 = add i32 %v2399, 6
+  %v2401 = add i32 %v2400, 0
+  %v2402 = add i32 %v2401, 1
+  %v2403 = add i32 %v2402, 2
+  %v2404 = add i32 %v2403, 3
+  %v2405 = add i32 %v2404, 4
+  %v2406 = add i32 %v2405, 5
+  %v2407 = add i32 %v2406, 6
+  %v2408 = add i32 %v2407, 0
+  %v2409 = add i32 %v2408, 1
+  %v2410 = add i32 %v2409, 2
+  %v2411 = add i32 %v2410, 3
+  %v2412 = add i32 %v2411, 4
+  %v2413 = add i32 %v2412, 5
+  %v2414 = add i32 %v2413, 6
+  %v2415 = add i32 %v2414, 0
+  %v2416 = add i32 %v2415, 1
+  %v2417 = add i32 %v2416, 2
+  %v2418 = add i32 %v2417, 3
+  %v2419 = add i32 %v2418, 4
+  %v2420 = add i32 %v2419, 5
+  %v2421 = add i32 %v2420, 6
+  %v2422 = add i32 %v2421, 0
+  %v2423 = add i32 %v2422, 1
+  %v2424 = add i32 %v2423, 2
+  %v2425 = add i32 %v2424, 3
+  %v2426 = add i32 %v2425, 4
+  %v2427 = add i32 %v2426, 5
+  %v2428 = add i32 %v2427, 6
+  %v2429 = add i32 %v2428, 0
+  %v2430 = add i32 %v2429, 1
+  %v2431 = add i32 %v2430, 2
+  %v2432 = add i32 %v2431, 3
+  %v2433 = add i32 %v2432, 4
+  %v2434 = add i32 %v2433, 5
+  %v2435 = add i32 %v2434, 6
+  %v2436 = add i32 %v2435, 0
+  %v2437 = add i32 %v2436, 1
+  %v2438 = add i32 %v2437, 2
+  %v2439 = add i32 %v2438, 3
+  %v2440 = add i32 %v2439, 4
+  %v2441 = add i32 %v2440, 5
+  %v2442 = add i32 %v2441, 6
+  %v2443 = add i32 %v2442, 0
+  %v2444 = add i32 %v2443, 1
+  %v2445 = add i32 %v2444, 2
+  %v2446 = add i32 %v2445, 3
+  %v2447 = add i32 %v2446, 4
+  %v2448 = add i32 %v2447, 5
+  %v2449 = add i32 %v2448, 6
+  %v2450 = add i32 %v2449, 0
+  %v2451 = add i32 %v2450, 1
+  %v2452 = add i32 %v2451, 2
+  %v2453 = add i32 %v2452, 3
+  %v2454 = add i32 %v2453, 4
+  %v2455 = add i32 %v2454, 5
+  %v2456 = add i32 %v2455, 6
+  %v2457 = add i32 %v2456, 0
+  %v2458 = add i32 %v2457, 1
+  %v2459 = add i32 %v2458, 2
+  %v2460 = add i32 %v2459, 3
+  %v2461 = add i32 %v2460, 4
+  %v2462 = add i32 %v2461, 5
+  %v2463 = add i32 %v2462, 6
+  %v2464 = add i32 %v2463, 0
+  %v2465 = add i32 %v2464, 1
+  %v2466 = add i32 %v2465, 2
+  %v2467 = add i32 %v2466, 3
+  %v2468 = add i32 %v2467, 4
+  %v2469 = add i32 %v2468, 5
+  %v2470 = add i32 %v2469, 6
+  %v2471 = add i32 %v2470, 0
+  %v2472 = add i32 %v2471, 1
+  %v2473 = add i32 %v2472, 2
+  %v2474 = add i32 %v2473, 3
+  %v2475 = add i32 %v2474, 4
+  %v2476 = add i32 %v2475, 5
+  %v2477 = add i32 %v2476, 6
+  %v2478 = add i32 %v2477, 0
+  %v2479 = add i32 %v2478, 1
+  %v2480 = add i32 %v2479, 2
+  %v2481 = add i32 %v2480, 3
+  %v2482 = add i32 %v2481, 4
+  %v2483 = add i32 %v2482, 5
+  %v2484 = add i32 %v2483, 6
+  %v2485 = add i32 %v2484, 0
+  %v2486 = add i32 %v2485, 1
+  %v2487 = add i32 %v2486, 2
+  %v2488 = add i32 %v2487, 3
+  %v2489 = add i32 %v2488, 4
+  %v2490 = add i32 %v2489, 5
+  %v2491 = add i32 %v2490, 6
+  %v2492 = add i32 %v2491, 0
+  %v2493 = add i32 %v2492, 1
+  %v2494 = add i32 %v2493, 2
+  %v2495 = add i32 %v2494, 3
+  %v2496 = add i32 %v2495, 4
+  %v2497 = add i32 %v2496, 5
+  %v2498 = add i32 %v2497, 6
+  %v2499 = add i32 %v2498, 0
+  %v2500 = add i32 %v2499, 1
+  %v2501 = add i32 %v2500, 2
+  %v2502 = add i32 %v2501, 3
+  %v2503 = add i32 %v2502, 4
+  %v2504 = add i32 %v2503, 5
+  %v2505 = add i32 %v2504, 6
+  %v2506 = add i32 %v2505, 0
+  %v2507 = add i32 %v2506, 1
+  %v2508 = add i32 %v2507, 2
+  %v2509 = add i32 %v2508, 3
+  %v2510 = add i32 %v2509, 4
+  %v2511 = add i32 %v2510, 5
+  %v2512 = add i32 %v2511, 6
+  %v2513 = add i32 %v2512, 0
+  %v2514 = add i32 %v2513, 1
+  %v2515 = add i32 %v2514, 2
+  %v2516 = add i32 %v2515, 3
+  %v2517 = add i32 %v2516, 4
+  %v2518 = add i32 %v2517, 5
+  %v2519 = add i32 %v2518, 6
+  %v2520 = add i32 %v2519, 0
+  %v2521 = add i32 %v2520, 1
+  %v2522 = add i32 %v2521, 2
+  %v2523 = add i32 %v2522, 3
+  %v2524 = add i32 %v2523, 4
+  %v2525 = add i32 %v2524, 5
+  %v2526 = add i32 %v2525, 6
+  %v2527 = add i32 %v2526, 0
+  %v2528 = add i32 %v2527, 1
+  %v2529 = add i32 %v2528, 2
+  %v2530 = add i32 %v2529, 3
+  %v2531 = add i32 %v2530, 4
+  %v2532 = add i32 %v2531, 5
+  %v2533 = add i32 %v2532, 6
+  %v2534 = add i32 %v2533, 0
+  %v2535 = add i32 %v2534, 1
+  %v2536 = add i32 %v2535, 2
+  %v2537 = add i32 %v2536, 3
+  %v2538 = add i32 %v2537, 4
+  %v2539 = add i32 %v2538, 5
+  %v2540 = add i32 %v2539, 6
+  %v2541 = add i32 %v2540, 0
+  %v2542 = add i32 %v2541, 1
+  %v2543 = add i32 %v2542, 2
+  %v2544 = add i32 %v2543, 3
+  %v2545 = add i32 %v2544, 4
+  %v2546 = add i32 %v2545, 5
+  %v2547 = add i32 %v2546, 6
+  %v2548 = add i32 %v2547, 0
+  %v2549 = add i32 %v2548, 1
+  %v2550 = add i32 %v2549, 2
+  %v2551 = add i32 %v2550, 3
+  %v2552 = add i32 %v2551, 4
+  %v2553 = add i32 %v2552, 5
+  %v2554 = add i32 %v2553, 6
+  %v2555 = add i32 %v2554, 0
+  %v2556 = add i32 %v2555, 1
+  %v2557 = add i32 %v2556, 2
+  %v2558 = add i32 %v2557, 3
+  %v2559 = add i32 %v2558, 4
+  %v2560 = add i32 %v2559, 5
+  %v2561 = add i32 %v2560, 6
+  %v2562 = add i32 %v2561, 0
+  %v2563 = add i32 %v2562, 1
+  %v2564 = add i32 %v2563, 2
+  %v2565 = add i32 %v2564, 3
+  %v2566 = add i32 %v2565, 4
+  %v2567 = add i32 %v2566, 5
+  %v2568 = add i32 %v2567, 6
+  %v2569 = add i32 %v2568, 0
+  %v2570 = add i32 %v2569, 1
+  %v2571 = add i32 %v2570, 2
+  %v2572 = add i32 %v2571, 3
+  %v2573 = add i32 %v2572, 4
+  %v2574 = add i32 %v2573, 5
+  %v2575 = add i32 %v2574, 6
+  %v2576 = add i32 %v2575, 0
+  %v2577 = add i32 %v2576, 1
+  %v2578 = add i32 %v2577, 2
+  %v2579 = add i32 %v2578, 3
+  %v2580 = add i32 %v2579, 4
+  %v2581 = add i32 %v2580, 5
+  %v2582 = add i32 %v2581, 6
+  %v2583 = add i32 %v2582, 0
+  %v2584 = add i32 %v2583, 1
+  %v2585 = add i32 %v2584, 2
+  %v2586 = add i32 %v2585, 3
+  %v2587 = add i32 %v2586, 4
+  %v2588 = add i32 %v2587, 5
+  %v2589 = add i32 %v2588, 6
+  %v2590 = add i32 %v2589, 0
+  %v2591 = add i32 %v2590, 1
+  %v2592 = add i32 %v2591, 2
+  %v2593 = add i32 %v2592, 3
+  %v2594 = add i32 %v2593, 4
+  %v2595 = add i32 %v2594, 5
+  %v2596 = add i32 %v2595, 6
+  %v2597 = add i32 %v2596, 0
+  %v2598 = add i32 %v2597, 1
+  %v2599 = add i32 %v2598, 2
+  %v2600 = add i32 %v2599, 3
+  %v2601 = add i32 %v2600, 4
+  %v2602 = add i32 %v2601, 5
+  %v2603 = add i32 %v2602, 6
+  %v2604 = add i32 %v2603, 0
+  %v2605 = add i32 %v2604, 1
+  %v2606 = add i32 %v2605, 2
+  %v2607 = add i32 %v2606, 3
+  %v2608 = add i32 %v2607, 4
+  %v2609 = add i32 %v2608, 5
+  %v2610 = add i32 %v2609, 6
+  %v2611 = add i32 %v2610, 0
+  %v2612 = add i32 %v2611, 1
+  %v2613 = add i32 %v2612, 2
+  %v2614 = add i32 %v2613, 3
+  %v2615 = add i32 %v2614, 4
+  %v2616 = add i32 %v2615, 5
+  %v2617 = add i32 %v2616, 6
+  %v2618 = add i32 %v2617, 0
+  %v2619 = add i32 %v2618, 1
+  %v2620 = add i32 %v2619, 2
+  %v2621 = add i32 %v2620, 3
+  %v2622 = add i32 %v2621, 4
+  %v2623 = add i32 %v2622, 5
+  %v2624 = add i32 %v2623, 6
+  %v2625 = add i32 %v2624, 0
+  %v2626 = add i32 %v2625, 1
+  %v2627 = add i32 %v2626, 2
+  %v2628 = add i32 %v2627, 3
+  %v2629 = add i32 %v2628, 4
+  %v2630 = add i32 %v2629, 5
+  %v2631 = add i32 %v2630, 6
+  %v2632 = add i32 %v2631, 0
+  %v2633 = add i32 %v2632, 1
+  %v2634 = add i32 %v2633, 2
+  %v2635 = add i32 %v2634, 3
+  %v2636 = add i32 %v2635, 4
+  %v2637 = add i32 %v2636, 5
+  %v2638 = add i32 %v2637, 6
+  %v2639 = add i32 %v2638, 0
+  %v2640 = add i32 %v2639, 1
+  %v2641 = add i32 %v2640, 2
+  %v2642 = add i32 %v2641, 3
+  %v2643 = add i32 %v2642, 4
+  %v2644 = add i32 %v2643, 5
+  %v2645 = add i32 %v2644, 6
+  %v2646 = add i32 %v2645, 0
+  %v2647 = add i32 %v2646, 1
+  %v2648 = add i32 %v2647, 2
+  %v2649 = add i32 %v2648, 3
+  %v2650 = add i32 %v2649, 4
+  %v2651 = add i32 %v2650, 5
+  %v2652 = add i32 %v2651, 6
+  %v2653 = add i32 %v2652, 0
+  %v2654 = add i32 %v2653, 1
+  %v2655 = add i32 %v2654, 2
+  %v2656 = add i32 %v2655, 3
+  %v2657 = add i32 %v2656, 4
+  %v2658 = add i32 %v2657, 5
+  %v2659 = add i32 %v2658, 6
+  %v2660 = add i32 %v2659, 0
+  %v2661 = add i32 %v2660, 1
+  %v2662 = add i32 %v2661, 2
+  %v2663 = add i32 %v2662, 3
+  %v2664 = add i32 %v2663, 4
+  %v2665 = add i32 %v2664, 5
+  %v2666 = add i32 %v2665, 6
+  %v2667 = add i32 %v2666, 0
+  %v2668 = add i32 %v2667, 1
+  %v2669 = add i32 %v2668, 2
+  %v2670 = add i32 %v2669, 3
+  %v2671 = add i32 %v2670, 4
+  %v2672 = add i32 %v2671, 5
+  %v2673 = add i32 %v2672, 6
+  %v2674 = add i32 %v2673, 0
+  %v2675 = add i32 %v2674, 1
+  %v2676 = add i32 %v2675, 2
+  %v2677 = add i32 %v2676, 3
+  %v2678 = add i32 %v2677, 4
+  %v2679 = add i32 %v2678, 5
+  %v2680 = add i32 %v2679, 6
+  %v2681 = add i32 %v2680, 0
+  %v2682 = add i32 %v2681, 1
+  %v2683 = add i32 %v2682, 2
+  %v2684 = add i32 %v2683, 3
+  %v2685 = add i32 %v2684, 4
+  %v2686 = add i32 %v2685, 5
+  %v2687 = add i32 %v2686, 6
+  %v2688 = add i32 %v2687, 0
+  %v2689 = add i32 %v2688, 1
+  %v2690 = add i32 %v2689, 2
+  %v2691 = add i32 %v2690, 3
+  %v2692 = add i32 %v2691, 4
+  %v2693 = add i32 %v2692, 5
+  %v2694 = add i32 %v2693, 6
+  %v2695 = add i32 %v2694, 0
+  %v2696 = add i32 %v2695, 1
+  %v2697 = add i32 %v2696, 2
+  %v2698 = add i32 %v2697, 3
+  %v2699 = add i32 %v2698, 4
+  %v2700 = add i32 %v2699, 5
+  %v2701 = add i32 %v2700, 6
+  %v2702 = add i32 %v2701, 0
+  %v2703 = add i32 %v2702, 1
+  %v2704 = add i32 %v2703, 2
+  %v2705 = add i32 %v2704, 3
+  %v2706 = add i32 %v2705, 4
+  %v2707 = add i32 %v2706, 5
+  %v2708 = add i32 %v2707, 6
+  %v2709 = add i32 %v2708, 0
+  %v2710 = add i32 %v2709, 1
+  %v2711 = add i32 %v2710, 2
+  %v2712 = add i32 %v2711, 3
+  %v2713 = add i32 %v2712, 4
+  %v2714 = add i32 %v2713, 5
+  %v2715 = add i32 %v2714, 6
+  %v2716 = add i32 %v2715, 0
+  %v2717 = add i32 %v2716, 1
+  %v2718 = add i32 %v2717, 2
+  %v2719 = add i32 %v2718, 3
+  %v2720 = add i32 %v2719, 4
+  %v2721 = add i32 %v2720, 5
+  %v2722 = add i32 %v2721, 6
+  %v2723 = add i32 %v2722, 0
+  %v2724 = add i32 %v2723, 1
+  %v2725 = add i32 %v2724, 2
+  %v2726 = add i32 %v2725, 3
+  %v2727 = add i32 %v2726, 4
+  %v2728 = add i32 %v2727, 5
+  %v2729 = add i32 %v2728, 6
+  %v2730 = add i32 %v2729, 0
+  %v2731 = add i32 %v2730, 1
+  %v2732 = add i32 %v2731, 2
+  %v2733 = add i32 %v2732, 3
+  %v2734 = add i32 %v2733, 4
+  %v2735 = add i32 %v2734, 5
+  %v2736 = add i32 %v2735, 6
+  %v2737 = add i32 %v2736, 0
+  %v2738 = add i32 %v2737, 1
+  %v2739 = add i32 %v2738, 2
+  %v2740 = add i32 %v2739, 3
+  %v2741 = add i32 %v2740, 4
+  %v2742 = add i32 %v2741, 5
+  %v2743 = add i32 %v2742, 6
+  %v2744 = add i32 %v2743, 0
+  %v2745 = add i32 %v2744, 1
+  %v2746 = add i32 %v2745, 2
+  %v2747 = add i32 %v2746, 3
+  %v2748 = add i32 %v2747, 4
+  %v2749 = add i32 %v2748, 5
+  %v2750 = add i32 %v2749, 6
+  %v2751 = add i32 %v2750, 0
+  %v2752 = add i32 %v2751, 1
+  %v2753 = add i32 %v2752, 2
+  %v2754 = add i32 %v2753, 3
+  %v2755 = add i32 %v2754, 4
+  %v2756 = add i32 %v2755, 5
+  %v2757 = add i32 %v2756, 6
+  %v2758 = add i32 %v2757, 0
+  %v2759 = add i32 %v2758, 1
+  %v2760 = add i32 %v2759, 2
+  %v2761 = add i32 %v2760, 3
+  %v2762 = add i32 %v2761, 4
+  %v2763 = add i32 %v2762, 5
+  %v2764 = add i32 %v2763, 6
+  %v2765 = add i32 %v2764, 0
+  %v2766 = add i32 %v2765, 1
+  %v2767 = add i32 %v2766, 2
+  %v2768 = add i32 %v2767, 3
+  %v2769 = add i32 %v2768, 4
+  %v2770 = add i32 %v2769, 5
+  %v2771 = add i32 %v2770, 6
+  %v2772 = add i32 %v2771, 0
+  %v2773 = add i32 %v2772, 1
+  %v2774 = add i32 %v2773, 2
+  %v2775 = add i32 %v2774, 3
+  %v2776 = add i32 %v2775, 4
+  %v2777 = add i32 %v2776, 5
+  %v2778 = add i32 %v2777, 6
+  %v2779 = add i32 %v2778, 0
+  %v2780 = add i32 %v2779, 1
+  %v2781 = add i32 %v2780, 2
+  %v2782 = add i32 %v2781, 3
+  %v2783 = add i32 %v2782, 4
+  %v2784 = add i32 %v2783, 5
+  %v2785 = add i32 %v2784, 6
+  %v2786 = add i32 %v2785, 0
+  %v2787 = add i32 %v2786, 1
+  %v2788 = add i32 %v2787, 2
+  %v2789 = add i32 %v2788, 3
+  %v2790 = add i32 %v2789, 4
+  %v2791 = add i32 %v2790, 5
+  %v2792 = add i32 %v2791, 6
+  %v2793 = add i32 %v2792, 0
+  %v2794 = add i32 %v2793, 1
+  %v2795 = add i32 %v2794, 2
+  %v2796 = add i32 %v2795, 3
+  %v2797 = add i32 %v2796, 4
+  %v2798 = add i32 %v2797, 5
+  %v2799 = add i32 %v2798, 6
+  %v2800 = add i32 %v2799, 0
+  %v2801 = add i32 %v2800, 1
+  %v2802 = add i32 %v2801, 2
+  %v2803 = add i32 %v2802, 3
+  %v2804 = add i32 %v2803, 4
+  %v2805 = add i32 %v2804, 5
+  %v2806 = add i32 %v2805, 6
+  %v2807 = add i32 %v2806, 0
+  %v2808 = add i32 %v2807, 1
+  %v2809 = add i32 %v2808, 2
+  %v2810 = add i32 %v2809, 3
+  %v2811 = add i32 %v2810, 4
+  %v2812 = add i32 %v2811, 5
+  %v2813 = add i32 %v2812, 6
+  %v2814 = add i32 %v2813, 0
+  %v2815 = add i32 %v2814, 1
+  %v2816 = add i32 %v2815, 2
+  %v2817 = add i32 %v2816, 3
+  %v2818 = add i32 %v2817, 4
+  %v2819 = add i32 %v2818, 5
+  %v2820 = add i32 %v2819, 6
+  %v2821 = add i32 %v2820, 0
+  %v2822 = add i32 %v2821, 1
+  %v2823 = add i32 %v2822, 2
+  %v2824 = add i32 %v2823, 3
+  %v2825 = add i32 %v2824, 4
+  %v2826 = add i32 %v2825, 5
+  %v2827 = add i32 %v2826, 6
+  %v2828 = add i32 %v2827, 0
+  %v2829 = add i32 %v2828, 1
+  %v2830 = add i32 %v2829, 2
+  %v2831 = add i32 %v2830, 3
+  %v2832 = add i32 %v2831, 4
+  %v2833 = add i32 %v2832, 5
+  %v2834 = add i32 %v2833, 6
+  %v2835 = add i32 %v2834, 0
+  %v2836 = add i32 %v2835, 1
+  %v2837 = add i32 %v2836, 2
+  %v2838 = add i32 %v2837, 3
+  %v2839 = add i32 %v2838, 4
+  %v2840 = add i32 %v2839, 5
+  %v2841 = add i32 %v2840, 6
+  %v2842 = add i32 %v2841, 0
+  %v2843 = add i32 %v2842, 1
+  %v2844 = add i32 %v2843, 2
+  %v2845 = add i32 %v2844, 3
+  %v2846 = add i32 %v2845, 4
+  %v2847 = add i32 %v2846, 5
+  %v2848 = add i32 %v2847, 6
+  %v2849 = add i32 %v2848, 0
+  %v2850 = add i32 %v2849, 1
+  %v2851 = add i32 %v2850, 2
+  %v2852 = add i32 %v2851, 3
+  %v2853 = add i32 %v2852, 4
+  %v2854 = add i32 %v2853, 5
+  %v2855 = add i32 %v2854, 6
+  %v2856 = add i32 %v2855, 0
+  %v2857 = add i32 %v2856, 1
+  %v2858 = add i32 %v2857, 2
+  %v2859 = add i32 %v2858, 3
+  %v2860 = add i32 %v2859, 4
+  %v2861 = add i32 %v2860, 5
+  %v2862 = add i32 %v2861, 6
+  %v2863 = add i32 %v2862, 0
+  %v2864 = add i32 %v2863, 1
+  %v2865 = add i32 %v2864, 2
+  %v2866 = add i32 %v2865, 3
+  %v2867 = add i32 %v2866, 4
+  %v2868 = add i32 %v2867, 5
+  %v2869 = add i32 %v2868, 6
+  %v2870 = add i32 %v2869, 0
+  %v2871 = add i32 %v2870, 1
+  %v2872 = add i32 %v2871, 2
+  %v2873 = add i32 %v2872, 3
+  %v2874 = add i32 %v2873, 4
+  %v2875 = add i32 %v2874, 5
+  %v2876 = add i32 %v2875, 6
+  %v2877 = add i32 %v2876, 0
+  %v2878 = add i32 %v2877, 1
+  %v2879 = add i32 %v2878, 2
+  %v2880 = add i32 %v2879, 3
+  %v2881 = add i32 %v2880, 4
+  %v2882 = add i32 %v2881, 5
+  %v2883 = add i32 %v2882, 6
+  %v2884 = add i32 %v2883, 0
+  %v2885 = add i32 %v2884, 1
+  %v2886 = add i32 %v2885, 2
+  %v2887 = add i32 %v2886, 3
+  %v2888 = add i32 %v2887, 4
+  %v2889 = add i32 %v2888, 5
+  %v2890 = add i32 %v2889, 6
+  %v2891 = add i32 %v2890, 0
+  %v2892 = add i32 %v2891, 1
+  %v2893 = add i32 %v2892, 2
+  %v2894 = add i32 %v2893, 3
+  %v2895 = add i32 %v2894, 4
+  %v2896 = add i32 %v2895, 5
+  %v2897 = add i32 %v2896, 6
+  %v2898 = add i32 %v2897, 0
+  %v2899 = add i32 %v2898, 1
+  %v2900 = add i32 %v2899, 2
+  %v2901 = add i32 %v2900, 3
+  %v2902 = add i32 %v2901, 4
+  %v2903 = add i32 %v2902, 5
+  %v2904 = add i32 %v2903, 6
+  %v2905 = add i32 %v2904, 0
+  %v2906 = add i32 %v2905, 1
+  %v2907 = add i32 %v2906, 2
+  %v2908 = add i32 %v2907, 3
+  %v2909 = add i32 %v2908, 4
+  %v2910 = add i32 %v2909, 5
+  %v2911 = add i32 %v2910, 6
+  %v2912 = add i32 %v2911, 0
+  %v2913 = add i32 %v2912, 1
+  %v2914 = add i32 %v2913, 2
+  %v2915 = add i32 %v2914, 3
+  %v2916 = add i32 %v2915, 4
+  %v2917 = add i32 %v2916, 5
+  %v2918 = add i32 %v2917, 6
+  %v2919 = add i32 %v2918, 0
+  %v2920 = add i32 %v2919, 1
+  %v2921 = add i32 %v2920, 2
+  %v2922 = add i32 %v2921, 3
+  %v2923 = add i32 %v2922, 4
+  %v2924 = add i32 %v2923, 5
+  %v2925 = add i32 %v2924, 6
+  %v2926 = add i32 %v2925, 0
+  %v2927 = add i32 %v2926, 1
+  %v2928 = add i32 %v2927, 2
+  %v2929 = add i32 %v2928, 3
+  %v2930 = add i32 %v2929, 4
+  %v2931 = add i32 %v2930, 5
+  %v2932 = add i32 %v2931, 6
+  %v2933 = add i32 %v2932, 0
+  %v2934 = add i32 %v2933, 1
+  %v2935 = add i32 %v2934, 2
+  %v2936 = add i32 %v2935, 3
+  %v2937 = add i32 %v2936, 4
+  %v2938 = add i32 %v2937, 5
+  %v2939 = add i32 %v2938, 6
+  %v2940 = add i32 %v2939, 0
+  %v2941 = add i32 %v2940, 1
+  %v2942 = add i32 %v2941, 2
+  %v2943 = add i32 %v2942, 3
+  %v2944 = add i32 %v2943, 4
+  %v2945 = add i32 %v2944, 5
+  %v2946 = add i32 %v2945, 6
+  %v2947 = add i32 %v2946, 0
+  %v2948 = add i32 %v2947, 1
+  %v2949 = add i32 %v2948, 2
+  %v2950 = add i32 %v2949, 3
+  %v2951 = add i32 %v2950, 4
+  %v2952 = add i32 %v2951, 5
+  %v2953 = add i32 %v2952, 6
+  %v2954 = add i32 %v2953, 0
+  %v2955 = add i32 %v2954, 1
+  %v2956 = add i32 %v2955, 2
+  %v2957 = add i32 %v2956, 3
+  %v2958 = add i32 %v2957, 4
+  %v2959 = add i32 %v2958, 5
+  %v2960 = add i32 %v2959, 6
+  %v2961 = add i32 %v2960, 0
+  %v2962 = add i32 %v2961, 1
+  %v2963 = add i32 %v2962, 2
+  %v2964 = add i32 %v2963, 3
+  %v2965 = add i32 %v2964, 4
+  %v2966 = add i32 %v2965, 5
+  %v2967 = add i32 %v2966, 6
+  %v2968 = add i32 %v2967, 0
+  %v2969 = add i32 %v2968, 1
+  %v2970 = add i32 %v2969, 2
+  %v2971 = add i32 %v2970, 3
+  %v2972 = add i32 %v2971, 4
+  %v2973 = add i32 %v2972, 5
+  %v2974 = add i32 %v2973, 6
+  %v2975 = add i32 %v2974, 0
+  %v2976 = add i32 %v2975, 1
+  %v2977 = add i32 %v2976, 2
+  %v2978 = add i32 %v2977, 3
+  %v2979 = add i32 %v2978, 4
+  %v2980 = add i32 %v2979, 5
+  %v2981 = add i32 %v2980, 6
+  %v2982 = add i32 %v2981, 0
+  %v2983 = add i32 %v2982, 1
+  %v2984 = add i32 %v2983, 2
+  %v2985 = add i32 %v2984, 3
+  %v2986 = add i32 %v2985, 4
+  %v2987 = add i32 %v2986, 5
+  %v2988 = add i32 %v2987, 6
+  %v2989 = add i32 %v2988, 0
+  %v2990 = add i32 %v2989, 1
+  %v2991 = add i32 %v2990, 2
+  %v2992 = add i32 %v2991, 3
+  %v2993 = add i32 %v2992, 4
+  %v2994 = add i32 %v2993, 5
+  %v2995 = add i32 %v2994, 6
+  %v2996 = add i32 %v2995, 0
+  %v2997 = add i32 %v2996, 1
+  %v2998 = add i32 %v2997, 2
+  %v2999 = add i32 %v2998, 3
+  %v3000 = add i32 %v2999, 4
+  %v3001 = add i32 %v3000, 5
+  %v3002 = add i32 %v3001, 6
+  %v3003 = add i32 %v3002, 0
+  %v3004 = add i32 %v3003, 1
+  %v3005 = add i32 %v3004, 2
+  %v3006 = add i32 %v3005, 3
+  %v3007 = add i32 %v3006, 4
+  %v3008 = add i32 %v3007, 5
+  %v3009 = add i32 %v3008, 6
+  %v3010 = add i32 %v3009, 0
+  %v3011 = add i32 %v3010, 1
+  %v3012 = add i32 %v3011, 2
+  %v3013 = add i32 %v3012, 3
+  %v3014 = add i32 %v3013, 4
+  %v3015 = add i32 %v3014, 5
+  %v3016 = add i32 %v3015, 6
+  %v3017 = add i32 %v3016, 0
+  %v3018 = add i32 %v3017, 1
+  %v3019 = add i32 %v3018, 2
+  %v3020 = add i32 %v3019, 3
+  %v3021 = add i32 %v3020, 4
+  %v3022 = add i32 %v3021, 5
+  %v3023 = add i32 %v3022, 6
+  %v3024 = add i32 %v3023, 0
+  %v3025 = add i32 %v3024, 1
+  %v3026 = add i32 %v3025, 2
+  %v3027 = add i32 %v3026, 3
+  %v3028 = add i32 %v3027, 4
+  %v3029 = add i32 %v3028, 5
+  %v3030 = add i32 %v3029, 6
+  %v3031 = add i32 %v3030, 0
+  %v3032 = add i32 %v3031, 1
+  %v3033 = add i32 %v3032, 2
+  %v3034 = add i32 %v3033, 3
+  %v3035 = add i32 %v3034, 4
+  %v3036 = add i32 %v3035, 5
+  %v3037 = add i32 %v3036, 6
+  %v3038 = add i32 %v3037, 0
+  %v3039 = add i32 %v3038, 1
+  %v3040 = add i32 %v3039, 2
+  %v3041 = add i32 %v3040, 3
+  %v3042 = add i32 %v3041, 4
+  %v3043 = add i32 %v3042, 5
+  %v3044 = add i32 %v3043, 6
+  %v3045 = add i32 %v3044, 0
+  %v3046 = add i32 %v3045, 1
+  %v3047 = add i32 %v3046, 2
+  %v3048 = add i32 %v3047, 3
+  %v3049 = add i32 %v3048, 4
+  %v3050 = add i32 %v3049, 5
+  %v3051 = add i32 %v3050, 6
+  %v3052 = add i32 %v3051, 0
+  %v3053 = add i32 %v3052, 1
+  %v3054 = add i32 %v3053, 2
+  %v3055 = add i32 %v3054, 3
+  %v3056 = add i32 %v3055, 4
+  %v3057 = add i32 %v3056, 5
+  %v3058 = add i32 %v3057, 6
+  %v3059 = add i32 %v3058, 0
+  %v3060 = add i32 %v3059, 1
+  %v3061 = add i32 %v3060, 2
+  %v3062 = add i32 %v3061, 3
+  %v3063 = add i32 %v3062, 4
+  %v3064 = add i32 %v3063, 5
+  %v3065 = add i32 %v3064, 6
+  %v3066 = add i32 %v3065, 0
+  %v3067 = add i32 %v3066, 1
+  %v3068 = add i32 %v3067, 2
+  %v3069 = add i32 %v3068, 3
+  %v3070 = add i32 %v3069, 4
+  %v3071 = add i32 %v3070, 5
+  %v3072 = add i32 %v3071, 6
+  %v3073 = add i32 %v3072, 0
+  %v3074 = add i32 %v3073, 1
+  %v3075 = add i32 %v3074, 2
+  %v3076 = add i32 %v3075, 3
+  %v3077 = add i32 %v3076, 4
+  %v3078 = add i32 %v3077, 5
+  %v3079 = add i32 %v3078, 6
+  %v3080 = add i32 %v3079, 0
+  %v3081 = add i32 %v3080, 1
+  %v3082 = add i32 %v3081, 2
+  %v3083 = add i32 %v3082, 3
+  %v3084 = add i32 %v3083, 4
+  %v3085 = add i32 %v3084, 5
+  %v3086 = add i32 %v3085, 6
+  %v3087 = add i32 %v3086, 0
+  %v3088 = add i32 %v3087, 1
+  %v3089 = add i32 %v3088, 2
+  %v3090 = add i32 %v3089, 3
+  %v3091 = add i32 %v3090, 4
+  %v3092 = add i32 %v3091, 5
+  %v3093 = add i32 %v3092, 6
+  %v3094 = add i32 %v3093, 0
+  %v3095 = add i32 %v3094, 1
+  %v3096 = add i32 %v3095, 2
+  %v3097 = add i32 %v3096, 3
+  %v3098 = add i32 %v3097, 4
+  %v3099 = add i32 %v3098, 5
+  %v3100 = add i32 %v3099, 6
+  %v3101 = add i32 %v3100, 0
+  %v3102 = add i32 %v3101, 1
+  %v3103 = add i32 %v3102, 2
+  %v3104 = add i32 %v3103, 3
+  %v3105 = add i32 %v3104, 4
+  %v3106 = add i32 %v3105, 5
+  %v3107 = add i32 %v3106, 6
+  %v3108 = add i32 %v3107, 0
+  %v3109 = add i32 %v3108, 1
+  %v3110 = add i32 %v3109, 2
+  %v3111 = add i32 %v3110, 3
+  %v3112 = add i32 %v3111, 4
+  %v3113 = add i32 %v3112, 5
+  %v3114 = add i32 %v3113, 6
+  %v3115 = add i32 %v3114, 0
+  %v3116 = add i32 %v3115, 1
+  %v3117 = add i32 %v3116, 2
+  %v3118 = add i32 %v3117, 3
+  %v3119 = add i32 %v3118, 4
+  %v3120 = add i32 %v3119, 5
+  %v3121 = add i32 %v3120, 6
+  %v3122 = add i32 %v3121, 0
+  %v3123 = add i32 %v3122, 1
+  %v3124 = add i32 %v3123, 2
+  %v3125 = add i32 %v3124, 3
+  %v3126 = add i32 %v3125, 4
+  %v3127 = add i32 %v3126, 5
+  %v3128 = add i32 %v3127, 6
+  %v3129 = add i32 %v3128, 0
+  %v3130 = add i32 %v3129, 1
+  %v3131 = add i32 %v3130, 2
+  %v3132 = add i32 %v3131, 3
+  %v3133 = add i32 %v3132, 4
+  %v3134 = add i32 %v3133, 5
+  %v3135 = add i32 %v3134, 6
+  %v3136 = add i32 %v3135, 0
+  %v3137 = add i32 %v3136, 1
+  %v3138 = add i32 %v3137, 2
+  %v3139 = add i32 %v3138, 3
+  %v3140 = add i32 %v3139, 4
+  %v3141 = add i32 %v3140, 5
+  %v3142 = add i32 %v3141, 6
+  %v3143 = add i32 %v3142, 0
+  %v3144 = add i32 %v3143, 1
+  %v3145 = add i32 %v3144, 2
+  %v3146 = add i32 %v3145, 3
+  %v3147 = add i32 %v3146, 4
+  %v3148 = add i32 %v3147, 5
+  %v3149 = add i32 %v3148, 6
+  %v3150 = add i32 %v3149, 0
+  %v3151 = add i32 %v3150, 1
+  %v3152 = add i32 %v3151, 2
+  %v3153 = add i32 %v3152, 3
+  %v3154 = add i32 %v3153, 4
+  %v3155 = add i32 %v3154, 5
+  %v3156 = add i32 %v3155, 6
+  %v3157 = add i32 %v3156, 0
+  %v3158 = add i32 %v3157, 1
+  %v3159 = add i32 %v3158, 2
+  %v3160 = add i32 %v3159, 3
+  %v3161 = add i32 %v3160, 4
+  %v3162 = add i32 %v3161, 5
+  %v3163 = add i32 %v3162, 6
+  %v3164 = add i32 %v3163, 0
+  %v3165 = add i32 %v3164, 1
+  %v3166 = add i32 %v3165, 2
+  %v3167 = add i32 %v3166, 3
+  %v3168 = add i32 %v3167, 4
+  %v3169 = add i32 %v3168, 5
+  %v3170 = add i32 %v3169, 6
+  %v3171 = add i32 %v3170, 0
+  %v3172 = add i32 %v3171, 1
+  %v3173 = add i32 %v3172, 2
+  %v3174 = add i32 %v3173, 3
+  %v3175 = add i32 %v3174, 4
+  %v3176 = add i32 %v3175, 5
+  %v3177 = add i32 %v3176, 6
+  %v3178 = add i32 %v3177, 0
+  %v3179 = add i32 %v3178, 1
+  %v3180 = add i32 %v3179, 2
+  %v3181 = add i32 %v3180, 3
+  %v3182 = add i32 %v3181, 4
+  %v3183 = add i32 %v3182, 5
+  %v3184 = add i32 %v3183, 6
+  %v3185 = add i32 %v3184, 0
+  %v3186 = add i32 %v3185, 1
+  %v3187 = add i32 %v3186, 2
+  %v3188 = add i32 %v3187, 3
+  %v3189 = add i32 %v3188, 4
+  %v3190 = add i32 %v3189, 5
+  %v3191 = add i32 %v3190, 6
+  %v3192 = add i32 %v3191, 0
+  %v3193 = add i32 %v3192, 1
+  %v3194 = add i32 %v3193, 2
+  %v3195 = add i32 %v3194, 3
+  %v3196 = add i32 %v3195, 4
+  %v3197 = add i32 %v3196, 5
+  %v3198 = add i32 %v3197, 6
+  %v3199 = add i32 %v3198, 0
+  %v3200 = add i32 %v3199, 1
+  %v3201 = add i32 %v3200, 2
+  %v3202 = add i32 %v3201, 3
+  %v3203 = add i32 %v3202, 4
+  %v3204 = add i32 %v3203, 5
+  %v3205 = add i32 %v3204, 6
+  %v3206 = add i32 %v3205, 0
+  %v3207 = add i32 %v3206, 1
+  %v3208 = add i32 %v3207, 2
+  %v3209 = add i32 %v3208, 3
+  %v3210 = add i32 %v3209, 4
+  %v3211 = add i32 %v3210, 5
+  %v3212 = add i32 %v3211, 6
+  %v3213 = add i32 %v3212, 0
+  %v3214 = add i32 %v3213, 1
+  %v3215 = add i32 %v3214, 2
+  %v3216 = add i32 %v3215, 3
+  %v3217 = add i32 %v3216, 4
+  %v3218 = add i32 %v3217, 5
+  %v3219 = add i32 %v3218, 6
+  %v3220 = add i32 %v3219, 0
+  %v3221 = add i32 %v3220, 1
+  %v3222 = add i32 %v3221, 2
+  %v3223 = add i32 %v3222, 3
+  %v3224 = add i32 %v3223, 4
+  %v3225 = add i32 %v3224, 5
+  %v3226 = add i32 %v3225, 6
+  %v3227 = add i32 %v3226, 0
+  %v3228 = add i32 %v3227, 1
+  %v3229 = add i32 %v3228, 2
+  %v3230 = add i32 %v3229, 3
+  %v3231 = add i32 %v3230, 4
+  %v3232 = add i32 %v3231, 5
+  %v3233 = add i32 %v3232, 6
+  %v3234 = add i32 %v3233, 0
+  %v3235 = add i32 %v3234, 1
+  %v3236 = add i32 %v3235, 2
+  %v3237 = add i32 %v3236, 3
+  %v3238 = add i32 %v3237, 4
+  %v3239 = add i32 %v3238, 5
+  %v3240 = add i32 %v3239, 6
+  %v3241 = add i32 %v3240, 0
+  %v3242 = add i32 %v3241, 1
+  %v3243 = add i32 %v3242, 2
+  %v3244 = add i32 %v3243, 3
+  %v3245 = add i32 %v3244, 4
+  %v3246 = add i32 %v3245, 5
+  %v3247 = add i32 %v3246, 6
+  %v3248 = add i32 %v3247, 0
+  %v3249 = add i32 %v3248, 1
+  %v3250 = add i32 %v3249, 2
+  %v3251 = add i32 %v3250, 3
+  %v3252 = add i32 %v3251, 4
+  %v3253 = add i32 %v3252, 5
+  %v3254 = add i32 %v3253, 6
+  %v3255 = add i32 %v3254, 0
+  %v3256 = add i32 %v3255, 1
+  %v3257 = add i32 %v3256, 2
+  %v3258 = add i32 %v3257, 3
+  %v3259 = add i32 %v3258, 4
+  %v3260 = add i32 %v3259, 5
+  %v3261 = add i32 %v3260, 6
+  %v3262 = add i32 %v3261, 0
+  %v3263 = add i32 %v3262, 1
+  %v3264 = add i32 %v3263, 2
+  %v3265 = add i32 %v3264, 3
+  %v3266 = add i32 %v3265, 4
+  %v3267 = add i32 %v3266, 5
+  %v3268 = add i32 %v3267, 6
+  %v3269 = add i32 %v3268, 0
+  %v3270 = add i32 %v3269, 1
+  %v3271 = add i32 %v3270, 2
+  %v3272 = add i32 %v3271, 3
+  %v3273 = add i32 %v3272, 4
+  %v3274 = add i32 %v3273, 5
+  %v3275 = add i32 %v3274, 6
+  %v3276 = add i32 %v3275, 0
+  %v3277 = add i32 %v3276, 1
+  %v3278 = add i32 %v3277, 2
+  %v3279 = add i32 %v3278, 3
+  %v3280 = add i32 %v3279, 4
+  %v3281 = add i32 %v3280, 5
+  %v3282 = add i32 %v3281, 6
+  %v3283 = add i32 %v3282, 0
+  %v3284 = add i32 %v3283, 1
+  %v3285 = add i32 %v3284, 2
+  %v3286 = add i32 %v3285, 3
+  %v3287 = add i32 %v3286, 4
+  %v3288 = add i32 %v3287, 5
+  %v3289 = add i32 %v3288, 6
+  %v3290 = add i32 %v3289, 0
+  %v3291 = add i32 %v3290, 1
+  %v3292 = add i32 %v3291, 2
+  %v3293 = add i32 %v3292, 3
+  %v3294 = add i32 %v3293, 4
+  %v3295 = add i32 %v3294, 5
+  %v3296 = add i32 %v3295, 6
+  %v3297 = add i32 %v3296, 0
+  %v3298 = add i32 %v3297, 1
+  %v3299 = add i32 %v3298, 2
+  %v3300 = add i32 %v3299, 3
+  %v3301 = add i32 %v3300, 4
+  %v3302 = add i32 %v3301, 5
+  %v3303 = add i32 %v3302, 6
+  %v3304 = add i32 %v3303, 0
+  %v3305 = add i32 %v3304, 1
+  %v3306 = add i32 %v3305, 2
+  %v3307 = add i32 %v3306, 3
+  %v3308 = add i32 %v3307, 4
+  %v3309 = add i32 %v3308, 5
+  %v3310 = add i32 %v3309, 6
+  %v3311 = add i32 %v3310, 0
+  %v3312 = add i32 %v3311, 1
+  %v3313 = add i32 %v3312, 2
+  %v3314 = add i32 %v3313, 3
+  %v3315 = add i32 %v3314, 4
+  %v3316 = add i32 %v3315, 5
+  %v3317 = add i32 %v3316, 6
+  %v3318 = add i32 %v3317, 0
+  %v3319 = add i32 %v3318, 1
+  %v3320 = add i32 %v3319, 2
+  %v3321 = add i32 %v3320, 3
+  %v3322 = add i32 %v3321, 4
+  %v3323 = add i32 %v3322, 5
+  %v3324 = add i32 %v3323, 6
+  %v3325 = add i32 %v3324, 0
+  %v3326 = add i32 %v3325, 1
+  %v3327 = add i32 %v3326, 2
+  %v3328 = add i32 %v3327, 3
+  %v3329 = add i32 %v3328, 4
+  %v3330 = add i32 %v3329, 5
+  %v3331 = add i32 %v3330, 6
+  %v3332 = add i32 %v3331, 0
+  %v3333 = add i32 %v3332, 1
+  %v3334 = add i32 %v3333, 2
+  %v3335 = add i32 %v3334, 3
+  %v3336 = add i32 %v3335, 4
+  %v3337 = add i32 %v3336, 5
+  %v3338 = add i32 %v3337, 6
+  %v3339 = add i32 %v3338, 0
+  %v3340 = add i32 %v3339, 1
+  %v3341 = add i32 %v3340, 2
+  %v3342 = add i32 %v3341, 3
+  %v3343 = add i32 %v3342, 4
+  %v3344 = add i32 %v3343, 5
+  %v3345 = add i32 %v3344, 6
+  %v3346 = add i32 %v3345, 0
+  %v3347 = add i32 %v3346, 1
+  %v3348 = add i32 %v3347, 2
+  %v3349 = add i32 %v3348, 3
+  %v3350 = add i32 %v3349, 4
+  %v3351 = add i32 %v3350, 5
+  %v3352 = add i32 %v3351, 6
+  %v3353 = add i32 %v3352, 0
+  %v3354 = add i32 %v3353, 1
+  %v3355 = add i32 %v3354, 2
+  %v3356 = add i32 %v3355, 3
+  %v3357 = add i32 %v3356, 4
+  %v3358 = add i32 %v3357, 5
+  %v3359 = add i32 %v3358, 6
+  %v3360 = add i32 %v3359, 0
+  %v3361 = add i32 %v3360, 1
+  %v3362 = add i32 %v3361, 2
+  %v3363 = add i32 %v3362, 3
+  %v3364 = add i32 %v3363, 4
+  %v3365 = add i32 %v3364, 5
+  %v3366 = add i32 %v3365, 6
+  %v3367 = add i32 %v3366, 0
+  %v3368 = add i32 %v3367, 1
+  %v3369 = add i32 %v3368, 2
+  %v3370 = add i32 %v3369, 3
+  %v3371 = add i32 %v3370, 4
+  %v3372 = add i32 %v3371, 5
+  %v3373 = add i32 %v3372, 6
+  %v3374 = add i32 %v3373, 0
+  %v3375 = add i32 %v3374, 1
+  %v3376 = add i32 %v3375, 2
+  %v3377 = add i32 %v3376, 3
+  %v3378 = add i32 %v3377, 4
+  %v3379 = add i32 %v3378, 5
+  %v3380 = add i32 %v3379, 6
+  %v3381 = add i32 %v3380, 0
+  %v3382 = add i32 %v3381, 1
+  %v3383 = add i32 %v3382, 2
+  %v3384 = add i32 %v3383, 3
+  %v3385 = add i32 %v3384, 4
+  %v3386 = add i32 %v3385, 5
+  %v3387 = add i32 %v3386, 6
+  %v3388 = add i32 %v3387, 0
+  %v3389 = add i32 %v3388, 1
+  %v3390 = add i32 %v3389, 2
+  %v3391 = add i32 %v3390, 3
+  %v3392 = add i32 %v3391, 4
+  %v3393 = add i32 %v3392, 5
+  %v3394 = add i32 %v3393, 6
+  %v3395 = add i32 %v3394, 0
+  %v3396 = add i32 %v3395, 1
+  %v3397 = add i32 %v3396, 2
+  %v3398 = add i32 %v3397, 3
+  %v3399 = add i32 %v3398, 4
+  %v3400 = add i32 %v3399, 5
+  %v3401 = add i32 %v3400, 6
+  %v3402 = add i32 %v3401, 0
+  %v3403 = add i32 %v3402, 1
+  %v3404 = add i32 %v3403, 2
+  %v3405 = add i32 %v3404, 3
+  %v3406 = add i32 %v3405, 4
+  %v3407 = add i32 %v3406, 5
+  %v3408 = add i32 %v3407, 6
+  %v3409 = add i32 %v3408, 0
+  %v3410 = add i32 %v3409, 1
+  %v3411 = add i32 %v3410, 2
+  %v3412 = add i32 %v3411, 3
+  %v3413 = add i32 %v3412, 4
+  %v3414 = add i32 %v3413, 5
+  %v3415 = add i32 %v3414, 6
+  %v3416 = add i32 %v3415, 0
+  %v3417 = add i32 %v3416, 1
+  %v3418 = add i32 %v3417, 2
+  %v3419 = add i32 %v3418, 3
+  %v3420 = add i32 %v3419, 4
+  %v3421 = add i32 %v3420, 5
+  %v3422 = add i32 %v3421, 6
+  %v3423 = add i32 %v3422, 0
+  %v3424 = add i32 %v3423, 1
+  %v3425 = add i32 %v3424, 2
+  %v3426 = add i32 %v3425, 3
+  %v3427 = add i32 %v3426, 4
+  %v3428 = add i32 %v3427, 5
+  %v3429 = add i32 %v3428, 6
+  %v3430 = add i32 %v3429, 0
+  %v3431 = add i32 %v3430, 1
+  %v3432 = add i32 %v3431, 2
+  %v3433 = add i32 %v3432, 3
+  %v3434 = add i32 %v3433, 4
+  %v3435 = add i32 %v3434, 5
+  %v3436 = add i32 %v3435, 6
+  %v3437 = add i32 %v3436, 0
+  %v3438 = add i32 %v3437, 1
+  %v3439 = add i32 %v3438, 2
+  %v3440 = add i32 %v3439, 3
+  %v3441 = add i32 %v3440, 4
+  %v3442 = add i32 %v3441, 5
+  %v3443 = add i32 %v3442, 6
+  %v3444 = add i32 %v3443, 0
+  %v3445 = add i32 %v3444, 1
+  %v3446 = add i32 %v3445, 2
+  %v3447 = add i32 %v3446, 3
+  %v3448 = add i32 %v3447, 4
+  %v3449 = add i32 %v3448, 5
+  %v3450 = add i32 %v3449, 6
+  %v3451 = add i32 %v3450, 0
+  %v3452 = add i32 %v3451, 1
+  %v3453 = add i32 %v3452, 2
+  %v3454 = add i32 %v3453, 3
+  %v3455 = add i32 %v3454, 4
+  %v3456 = add i32 %v3455, 5
+  %v3457 = add i32 %v3456, 6
+  %v3458 = add i32 %v3457, 0
+  %v3459 = add i32 %v3458, 1
+  %v3460 = add i32 %v3459, 2
+  %v3461 = add i32 %v3460, 3
+  %v3462 = add i32 %v3461, 4
+  %v3463 = add i32 %v3462, 5
+  %v3464 = add i32 %v3463, 6
+  %v3465 = add i32 %v3464, 0
+  %v3466 = add i32 %v3465, 1
+  %v3467 = add i32 %v3466, 2
+  %v3468 = add i32 %v3467, 3
+  %v3469 = add i32 %v3468, 4
+  %v3470 = add i32 %v3469, 5
+  %v3471 = add i32 %v3470, 6
+  %v3472 = add i32 %v3471, 0
+  %v3473 = add i32 %v3472, 1
+  %v3474 = add i32 %v3473, 2
+  %v3475 = add i32 %v3474, 3
+  %v3476 = add i32 %v3475, 4
+  %v3477 = add i32 %v3476, 5
+  %v3478 = add i32 %v3477, 6
+  %v3479 = add i32 %v3478, 0
+  %v3480 = add i32 %v3479, 1
+  %v3481 = add i32 %v3480, 2
+  %v3482 = add i32 %v3481, 3
+  %v3483 = add i32 %v3482, 4
+  %v3484 = add i32 %v3483, 5
+  %v3485 = add i32 %v3484, 6
+  %v3486 = add i32 %v3485, 0
+  %v3487 = add i32 %v3486, 1
+  %v3488 = add i32 %v3487, 2
+  %v3489 = add i32 %v3488, 3
+  %v3490 = add i32 %v3489, 4
+  %v3491 = add i32 %v3490, 5
+  %v3492 = add i32 %v3491, 6
+  %v3493 = add i32 %v3492, 0
+  %v3494 = add i32 %v3493, 1
+  %v3495 = add i32 %v3494, 2
+  %v3496 = add i32 %v3495, 3
+  %v3497 = add i32 %v3496, 4
+  %v3498 = add i32 %v3497, 5
+  %v3499 = add i32 %v3498, 6
+  %v3500 = add i32 %v3499, 0
+  %v3501 = add i32 %v3500, 1
+  %v3502 = add i32 %v3501, 2
+  %v3503 = add i32 %v3502, 3
+  %v3504 = add i32 %v3503, 4
+  %v3505 = add i32 %v3504, 5
+  %v3506 = add i32 %v3505, 6
+  %v3507 = add i32 %v3506, 0
+  %v3508 = add i32 %v3507, 1
+  %v3509 = add i32 %v3508, 2
+  %v3510 = add i32 %v3509, 3
+  %v3511 = add i32 %v3510, 4
+  %v3512 = add i32 %v3511, 5
+  %v3513 = add i32 %v3512, 6
+  %v3514 = add i32 %v3513, 0
+  %v3515 = add i32 %v3514, 1
+  %v3516 = add i32 %v3515, 2
+  %v3517 = add i32 %v3516, 3
+  %v3518 = add i32 %v3517, 4
+  %v3519 = add i32 %v3518, 5
+  %v3520 = add i32 %v3519, 6
+  %v3521 = add i32 %v3520, 0
+  %v3522 = add i32 %v3521, 1
+  %v3523 = add i32 %v3522, 2
+  %v3524 = add i32 %v3523, 3
+  %v3525 = add i32 %v3524, 4
+  %v3526 = add i32 %v3525, 5
+  %v3527 = add i32 %v3526, 6
+  %v3528 = add i32 %v3527, 0
+  %v3529 = add i32 %v3528, 1
+  %v3530 = add i32 %v3529, 2
+  %v3531 = add i32 %v3530, 3
+  %v3532 = add i32 %v3531, 4
+  %v3533 = add i32 %v3532, 5
+  %v3534 = add i32 %v3533, 6
+  %v3535 = add i32 %v3534, 0
+  %v3536 = add i32 %v3535, 1
+  %v3537 = add i32 %v3536, 2
+  %v3538 = add i32 %v3537, 3
+  %v3539 = add i32 %v3538, 4
+  %v3540 = add i32 %v3539, 5
+  %v3541 = add i32 %v3540, 6
+  %v3542 = add i32 %v3541, 0
+  %v3543 = add i32 %v3542, 1
+  %v3544 = add i32 %v3543, 2
+  %v3545 = add i32 %v3544, 3
+  %v3546 = add i32 %v3545, 4
+  %v3547 = add i32 %v3546, 5
+  %v3548 = add i32 %v3547, 6
+  %v3549 = add i32 %v3548, 0
+  %v3550 = add i32 %v3549, 1
+  %v3551 = add i32 %v3550, 2
+  %v3552 = add i32 %v3551, 3
+  %v3553 = add i32 %v3552, 4
+  %v3554 = add i32 %v3553, 5
+  %v3555 = add i32 %v3554, 6
+  %v3556 = add i32 %v3555, 0
+  %v3557 = add i32 %v3556, 1
+  %v3558 = add i32 %v3557, 2
+  %v3559 = add i32 %v3558, 3
+  %v3560 = add i32 %v3559, 4
+  %v3561 = add i32 %v3560, 5
+  %v3562 = add i32 %v3561, 6
+  %v3563 = add i32 %v3562, 0
+  %v3564 = add i32 %v3563, 1
+  %v3565 = add i32 %v3564, 2
+  %v3566 = add i32 %v3565, 3
+  %v3567 = add i32 %v3566, 4
+  %v3568 = add i32 %v3567, 5
+  %v3569 = add i32 %v3568, 6
+  %v3570 = add i32 %v3569, 0
+  %v3571 = add i32 %v3570, 1
+  %v3572 = add i32 %v3571, 2
+  %v3573 = add i32 %v3572, 3
+  %v3574 = add i32 %v3573, 4
+  %v3575 = add i32 %v3574, 5
+  %v3576 = add i32 %v3575, 6
+  %v3577 = add i32 %v3576, 0
+  %v3578 = add i32 %v3577, 1
+  %v3579 = add i32 %v3578, 2
+  %v3580 = add i32 %v3579, 3
+  %v3581 = add i32 %v3580, 4
+  %v3582 = add i32 %v3581, 5
+  %v3583 = add i32 %v3582, 6
+  %v3584 = add i32 %v3583, 0
+  %v3585 = add i32 %v3584, 1
+  %v3586 = add i32 %v3585, 2
+  %v3587 = add i32 %v3586, 3
+  %v3588 = add i32 %v3587, 4
+  %v3589 = add i32 %v3588, 5
+  %v3590 = add i32 %v3589, 6
+  %v3591 = add i32 %v3590, 0
+  %v3592 = add i32 %v3591, 1
+  %v3593 = add i32 %v3592, 2
+  %v3594 = add i32 %v3593, 3
+  %v3595 = add i32 %v3594, 4
+  %v3596 = add i32 %v3595, 5
+  %v3597 = add i32 %v3596, 6
+  %v3598 = add i32 %v3597, 0
+  %v3599 = add i32 %v3598, 1
+  %v3600 = add i32 %v3599, 2
+  %v3601 = add i32 %v3600, 3
+  %v3602 = add i32 %v3601, 4
+  %v3603 = add i32 %v3602, 5
+  %v3604 = add i32 %v3603, 6
+  %v3605 = add i32 %v3604, 0
+  %v3606 = add i32 %v3605, 1
+  %v3607 = add i32 %v3606, 2
+  %v3608 = add i32 %v3607, 3
+  %v3609 = add i32 %v3608, 4
+  %v3610 = add i32 %v3609, 5
+  %v3611 = add i32 %v3610, 6
+  %v3612 = add i32 %v3611, 0
+  %v3613 = add i32 %v3612, 1
+  %v3614 = add i32 %v3613, 2
+  %v3615 = add i32 %v3614, 3
+  %v3616 = add i32 %v3615, 4
+  %v3617 = add i32 %v3616, 5
+  %v3618 = add i32 %v3617, 6
+  %v3619 = add i32 %v3618, 0
+  %v3620 = add i32 %v3619, 1
+  %v3621 = add i32 %v3620, 2
+  %v3622 = add i32 %v3621, 3
+  %v3623 = add i32 %v3622, 4
+  %v3624 = add i32 %v3623, 5
+  %v3625 = add i32 %v3624, 6
+  %v3626 = add i32 %v3625, 0
+  %v3627 = add i32 %v3626, 1
+  %v3628 = add i32 %v3627, 2
+  %v3629 = add i32 %v3628, 3
+  %v3630 = add i32 %v3629, 4
+  %v3631 = add i32 %v3630, 5
+  %v3632 = add i32 %v3631, 6
+  %v3633 = add i32 %v3632, 0
+  %v3634 = add i32 %v3633, 1
+  %v3635 = add i32 %v3634, 2
+  %v3636 = add i32 %v3635, 3
+  %v3637 = add i32 %v3636, 4
+  %v3638 = add i32 %v3637, 5
+  %v3639 = add i32 %v3638, 6
+  %v3640 = add i32 %v3639, 0
+  %v3641 = add i32 %v3640, 1
+  %v3642 = add i32 %v3641, 2
+  %v3643 = add i32 %v3642, 3
+  %v3644 = add i32 %v3643, 4
+  %v3645 = add i32 %v3644, 5
+  %v3646 = add i32 %v3645, 6
+  %v3647 = add i32 %v3646, 0
+  %v3648 = add i32 %v3647, 1
+  %v3649 = add i32 %v3648, 2
+  %v3650 = add i32 %v3649, 3
+  %v3651 = add i32 %v3650, 4
+  %v3652 = add i32 %v3651, 5
+  %v3653 = add i32 %v3652, 6
+  %v3654 = add i32 %v3653, 0
+  %v3655 = add i32 %v3654, 1
+  %v3656 = add i32 %v3655, 2
+  %v3657 = add i32 %v3656, 3
+  %v3658 = add i32 %v3657, 4
+  %v3659 = add i32 %v3658, 5
+  %v3660 = add i32 %v3659, 6
+  %v3661 = add i32 %v3660, 0
+  %v3662 = add i32 %v3661, 1
+  %v3663 = add i32 %v3662, 2
+  %v3664 = add i32 %v3663, 3
+  %v3665 = add i32 %v3664, 4
+  %v3666 = add i32 %v3665, 5
+  %v3667 = add i32 %v3666, 6
+  %v3668 = add i32 %v3667, 0
+  %v3669 = add i32 %v3668, 1
+  %v3670 = add i32 %v3669, 2
+  %v3671 = add i32 %v3670, 3
+  %v3672 = add i32 %v3671, 4
+  %v3673 = add i32 %v3672, 5
+  %v3674 = add i32 %v3673, 6
+  %v3675 = add i32 %v3674, 0
+  %v3676 = add i32 %v3675, 1
+  %v3677 = add i32 %v3676, 2
+  %v3678 = add i32 %v3677, 3
+  %v3679 = add i32 %v3678, 4
+  %v3680 = add i32 %v3679, 5
+  %v3681 = add i32 %v3680, 6
+  %v3682 = add i32 %v3681, 0
+  %v3683 = add i32 %v3682, 1
+  %v3684 = add i32 %v3683, 2
+  %v3685 = add i32 %v3684, 3
+  %v3686 = add i32 %v3685, 4
+  %v3687 = add i32 %v3686, 5
+  %v3688 = add i32 %v3687, 6
+  %v3689 = add i32 %v3688, 0
+  %v3690 = add i32 %v3689, 1
+  %v3691 = add i32 %v3690, 2
+  %v3692 = add i32 %v3691, 3
+  %v3693 = add i32 %v3692, 4
+  %v3694 = add i32 %v3693, 5
+  %v3695 = add i32 %v3694, 6
+  %v3696 = add i32 %v3695, 0
+  %v3697 = add i32 %v3696, 1
+  %v3698 = add i32 %v3697, 2
+  %v3699 = add i32 %v3698, 3
+  %v3700 = add i32 %v3699, 4
+  %v3701 = add i32 %v3700, 5
+  %v3702 = add i32 %v3701, 6
+  %v3703 = add i32 %v3702, 0
+  %v3704 = add i32 %v3703, 1
+  %v3705 = add i32 %v3704, 2
+  %v3706 = add i32 %v3705, 3
+  %v3707 = add i32 %v3706, 4
+  %v3708 = add i32 %v3707, 5
+  %v3709 = add i32 %v3708, 6
+  %v3710 = add i32 %v3709, 0
+  %v3711 = add i32 %v3710, 1
+  %v3712 = add i32 %v3711, 2
+  %v3713 = add i32 %v3712, 3
+  %v3714 = add i32 %v3713, 4
+  %v3715 = add i32 %v3714, 5
+  %v3716 = add i32 %v3715, 6
+  %v3717 = add i32 %v3716, 0
+  %v3718 = add i32 %v3717, 1
+  %v3719 = add i32 %v3718, 2
+  %v3720 = add i32 %v3719, 3
+  %v3721 = add i32 %v3720, 4
+  %v3722 = add i32 %v3721, 5
+  %v3723 = add i32 %v3722, 6
+  %v3724 = add i32 %v3723, 0
+  %v3725 = add i32 %v3724, 1
+  %v3726 = add i32 %v3725, 2
+  %v3727 = add i32 %v3726, 3
+  %v3728 = add i32 %v3727, 4
+  %v3729 = add i32 %v3728, 5
+  %v3730 = add i32 %v3729, 6
+  %v3731 = add i32 %v3730, 0
+  %v3732 = add i32 %v3731, 1
+  %v3733 = add i32 %v3732, 2
+  %v3734 = add i32 %v3733, 3
+  %v3735 = add i32 %v3734, 4
+  %v3736 = add i32 %v3735, 5
+  %v3737 = add i32 %v3736, 6
+  %v3738 = add i32 %v3737, 0
+  %v3739 = add i32 %v3738, 1
+  %v3740 = add i32 %v3739, 2
+  %v3741 = add i32 %v3740, 3
+  %v3742 = add i32 %v3741, 4
+  %v3743 = add i32 %v3742, 5
+  %v3744 = add i32 %v3743, 6
+  %v3745 = add i32 %v3744, 0
+  %v3746 = add i32 %v3745, 1
+  %v3747 = add i32 %v3746, 2
+  %v3748 = add i32 %v3747, 3
+  %v3749 = add i32 %v3748, 4
+  %v3750 = add i32 %v3749, 5
+  %v3751 = add i32 %v3750, 6
+  %v3752 = add i32 %v3751, 0
+  %v3753 = add i32 %v3752, 1
+  %v3754 = add i32 %v3753, 2
+  %v3755 = add i32 %v3754, 3
+  %v3756 = add i32 %v3755, 4
+  %v3757 = add i32 %v3756, 5
+  %v3758 = add i32 %v3757, 6
+  %v3759 = add i32 %v3758, 0
+  %v3760 = add i32 %v3759, 1
+  %v3761 = add i32 %v3760, 2
+  %v3762 = add i32 %v3761, 3
+  %v3763 = add i32 %v3762, 4
+  %v3764 = add i32 %v3763, 5
+  %v3765 = add i32 %v3764, 6
+  %v3766 = add i32 %v3765, 0
+  %v3767 = add i32 %v3766, 1
+  %v3768 = add i32 %v3767, 2
+  %v3769 = add i32 %v3768, 3
+  %v3770 = add i32 %v3769, 4
+  %v3771 = add i32 %v3770, 5
+  %v3772 = add i32 %v3771, 6
+  %v3773 = add i32 %v3772, 0
+  %v3774 = add i32 %v3773, 1
+  %v3775 = add i32 %v3774, 2
+  %v3776 = add i32 %v3775, 3
+  %v3777 = add i32 %v3776, 4
+  %v3778 = add i32 %v3777, 5
+  %v3779 = add i32 %v3778, 6
+  %v3780 = add i32 %v3779, 0
+  %v3781 = add i32 %v3780, 1
+  %v3782 = add i32 %v3781, 2
+  %v3783 = add i32 %v3782, 3
+  %v3784 = add i32 %v3783, 4
+  %v3785 = add i32 %v3784, 5
+  %v3786 = add i32 %v3785, 6
+  %v3787 = add i32 %v3786, 0
+  %v3788 = add i32 %v3787, 1
+  %v3789 = add i32 %v3788, 2
+  %v3790 = add i32 %v3789, 3
+  %v3791 = add i32 %v3790, 4
+  %v3792 = add i32 %v3791, 5
+  %v3793 = add i32 %v3792, 6
+  %v3794 = add i32 %v3793, 0
+  %v3795 = add i32 %v3794, 1
+  %v3796 = add i32 %v3795, 2
+  %v3797 = add i32 %v3796, 3
+  %v3798 = add i32 %v3797, 4
+  %v3799 = add i32 %v3798, 5
+  %v3800 = add i32 %v3799, 6
+  %v3801 = add i32 %v3800, 0
+  %v3802 = add i32 %v3801, 1
+  %v3803 = add i32 %v3802, 2
+  %v3804 = add i32 %v3803, 3
+  %v3805 = add i32 %v3804, 4
+  %v3806 = add i32 %v3805, 5
+  %v3807 = add i32 %v3806, 6
+  %v3808 = add i32 %v3807, 0
+  %v3809 = add i32 %v3808, 1
+  %v3810 = add i32 %v3809, 2
+  %v3811 = add i32 %v3810, 3
+  %v3812 = add i32 %v3811, 4
+  %v3813 = add i32 %v3812, 5
+  %v3814 = add i32 %v3813, 6
+  %v3815 = add i32 %v3814, 0
+  %v3816 = add i32 %v3815, 1
+  %v3817 = add i32 %v3816, 2
+  %v3818 = add i32 %v3817, 3
+  %v3819 = add i32 %v3818, 4
+  %v3820 = add i32 %v3819, 5
+  %v3821 = add i32 %v3820, 6
+  %v3822 = add i32 %v3821, 0
+  %v3823 = add i32 %v3822, 1
+  %v3824 = add i32 %v3823, 2
+  %v3825 = add i32 %v3824, 3
+  %v3826 = add i32 %v3825, 4
+  %v3827 = add i32 %v3826, 5
+  %v3828 = add i32 %v3827, 6
+  %v3829 = add i32 %v3828, 0
+  %v3830 = add i32 %v3829, 1
+  %v3831 = add i32 %v3830, 2
+  %v3832 = add i32 %v3831, 3
+  %v3833 = add i32 %v3832, 4
+  %v3834 = add i32 %v3833, 5
+  %v3835 = add i32 %v3834, 6
+  %v3836 = add i32 %v3835, 0
+  %v3837 = add i32 %v3836, 1
+  %v3838 = add i32 %v3837, 2
+  %v3839 = add i32 %v3838, 3
+  %v3840 = add i32 %v3839, 4
+  %v3841 = add i32 %v3840, 5
+  %v3842 = add i32 %v3841, 6
+  %v3843 = add i32 %v3842, 0
+  %v3844 = add i32 %v3843, 1
+  %v3845 = add i32 %v3844, 2
+  %v3846 = add i32 %v3845, 3
+  %v3847 = add i32 %v3846, 4
+  %v3848 = add i32 %v3847, 5
+  %v3849 = add i32 %v3848, 6
+  %v3850 = add i32 %v3849, 0
+  %v3851 = add i32 %v3850, 1
+  %v3852 = add i32 %v3851, 2
+  %v3853 = add i32 %v3852, 3
+  %v3854 = add i32 %v3853, 4
+  %v3855 = add i32 %v3854, 5
+  %v3856 = add i32 %v3855, 6
+  %v3857 = add i32 %v3856, 0
+  %v3858 = add i32 %v3857, 1
+  %v3859 = add i32 %v3858, 2
+  %v3860 = add i32 %v3859, 3
+  %v3861 = add i32 %v3860, 4
+  %v3862 = add i32 %v3861, 5
+  %v3863 = add i32 %v3862, 6
+  %v3864 = add i32 %v3863, 0
+  %v3865 = add i32 %v3864, 1
+  %v3866 = add i32 %v3865, 2
+  %v3867 = add i32 %v3866, 3
+  %v3868 = add i32 %v3867, 4
+  %v3869 = add i32 %v3868, 5
+  %v3870 = add i32 %v3869, 6
+  %v3871 = add i32 %v3870, 0
+  %v3872 = add i32 %v3871, 1
+  %v3873 = add i32 %v3872, 2
+  %v3874 = add i32 %v3873, 3
+  %v3875 = add i32 %v3874, 4
+  %v3876 = add i32 %v3875, 5
+  %v3877 = add i32 %v3876, 6
+  %v3878 = add i32 %v3877, 0
+  %v3879 = add i32 %v3878, 1
+  %v3880 = add i32 %v3879, 2
+  %v3881 = add i32 %v3880, 3
+  %v3882 = add i32 %v3881, 4
+  %v3883 = add i32 %v3882, 5
+  %v3884 = add i32 %v3883, 6
+  %v3885 = add i32 %v3884, 0
+  %v3886 = add i32 %v3885, 1
+  %v3887 = add i32 %v3886, 2
+  %v3888 = add i32 %v3887, 3
+  %v3889 = add i32 %v3888, 4
+  %v3890 = add i32 %v3889, 5
+  %v3891 = add i32 %v3890, 6
+  %v3892 = add i32 %v3891, 0
+  %v3893 = add i32 %v3892, 1
+  %v3894 = add i32 %v3893, 2
+  %v3895 = add i32 %v3894, 3
+  %v3896 = add i32 %v3895, 4
+  %v3897 = add i32 %v3896, 5
+  %v3898 = add i32 %v3897, 6
+  %v3899 = add i32 %v3898, 0
+  %v3900 = add i32 %v3899, 1
+  %v3901 = add i32 %v3900, 2
+  %v3902 = add i32 %v3901, 3
+  %v3903 = add i32 %v3902, 4
+  %v3904 = add i32 %v3903, 5
+  %v3905 = add i32 %v3904, 6
+  %v3906 = add i32 %v3905, 0
+  %v3907 = add i32 %v3906, 1
+  %v3908 = add i32 %v3907, 2
+  %v3909 = add i32 %v3908, 3
+  %v3910 = add i32 %v3909, 4
+  %v3911 = add i32 %v3910, 5
+  %v3912 = add i32 %v3911, 6
+  %v3913 = add i32 %v3912, 0
+  %v3914 = add i32 %v3913, 1
+  %v3915 = add i32 %v3914, 2
+  %v3916 = add i32 %v3915, 3
+  %v3917 = add i32 %v3916, 4
+  %v3918 = add i32 %v3917, 5
+  %v3919 = add i32 %v3918, 6
+  %v3920 = add i32 %v3919, 0
+  %v3921 = add i32 %v3920, 1
+  %v3922 = add i32 %v3921, 2
+  %v3923 = add i32 %v3922, 3
+  %v3924 = add i32 %v3923, 4
+  %v3925 = add i32 %v3924, 5
+  %v3926 = add i32 %v3925, 6
+  %v3927 = add i32 %v3926, 0
+  %v3928 = add i32 %v3927, 1
+  %v3929 = add i32 %v3928, 2
+  %v3930 = add i32 %v3929, 3
+  %v3931 = add i32 %v3930, 4
+  %v3932 = add i32 %v3931, 5
+  %v3933 = add i32 %v3932, 6
+  %v3934 = add i32 %v3933, 0
+  %v3935 = add i32 %v3934, 1
+  %v3936 = add i32 %v3935, 2
+  %v3937 = add i32 %v3936, 3
+  %v3938 = add i32 %v3937, 4
+  %v3939 = add i32 %v3938, 5
+  %v3940 = add i32 %v3939, 6
+  %v3941 = add i32 %v3940, 0
+  %v3942 = add i32 %v3941, 1
+  %v3943 = add i32 %v3942, 2
+  %v3944 = add i32 %v3943, 3
+  %v3945 = add i32 %v3944, 4
+  %v3946 = add i32 %v3945, 5
+  %v3947 = add i32 %v3946, 6
+  %v3948 = add i32 %v3947, 0
+  %v3949 = add i32 %v3948, 1
+  %v3950 = add i32 %v3949, 2
+  %v3951 = add i32 %v3950, 3
+  %v3952 = add i32 %v3951, 4
+  %v3953 = add i32 %v3952, 5
+  %v3954 = add i32 %v3953, 6
+  %v3955 = add i32 %v3954, 0
+  %v3956 = add i32 %v3955, 1
+  %v3957 = add i32 %v3956, 2
+  %v3958 = add i32 %v3957, 3
+  %v3959 = add i32 %v3958, 4
+  %v3960 = add i32 %v3959, 5
+  %v3961 = add i32 %v3960, 6
+  %v3962 = add i32 %v3961, 0
+  %v3963 = add i32 %v3962, 1
+  %v3964 = add i32 %v3963, 2
+  %v3965 = add i32 %v3964, 3
+  %v3966 = add i32 %v3965, 4
+  %v3967 = add i32 %v3966, 5
+  %v3968 = add i32 %v3967, 6
+  %v3969 = add i32 %v3968, 0
+  %v3970 = add i32 %v3969, 1
+  %v3971 = add i32 %v3970, 2
+  %v3972 = add i32 %v3971, 3
+  %v3973 = add i32 %v3972, 4
+  %v3974 = add i32 %v3973, 5
+  %v3975 = add i32 %v3974, 6
+  %v3976 = add i32 %v3975, 0
+  %v3977 = add i32 %v3976, 1
+  %v3978 = add i32 %v3977, 2
+  %v3979 = add i32 %v3978, 3
+  %v3980 = add i32 %v3979, 4
+  %v3981 = add i32 %v3980, 5
+  %v3982 = add i32 %v3981, 6
+  %v3983 = add i32 %v3982, 0
+  %v3984 = add i32 %v3983, 1
+  %v3985 = add i32 %v3984, 2
+  %v3986 = add i32 %v3985, 3
+  %v3987 = add i32 %v3986, 4
+  %v3988 = add i32 %v3987, 5
+  %v3989 = add i32 %v3988, 6
+  %v3990 = add i32 %v3989, 0
+  %v3991 = add i32 %v3990, 1
+  %v3992 = add i32 %v3991, 2
+  %v3993 = add i32 %v3992, 3
+  %v3994 = add i32 %v3993, 4
+  %v3995 = add i32 %v3994, 5
+  %v3996 = add i32 %v3995, 6
+  %v3997 = add i32 %v3996, 0
+  %v3998 = add i32 %v3997, 1
+  %v3999 = add i32 %v3998, 2
+  %v4000 = add i32 %v3999, 3
+  %v4001 = add i32 %v4000, 4
+  %v4002 = add i32 %v4001, 5
+  %v4003 = add i32 %v4002, 6
+  %v4004 = add i32 %v4003, 0
+  %v4005 = add i32 %v4004, 1
+  %v4006 = add i32 %v4005, 2
+  %v4007 = add i32 %v4006, 3
+  %v4008 = add i32 %v4007, 4
+  %v4009 = add i32 %v4008, 5
+  %v4010 = add i32 %v4009, 6
+  %v4011 = add i32 %v4010, 0
+  %v4012 = add i32 %v4011, 1
+  %v4013 = add i32 %v4012, 2
+  %v4014 = add i32 %v4013, 3
+  %v4015 = add i32 %v4014, 4
+  %v4016 = add i32 %v4015, 5
+  %v4017 = add i32 %v4016, 6
+  %v4018 = add i32 %v4017, 0
+  %v4019 = add i32 %v4018, 1
+  %v4020 = add i32 %v4019, 2
+  %v4021 = add i32 %v4020, 3
+  %v4022 = add i32 %v4021, 4
+  %v4023 = add i32 %v4022, 5
+  %v4024 = add i32 %v4023, 6
+  %v4025 = add i32 %v4024, 0
+  %v4026 = add i32 %v4025, 1
+  %v4027 = add i32 %v4026, 2
+  %v4028 = add i32 %v4027, 3
+  %v4029 = add i32 %v4028, 4
+  %v4030 = add i32 %v4029, 5
+  %v4031 = add i32 %v4030, 6
+  %v4032 = add i32 %v4031, 0
+  %v4033 = add i32 %v4032, 1
+  %v4034 = add i32 %v4033, 2
+  %v4035 = add i32 %v4034, 3
+  %v4036 = add i32 %v4035, 4
+  %v4037 = add i32 %v4036, 5
+  %v4038 = add i32 %v4037, 6
+  %v4039 = add i32 %v4038, 0
+  %v4040 = add i32 %v4039, 1
+  %v4041 = add i32 %v4040, 2
+  %v4042 = add i32 %v4041, 3
+  %v4043 = add i32 %v4042, 4
+  %v4044 = add i32 %v4043, 5
+  %v4045 = add i32 %v4044, 6
+  %v4046 = add i32 %v4045, 0
+  %v4047 = add i32 %v4046, 1
+  %v4048 = add i32 %v4047, 2
+  %v4049 = add i32 %v4048, 3
+  %v4050 = add i32 %v4049, 4
+  %v4051 = add i32 %v4050, 5
+  %v4052 = add i32 %v4051, 6
+  %v4053 = add i32 %v4052, 0
+  %v4054 = add i32 %v4053, 1
+  %v4055 = add i32 %v4054, 2
+  %v4056 = add i32 %v4055, 3
+  %v4057 = add i32 %v4056, 4
+  %v4058 = add i32 %v4057, 5
+  %v4059 = add i32 %v4058, 6
+  %v4060 = add i32 %v4059, 0
+  %v4061 = add i32 %v4060, 1
+  %v4062 = add i32 %v4061, 2
+  %v4063 = add i32 %v4062, 3
+  %v4064 = add i32 %v4063, 4
+  %v4065 = add i32 %v4064, 5
+  %v4066 = add i32 %v4065, 6
+  %v4067 = add i32 %v4066, 0
+  %v4068 = add i32 %v4067, 1
+  %v4069 = add i32 %v4068, 2
+  %v4070 = add i32 %v4069, 3
+  %v4071 = add i32 %v4070, 4
+  %v4072 = add i32 %v4071, 5
+  %v4073 = add i32 %v4072, 6
+  %v4074 = add i32 %v4073, 0
+  %v4075 = add i32 %v4074, 1
+  %v4076 = add i32 %v4075, 2
+  %v4077 = add i32 %v4076, 3
+  %v4078 = add i32 %v4077, 4
+  %v4079 = add i32 %v4078, 5
+  %v4080 = add i32 %v4079, 6
+  %v4081 = add i32 %v4080, 0
+  %v4082 = add i32 %v4081, 1
+  %v4083 = add i32 %v4082, 2
+  %v4084 = add i32 %v4083, 3
+  %v4085 = add i32 %v4084, 4
+  %v4086 = add i32 %v4085, 5
+  %v4087 = add i32 %v4086, 6
+  %v4088 = add i32 %v4087, 0
+  %v4089 = add i32 %v4088, 1
+  %v4090 = add i32 %v4089, 2
+  %v4091 = add i32 %v4090, 3
+  %v4092 = add i32 %v4091, 4
+  %v4093 = add i32 %v4092, 5
+  %v4094 = add i32 %v4093, 6
+  %v4095 = add i32 %v4094, 0
+  %v4096 = add i32 %v4095, 1
+  %v4097 = add i32 %v4096, 2
+  %v4098 = add i32 %v4097, 3
+  %v4099 = add i32 %v4098, 4
+  %v4100 = add i32 %v4099, 5
+  %v4101 = add i32 %v4100, 6
+  %v4102 = add i32 %v4101, 0
+  %v4103 = add i32 %v4102, 1
+  %v4104 = add i32 %v4103, 2
+  %v4105 = add i32 %v4104, 3
+  %v4106 = add i32 %v4105, 4
+  %v4107 = add i32 %v4106, 5
+  %v4108 = add i32 %v4107, 6
+  %v4109 = add i32 %v4108, 0
+  %v4110 = add i32 %v4109, 1
+  %v4111 = add i32 %v4110, 2
+  %v4112 = add i32 %v4111, 3
+  %v4113 = add i32 %v4112, 4
+  %v4114 = add i32 %v4113, 5
+  %v4115 = add i32 %v4114, 6
+  %v4116 = add i32 %v4115, 0
+  %v4117 = add i32 %v4116, 1
+  %v4118 = add i32 %v4117, 2
+  %v4119 = add i32 %v4118, 3
+  %v4120 = add i32 %v4119, 4
+  %v4121 = add i32 %v4120, 5
+  %v4122 = add i32 %v4121, 6
+  %v4123 = add i32 %v4122, 0
+  %v4124 = add i32 %v4123, 1
+  %v4125 = add i32 %v4124, 2
+  %v4126 = add i32 %v4125, 3
+  %v4127 = add i32 %v4126, 4
+  %v4128 = add i32 %v4127, 5
+  %v4129 = add i32 %v4128, 6
+  %v4130 = add i32 %v4129, 0
+  %v4131 = add i32 %v4130, 1
+  %v4132 = add i32 %v4131, 2
+  %v4133 = add i32 %v4132, 3
+  %v4134 = add i32 %v4133, 4
+  %v4135 = add i32 %v4134, 5
+  %v4136 = add i32 %v4135, 6
+  %v4137 = add i32 %v4136, 0
+  %v4138 = add i32 %v4137, 1
+  %v4139 = add i32 %v4138, 2
+  %v4140 = add i32 %v4139, 3
+  %v4141 = add i32 %v4140, 4
+  %v4142 = add i32 %v4141, 5
+  %v4143 = add i32 %v4142, 6
+  %v4144 = add i32 %v4143, 0
+  %v4145 = add i32 %v4144, 1
+  %v4146 = add i32 %v4145, 2
+  %v4147 = add i32 %v4146, 3
+  %v4148 = add i32 %v4147, 4
+  %v4149 = add i32 %v4148, 5
+  %v4150 = add i32 %v4149, 6
+  %v4151 = add i32 %v4150, 0
+  %v4152 = add i32 %v4151, 1
+  %v4153 = add i32 %v4152, 2
+  %v4154 = add i32 %v4153, 3
+  %v4155 = add i32 %v4154, 4
+  %v4156 = add i32 %v4155, 5
+  %v4157 = add i32 %v4156, 6
+  %v4158 = add i32 %v4157, 0
+  %v4159 = add i32 %v4158, 1
+  %v4160 = add i32 %v4159, 2
+  %v4161 = add i32 %v4160, 3
+  %v4162 = add i32 %v4161, 4
+  %v4163 = add i32 %v4162, 5
+  %v4164 = add i32 %v4163, 6
+  %v4165 = add i32 %v4164, 0
+  %v4166 = add i32 %v4165, 1
+  %v4167 = add i32 %v4166, 2
+  %v4168 = add i32 %v4167, 3
+  %v4169 = add i32 %v4168, 4
+  %v4170 = add i32 %v4169, 5
+  %v4171 = add i32 %v4170, 6
+  %v4172 = add i32 %v4171, 0
+  %v4173 = add i32 %v4172, 1
+  %v4174 = add i32 %v4173, 2
+  %v4175 = add i32 %v4174, 3
+  %v4176 = add i32 %v4175, 4
+  %v4177 = add i32 %v4176, 5
+  %v4178 = add i32 %v4177, 6
+  %v4179 = add i32 %v4178, 0
+  %v4180 = add i32 %v4179, 1
+  %v4181 = add i32 %v4180, 2
+  %v4182 = add i32 %v4181, 3
+  %v4183 = add i32 %v4182, 4
+  %v4184 = add i32 %v4183, 5
+  %v4185 = add i32 %v4184, 6
+  %v4186 = add i32 %v4185, 0
+  %v4187 = add i32 %v4186, 1
+  %v4188 = add i32 %v4187, 2
+  %v4189 = add i32 %v4188, 3
+  %v4190 = add i32 %v4189, 4
+  %v4191 = add i32 %v4190, 5
+  %v4192 = add i32 %v4191, 6
+  %v4193 = add i32 %v4192, 0
+  %v4194 = add i32 %v4193, 1
+  %v4195 = add i32 %v4194, 2
+  %v4196 = add i32 %v4195, 3
+  %v4197 = add i32 %v4196, 4
+  %v4198 = add i32 %v4197, 5
+  %v4199 = add i32 %v4198, 6
+  %v4200 = add i32 %v4199, 0
+  %v4201 = add i32 %v4200, 1
+  %v4202 = add i32 %v4201, 2
+  %v4203 = add i32 %v4202, 3
+  %v4204 = add i32 %v4203, 4
+  %v4205 = add i32 %v4204, 5
+  %v4206 = add i32 %v4205, 6
+  %v4207 = add i32 %v4206, 0
+  %v4208 = add i32 %v4207, 1
+  %v4209 = add i32 %v4208, 2
+  %v4210 = add i32 %v4209, 3
+  %v4211 = add i32 %v4210, 4
+  %v4212 = add i32 %v4211, 5
+  %v4213 = add i32 %v4212, 6
+  %v4214 = add i32 %v4213, 0
+  %v4215 = add i32 %v4214, 1
+  %v4216 = add i32 %v4215, 2
+  %v4217 = add i32 %v4216, 3
+  %v4218 = add i32 %v4217, 4
+  %v4219 = add i32 %v4218, 5
+  %v4220 = add i32 %v4219, 6
+  %v4221 = add i32 %v4220, 0
+  %v4222 = add i32 %v4221, 1
+  %v4223 = add i32 %v4222, 2
+  %v4224 = add i32 %v4223, 3
+  %v4225 = add i32 %v4224, 4
+  %v4226 = add i32 %v4225, 5
+  %v4227 = add i32 %v4226, 6
+  %v4228 = add i32 %v4227, 0
+  %v4229 = add i32 %v4228, 1
+  %v4230 = add i32 %v4229, 2
+  %v4231 = add i32 %v4230, 3
+  %v4232 = add i32 %v4231, 4
+  %v4233 = add i32 %v4232, 5
+  %v4234 = add i32 %v4233, 6
+  %v4235 = add i32 %v4234, 0
+  %v4236 = add i32 %v4235, 1
+  %v4237 = add i32 %v4236, 2
+  %v4238 = add i32 %v4237, 3
+  %v4239 = add i32 %v4238, 4
+  %v4240 = add i32 %v4239, 5
+  %v4241 = add i32 %v4240, 6
+  %v4242 = add i32 %v4241, 0
+  %v4243 = add i32 %v4242, 1
+  %v4244 = add i32 %v4243, 2
+  %v4245 = add i32 %v4244, 3
+  %v4246 = add i32 %v4245, 4
+  %v4247 = add i32 %v4246, 5
+  %v4248 = add i32 %v4247, 6
+  %v4249 = add i32 %v4248, 0
+  %v4250 = add i32 %v4249, 1
+  %v4251 = add i32 %v4250, 2
+  %v4252 = add i32 %v4251, 3
+  %v4253 = add i32 %v4252, 4
+  %v4254 = add i32 %v4253, 5
+  %v4255 = add i32 %v4254, 6
+  %v4256 = add i32 %v4255, 0
+  %v4257 = add i32 %v4256, 1
+  %v4258 = add i32 %v4257, 2
+  %v4259 = add i32 %v4258, 3
+  %v4260 = add i32 %v4259, 4
+  %v4261 = add i32 %v4260, 5
+  %v4262 = add i32 %v4261, 6
+  %v4263 = add i32 %v4262, 0
+  %v4264 = add i32 %v4263, 1
+  %v4265 = add i32 %v4264, 2
+  %v4266 = add i32 %v4265, 3
+  %v4267 = add i32 %v4266, 4
+  %v4268 = add i32 %v4267, 5
+  %v4269 = add i32 %v4268, 6
+  %v4270 = add i32 %v4269, 0
+  %v4271 = add i32 %v4270, 1
+  %v4272 = add i32 %v4271, 2
+  %v4273 = add i32 %v4272, 3
+  %v4274 = add i32 %v4273, 4
+  %v4275 = add i32 %v4274, 5
+  %v4276 = add i32 %v4275, 6
+  %v4277 = add i32 %v4276, 0
+  %v4278 = add i32 %v4277, 1
+  %v4279 = add i32 %v4278, 2
+  %v4280 = add i32 %v4279, 3
+  %v4281 = add i32 %v4280, 4
+  %v4282 = add i32 %v4281, 5
+  %v4283 = add i32 %v4282, 6
+  %v4284 = add i32 %v4283, 0
+  %v4285 = add i32 %v4284, 1
+  %v4286 = add i32 %v4285, 2
+  %v4287 = add i32 %v4286, 3
+  %v4288 = add i32 %v4287, 4
+  %v4289 = add i32 %v4288, 5
+  %v4290 = add i32 %v4289, 6
+  %v4291 = add i32 %v4290, 0
+  %v4292 = add i32 %v4291, 1
+  %v4293 = add i32 %v4292, 2
+  %v4294 = add i32 %v4293, 3
+  %v4295 = add i32 %v4294, 4
+  %v4296 = add i32 %v4295, 5
+  %v4297 = add i32 %v4296, 6
+  %v4298 = add i32 %v4297, 0
+  %v4299 = add i32 %v4298, 1
+  %v4300 = add i32 %v4299, 2
+  %v4301 = add i32 %v4300, 3
+  %v4302 = add i32 %v4301, 4
+  %v4303 = add i32 %v4302, 5
+  %v4304 = add i32 %v4303, 6
+  %v4305 = add i32 %v4304, 0
+  %v4306 = add i32 %v4305, 1
+  %v4307 = add i32 %v4306, 2
+  %v4308 = add i32 %v4307, 3
+  %v4309 = add i32 %v4308, 4
+  %v4310 = add i32 %v4309, 5
+  %v4311 = add i32 %v4310, 6
+  %v4312 = add i32 %v4311, 0
+  %v4313 = add i32 %v4312, 1
+  %v4314 = add i32 %v4313, 2
+  %v4315 = add i32 %v4314, 3
+  %v4316 = add i32 %v4315, 4
+  %v4317 = add i32 %v4316, 5
+  %v4318 = add i32 %v4317, 6
+  %v4319 = add i32 %v4318, 0
+  %v4320 = add i32 %v4319, 1
+  %v4321 = add i32 %v4320, 2
+  %v4322 = add i32 %v4321, 3
+  %v4323 = add i32 %v4322, 4
+  %v4324 = add i32 %v4323, 5
+  %v4325 = add i32 %v4324, 6
+  %v4326 = add i32 %v4325, 0
+  %v4327 = add i32 %v4326, 1
+  %v4328 = add i32 %v4327, 2
+  %v4329 = add i32 %v4328, 3
+  %v4330 = add i32 %v4329, 4
+  %v4331 = add i32 %v4330, 5
+  %v4332 = add i32 %v4331, 6
+  %v4333 = add i32 %v4332, 0
+  %v4334 = add i32 %v4333, 1
+  %v4335 = add i32 %v4334, 2
+  %v4336 = add i32 %v4335, 3
+  %v4337 = add i32 %v4336, 4
+  %v4338 = add i32 %v4337, 5
+  %v4339 = add i32 %v4338, 6
+  %v4340 = add i32 %v4339, 0
+  %v4341 = add i32 %v4340, 1
+  %v4342 = add i32 %v4341, 2
+  %v4343 = add i32 %v4342, 3
+  %v4344 = add i32 %v4343, 4
+  %v4345 = add i32 %v4344, 5
+  %v4346 = add i32 %v4345, 6
+  %v4347 = add i32 %v4346, 0
+  %v4348 = add i32 %v4347, 1
+  %v4349 = add i32 %v4348, 2
+  %v4350 = add i32 %v4349, 3
+  %v4351 = add i32 %v4350, 4
+  %v4352 = add i32 %v4351, 5
+  %v4353 = add i32 %v4352, 6
+  %v4354 = add i32 %v4353, 0
+  %v4355 = add i32 %v4354, 1
+  %v4356 = add i32 %v4355, 2
+  %v4357 = add i32 %v4356, 3
+  %v4358 = add i32 %v4357, 4
+  %v4359 = add i32 %v4358, 5
+  %v4360 = add i32 %v4359, 6
+  %v4361 = add i32 %v4360, 0
+  %v4362 = add i32 %v4361, 1
+  %v4363 = add i32 %v4362, 2
+  %v4364 = add i32 %v4363, 3
+  %v4365 = add i32 %v4364, 4
+  %v4366 = add i32 %v4365, 5
+  %v4367 = add i32 %v4366, 6
+  %v4368 = add i32 %v4367, 0
+  %v4369 = add i32 %v4368, 1
+  %v4370 = add i32 %v4369, 2
+  %v4371 = add i32 %v4370, 3
+  %v4372 = add i32 %v4371, 4
+  %v4373 = add i32 %v4372, 5
+  %v4374 = add i32 %v4373, 6
+  %v4375 = add i32 %v4374, 0
+  %v4376 = add i32 %v4375, 1
+  %v4377 = add i32 %v4376, 2
+  %v4378 = add i32 %v4377, 3
+  %v4379 = add i32 %v4378, 4
+  %v4380 = add i32 %v4379, 5
+  %v4381 = add i32 %v4380, 6
+  %v4382 = add i32 %v4381, 0
+  %v4383 = add i32 %v4382, 1
+  %v4384 = add i32 %v4383, 2
+  %v4385 = add i32 %v4384, 3
+  %v4386 = add i32 %v4385, 4
+  %v4387 = add i32 %v4386, 5
+  %v4388 = add i32 %v4387, 6
+  %v4389 = add i32 %v4388, 0
+  %v4390 = add i32 %v4389, 1
+  %v4391 = add i32 %v4390, 2
+  %v4392 = add i32 %v4391, 3
+  %v4393 = add i32 %v4392, 4
+  %v4394 = add i32 %v4393, 5
+  %v4395 = add i32 %v4394, 6
+  %v4396 = add i32 %v4395, 0
+  %v4397 = add i32 %v4396, 1
+  %v4398 = add i32 %v4397, 2
+  %v4399 = add i32 %v4398, 3
+  %saved = load i32, ptr %p
+  %sum = add i32 %v4399, %saved
+  store i32 %sum, ptr %p
+  %r = load i32, ptr %p
+  ret i32 %r
+}
+
+define i32 @main() {
+  %r = call i32 @chain(i32 5)
+  %m = srem i32 %r, 256
+  ret i32 %m
+}
@@ -0,0 +1,62 @@
+; Loads of globals, products of arguments and divisions by constants are computed once before the loop,
+; including for a loop entered from several blocks
+@scale = global i32 3
+
+define i32 @f(i32 %n, i32 %a, i32 %b) {
+entry:
+  br label %loop
+loop:
+  %i = phi i32 [ 0, %entry ], [ %next, %latch ]
+  %acc = phi i32 [ 0, %entry ], [ %acc3, %latch ]
+  %done = icmp sge i32 %i, %n
+  br i1 %done, label %exit, label %body
+body:
+  %s = load i32, ptr @scale
+  %ab = mul i32 %a, %b
+  %k = add i32 %ab, %s
+  %q = sdiv i32 %k, 2
+  %x = mul i32 %i, %k
+  %y = mul i32 7, %i
+  %acc2 = add i32 %acc, %x
+  %odd = srem i32 %i, 2
+  %c = icmp eq i32 %odd, 0
+  br i1 %c, label %even, label %latch
+even:
+  %e = add i32 %acc2, %y
+  br label %latch
+latch:
+  %acc3 = phi i32 [ %acc2, %body ], [ %e, %even ]
+  %acc4 = add i32 %acc3, %q
+  %next = add i32 %i, 2
+  br label %loop
+exit:
+  ret i32 %acc
+}
+
+define i32 @g(i32 %n) {
+entry:
+  %c0 = icmp sgt i32 %n, 100
+  br i1 %c0, label %big, label %small
+big:
+  br label %loop
+small:
+  br label %loop
+loop:
+  %i = phi i32 [ 1, %big ], [ 2, %small ], [ %i1, %loop ]
+  %s = phi i32 [ 0, %big ], [ 0, %small ], [ %s1, %loop ]
+  %m = mul i32 %i, 5
+  %s1 = add i32 %s, %m
+  %i1 = add i32 %i, 1
+  %c = icmp slt i32 %i1, %n
+  br i1 %c, label %loop, label %out
+out:
+  ret i32 %s1
+}
+
+define i32 @main() {
+  %r = call i32 @f(i32 9, i32 2, i32 5)
+  %r2 = call i32 @g(i32 12)
+  %t = add i32 %r, %r2
+  %u = srem i32 %t, 256
+  ret i32 %u
+}
@@ -0,0 +1,52 @@
+; Instructions that may trap, or read memory the loop writes, stay in the loop. Moved before the loops
+; that never run below, the divisions would fault.
+@zero = global i32 0
+@min = global i32 -2147483648
+@count = global i32 0
+
+define i32 @never(i32 %n, i32 %a) {
+entry:
+  %z = load i32, ptr @zero
+  %m = load i32, ptr @min
+  br label %loop
+loop:
+  %i = phi i32 [ 0, %entry ], [ %next, %body ]
+  %acc = phi i32 [ 0, %entry ], [ %acc3, %body ]
+  %done = icmp sge i32 %i, %n
+  br i1 %done, label %exit, label %body
+body:
+  %q = sdiv i32 %a, %z
+  %r = srem i32 %m, -1
+  %u = udiv i32 %a, %z
+  %acc1 = add i32 %acc, %q
+  %acc2 = add i32 %acc1, %r
+  %acc3 = add i32 %acc2, %u
+  %next = add i32 %i, 1
+  br label %loop
+exit:
+  ret i32 %acc
+}
+
+define i32 @counting(i32 %n) {
+entry:
+  br label %loop
+loop:
+  %i = phi i32 [ 0, %entry ], [ %next, %loop ]
+  %acc = phi i32 [ 0, %entry ], [ %acc2, %loop ]
+  %c = load i32, ptr @count
+  %c2 = add i32 %c, 2
+  store i32 %c2, ptr @count
+  %acc2 = add i32 %acc, %c
+  %next = add i32 %i, 1
+  %more = icmp slt i32 %next, %n
+  br i1 %more, label %loop, label %exit
+exit:
+  ret i32 %acc2
+}
+
+define i32 @main() {
+  %a = call i32 @never(i32 0, i32 42)
+  %b = call i32 @counting(i32 6)
+  %r = add i32 %a, %b
+  ret i32 %r
+}
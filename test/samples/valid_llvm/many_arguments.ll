@@ -0,0 +1,41 @@
+; Arguments past the sixth go on the stack, in odd and even numbers, and are read after calls that
+; reuse the argument registers
+define i32 @seven(i32 %a, i32 %b, i32 %c, i32 %d, i32 %e, i32 %f, i32 %g) {
+  %ab = sub i32 %a, %b
+  %abc = add i32 %ab, %c
+  %abcd = mul i32 %abc, %d
+  %abcde = sub i32 %abcd, %e
+  %abcdef = add i32 %abcde, %f
+  %r = mul i32 %abcdef, %g
+  ret i32 %r
+}
+
+define i32 @ten(i32 %a, i32 %b, i32 %c, i32 %d, i32 %e, i32 %f, i32 %g, i32 %h, i32 %i, i32 %j) {
+  %first = call i32 @seven(i32 %j, i32 %i, i32 %h, i32 %g, i32 %f, i32 %e, i32 %d)
+  %second = call i32 @seven(i32 %a, i32 %b, i32 %c, i32 %d, i32 %e, i32 %f, i32 %g)
+  %s = add i32 %first, %second
+  %s1 = add i32 %s, %h
+  %s2 = mul i32 %s1, %i
+  %s3 = sub i32 %s2, %j
+  %s4 = add i32 %s3, %a
+  ret i32 %s4
+}
+
+define i32 @eight(i32 %a, i32 %b, i32 %c, i32 %d, i32 %e, i32 %f, i32 %g, i32 %h) {
+  %x = sdiv i32 %h, %a
+  %y = srem i32 %g, %b
+  %t = call i32 @ten(i32 %h, i32 %g, i32 %f, i32 %e, i32 %d, i32 %c, i32 %b, i32 %a, i32 %x, i32 %y)
+  %r = add i32 %t, %h
+  ret i32 %r
+}
+
+define i32 @main() {
+  %a = call i32 @seven(i32 1, i32 2, i32 3, i32 4, i32 5, i32 6, i32 7)
+  %b = call i32 @ten(i32 1, i32 2, i32 3, i32 4, i32 5, i32 6, i32 7, i32 8, i32 9, i32 10)
+  %c = call i32 @eight(i32 3, i32 5, i32 7, i32 11, i32 13, i32 17, i32 19, i32 23)
+  %d = call i32 @seven(i32 %a, i32 %b, i32 %c, i32 %a, i32 %b, i32 %c, i32 1)
+  %r = srem i32 %d, 256
+  %p = add i32 %r, 256
+  %q = srem i32 %p, 256
+  ret i32 %q
+}
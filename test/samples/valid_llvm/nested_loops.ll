@@ -0,0 +1,70 @@
+; Loops inside loops, a loop left from the middle of its body and one whose test comes last
+define i32 @grid(i32 %n) {
+entry:
+  br label %outer
+outer:
+  %i = phi i32 [ 0, %entry ], [ %i1, %outer_latch ]
+  %total = phi i32 [ 0, %entry ], [ %row_total, %outer_latch ]
+  br label %inner
+inner:
+  %j = phi i32 [ 0, %outer ], [ %j1, %inner ]
+  %row = phi i32 [ %total, %outer ], [ %row1, %inner ]
+  %ij = mul i32 %i, %j
+  %row1 = add i32 %row, %ij
+  %j1 = add i32 %j, 1
+  %more_j = icmp slt i32 %j1, %i
+  br i1 %more_j, label %inner, label %outer_latch
+outer_latch:
+  %row_total = phi i32 [ %row1, %inner ]
+  %i1 = add i32 %i, 1
+  %more_i = icmp slt i32 %i1, %n
+  br i1 %more_i, label %outer, label %exit
+exit:
+  ret i32 %row_total
+}
+
+define i32 @first_square_above(i32 %limit) {
+entry:
+  br label %loop
+loop:
+  %i = phi i32 [ 1, %entry ], [ %next, %continue ]
+  %square = mul i32 %i, %i
+  %found = icmp sgt i32 %square, %limit
+  br i1 %found, label %exit, label %continue
+continue:
+  %next = add i32 %i, 1
+  %give_up = icmp sgt i32 %next, 1000
+  br i1 %give_up, label %exit, label %loop
+exit:
+  %result = phi i32 [ %i, %loop ], [ -1, %continue ]
+  ret i32 %result
+}
+
+define i32 @collatz_steps(i32 %start) {
+entry:
+  br label %body
+body:
+  %x = phi i32 [ %start, %entry ], [ %x1, %body ]
+  %steps = phi i32 [ 0, %entry ], [ %steps1, %body ]
+  %parity = srem i32 %x, 2
+  %odd = icmp eq i32 %parity, 1
+  %half = sdiv i32 %x, 2
+  %triple = mul i32 %x, 3
+  %triple1 = add i32 %triple, 1
+  %x1 = select i1 %odd, i32 %triple1, i32 %half
+  %steps1 = add i32 %steps, 1
+  %more = icmp ne i32 %x1, 1
+  br i1 %more, label %body, label %exit
+exit:
+  ret i32 %steps1
+}
+
+define i32 @main() {
+  %a = call i32 @grid(i32 7)
+  %b = call i32 @first_square_above(i32 300)
+  %c = call i32 @collatz_steps(i32 27)
+  %ab = add i32 %a, %b
+  %abc = add i32 %ab, %c
+  %r = srem i32 %abc, 256
+  ret i32 %r
+}
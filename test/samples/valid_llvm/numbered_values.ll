@@ -0,0 +1,24 @@
+; Unnamed values and blocks are numbered, the unlabeled entry block included, and phis may refer to values
+; defined further down
+define i32 @triangle(i32 %0) {
+  br label %2
+
+2:
+  %3 = phi i32 [ 0, %1 ], [ %7, %6 ]
+  %4 = phi i32 [ 0, %1 ], [ %8, %6 ]
+  %5 = icmp sgt i32 %3, %0
+  br i1 %5, label %9, label %6
+
+6:
+  %7 = add i32 %3, 1
+  %8 = add i32 %4, %3
+  br label %2
+
+9:
+  ret i32 %4
+}
+
+define i32 @main() {
+  %1 = call i32 @triangle(i32 12)
+  ret i32 %1
+}
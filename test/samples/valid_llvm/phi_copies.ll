@@ -0,0 +1,66 @@
+; Phis read their incoming values all at once, so values swapped or rotated between iterations must not
+; overwrite each other, and copies on critical edges must only happen on their own edge
+define i32 @fibonacci(i32 %n) {
+entry:
+  br label %loop
+loop:
+  %a = phi i32 [ 0, %entry ], [ %b, %loop ]
+  %b = phi i32 [ 1, %entry ], [ %sum, %loop ]
+  %i = phi i32 [ 0, %entry ], [ %i1, %loop ]
+  %sum = add i32 %a, %b
+  %i1 = add i32 %i, 1
+  %more = icmp slt i32 %i1, %n
+  br i1 %more, label %loop, label %exit
+exit:
+  ret i32 %a
+}
+
+define i32 @rotate(i32 %n) {
+entry:
+  br label %loop
+loop:
+  %x = phi i32 [ 1, %entry ], [ %y, %loop ]
+  %y = phi i32 [ 2, %entry ], [ %z, %loop ]
+  %z = phi i32 [ 3, %entry ], [ %x, %loop ]
+  %i = phi i32 [ 0, %entry ], [ %i1, %loop ]
+  %i1 = add i32 %i, 1
+  %more = icmp slt i32 %i1, %n
+  br i1 %more, label %loop, label %exit
+exit:
+  %x100 = mul i32 %x, 100
+  %y10 = mul i32 %y, 10
+  %xy = add i32 %x100, %y10
+  %xyz = add i32 %xy, %z
+  ret i32 %xyz
+}
+
+define i32 @critical_edge(i32 %n) {
+entry:
+  %small = icmp slt i32 %n, 10
+  br i1 %small, label %join, label %big
+big:
+  %half = sdiv i32 %n, 2
+  %even = icmp eq i32 %n, 20
+  br i1 %even, label %join, label %other
+other:
+  br label %join
+join:
+  %v = phi i32 [ %n, %entry ], [ %half, %big ], [ 7, %other ]
+  %w = phi i32 [ 1, %entry ], [ 2, %big ], [ 3, %other ]
+  %r = mul i32 %v, %w
+  ret i32 %r
+}
+
+define i32 @main() {
+  %f = call i32 @fibonacci(i32 11)
+  %r = call i32 @rotate(i32 5)
+  %c1 = call i32 @critical_edge(i32 4)
+  %c2 = call i32 @critical_edge(i32 20)
+  %c3 = call i32 @critical_edge(i32 31)
+  %s1 = add i32 %f, %r
+  %s2 = add i32 %s1, %c1
+  %s3 = add i32 %s2, %c2
+  %s4 = add i32 %s3, %c3
+  %m = srem i32 %s4, 256
+  ret i32 %m
+}
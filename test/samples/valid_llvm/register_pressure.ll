@@ -0,0 +1,190 @@
+; More values are live at once than there are registers, across calls and divisions that need particular
+; registers, so some must be spilled and reloaded
+@seed = global i32 3
+
+define i32 @id(i32 %x) noinline {
+  ret i32 %x
+}
+
+define i32 @main() {
+entry:
+  %s = load i32, ptr @seed
+  %v0 = mul i32 %s, 2
+  %v1 = mul i32 %s, 3
+  %v2 = mul i32 %s, 4
+  %v3 = mul i32 %s, 5
+  %v4 = mul i32 %s, 6
+  %v5 = mul i32 %s, 7
+  %v6 = mul i32 %s, 8
+  %v7 = mul i32 %s, 9
+  %v8 = mul i32 %s, 10
+  %v9 = mul i32 %s, 11
+  %v10 = mul i32 %s, 12
+  %v11 = mul i32 %s, 13
+  %v12 = mul i32 %s, 14
+  %v13 = mul i32 %s, 15
+  %v14 = mul i32 %s, 16
+  %v15 = mul i32 %s, 17
+  %v16 = mul i32 %s, 18
+  %v17 = mul i32 %s, 19
+  %v18 = mul i32 %s, 20
+  %v19 = mul i32 %s, 21
+  %v20 = mul i32 %s, 22
+  %v21 = mul i32 %s, 23
+  %v22 = mul i32 %s, 24
+  %v23 = mul i32 %s, 25
+  %c0 = call i32 @id(i32 %v0)
+  %c1 = call i32 @id(i32 %v1)
+  %c2 = call i32 @id(i32 %v2)
+  %c3 = call i32 @id(i32 %v3)
+  %c4 = call i32 @id(i32 %v4)
+  %c5 = call i32 @id(i32 %v5)
+  %c6 = call i32 @id(i32 %v6)
+  %c7 = call i32 @id(i32 %v7)
+  %c8 = call i32 @id(i32 %v8)
+  %c9 = call i32 @id(i32 %v9)
+  %c10 = call i32 @id(i32 %v10)
+  %c11 = call i32 @id(i32 %v11)
+  %c12 = call i32 @id(i32 %v12)
+  %c13 = call i32 @id(i32 %v13)
+  %c14 = call i32 @id(i32 %v14)
+  %c15 = call i32 @id(i32 %v15)
+  %c16 = call i32 @id(i32 %v16)
+  %c17 = call i32 @id(i32 %v17)
+  %c18 = call i32 @id(i32 %v18)
+  %c19 = call i32 @id(i32 %v19)
+  %c20 = call i32 @id(i32 %v20)
+  %c21 = call i32 @id(i32 %v21)
+  %c22 = call i32 @id(i32 %v22)
+  %c23 = call i32 @id(i32 %v23)
+  %d0 = sdiv i32 %c0, %v1
+  %m0 = srem i32 %v0, %c5
+  %d1 = sdiv i32 %c1, %v2
+  %m1 = srem i32 %v1, %c6
+  %d2 = sdiv i32 %c2, %v3
+  %m2 = srem i32 %v2, %c7
+  %d3 = sdiv i32 %c3, %v4
+  %m3 = srem i32 %v3, %c8
+  %d4 = sdiv i32 %c4, %v5
+  %m4 = srem i32 %v4, %c9
+  %d5 = sdiv i32 %c5, %v6
+  %m5 = srem i32 %v5, %c10
+  %d6 = sdiv i32 %c6, %v7
+  %m6 = srem i32 %v6, %c11
+  %d7 = sdiv i32 %c7, %v8
+  %m7 = srem i32 %v7, %c12
+  %d8 = sdiv i32 %c8, %v9
+  %m8 = srem i32 %v8, %c13
+  %d9 = sdiv i32 %c9, %v10
+  %m9 = srem i32 %v9, %c14
+  %d10 = sdiv i32 %c10, %v11
+  %m10 = srem i32 %v10, %c15
+  %d11 = sdiv i32 %c11, %v12
+  %m11 = srem i32 %v11, %c16
+  %d12 = sdiv i32 %c12, %v13
+  %m12 = srem i32 %v12, %c17
+  %d13 = sdiv i32 %c13, %v14
+  %m13 = srem i32 %v13, %c18
+  %d14 = sdiv i32 %c14, %v15
+  %m14 = srem i32 %v14, %c19
+  %d15 = sdiv i32 %c15, %v16
+  %m15 = srem i32 %v15, %c20
+  %d16 = sdiv i32 %c16, %v17
+  %m16 = srem i32 %v16, %c21
+  %d17 = sdiv i32 %c17, %v18
+  %m17 = srem i32 %v17, %c22
+  %d18 = sdiv i32 %c18, %v19
+  %m18 = srem i32 %v18, %c23
+  %d19 = sdiv i32 %c19, %v20
+  %m19 = srem i32 %v19, %c0
+  %d20 = sdiv i32 %c20, %v21
+  %m20 = srem i32 %v20, %c1
+  %d21 = sdiv i32 %c21, %v22
+  %m21 = srem i32 %v21, %c2
+  %d22 = sdiv i32 %c22, %v23
+  %m22 = srem i32 %v22, %c3
+  %d23 = sdiv i32 %c23, %v0
+  %m23 = srem i32 %v23, %c4
+  br label %loop
+loop:
+  %k = phi i32 [ 0, %entry ], [ %k1, %loop ]
+  %acc = phi i32 [ 0, %entry ], [ %acc23, %loop ]
+  %t0 = add i32 %d0, %m0
+  %u0 = mul i32 %t0, %v3
+  %acc0 = add i32 %acc, %u0
+  %t1 = add i32 %d1, %m1
+  %u1 = mul i32 %t1, %v4
+  %acc1 = add i32 %acc0, %u1
+  %t2 = add i32 %d2, %m2
+  %u2 = mul i32 %t2, %v5
+  %acc2 = add i32 %acc1, %u2
+  %t3 = add i32 %d3, %m3
+  %u3 = mul i32 %t3, %v6
+  %acc3 = add i32 %acc2, %u3
+  %t4 = add i32 %d4, %m4
+  %u4 = mul i32 %t4, %v7
+  %acc4 = add i32 %acc3, %u4
+  %t5 = add i32 %d5, %m5
+  %u5 = mul i32 %t5, %v8
+  %acc5 = add i32 %acc4, %u5
+  %t6 = add i32 %d6, %m6
+  %u6 = mul i32 %t6, %v9
+  %acc6 = add i32 %acc5, %u6
+  %t7 = add i32 %d7, %m7
+  %u7 = mul i32 %t7, %v10
+  %acc7 = add i32 %acc6, %u7
+  %t8 = add i32 %d8, %m8
+  %u8 = mul i32 %t8, %v11
+  %acc8 = add i32 %acc7, %u8
+  %t9 = add i32 %d9, %m9
+  %u9 = mul i32 %t9, %v12
+  %acc9 = add i32 %acc8, %u9
+  %t10 = add i32 %d10, %m10
+  %u10 = mul i32 %t10, %v13
+  %acc10 = add i32 %acc9, %u10
+  %t11 = add i32 %d11, %m11
+  %u11 = mul i32 %t11, %v14
+  %acc11 = add i32 %acc10, %u11
+  %t12 = add i32 %d12, %m12
+  %u12 = mul i32 %t12, %v15
+  %acc12 = add i32 %acc11, %u12
+  %t13 = add i32 %d13, %m13
+  %u13 = mul i32 %t13, %v16
+  %acc13 = add i32 %acc12, %u13
+  %t14 = add i32 %d14, %m14
+  %u14 = mul i32 %t14, %v17
+  %acc14 = add i32 %acc13, %u14
+  %t15 = add i32 %d15, %m15
+  %u15 = mul i32 %t15, %v18
+  %acc15 = add i32 %acc14, %u15
+  %t16 = add i32 %d16, %m16
+  %u16 = mul i32 %t16, %v19
+  %acc16 = add i32 %acc15, %u16
+  %t17 = add i32 %d17, %m17
+  %u17 = mul i32 %t17, %v20
+  %acc17 = add i32 %acc16, %u17
+  %t18 = add i32 %d18, %m18
+  %u18 = mul i32 %t18, %v21
+  %acc18 = add i32 %acc17, %u18
+  %t19 = add i32 %d19, %m19
+  %u19 = mul i32 %t19, %v22
+  %acc19 = add i32 %acc18, %u19
+  %t20 = add i32 %d20, %m20
+  %u20 = mul i32 %t20, %v23
+  %acc20 = add i32 %acc19, %u20
+  %t21 = add i32 %d21, %m21
+  %u21 = mul i32 %t21, %v0
+  %acc21 = add i32 %acc20, %u21
+  %t22 = add i32 %d22, %m22
+  %u22 = mul i32 %t22, %v1
+  %acc22 = add i32 %acc21, %u22
+  %t23 = add i32 %d23, %m23
+  %u23 = mul i32 %t23, %v2
+  %acc23 = add i32 %acc22, %u23
+  %k1 = add i32 %k, 1
+  %more = icmp slt i32 %k1, 3
+  br i1 %more, label %loop, label %exit
+exit:
+  %r = srem i32 %acc23, 256
+  ret i32 %r
+}
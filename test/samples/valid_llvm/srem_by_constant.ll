@@ -0,0 +1,217 @@
+; Signed remainder by constants, which britten turns into multiplications by magic numbers and shifts. The
+; dividends are loaded from globals so that nothing is folded, and main returns the number of wrong results.
+@x0 = global i32 -2147483648
+@x1 = global i32 -1
+@x2 = global i32 1
+@x3 = global i32 100
+@x4 = global i32 -100
+@x5 = global i32 2147483647
+@x6 = global i32 -123456789
+@failures = global i32 0
+
+define void @check(i32 %got, i32 %expected) noinline {
+entry:
+  %wrong = icmp ne i32 %got, %expected
+  br i1 %wrong, label %fail, label %done
+fail:
+  %f = load i32, ptr @failures
+  %f1 = add i32 %f, 1
+  store i32 %f1, ptr @failures
+  br label %done
+done:
+  ret void
+}
+
+define i32 @main() {
+  %x0 = load i32, ptr @x0
+  %x1 = load i32, ptr @x1
+  %x2 = load i32, ptr @x2
+  %x3 = load i32, ptr @x3
+  %x4 = load i32, ptr @x4
+  %x5 = load i32, ptr @x5
+  %x6 = load i32, ptr @x6
+  %r0_0 = srem i32 %x0, 3
+  call void @check(i32 %r0_0, i32 -2)
+  %r0_1 = srem i32 %x0, 7
+  call void @check(i32 %r0_1, i32 -2)
+  %r0_2 = srem i32 %x0, -7
+  call void @check(i32 %r0_2, i32 -2)
+  %r0_3 = srem i32 %x0, 641
+  call void @check(i32 %r0_3, i32 -320)
+  %r0_4 = srem i32 %x0, 2147483647
+  call void @check(i32 %r0_4, i32 -1)
+  %r0_5 = srem i32 %x0, -2147483648
+  call void @check(i32 %r0_5, i32 0)
+  %r0_6 = srem i32 %x0, 2
+  call void @check(i32 %r0_6, i32 0)
+  %r0_7 = srem i32 %x0, 16
+  call void @check(i32 %r0_7, i32 0)
+  %r0_8 = srem i32 %x0, 1024
+  call void @check(i32 %r0_8, i32 0)
+  %r0_9 = srem i32 %x0, 1073741824
+  call void @check(i32 %r0_9, i32 0)
+  %r0_10 = srem i32 %x0, -2
+  call void @check(i32 %r0_10, i32 0)
+  %r0_11 = srem i32 %x0, -16
+  call void @check(i32 %r0_11, i32 0)
+  %r0_12 = srem i32 %x0, -1073741824
+  call void @check(i32 %r0_12, i32 0)
+  %r1_0 = srem i32 %x1, 3
+  call void @check(i32 %r1_0, i32 -1)
+  %r1_1 = srem i32 %x1, 7
+  call void @check(i32 %r1_1, i32 -1)
+  %r1_2 = srem i32 %x1, -7
+  call void @check(i32 %r1_2, i32 -1)
+  %r1_3 = srem i32 %x1, 641
+  call void @check(i32 %r1_3, i32 -1)
+  %r1_4 = srem i32 %x1, 2147483647
+  call void @check(i32 %r1_4, i32 -1)
+  %r1_5 = srem i32 %x1, -2147483648
+  call void @check(i32 %r1_5, i32 -1)
+  %r1_6 = srem i32 %x1, 2
+  call void @check(i32 %r1_6, i32 -1)
+  %r1_7 = srem i32 %x1, 16
+  call void @check(i32 %r1_7, i32 -1)
+  %r1_8 = srem i32 %x1, 1024
+  call void @check(i32 %r1_8, i32 -1)
+  %r1_9 = srem i32 %x1, 1073741824
+  call void @check(i32 %r1_9, i32 -1)
+  %r1_10 = srem i32 %x1, -2
+  call void @check(i32 %r1_10, i32 -1)
+  %r1_11 = srem i32 %x1, -16
+  call void @check(i32 %r1_11, i32 -1)
+  %r1_12 = srem i32 %x1, -1073741824
+  call void @check(i32 %r1_12, i32 -1)
+  %r2_0 = srem i32 %x2, 3
+  call void @check(i32 %r2_0, i32 1)
+  %r2_1 = srem i32 %x2, 7
+  call void @check(i32 %r2_1, i32 1)
+  %r2_2 = srem i32 %x2, -7
+  call void @check(i32 %r2_2, i32 1)
+  %r2_3 = srem i32 %x2, 641
+  call void @check(i32 %r2_3, i32 1)
+  %r2_4 = srem i32 %x2, 2147483647
+  call void @check(i32 %r2_4, i32 1)
+  %r2_5 = srem i32 %x2, -2147483648
+  call void @check(i32 %r2_5, i32 1)
+  %r2_6 = srem i32 %x2, 2
+  call void @check(i32 %r2_6, i32 1)
+  %r2_7 = srem i32 %x2, 16
+  call void @check(i32 %r2_7, i32 1)
+  %r2_8 = srem i32 %x2, 1024
+  call void @check(i32 %r2_8, i32 1)
+  %r2_9 = srem i32 %x2, 1073741824
+  call void @check(i32 %r2_9, i32 1)
+  %r2_10 = srem i32 %x2, -2
+  call void @check(i32 %r2_10, i32 1)
+  %r2_11 = srem i32 %x2, -16
+  call void @check(i32 %r2_11, i32 1)
+  %r2_12 = srem i32 %x2, -1073741824
+  call void @check(i32 %r2_12, i32 1)
+  %r3_0 = srem i32 %x3, 3
+  call void @check(i32 %r3_0, i32 1)
+  %r3_1 = srem i32 %x3, 7
+  call void @check(i32 %r3_1, i32 2)
+  %r3_2 = srem i32 %x3, -7
+  call void @check(i32 %r3_2, i32 2)
+  %r3_3 = srem i32 %x3, 641
+  call void @check(i32 %r3_3, i32 100)
+  %r3_4 = srem i32 %x3, 2147483647
+  call void @check(i32 %r3_4, i32 100)
+  %r3_5 = srem i32 %x3, -2147483648
+  call void @check(i32 %r3_5, i32 100)
+  %r3_6 = srem i32 %x3, 2
+  call void @check(i32 %r3_6, i32 0)
+  %r3_7 = srem i32 %x3, 16
+  call void @check(i32 %r3_7, i32 4)
+  %r3_8 = srem i32 %x3, 1024
+  call void @check(i32 %r3_8, i32 100)
+  %r3_9 = srem i32 %x3, 1073741824
+  call void @check(i32 %r3_9, i32 100)
+  %r3_10 = srem i32 %x3, -2
+  call void @check(i32 %r3_10, i32 0)
+  %r3_11 = srem i32 %x3, -16
+  call void @check(i32 %r3_11, i32 4)
+  %r3_12 = srem i32 %x3, -1073741824
+  call void @check(i32 %r3_12, i32 100)
+  %r4_0 = srem i32 %x4, 3
+  call void @check(i32 %r4_0, i32 -1)
+  %r4_1 = srem i32 %x4, 7
+  call void @check(i32 %r4_1, i32 -2)
+  %r4_2 = srem i32 %x4, -7
+  call void @check(i32 %r4_2, i32 -2)
+  %r4_3 = srem i32 %x4, 641
+  call void @check(i32 %r4_3, i32 -100)
+  %r4_4 = srem i32 %x4, 2147483647
+  call void @check(i32 %r4_4, i32 -100)
+  %r4_5 = srem i32 %x4, -2147483648
+  call void @check(i32 %r4_5, i32 -100)
+  %r4_6 = srem i32 %x4, 2
+  call void @check(i32 %r4_6, i32 0)
+  %r4_7 = srem i32 %x4, 16
+  call void @check(i32 %r4_7, i32 -4)
+  %r4_8 = srem i32 %x4, 1024
+  call void @check(i32 %r4_8, i32 -100)
+  %r4_9 = srem i32 %x4, 1073741824
+  call void @check(i32 %r4_9, i32 -100)
+  %r4_10 = srem i32 %x4, -2
+  call void @check(i32 %r4_10, i32 0)
+  %r4_11 = srem i32 %x4, -16
+  call void @check(i32 %r4_11, i32 -4)
+  %r4_12 = srem i32 %x4, -1073741824
+  call void @check(i32 %r4_12, i32 -100)
+  %r5_0 = srem i32 %x5, 3
+  call void @check(i32 %r5_0, i32 1)
+  %r5_1 = srem i32 %x5, 7
+  call void @check(i32 %r5_1, i32 1)
+  %r5_2 = srem i32 %x5, -7
+  call void @check(i32 %r5_2, i32 1)
+  %r5_3 = srem i32 %x5, 641
+  call void @check(i32 %r5_3, i32 319)
+  %r5_4 = srem i32 %x5, 2147483647
+  call void @check(i32 %r5_4, i32 0)
+  %r5_5 = srem i32 %x5, -2147483648
+  call void @check(i32 %r5_5, i32 2147483647)
+  %r5_6 = srem i32 %x5, 2
+  call void @check(i32 %r5_6, i32 1)
+  %r5_7 = srem i32 %x5, 16
+  call void @check(i32 %r5_7, i32 15)
+  %r5_8 = srem i32 %x5, 1024
+  call void @check(i32 %r5_8, i32 1023)
+  %r5_9 = srem i32 %x5, 1073741824
+  call void @check(i32 %r5_9, i32 1073741823)
+  %r5_10 = srem i32 %x5, -2
+  call void @check(i32 %r5_10, i32 1)
+  %r5_11 = srem i32 %x5, -16
+  call void @check(i32 %r5_11, i32 15)
+  %r5_12 = srem i32 %x5, -1073741824
+  call void @check(i32 %r5_12, i32 1073741823)
+  %r6_0 = srem i32 %x6, 3
+  call void @check(i32 %r6_0, i32 0)
+  %r6_1 = srem i32 %x6, 7
+  call void @check(i32 %r6_1, i32 -1)
+  %r6_2 = srem i32 %x6, -7
+  call void @check(i32 %r6_2, i32 -1)
+  %r6_3 = srem i32 %x6, 641
+  call void @check(i32 %r6_3, i32 -189)
+  %r6_4 = srem i32 %x6, 2147483647
+  call void @check(i32 %r6_4, i32 -123456789)
+  %r6_5 = srem i32 %x6, -2147483648
+  call void @check(i32 %r6_5, i32 -123456789)
+  %r6_6 = srem i32 %x6, 2
+  call void @check(i32 %r6_6, i32 -1)
+  %r6_7 = srem i32 %x6, 16
+  call void @check(i32 %r6_7, i32 -5)
+  %r6_8 = srem i32 %x6, 1024
+  call void @check(i32 %r6_8, i32 -277)
+  %r6_9 = srem i32 %x6, 1073741824
+  call void @check(i32 %r6_9, i32 -123456789)
+  %r6_10 = srem i32 %x6, -2
+  call void @check(i32 %r6_10, i32 -1)
+  %r6_11 = srem i32 %x6, -16
+  call void @check(i32 %r6_11, i32 -5)
+  %r6_12 = srem i32 %x6, -1073741824
+  call void @check(i32 %r6_12, i32 -123456789)
+  %failures = load i32, ptr @failures
+  ret i32 %failures
+}
@@ -0,0 +1,52 @@
+; Multiplications of loop counters by constants and by loop invariant values become counters of their own,
+; for counters going up and down by any step, and products that overflow
+@factor = global i32 9
+
+define i32 @up(i32 %n) {
+entry:
+  %k = load i32, ptr @factor
+  br label %loop
+loop:
+  %i = phi i32 [ 3, %entry ], [ %next, %loop ]
+  %acc = phi i32 [ 0, %entry ], [ %acc3, %loop ]
+  %a = mul i32 %i, 7
+  %b = mul i32 %k, %i
+  %c = mul i32 %i, 1000000007
+  %acc1 = add i32 %acc, %a
+  %acc2 = add i32 %acc1, %b
+  %acc3 = sub i32 %acc2, %c
+  %next = add i32 %i, 3
+  %more = icmp slt i32 %next, %n
+  br i1 %more, label %loop, label %exit
+exit:
+  %last = mul i32 %next, 5
+  %r = add i32 %acc3, %last
+  ret i32 %r
+}
+
+define i32 @down(i32 %n) {
+entry:
+  br label %loop
+loop:
+  %i = phi i32 [ %n, %entry ], [ %next, %body ]
+  %acc = phi i32 [ 0, %entry ], [ %acc1, %body ]
+  %done = icmp sle i32 %i, 0
+  br i1 %done, label %exit, label %body
+body:
+  %a = mul i32 %i, -6
+  %acc1 = add i32 %acc, %a
+  %next = add i32 %i, -2
+  br label %loop
+exit:
+  ret i32 %acc
+}
+
+define i32 @main() {
+  %u = call i32 @up(i32 40)
+  %d = call i32 @down(i32 15)
+  %s = add i32 %u, %d
+  %r = srem i32 %s, 256
+  %p = add i32 %r, 256
+  %q = srem i32 %p, 256
+  ret i32 %q
+}
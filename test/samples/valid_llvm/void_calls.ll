@@ -0,0 +1,27 @@
+; Calls with attributes, tail calls and void functions returning early
+@total = global i32 1
+
+define void @add_if_odd(i32 %x) #0 {
+entry:
+  %r = srem i32 %x, 2
+  %even = icmp eq i32 %r, 0
+  br i1 %even, label %skip, label %add
+skip:
+  ret void
+add:
+  %t = load i32, ptr @total
+  %u = add nsw i32 %t, %x
+  store i32 %u, ptr @total
+  ret void
+}
+
+define i32 @main() {
+  call void @add_if_odd(i32 3) #1
+  tail call void @add_if_odd(i32 4)
+  call void @add_if_odd(i32 noundef 11)
+  %t = load i32, ptr @total, align 4
+  ret i32 %t
+}
+
+attributes #0 = { nounwind }
+attributes #1 = { nounwind }
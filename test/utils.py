@@ -42,7 +42,9 @@ def cleanup_samples_directory() -> int:
     files_removed = 0
     for root, dirs, files in os.walk(samples_dir):
         for file in files:
-            if not file.endswith('.c') and file != 'LICENSE':
+            # Textual IR is a source in the folders of IR samples and compiler output everywhere else
+            is_ir_source = file.endswith('.ll') and os.path.basename(root).endswith('_llvm')
+            if not file.endswith('.c') and not is_ir_source and file != 'LICENSE':
                 file_path = os.path.join(root, file)
                 try:
                     os.remove(file_path)
@@ -51,9 +53,9 @@ def cleanup_samples_directory() -> int:
                     print(f"Error removing file {file_path}: {e}")
     return files_removed
 
-def get_executable_return_code(executable: str) -> Optional[int]:
+def get_executable_return_code(executable: str, runner: List[str] = []) -> Optional[int]:
     try:
-        result = subprocess.run([executable], capture_output=True, text=False)
+        result = subprocess.run([*runner, executable], capture_output=True, text=False)
         return result.returncode
     except Exception as e:
         print(f"Error executing {executable}: {e}")